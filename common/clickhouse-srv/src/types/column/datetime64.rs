@@ -21,6 +21,9 @@ use crate::errors::Result;
 use crate::types::column::column_data::BoxColumnData;
 use crate::types::column::column_data::ColumnData;
 use crate::types::column::list::List;
+use crate::types::column::nullable::NullableColumnData;
+use crate::types::column::ArcColumnWrapper;
+use crate::types::column::ColumnWrapper;
 use crate::types::DateTimeType;
 use crate::types::SqlType;
 use crate::types::Value;
@@ -55,6 +58,32 @@ impl DateTime64ColumnData {
             params: (precision, timezone),
         }
     }
+
+    /// Create the column from the ticks of 10^-precision seconds since UNIX epoch.
+    pub fn from_ticks<W: ColumnWrapper>(ticks: Vec<i64>, precision: u32, tz: Tz) -> W::Wrapper {
+        let mut data = List::with_capacity(ticks.len());
+        for value in ticks {
+            data.push(value);
+        }
+        W::wrap(DateTime64ColumnData {
+            data,
+            params: (precision, tz),
+        })
+    }
+
+    /// Create the nullable column from the ticks, `None` is null.
+    pub fn from_opt_ticks<W: ColumnWrapper>(
+        ticks: Vec<Option<i64>>,
+        precision: u32,
+        tz: Tz,
+    ) -> W::Wrapper {
+        let nulls = ticks.iter().map(|v| v.is_none() as u8).collect();
+        let values = ticks.into_iter().map(|v| v.unwrap_or(0)).collect();
+        W::wrap(NullableColumnData {
+            inner: Self::from_ticks::<ArcColumnWrapper>(values, precision, tz),
+            nulls,
+        })
+    }
 }
 
 impl ColumnData for DateTime64ColumnData {
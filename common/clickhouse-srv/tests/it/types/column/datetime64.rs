@@ -15,6 +15,10 @@
 use chrono::prelude::*;
 use chrono_tz::Tz;
 use common_clickhouse_srv::types::column::datetime64::*;
+use common_clickhouse_srv::types::column::ArcColumnWrapper;
+use common_clickhouse_srv::types::column::ColumnData;
+use common_clickhouse_srv::types::DateTimeType;
+use common_clickhouse_srv::types::SqlType;
 
 #[test]
 fn test_to_datetime() {
@@ -29,3 +33,28 @@ fn test_from_datetime() {
     let actual = from_datetime(origin, 3);
     assert_eq!(actual, 1_546_300_800_000)
 }
+
+#[test]
+fn test_from_ticks() {
+    let column = DateTime64ColumnData::from_ticks::<ArcColumnWrapper>(
+        vec![1_546_300_800_000, 1_546_300_800_123],
+        3,
+        Tz::UTC,
+    );
+    assert_eq!(
+        column.sql_type(),
+        SqlType::DateTime(DateTimeType::DateTime64(3, Tz::UTC))
+    );
+    assert_eq!(column.len(), 2);
+
+    let column = DateTime64ColumnData::from_opt_ticks::<ArcColumnWrapper>(
+        vec![Some(1_546_300_800_000), None],
+        3,
+        Tz::UTC,
+    );
+    assert_eq!(
+        column.sql_type(),
+        SqlType::Nullable(&SqlType::DateTime(DateTimeType::DateTime64(3, Tz::UTC)))
+    );
+    assert_eq!(column.len(), 2);
+}
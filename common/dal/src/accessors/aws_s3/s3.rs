@@ -15,6 +15,8 @@
 
 use std::str::FromStr;
 
+use chrono::DateTime;
+use chrono::Utc;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use futures::Stream;
@@ -24,12 +26,15 @@ use rusoto_core::ByteStream;
 use rusoto_core::Client;
 use rusoto_core::HttpClient;
 use rusoto_core::Region;
+use rusoto_s3::DeleteObjectRequest;
+use rusoto_s3::ListObjectsV2Request;
 use rusoto_s3::PutObjectRequest;
 use rusoto_s3::S3Client;
 use rusoto_s3::S3 as RusotoS3;
 
 use crate::DataAccessor;
 use crate::InputStream;
+use crate::ObjectMeta;
 use crate::S3InputStream;
 
 pub struct S3 {
@@ -174,4 +179,58 @@ impl DataAccessor for S3 {
         self.put_byte_stream(path, ByteStream::new_with_size(input_stream, stream_len))
             .await
     }
+
+    async fn list(&self, prefix: &str) -> common_exception::Result<Vec<ObjectMeta>> {
+        let mut objects = vec![];
+        let mut continuation_token = None;
+        loop {
+            let req = ListObjectsV2Request {
+                bucket: self.bucket.to_string(),
+                prefix: Some(prefix.to_string()),
+                continuation_token,
+                ..Default::default()
+            };
+            let output = self
+                .client
+                .list_objects_v2(req)
+                .await
+                .map_err(|e| ErrorCode::DALTransportError(e.to_string()))?;
+            for object in output.contents.unwrap_or_default() {
+                let (location, last_modified) = match (object.key, object.last_modified) {
+                    (Some(location), Some(last_modified)) => (location, last_modified),
+                    _ => continue,
+                };
+                let last_modified = DateTime::parse_from_rfc3339(&last_modified).map_err(|e| {
+                    ErrorCode::DALTransportError(format!(
+                        "invalid last modified time {} of object {}, {}",
+                        last_modified, location, e
+                    ))
+                })?;
+                objects.push(ObjectMeta {
+                    location,
+                    last_modified: last_modified.with_timezone(&Utc),
+                });
+            }
+
+            // the objects are returned in pages, up to 1000 objects for each
+            match (output.is_truncated, output.next_continuation_token) {
+                (Some(true), Some(token)) => continuation_token = Some(token),
+                _ => break,
+            }
+        }
+        Ok(objects)
+    }
+
+    async fn remove(&self, path: &str) -> common_exception::Result<()> {
+        let req = DeleteObjectRequest {
+            bucket: self.bucket.to_string(),
+            key: path.to_string(),
+            ..Default::default()
+        };
+        self.client
+            .delete_object(req)
+            .await
+            .map_err(|e| ErrorCode::DALTransportError(e.to_string()))?;
+        Ok(())
+    }
 }
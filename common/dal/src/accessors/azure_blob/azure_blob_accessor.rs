@@ -27,6 +27,7 @@ use crate::AzureBlobInputStream;
 use crate::Bytes;
 use crate::DataAccessor;
 use crate::InputStream;
+use crate::ObjectMeta;
 
 pub struct AzureBlobAccessor {
     client: Arc<StorageClient>,
@@ -160,4 +161,44 @@ impl DataAccessor for AzureBlobAccessor {
         }
         self.put_blob(path, data).await
     }
+
+    async fn list(&self, prefix: &str) -> common_exception::Result<Vec<ObjectMeta>> {
+        let container = self.client.as_container_client(&self.container);
+        let mut objects = vec![];
+        let mut next_marker = None;
+        loop {
+            let mut request = container.list_blobs().prefix(prefix);
+            if let Some(marker) = next_marker {
+                request = request.next_marker(marker);
+            }
+            let response = request.execute().await.map_err(|e| {
+                ErrorCode::DALTransportError(format!("Failed on azure blob list operation, {}", e))
+            })?;
+            objects.extend(response.blobs.blobs.into_iter().map(|blob| ObjectMeta {
+                location: blob.name,
+                last_modified: blob.properties.last_modified,
+            }));
+
+            match response.next_marker {
+                Some(marker) => next_marker = Some(marker),
+                None => break,
+            }
+        }
+        Ok(objects)
+    }
+
+    async fn remove(&self, path: &str) -> common_exception::Result<()> {
+        let blob = self
+            .client
+            .as_container_client(&self.container)
+            .as_blob_client(path);
+
+        match blob.delete().execute().await {
+            Err(e) => Err(ErrorCode::DALTransportError(format!(
+                "Failed on azure blob delete operation, {}",
+                e
+            ))),
+            Ok(_) => Ok(()),
+        }
+    }
 }
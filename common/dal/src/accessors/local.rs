@@ -20,6 +20,8 @@ use std::path::Path;
 use std::path::PathBuf;
 
 use async_compat::CompatExt;
+use chrono::DateTime;
+use chrono::Utc;
 use common_base::tokio;
 use common_exception::ErrorCode;
 use common_exception::Result;
@@ -29,6 +31,7 @@ use tokio::io::AsyncWriteExt;
 
 use crate::DataAccessor;
 use crate::InputStream;
+use crate::ObjectMeta;
 
 pub struct Local {
     root: PathBuf,
@@ -59,6 +62,21 @@ impl Local {
             )))
         }
     }
+
+    // The location of a file under the root, which is separated by `/`
+    fn location_of(&self, path: &Path) -> Result<String> {
+        let relative = path.strip_prefix(&self.root).map_err(|e| {
+            ErrorCode::UnknownException(format!(
+                "path {:?} is not under root path {:?}, {}",
+                path, self.root, e
+            ))
+        })?;
+        let components = relative
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy().to_string())
+            .collect::<Vec<_>>();
+        Ok(components.join("/"))
+    }
 }
 
 #[async_trait::async_trait]
@@ -108,6 +126,48 @@ impl DataAccessor for Local {
         new_file.flush().await?;
         Ok(())
     }
+
+    async fn list(&self, prefix: &str) -> common_exception::Result<Vec<ObjectMeta>> {
+        // walks the directory which the prefix lies in, e.g. `a/b` for prefix `a/b/c`
+        let dir = match prefix.rfind('/') {
+            Some(pos) => &prefix[..pos],
+            None => "",
+        };
+        let mut dirs = vec![self.prefix_with_root(dir)?];
+        let mut objects = vec![];
+        while let Some(dir) = dirs.pop() {
+            let mut entries = match tokio::fs::read_dir(&dir).await {
+                Ok(entries) => entries,
+                Err(e) if e.kind() == ErrorKind::NotFound => continue,
+                Err(e) => return Err(e.into()),
+            };
+            while let Some(entry) = entries.next_entry().await? {
+                let path = entry.path();
+                let metadata = entry.metadata().await?;
+                if metadata.is_dir() {
+                    dirs.push(path);
+                    continue;
+                }
+                let location = self.location_of(&path)?;
+                if location.starts_with(prefix) {
+                    objects.push(ObjectMeta {
+                        location,
+                        last_modified: DateTime::<Utc>::from(metadata.modified()?),
+                    });
+                }
+            }
+        }
+        objects.sort_by(|a, b| a.location.cmp(&b.location));
+        Ok(objects)
+    }
+
+    async fn remove(&self, path: &str) -> common_exception::Result<()> {
+        let path = self.prefix_with_root(path)?;
+        match tokio::fs::remove_file(path).await {
+            Err(e) if e.kind() != ErrorKind::NotFound => Err(e.into()),
+            _ => Ok(()),
+        }
+    }
 }
 
 // from cargo::util::path
@@ -14,13 +14,17 @@
 
 use std::io::Read;
 use std::io::Seek;
+use std::io::SeekFrom;
 use std::sync::Arc;
 
+use chrono::DateTime;
+use chrono::Utc;
 use common_exception::Result;
 use futures::stream::Stream;
 use futures::AsyncRead;
 use futures::AsyncReadExt;
 use futures::AsyncSeek;
+use futures::AsyncSeekExt;
 use serde::de::DeserializeOwned;
 
 pub type Bytes = Vec<u8>;
@@ -35,6 +39,13 @@ pub trait SeekableReader: Read + Seek {}
 
 impl<T> SeekableReader for T where T: Read + Seek {}
 
+/// An object listed by `DataAccessor::list`
+#[derive(Clone, Debug, PartialEq)]
+pub struct ObjectMeta {
+    pub location: String,
+    pub last_modified: DateTime<Utc>,
+}
+
 #[async_trait::async_trait]
 pub trait DataAccessor: Send + Sync {
     fn get_input_stream(&self, path: &str, stream_len: Option<u64>) -> Result<InputStream>;
@@ -53,12 +64,25 @@ pub trait DataAccessor: Send + Sync {
         stream_len: usize,
     ) -> Result<()>;
 
+    /// Lists the objects whose location starts with the `prefix`
+    async fn list(&self, prefix: &str) -> Result<Vec<ObjectMeta>>;
+
+    /// Removes the object of the `path`
+    async fn remove(&self, path: &str) -> Result<()>;
+
     async fn read(&self, location: &str) -> Result<Vec<u8>> {
         let mut input_stream = self.get_input_stream(location, None)?;
         let mut buffer = vec![];
         input_stream.read_to_end(&mut buffer).await?;
         Ok(buffer)
     }
+
+    /// Returns the size in bytes of the object at `location`
+    async fn object_size(&self, location: &str) -> Result<u64> {
+        let mut input_stream = self.get_input_stream(location, None)?;
+        let size = input_stream.seek(SeekFrom::End(0)).await?;
+        Ok(size)
+    }
 }
 
 pub async fn read_obj<T: DeserializeOwned>(da: Arc<dyn DataAccessor>, loc: String) -> Result<T> {
@@ -31,6 +31,7 @@ pub use data_accessor::AsyncSeekableReader;
 pub use data_accessor::Bytes;
 pub use data_accessor::DataAccessor;
 pub use data_accessor::InputStream;
+pub use data_accessor::ObjectMeta;
 pub use data_accessor::SeekableReader;
 pub use in_memory_data::InMemoryData;
 pub use schemes::StorageScheme;
@@ -13,9 +13,12 @@
 //  limitations under the License.
 //
 
+use chrono::Duration;
+use chrono::Utc;
 use common_base::tokio;
 use common_dal::DataAccessor;
 use common_dal::Local;
+use common_exception::ErrorCode;
 use tempfile::TempDir;
 
 async fn local_read(loops: u32) -> common_exception::Result<()> {
@@ -50,3 +53,57 @@ async fn test_da_local_normal() -> common_exception::Result<()> {
     let read_fut = local_read(1000);
     read_fut.await
 }
+
+#[tokio::test]
+async fn test_da_local_list_remove() -> common_exception::Result<()> {
+    let tmp_root_dir = TempDir::new().unwrap();
+    let root_path = tmp_root_dir.path().to_str().unwrap();
+    let local_da = Local::new(root_path);
+
+    for file in ["_b/1", "_b/2", "_sg/1", "_ss/1", "_ss/sub/2"] {
+        local_da.put(file, vec![1, 2, 3]).await?;
+    }
+
+    let da = &local_da;
+    let list = |prefix: &'static str| async move {
+        let objects = da.list(prefix).await?;
+        Ok::<_, ErrorCode>(
+            objects
+                .into_iter()
+                .map(|object| object.location)
+                .collect::<Vec<_>>(),
+        )
+    };
+    assert_eq!(list("_b/").await?, vec!["_b/1", "_b/2"]);
+    assert_eq!(list("_ss/").await?, vec!["_ss/1", "_ss/sub/2"]);
+    assert_eq!(list("_s").await?, vec!["_sg/1", "_ss/1", "_ss/sub/2"]);
+    assert_eq!(list("_b/1").await?, vec!["_b/1"]);
+    assert!(list("not_exist/").await?.is_empty());
+
+    // the objects are just written
+    let now = Utc::now();
+    for object in local_da.list("").await? {
+        assert!(object.last_modified <= now);
+        assert!(object.last_modified > now - Duration::hours(1));
+    }
+
+    local_da.remove("_b/1").await?;
+    assert_eq!(list("_b/").await?, vec!["_b/2"]);
+    assert!(local_da.read("_b/1").await.is_err());
+
+    // removing an absent object is ok
+    local_da.remove("_b/1").await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_da_local_object_size() -> common_exception::Result<()> {
+    let tmp_root_dir = TempDir::new().unwrap();
+    let root_path = tmp_root_dir.path().to_str().unwrap();
+    let local_da = Local::new(root_path);
+
+    local_da.put("_b/1", vec![1, 2, 3]).await?;
+    assert_eq!(local_da.object_size("_b/1").await?, 3);
+    assert!(local_da.object_size("_b/2").await.is_err());
+    Ok(())
+}
@@ -114,6 +114,20 @@ pub trait ArrayCompare<Rhs>: Debug {
             self,
         )))
     }
+
+    fn regexp(&self, _rhs: Rhs) -> Result<DFBooleanArray> {
+        Err(ErrorCode::BadDataValueType(format!(
+            "Unsupported compare operation: regexp for {:?}",
+            self,
+        )))
+    }
+
+    fn nregexp(&self, _rhs: Rhs) -> Result<DFBooleanArray> {
+        Err(ErrorCode::BadDataValueType(format!(
+            "Unsupported compare operation: nregexp for {:?}",
+            self,
+        )))
+    }
 }
 
 impl<T> DFPrimitiveArray<T>
@@ -282,6 +296,22 @@ impl DFStringArray {
     fn nlike_scalar(&self, rhs: &[u8]) -> Result<DFBooleanArray> {
         self.a_like_binary_scalar(rhs, |x| !x)
     }
+
+    fn regexp(&self, rhs: &DFStringArray) -> Result<DFBooleanArray> {
+        self.a_regexp_binary(rhs, |x| x)
+    }
+
+    fn regexp_scalar(&self, rhs: &[u8]) -> Result<DFBooleanArray> {
+        self.a_regexp_binary_scalar(rhs, |x| x)
+    }
+
+    fn nregexp(&self, rhs: &DFStringArray) -> Result<DFBooleanArray> {
+        self.a_regexp_binary(rhs, |x| !x)
+    }
+
+    fn nregexp_scalar(&self, rhs: &[u8]) -> Result<DFBooleanArray> {
+        self.a_regexp_binary_scalar(rhs, |x| !x)
+    }
 }
 
 macro_rules! impl_like_string {
@@ -339,6 +369,14 @@ impl ArrayCompare<&DFStringArray> for DFStringArray {
     fn nlike(&self, rhs: &DFStringArray) -> Result<DFBooleanArray> {
         impl_like_string! {self, rhs, nlike, nlike_scalar}
     }
+
+    fn regexp(&self, rhs: &DFStringArray) -> Result<DFBooleanArray> {
+        impl_like_string! {self, rhs, regexp, regexp_scalar}
+    }
+
+    fn nregexp(&self, rhs: &DFStringArray) -> Result<DFBooleanArray> {
+        impl_like_string! {self, rhs, nregexp, nregexp_scalar}
+    }
 }
 
 impl ArrayCompare<&DFNullArray> for DFNullArray {}
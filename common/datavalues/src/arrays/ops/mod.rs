@@ -22,6 +22,7 @@ mod fill;
 mod group_hash;
 mod r#if;
 mod like;
+mod regexp;
 mod scatter;
 mod take;
 mod take_random;
@@ -39,6 +40,7 @@ pub use fill::*;
 pub use group_hash::GroupHash;
 pub use like::*;
 pub use r#if::*;
+pub use regexp::*;
 pub use scatter::*;
 pub use take::*;
 pub use take_random::*;
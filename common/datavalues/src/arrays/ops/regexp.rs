@@ -0,0 +1,110 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use common_arrow::arrow::bitmap::Bitmap;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use regex::bytes::Regex as BytesRegex;
+use regex::bytes::RegexBuilder as BytesRegexBuilder;
+
+use crate::prelude::*;
+
+impl DFStringArray {
+    /// Match the strings with the regular expression patterns row by row,
+    /// the same pattern is compiled only once.
+    pub fn a_regexp_binary<F>(&self, rhs: &Self, op: F) -> Result<DFBooleanArray>
+    where F: Fn(bool) -> bool {
+        if self.len() != rhs.len() {
+            return Err(ErrorCode::BadArguments(
+                "Cannot perform comparison operation on arrays of different length".to_string(),
+            ));
+        }
+
+        let validity = combine_validities(self.array.validity(), rhs.array.validity());
+
+        let mut map = HashMap::new();
+
+        let values = Bitmap::try_from_trusted_len_iter(
+            self.into_no_null_iter()
+                .zip(rhs.into_no_null_iter())
+                .map::<Result<bool>, _>(|(lhs, rhs)| {
+                    let pattern = if let Some(pattern) = map.get(rhs) {
+                        pattern
+                    } else {
+                        let re = build_regexp(rhs, b"")?;
+                        map.insert(rhs, re);
+                        map.get(rhs).unwrap()
+                    };
+                    Ok(op(pattern.is_match(lhs)))
+                }),
+        )?;
+
+        Ok(DFBooleanArray::from_arrow_data(values, validity))
+    }
+
+    /// Match the strings with a constant regular expression pattern.
+    pub fn a_regexp_binary_scalar<F>(&self, rhs: &[u8], op: F) -> Result<DFBooleanArray>
+    where F: Fn(bool) -> bool {
+        let arr = self.inner();
+        let validity = arr.validity();
+
+        let re = build_regexp(rhs, b"")?;
+        let values = Bitmap::from_trusted_len_iter(arr.values_iter().map(|x| op(re.is_match(x))));
+        Ok(DFBooleanArray::from_arrow_data(values, validity.cloned()))
+    }
+}
+
+/// Build the regular expression from the pattern with the MySQL style match type:
+///
+/// 'c': case sensitive matching (default).
+/// 'i': case insensitive matching.
+/// 'm': multiple-line mode, `^` and `$` match at line terminators.
+/// 'n': the `.` character matches line terminators.
+/// 'u': unix-only line endings, accepted for compatibility.
+///
+/// If conflicting options are given, the rightmost one takes precedence.
+pub fn build_regexp(pattern: &[u8], match_type: &[u8]) -> Result<BytesRegex> {
+    let pattern = simdutf8::basic::from_utf8(pattern).map_err(|e| {
+        ErrorCode::BadArguments(format!(
+            "Unable to convert the regular expression pattern to string: {}",
+            e
+        ))
+    })?;
+
+    let mut builder = BytesRegexBuilder::new(pattern);
+    for &c in match_type {
+        match c {
+            b'c' => builder.case_insensitive(false),
+            b'i' => builder.case_insensitive(true),
+            b'm' => builder.multi_line(true),
+            b'n' => builder.dot_matches_new_line(true),
+            b'u' => &mut builder,
+            _ => {
+                return Err(ErrorCode::BadArguments(format!(
+                    "Incorrect arguments to regular expression match type: {}",
+                    String::from_utf8_lossy(match_type)
+                )))
+            }
+        };
+    }
+
+    builder.build().map_err(|e| {
+        ErrorCode::BadArguments(format!(
+            "Unable to build regex from the pattern {}: {}",
+            pattern, e
+        ))
+    })
+}
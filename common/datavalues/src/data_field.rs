@@ -17,6 +17,7 @@ use std::collections::BTreeMap;
 use common_arrow::arrow::datatypes::Field as ArrowField;
 use common_macros::MallocSizeOf;
 
+use crate::map_entries_types;
 use crate::DataType;
 
 #[derive(
@@ -78,11 +79,20 @@ impl DataField {
             DataType::Date16 => Some("Date16"),
             DataType::Date32 => Some("Date32"),
             DataType::DateTime32(_) => Some("DateTime32"),
+            DataType::DateTime64(_, _) => Some("DateTime64"),
+            DataType::Decimal(_, _) => Some("Decimal"),
+            DataType::Variant => Some("Variant"),
+            DataType::Map(_, _) => Some("Map"),
             _ => None,
         };
 
         let custom_metadata = match self.data_type() {
             DataType::DateTime32(tz) => tz.clone(),
+            DataType::DateTime64(precision, tz) => match tz {
+                Some(tz) => Some(format!("{},{}", precision, tz)),
+                None => Some(precision.to_string()),
+            },
+            DataType::Decimal(precision, scale) => Some(format!("{},{}", precision, scale)),
             _ => None,
         };
 
@@ -113,7 +123,32 @@ impl From<&ArrowField> for DataField {
                 match custom_name.as_str() {
                     "Date16" => dt = DataType::Date16,
                     "Date32" => dt = DataType::Date32,
+                    "Variant" => dt = DataType::Variant,
+                    "Map" => {
+                        if let Some((key, value)) = map_entries_types(&dt) {
+                            dt = DataType::Map(Box::new(key), Box::new(value));
+                        }
+                    }
                     "DateTime32" => dt = DataType::DateTime32(metatada.cloned()),
+                    "DateTime64" => {
+                        // The metadata is `precision[,timezone]`.
+                        let (precision, tz) = match metatada.and_then(|m| m.split_once(',')) {
+                            Some((precision, tz)) => (Some(precision), Some(tz.to_string())),
+                            None => (metatada.map(|m| m.as_str()), None),
+                        };
+                        if let Some(Ok(precision)) = precision.map(|p| p.parse()) {
+                            dt = DataType::DateTime64(precision, tz);
+                        }
+                    }
+                    "Decimal" => {
+                        // The metadata is `precision,scale`, keep the physical type if it's broken.
+                        let args = metatada.and_then(|m| m.split_once(','));
+                        if let Some((precision, scale)) = args {
+                            if let (Ok(precision), Ok(scale)) = (precision.parse(), scale.parse()) {
+                                dt = DataType::Decimal(precision, scale);
+                            }
+                        }
+                    }
                     _ => {}
                 }
             }
@@ -218,10 +218,17 @@ impl DataValue {
                     }
                     Ok(builder.finish().into_series())
                 }
-                other => Result::Err(ErrorCode::BadDataValueType(format!(
-                    "Unexpected type:{} for DataValue List",
-                    other
-                ))),
+                // The list of the `(key, value)` structs is a map.
+                DataType::Struct(_) => match map_entries_types(&self.data_type()) {
+                    Some((key_type, value_type)) => {
+                        build_map_array(&self.to_values(size)?, &key_type, &value_type)
+                    }
+                    None => Result::Err(ErrorCode::BadDataValueType(format!(
+                        "Unexpected type:{} for DataValue List",
+                        data_type
+                    ))),
+                },
+                other => build_list_array(&self.to_values(size)?, other),
             },
             DataValue::Struct(v) => {
                 let mut arrays = vec![];
@@ -410,10 +417,15 @@ impl From<&DataType> for DataValue {
             DataType::Date16 => DataValue::UInt16(None),
             DataType::Date32 => DataValue::Int32(None),
             DataType::DateTime32(_) => DataValue::UInt32(None),
+            DataType::DateTime64(_, _) => DataValue::Int64(None),
             DataType::List(f) => DataValue::List(None, f.data_type().clone()),
             DataType::Struct(_) => DataValue::Struct(vec![]),
-            DataType::String => DataValue::String(None),
+            DataType::Map(key, value) => {
+                DataValue::List(None, map_entries_field(key, value).data_type().clone())
+            }
+            DataType::String | DataType::Variant => DataValue::String(None),
             DataType::Interval(_) => DataValue::Int64(None),
+            DataType::Decimal(_, _) => DataValue::Int64(None),
         }
     }
 }
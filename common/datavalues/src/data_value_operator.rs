@@ -44,6 +44,8 @@ pub enum DataValueComparisonOperator {
     NotEq,
     Like,
     NotLike,
+    Regexp,
+    NotRegexp,
 }
 
 impl std::fmt::Display for DataValueComparisonOperator {
@@ -57,6 +59,8 @@ impl std::fmt::Display for DataValueComparisonOperator {
             DataValueComparisonOperator::NotEq => "!=",
             DataValueComparisonOperator::Like => "LIKE",
             DataValueComparisonOperator::NotLike => "NOT LIKE",
+            DataValueComparisonOperator::Regexp => "REGEXP",
+            DataValueComparisonOperator::NotRegexp => "NOT REGEXP",
         };
         write!(f, "{}", display)
     }
@@ -84,7 +84,7 @@ impl DataValue {
                 try_build_array! {PrimitiveArrayBuilder, f64, Float64, values}
             }
             DataType::Boolean => try_build_array! {values},
-            DataType::String => try_build_array! {String, values},
+            DataType::String | DataType::Variant => try_build_array! {String, values},
             DataType::Date16 => {
                 try_build_array! {PrimitiveArrayBuilder, u16, UInt16, values}
             }
@@ -94,6 +94,11 @@ impl DataValue {
             DataType::DateTime32(_) => {
                 try_build_array! {PrimitiveArrayBuilder, u32, UInt32, values}
             }
+            DataType::Decimal(_, _) | DataType::DateTime64(_, _) => {
+                try_build_array! {PrimitiveArrayBuilder, i64, Int64, values}
+            }
+            DataType::List(field) => build_list_array(values, field.data_type()),
+            DataType::Map(key, value) => build_map_array(values, key, value),
             other => Result::Err(ErrorCode::BadDataValueType(format!(
                 "Unexpected type:{} for DataValue List",
                 other
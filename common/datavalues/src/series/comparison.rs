@@ -90,4 +90,14 @@ impl ArrayCompare<&Series> for Series {
     fn nlike(&self, rhs: &Series) -> Result<DFBooleanArray> {
         impl_compare!(self.as_ref(), rhs.as_ref(), nlike)
     }
+
+    /// Create a boolean mask by checking if lhs matches the regular expression rhs.
+    fn regexp(&self, rhs: &Series) -> Result<DFBooleanArray> {
+        impl_compare!(self.as_ref(), rhs.as_ref(), regexp)
+    }
+
+    /// Create a boolean mask by checking if lhs does not match the regular expression rhs.
+    fn nregexp(&self, rhs: &Series) -> Result<DFBooleanArray> {
+        impl_compare!(self.as_ref(), rhs.as_ref(), nregexp)
+    }
 }
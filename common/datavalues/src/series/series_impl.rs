@@ -183,6 +183,14 @@ pub trait SeriesTrait: Send + Sync + fmt::Debug {
         )))
     }
 
+    /// Unpack to DFArray of data_type list
+    fn list(&self) -> Result<&DFListArray> {
+        Err(ErrorCode::IllegalDataType(format!(
+            "{:?} != list",
+            self.data_type()
+        )))
+    }
+
     /// Take by index from an iterator. This operation clones the data.
     ///
     /// # Safety
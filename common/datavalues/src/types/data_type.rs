@@ -19,6 +19,7 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_macros::MallocSizeOf;
 
+use crate::map_entries_field;
 use crate::DataField;
 use crate::PhysicalDataType;
 
@@ -50,11 +51,29 @@ pub enum DataType {
     /// Option<String> indicates the timezone, if it's None, it's UTC
     DateTime32(Option<String>),
 
+    /// A 64-bit datetime representing the elapsed time since UNIX epoch (1970-01-01)
+    /// in ticks of 10^-precision seconds, it's physical type is Int64
+    /// The precision is between 0 and 9, Option<String> indicates the timezone like DateTime32
+    DateTime64(u32, Option<String>),
+
     Interval(IntervalUnit),
 
+    /// A fixed-point number with the precision and the scale, the value is stored as
+    /// an Int64 scaled by 10^scale, so the precision can't exceed 18
+    Decimal(u8, u8),
+
     List(Box<DataField>),
     Struct(Vec<DataField>),
+
+    /// A map from the keys to the values, it's physical type is List of the `(key, value)` structs,
+    /// which is the same layout as the arrow's map
+    Map(Box<DataType>, Box<DataType>),
+
     String,
+
+    /// A semi-structured value holding any JSON document, it's physical type is String
+    /// The value is validated and stored as the compact JSON text
+    Variant,
 }
 
 #[derive(
@@ -129,7 +148,10 @@ impl DataType {
     pub fn is_date_or_date_time(&self) -> bool {
         matches!(
             self,
-            DataType::Date16 | DataType::Date32 | DataType::DateTime32(_)
+            DataType::Date16
+                | DataType::Date32
+                | DataType::DateTime32(_)
+                | DataType::DateTime64(_, _)
         )
     }
 
@@ -162,6 +184,21 @@ impl DataType {
         matches!(self, DataType::Interval(_))
     }
 
+    #[inline]
+    pub fn is_decimal(&self) -> bool {
+        matches!(self, DataType::Decimal(_, _))
+    }
+
+    #[inline]
+    pub fn is_variant(&self) -> bool {
+        matches!(self, DataType::Variant)
+    }
+
+    #[inline]
+    pub fn is_map(&self) -> bool {
+        matches!(self, DataType::Map(_, _))
+    }
+
     #[inline]
     pub fn numeric_byte_size(&self) -> Result<usize> {
         match self {
@@ -195,13 +232,19 @@ impl DataType {
             Date32 => ArrowDataType::Int32,
             // we don't use DataType::Extension because extension types are not supported in parquet
             DateTime32(_) => ArrowDataType::UInt32,
+            DateTime64(_, _) => ArrowDataType::Int64,
             List(dt) => ArrowDataType::LargeList(Box::new(dt.to_arrow())),
             Struct(fs) => {
                 let arrows_fields = fs.iter().map(|f| f.to_arrow()).collect();
                 ArrowDataType::Struct(arrows_fields)
             }
-            String => ArrowDataType::LargeBinary,
+            Map(key, value) => {
+                let entries = map_entries_field(key, value);
+                ArrowDataType::LargeList(Box::new(entries.to_arrow()))
+            }
+            String | Variant => ArrowDataType::LargeBinary,
             Interval(_) => ArrowDataType::Int64,
+            Decimal(_, _) => ArrowDataType::Int64,
         }
     }
 }
@@ -291,10 +334,20 @@ impl fmt::Debug for DataType {
                     write!(f, "DateTime32")
                 }
             }
+            Self::DateTime64(precision, tz) => {
+                if let Some(tz) = tz {
+                    write!(f, "DateTime64({}, {:?})", precision, tz)
+                } else {
+                    write!(f, "DateTime64({})", precision)
+                }
+            }
             Self::List(arg0) => f.debug_tuple("List").field(arg0).finish(),
             Self::Struct(arg0) => f.debug_tuple("Struct").field(arg0).finish(),
+            Self::Map(key, value) => write!(f, "Map({:?}, {:?})", key, value),
             Self::String => write!(f, "String"),
+            Self::Variant => write!(f, "Variant"),
             Self::Interval(unit) => write!(f, "Interval({})", unit),
+            Self::Decimal(precision, scale) => write!(f, "Decimal({}, {})", precision, scale),
         }
     }
 }
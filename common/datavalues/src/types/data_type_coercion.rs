@@ -20,6 +20,7 @@ use common_exception::Result;
 use crate::prelude::DataType;
 use crate::DataField;
 use crate::DataValueArithmeticOperator;
+use crate::MAX_DECIMAL_PRECISION;
 
 fn next_size(size: usize) -> usize {
     if size < 8_usize {
@@ -200,6 +201,96 @@ pub fn numerical_arithmetic_coercion(
     }
 }
 
+/// The precision and the scale of a decimal, integers are treated as decimals without fraction.
+fn decimal_precision_scale(data_type: &DataType) -> Option<(u8, u8)> {
+    match data_type {
+        DataType::Decimal(precision, scale) => Some((*precision, *scale)),
+        DataType::Int8 | DataType::UInt8 => Some((3, 0)),
+        DataType::Int16 | DataType::UInt16 => Some((5, 0)),
+        DataType::Int32 | DataType::UInt32 => Some((10, 0)),
+        DataType::Int64 | DataType::UInt64 => Some((MAX_DECIMAL_PRECISION, 0)),
+        _ => None,
+    }
+}
+
+/// Coercion rule when one of the types is decimal: decimals and integers are merged into a
+/// decimal keeping both the integer digits and the fraction digits, when these don't fit in
+/// the max precision (e.g. Int64 against a decimal with fraction), or when the other side is
+/// a float or a string, the merged type is Float64.
+fn decimal_coercion(lhs_type: &DataType, rhs_type: &DataType) -> Option<DataType> {
+    let is_number_like = |t: &DataType| t.is_numeric() || t.is_decimal() || t == &DataType::String;
+
+    match (
+        decimal_precision_scale(lhs_type),
+        decimal_precision_scale(rhs_type),
+    ) {
+        (Some((lhs_precision, lhs_scale)), Some((rhs_precision, rhs_scale))) => {
+            let scale = cmp::max(lhs_scale, rhs_scale);
+            let integer_digits = cmp::max(lhs_precision - lhs_scale, rhs_precision - rhs_scale);
+            match integer_digits + scale {
+                precision if precision <= MAX_DECIMAL_PRECISION => {
+                    Some(DataType::Decimal(precision, scale))
+                }
+                _ => Some(DataType::Float64),
+            }
+        }
+        _ if is_number_like(lhs_type) && is_number_like(rhs_type) => Some(DataType::Float64),
+        _ => None,
+    }
+}
+
+#[inline]
+pub fn decimal_arithmetic_coercion(
+    op: &DataValueArithmeticOperator,
+    lhs_type: &DataType,
+    rhs_type: &DataType,
+) -> Result<DataType> {
+    if lhs_type.is_floating() || rhs_type.is_floating() {
+        return Ok(DataType::Float64);
+    }
+
+    let (lhs_precision, lhs_scale, rhs_precision, rhs_scale) = match (
+        decimal_precision_scale(lhs_type),
+        decimal_precision_scale(rhs_type),
+    ) {
+        (Some((p1, s1)), Some((p2, s2))) => (p1, s1, p2, s2),
+        _ => {
+            return Result::Err(ErrorCode::BadDataValueType(format!(
+                "DataValue Error: Unsupported decimal coercion ({:?}) {} ({:?})",
+                lhs_type, op, rhs_type
+            )));
+        }
+    };
+
+    // Same as ClickHouse, except that the precision is limited to 18.
+    match op {
+        DataValueArithmeticOperator::Plus | DataValueArithmeticOperator::Minus => {
+            let scale = cmp::max(lhs_scale, rhs_scale);
+            let integer_digits = cmp::max(lhs_precision - lhs_scale, rhs_precision - rhs_scale);
+            let precision = cmp::min(MAX_DECIMAL_PRECISION, integer_digits + scale + 1);
+            Ok(DataType::Decimal(precision, scale))
+        }
+        DataValueArithmeticOperator::Mul => {
+            let scale = lhs_scale + rhs_scale;
+            if scale > MAX_DECIMAL_PRECISION {
+                return Result::Err(ErrorCode::BadDataValueType(format!(
+                    "DataValue Error: The scale of ({:?}) {} ({:?}) exceeds {}",
+                    lhs_type, op, rhs_type, MAX_DECIMAL_PRECISION
+                )));
+            }
+            let precision = cmp::min(MAX_DECIMAL_PRECISION, lhs_precision + rhs_precision);
+            Ok(DataType::Decimal(cmp::max(precision, scale), scale))
+        }
+        DataValueArithmeticOperator::Div => Ok(DataType::Decimal(MAX_DECIMAL_PRECISION, lhs_scale)),
+        DataValueArithmeticOperator::Modulo => {
+            let scale = cmp::max(lhs_scale, rhs_scale);
+            let precision = cmp::max(lhs_precision, rhs_precision);
+            Ok(DataType::Decimal(cmp::max(precision, scale), scale))
+        }
+        DataValueArithmeticOperator::IntDiv => Ok(DataType::Int64),
+    }
+}
+
 #[inline]
 pub fn datetime_arithmetic_coercion(
     op: &DataValueArithmeticOperator,
@@ -228,6 +319,9 @@ pub fn datetime_arithmetic_coercion(
         DataValueArithmeticOperator::Minus => {
             if b.is_numeric() || b.is_interval() {
                 Ok(a)
+            } else if matches!(a, DataType::DateTime64(_, _)) {
+                // DateTime64 minus DateTime64, the ticks don't fit in Int32
+                Ok(DataType::Int64)
             } else {
                 // Date minus Date or DateTime minus DateTime
                 Ok(DataType::Int32)
@@ -272,6 +366,18 @@ pub fn numerical_unary_arithmetic_coercion(
     op: &DataValueArithmeticOperator,
     val_type: &DataType,
 ) -> Result<DataType> {
+    if val_type.is_decimal() {
+        return match op {
+            DataValueArithmeticOperator::Plus | DataValueArithmeticOperator::Minus => {
+                Ok(val_type.clone())
+            }
+            other => Result::Err(ErrorCode::UnknownFunction(format!(
+                "Unexpected operator:{:?} to unary function",
+                other
+            ))),
+        };
+    }
+
     // error on any non-numeric type
     if !val_type.is_numeric() {
         return Result::Err(ErrorCode::BadDataValueType(format!(
@@ -300,6 +406,39 @@ pub fn numerical_unary_arithmetic_coercion(
     }
 }
 
+// DateTime64 mixed with other dates or datetimes keeps the larger precision, so that
+// no sub-second digits are lost.
+fn datetime64_coercion(lhs_type: &DataType, rhs_type: &DataType) -> Option<DataType> {
+    let precision = |data_type: &DataType| match data_type {
+        DataType::DateTime64(precision, _) => Some(*precision),
+        _ if data_type.is_date_or_date_time() => Some(0),
+        _ => None,
+    };
+
+    if !matches!(lhs_type, DataType::DateTime64(_, _))
+        && !matches!(rhs_type, DataType::DateTime64(_, _))
+    {
+        return None;
+    }
+
+    let precision = cmp::max(precision(lhs_type)?, precision(rhs_type)?);
+    Some(DataType::DateTime64(precision, None))
+}
+
+// Variant compared with a scalar is casted to the type of the scalar, like `v['name'] = 'abc'`.
+fn variant_coercion(lhs_type: &DataType, rhs_type: &DataType) -> Option<DataType> {
+    let other = match (lhs_type, rhs_type) {
+        (DataType::Variant, other) | (other, DataType::Variant) => other,
+        _ => return None,
+    };
+
+    match other {
+        DataType::String | DataType::Boolean => Some(other.clone()),
+        _ if other.is_numeric() => Some(DataType::Float64),
+        _ => None,
+    }
+}
+
 // coercion rules for compare operations. This is a superset of all numerical coercion rules.
 pub fn compare_coercion(lhs_type: &DataType, rhs_type: &DataType) -> Result<DataType> {
     if lhs_type == rhs_type {
@@ -321,6 +460,20 @@ pub fn compare_coercion(lhs_type: &DataType, rhs_type: &DataType) -> Result<Data
         }
     }
 
+    // one of is variant and other is number, boolean or string
+    if lhs_type.is_variant() || rhs_type.is_variant() {
+        if let Some(data_type) = variant_coercion(lhs_type, rhs_type) {
+            return Ok(data_type);
+        }
+    }
+
+    // one of is decimal and other is number, decimal or string
+    if lhs_type.is_decimal() || rhs_type.is_decimal() {
+        if let Some(data_type) = decimal_coercion(lhs_type, rhs_type) {
+            return Ok(data_type);
+        }
+    }
+
     // one of is String and other is number
     if (lhs_type.is_numeric() && rhs_type == &DataType::String)
         || (rhs_type.is_numeric() && lhs_type == &DataType::String)
@@ -345,6 +498,10 @@ pub fn compare_coercion(lhs_type: &DataType, rhs_type: &DataType) -> Result<Data
 
     // one of is datetime and other is number or string
     if lhs_type.is_date_or_date_time() || rhs_type.is_date_or_date_time() {
+        if let Some(data_type) = datetime64_coercion(lhs_type, rhs_type) {
+            return Ok(data_type);
+        }
+
         // one of is datetime
         if matches!(lhs_type, DataType::DateTime32(_))
             || matches!(rhs_type, DataType::DateTime32(_))
@@ -426,6 +583,13 @@ pub fn merge_types(lhs_type: &DataType, rhs_type: &DataType) -> Result<DataType>
             }
             if lhs_type.is_numeric() && rhs_type.is_numeric() {
                 numerical_coercion(lhs_type, rhs_type, false)
+            } else if let (true, Some(data_type)) = (
+                lhs_type.is_decimal() || rhs_type.is_decimal(),
+                decimal_coercion(lhs_type, rhs_type),
+            ) {
+                Ok(data_type)
+            } else if let Some(data_type) = datetime64_coercion(lhs_type, rhs_type) {
+                Ok(data_type)
             } else {
                 Result::Err(ErrorCode::BadDataValueType(format!(
                     "Can't merge types from {} and {}",
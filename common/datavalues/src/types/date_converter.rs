@@ -17,11 +17,16 @@ use chrono::DateTime;
 use chrono::Duration;
 use chrono::TimeZone;
 use chrono_tz::Tz;
+use common_exception::ErrorCode;
+use common_exception::Result;
 use num::cast::AsPrimitive;
 
+use crate::DataType;
+
 pub trait DateConverter {
     fn to_date(&self, tz: &Tz) -> Date<Tz>;
     fn to_date_time(&self, tz: &Tz) -> DateTime<Tz>;
+    fn to_date_time64(&self, precision: u32, tz: &Tz) -> DateTime<Tz>;
 }
 
 impl<T> DateConverter for T
@@ -36,4 +41,74 @@ where T: AsPrimitive<i64>
     fn to_date_time(&self, tz: &Tz) -> DateTime<Tz> {
         tz.timestamp_millis(self.as_() * 1000)
     }
+
+    fn to_date_time64(&self, precision: u32, tz: &Tz) -> DateTime<Tz> {
+        let ticks_per_second = datetime64_ticks_per_second(precision);
+        let seconds = self.as_().div_euclid(ticks_per_second);
+        let ticks = self.as_().rem_euclid(ticks_per_second);
+        let nanos = ticks * datetime64_ticks_per_second(MAX_DATETIME64_PRECISION - precision);
+        tz.timestamp(seconds, nanos as u32)
+    }
+}
+
+/// DateTime64 keeps at most nanoseconds.
+pub const MAX_DATETIME64_PRECISION: u32 = 9;
+/// The precision of `DateTime64` without arguments, i.e. milliseconds.
+pub const DEFAULT_DATETIME64_PRECISION: u32 = 3;
+
+pub fn create_datetime64_type(precision: u32, tz: Option<String>) -> Result<DataType> {
+    if precision > MAX_DATETIME64_PRECISION {
+        return Err(ErrorCode::BadArguments(format!(
+            "DateTime64 precision must be between 0 and {}, but got {}",
+            MAX_DATETIME64_PRECISION, precision
+        )));
+    }
+    if let Some(tz) = &tz {
+        tz.parse::<Tz>().map_err(|_| {
+            ErrorCode::BadArguments(format!("Unknown timezone '{}' of DateTime64", tz))
+        })?;
+    }
+    Ok(DataType::DateTime64(precision, tz))
+}
+
+#[inline]
+pub fn datetime64_ticks_per_second(precision: u32) -> i64 {
+    10_i64.pow(precision)
+}
+
+/// The ticks of a datetime with the precision, the extra sub-second digits are truncated.
+#[inline]
+pub fn datetime64_from_date_time<T: TimeZone>(date_time: &DateTime<T>, precision: u32) -> i64 {
+    let sub_second = date_time.timestamp_subsec_nanos() as i64
+        / datetime64_ticks_per_second(MAX_DATETIME64_PRECISION - precision);
+    date_time.timestamp() * datetime64_ticks_per_second(precision) + sub_second
+}
+
+/// Change the precision of the ticks, the extra sub-second digits are truncated towards
+/// the past, so that the result is still in the same second.
+#[inline]
+pub fn datetime64_rescale(ticks: i64, from_precision: u32, to_precision: u32) -> i64 {
+    if from_precision <= to_precision {
+        ticks * datetime64_ticks_per_second(to_precision - from_precision)
+    } else {
+        ticks.div_euclid(datetime64_ticks_per_second(from_precision - to_precision))
+    }
+}
+
+/// Format the ticks like `2021-08-30 10:47:42.123`, with exactly `precision` fractional digits.
+pub fn datetime64_to_string(ticks: i64, precision: u32, tz: &Tz) -> String {
+    let seconds = ticks
+        .to_date_time64(precision, tz)
+        .format("%Y-%m-%d %H:%M:%S");
+    if precision == 0 {
+        return seconds.to_string();
+    }
+
+    let fraction = ticks.rem_euclid(datetime64_ticks_per_second(precision));
+    format!(
+        "{}.{:0>width$}",
+        seconds,
+        fraction,
+        width = precision as usize
+    )
 }
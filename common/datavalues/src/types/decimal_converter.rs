@@ -0,0 +1,197 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::DataType;
+
+/// Decimals are stored as Int64, so at most 18 digits are guaranteed to fit in.
+pub const MAX_DECIMAL_PRECISION: u8 = 18;
+/// The precision of `DECIMAL` without arguments, same as MySQL.
+pub const DEFAULT_DECIMAL_PRECISION: u8 = 10;
+
+static POWERS_OF_TEN: [i64; 19] = [
+    1,
+    10,
+    100,
+    1_000,
+    10_000,
+    100_000,
+    1_000_000,
+    10_000_000,
+    100_000_000,
+    1_000_000_000,
+    10_000_000_000,
+    100_000_000_000,
+    1_000_000_000_000,
+    10_000_000_000_000,
+    100_000_000_000_000,
+    1_000_000_000_000_000,
+    10_000_000_000_000_000,
+    100_000_000_000_000_000,
+    1_000_000_000_000_000_000,
+];
+
+pub fn create_decimal_type(precision: u8, scale: u8) -> Result<DataType> {
+    if precision == 0 || precision > MAX_DECIMAL_PRECISION {
+        return Err(ErrorCode::BadArguments(format!(
+            "Decimal precision must be between 1 and {}, but got {}",
+            MAX_DECIMAL_PRECISION, precision
+        )));
+    }
+
+    if scale > precision {
+        return Err(ErrorCode::BadArguments(format!(
+            "Decimal scale {} can't be greater than the precision {}",
+            scale, precision
+        )));
+    }
+
+    Ok(DataType::Decimal(precision, scale))
+}
+
+/// The multiplier between the stored integer and the decimal value, i.e. 10^scale.
+#[inline]
+pub fn decimal_factor(scale: u8) -> i64 {
+    POWERS_OF_TEN[scale as usize]
+}
+
+/// Check the scaled value fits in the precision.
+#[inline]
+pub fn check_decimal(value: i128, precision: u8) -> Result<i64> {
+    let max = POWERS_OF_TEN[precision as usize] as i128;
+    match value > -max && value < max {
+        true => Ok(value as i64),
+        false => Err(ErrorCode::Overflow(format!(
+            "Decimal overflow, the value doesn't fit in the precision {}",
+            precision
+        ))),
+    }
+}
+
+/// Change the scale of a decimal value, the dropped digits are rounded half away from zero.
+pub fn decimal_rescale(value: i64, from_scale: u8, to_scale: u8, to_precision: u8) -> Result<i64> {
+    let value = value as i128;
+    let value = match from_scale <= to_scale {
+        true => value * decimal_factor(to_scale - from_scale) as i128,
+        false => div_round(value, decimal_factor(from_scale - to_scale) as i128),
+    };
+    check_decimal(value, to_precision)
+}
+
+/// Division of integers, the result is rounded half away from zero.
+#[inline]
+pub fn div_round(lhs: i128, rhs: i128) -> i128 {
+    let quotient = lhs / rhs;
+    let remainder = lhs % rhs;
+    match remainder.abs() * 2 >= rhs.abs() {
+        true if (lhs < 0) == (rhs < 0) => quotient + 1,
+        true => quotient - 1,
+        false => quotient,
+    }
+}
+
+pub fn decimal_to_f64(value: i64, scale: u8) -> f64 {
+    value as f64 / decimal_factor(scale) as f64
+}
+
+pub fn decimal_from_f64(value: f64, precision: u8, scale: u8) -> Result<i64> {
+    let scaled = (value * decimal_factor(scale) as f64).round();
+    if !scaled.is_finite() || scaled.abs() >= i64::MAX as f64 {
+        return Err(ErrorCode::Overflow(format!(
+            "Decimal overflow, {} doesn't fit in Decimal({}, {})",
+            value, precision, scale
+        )));
+    }
+    check_decimal(scaled as i128, precision)
+}
+
+/// Format the value with exactly `scale` fractional digits, like `-1.50`.
+pub fn decimal_to_string(value: i64, scale: u8) -> String {
+    let digits = (value as i128).abs().to_string();
+    let sign = if value < 0 { "-" } else { "" };
+    if scale == 0 {
+        return format!("{}{}", sign, digits);
+    }
+
+    let scale = scale as usize;
+    let digits = format!("{:0>width$}", digits, width = scale + 1);
+    let (integer, fraction) = digits.split_at(digits.len() - scale);
+    format!("{}{}.{}", sign, integer, fraction)
+}
+
+/// Parse a decimal literal like `-12.345` or `1e3`, extra fractional digits are rounded.
+pub fn decimal_from_str(value: &str, precision: u8, scale: u8) -> Result<i64> {
+    let error = || {
+        ErrorCode::BadBytes(format!(
+            "Cannot parse value '{}' to Decimal({}, {})",
+            value, precision, scale
+        ))
+    };
+
+    let trimmed = value.trim();
+    if trimmed.contains(|c| c == 'e' || c == 'E') {
+        let value = trimmed.parse::<f64>().map_err(|_| error())?;
+        return decimal_from_f64(value, precision, scale);
+    }
+
+    let (negative, unsigned) = match trimmed.as_bytes().first() {
+        Some(b'-') => (true, &trimmed[1..]),
+        Some(b'+') => (false, &trimmed[1..]),
+        _ => (false, trimmed),
+    };
+
+    let (integer, fraction) = match unsigned.split_once('.') {
+        Some((integer, fraction)) => (integer, fraction),
+        None => (unsigned, ""),
+    };
+
+    let is_digits = |s: &str| s.bytes().all(|c| c.is_ascii_digit());
+    if (integer.is_empty() && fraction.is_empty()) || !is_digits(integer) || !is_digits(fraction) {
+        return Err(error());
+    }
+
+    let mut result: i128 = 0;
+    for c in integer.bytes() {
+        result = result * 10 + (c - b'0') as i128;
+        // Fail early, so that the long integers can't overflow the i128.
+        if result >= POWERS_OF_TEN[MAX_DECIMAL_PRECISION as usize] as i128 {
+            return Err(ErrorCode::Overflow(format!(
+                "Decimal overflow, '{}' doesn't fit in Decimal({}, {})",
+                value, precision, scale
+            )));
+        }
+    }
+
+    let mut fraction_digits = fraction.bytes();
+    for _ in 0..scale {
+        let digit = fraction_digits.next().map(|c| c - b'0').unwrap_or(0);
+        result = result * 10 + digit as i128;
+    }
+
+    if matches!(fraction_digits.next(), Some(c) if c >= b'5') {
+        result += 1;
+    }
+
+    if negative {
+        result = -result;
+    }
+    check_decimal(result, precision).map_err(|_| {
+        ErrorCode::Overflow(format!(
+            "Decimal overflow, '{}' doesn't fit in Decimal({}, {})",
+            value, precision, scale
+        ))
+    })
+}
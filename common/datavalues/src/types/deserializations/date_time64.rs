@@ -0,0 +1,79 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::TimeZone;
+use chrono_tz::Tz;
+use common_exception::*;
+use common_io::prelude::*;
+
+use crate::prelude::*;
+
+pub struct DateTime64Deserializer {
+    pub builder: PrimitiveArrayBuilder<i64>,
+    pub precision: u32,
+    pub tz: Tz,
+}
+
+impl TypeDeserializer for DateTime64Deserializer {
+    fn de(&mut self, reader: &mut &[u8]) -> Result<()> {
+        let value: i64 = reader.read_scalar()?;
+        self.builder.append_value(value);
+        Ok(())
+    }
+
+    fn de_batch(&mut self, reader: &[u8], step: usize, rows: usize) -> Result<()> {
+        for row in 0..rows {
+            let mut reader = &reader[step * row..];
+            let value: i64 = reader.read_scalar()?;
+            self.builder.append_value(value);
+        }
+        Ok(())
+    }
+
+    // Accept the ticks like `1630320462123` or the text like `2021-08-30 10:47:42.123`.
+    fn de_text(&mut self, reader: &[u8]) -> Result<()> {
+        if reader.eq_ignore_ascii_case(b"null") {
+            self.builder.append_null();
+            return Ok(());
+        }
+
+        match lexical_core::parse::<i64>(reader) {
+            Ok(v) => {
+                self.builder.append_value(v);
+                Ok(())
+            }
+            Err(_) => {
+                let v = std::str::from_utf8(reader)
+                    .map_err_to_code(ErrorCode::BadBytes, || "Cannot convert value to utf8")?;
+                let res = self
+                    .tz
+                    .datetime_from_str(v, "%Y-%m-%d %H:%M:%S%.f")
+                    .map_err_to_code(ErrorCode::BadBytes, || {
+                        "Cannot parse value to DateTime64 type"
+                    })?;
+                self.builder
+                    .append_value(datetime64_from_date_time(&res, self.precision));
+                Ok(())
+            }
+        }
+    }
+
+    fn de_null(&mut self) {
+        self.builder.append_null()
+    }
+
+    fn finish_to_series(&mut self) -> Series {
+        self.builder.finish().into_series()
+    }
+}
@@ -21,14 +21,20 @@ use crate::prelude::*;
 mod boolean;
 mod date;
 mod date_time;
+mod date_time64;
+mod decimal;
 mod number;
 mod string;
+mod variant;
 
 pub use boolean::*;
 pub use date::*;
 pub use date_time::*;
+pub use date_time64::*;
+pub use decimal::*;
 pub use number::*;
 pub use string::*;
+pub use variant::*;
 
 pub trait TypeDeserializer: Send + Sync {
     fn de(&mut self, reader: &mut &[u8]) -> Result<()>;
@@ -66,9 +72,25 @@ impl DataType {
                         tz: tz.parse::<Tz>().unwrap(),
                     }))
                 }
+                DataType::DateTime64(precision, tz) => {
+                    let tz = tz.unwrap_or_else(|| "UTC".to_string());
+                    Ok(Box::new(DateTime64Deserializer {
+                        builder: PrimitiveArrayBuilder::<i64>::with_capacity(capacity),
+                        precision,
+                        tz: tz.parse::<Tz>().unwrap(),
+                    }))
+                }
+                DataType::Decimal(precision, scale) => Ok(Box::new(DecimalDeserializer {
+                    builder: PrimitiveArrayBuilder::<i64>::with_capacity(capacity),
+                    precision,
+                    scale,
+                })),
                 DataType::String => Ok(Box::new(StringDeserializer {
                     builder: StringArrayBuilder::with_capacity(capacity),
                 })),
+                DataType::Variant => Ok(Box::new(VariantDeserializer {
+                    builder: StringArrayBuilder::with_capacity(capacity),
+                })),
                 DataType::Interval(_) => Ok(Box::new(DateDeserializer::<i64> {
                     builder: PrimitiveArrayBuilder::<i64>::with_capacity(capacity),
                 })),
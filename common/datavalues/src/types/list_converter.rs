@@ -0,0 +1,92 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_arrow::arrow::bitmap::Bitmap;
+use common_arrow::arrow::buffer::Buffer;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::prelude::*;
+
+/// The type of the list with the nullable items.
+pub fn create_list_type(item_type: DataType) -> DataType {
+    DataType::List(Box::new(DataField::new("item", item_type, true)))
+}
+
+/// Build the list array from the values, each of them is a list of the items.
+pub fn build_list_array(values: &[DataValue], item_type: &DataType) -> Result<Series> {
+    let mut offsets = Vec::with_capacity(values.len() + 1);
+    let mut items = vec![];
+    offsets.push(0i64);
+
+    for value in values {
+        match value {
+            DataValue::List(Some(list), _) => {
+                items.extend(list.iter().map(|item| match item {
+                    DataValue::Null => DataValue::from(item_type),
+                    item => item.clone(),
+                }));
+            }
+            DataValue::List(None, _) | DataValue::Null => {}
+            other => {
+                return Err(ErrorCode::BadDataValueType(format!(
+                    "Unexpected type:{:?} for DataValue List",
+                    other.data_type()
+                )));
+            }
+        }
+        offsets.push(items.len() as i64);
+    }
+
+    let items = DataValue::try_into_data_array(&items, item_type)?;
+    let validity = Bitmap::from_trusted_len_iter(values.iter().map(|v| !v.is_null()));
+    Ok(build_list_array_from_offsets(
+        offsets,
+        &items,
+        item_type,
+        Some(validity),
+    ))
+}
+
+/// Build the list array with the items sliced by the offsets.
+pub fn build_list_array_from_offsets(
+    offsets: Vec<i64>,
+    items: &Series,
+    item_type: &DataType,
+    validity: Option<Bitmap>,
+) -> Series {
+    let array = LargeListArray::from_data(
+        create_list_type(item_type.clone()).to_arrow(),
+        Buffer::from_slice(&offsets),
+        items.get_array_ref(),
+        validity,
+    );
+    DFListArray::new(array).into_series()
+}
+
+/// Replace the items of the list array, the offsets and the validity are kept.
+pub fn list_array_with_items(array: &DFListArray, items: &Series, item_type: &DataType) -> Series {
+    let array = LargeListArray::from_data(
+        create_list_type(item_type.clone()).to_arrow(),
+        array.inner().offsets().clone(),
+        items.get_array_ref(),
+        array.inner().validity().cloned(),
+    );
+    DFListArray::new(array).into_series()
+}
+
+/// Get the flattened items of the list array, they are sliced by the list offsets.
+pub fn list_array_items(array: &DFListArray) -> Series {
+    array.inner().values().clone().into_series()
+}
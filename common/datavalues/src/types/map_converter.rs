@@ -0,0 +1,174 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_arrow::arrow::array::StructArray;
+use common_arrow::arrow::bitmap::Bitmap;
+use common_arrow::arrow::buffer::Buffer;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::prelude::*;
+
+/// The field of the map entries, a map is physically a list of the `(key, value)` structs.
+pub fn map_entries_field(key_type: &DataType, value_type: &DataType) -> DataField {
+    let fields = vec![
+        DataField::new("key", key_type.clone(), false),
+        DataField::new("value", value_type.clone(), true),
+    ];
+    DataField::new("entries", DataType::Struct(fields), false)
+}
+
+pub fn create_map_type(key_type: DataType, value_type: DataType) -> Result<DataType> {
+    let valid_key = key_type.is_integer()
+        || matches!(
+            key_type,
+            DataType::String | DataType::Date16 | DataType::Date32 | DataType::DateTime32(_)
+        );
+    if !valid_key {
+        return Err(ErrorCode::IllegalDataType(format!(
+            "Map key type must be integer, string or date, but got {:?}",
+            key_type
+        )));
+    }
+
+    if value_type == DataType::Null {
+        return Err(ErrorCode::IllegalDataType(
+            "Map value type can't be Null".to_string(),
+        ));
+    }
+    Ok(DataType::Map(Box::new(key_type), Box::new(value_type)))
+}
+
+/// Get the key type and the value type from the physical type of the map.
+pub fn map_entries_types(data_type: &DataType) -> Option<(DataType, DataType)> {
+    if let DataType::List(entries) = data_type {
+        if let DataType::Struct(fields) = entries.data_type() {
+            if fields.len() == 2 && fields[0].name() == "key" && fields[1].name() == "value" {
+                let key_type = fields[0].data_type().clone();
+                let value_type = fields[1].data_type().clone();
+                return Some((key_type, value_type));
+            }
+        }
+    }
+    None
+}
+
+/// Get the flattened keys and values of the map array, they are sliced by the list offsets.
+pub fn map_array_entries(array: &DFListArray) -> Result<(Series, Series)> {
+    let entries = array.inner().values();
+    match entries.as_any().downcast_ref::<StructArray>() {
+        Some(entries) if entries.values().len() == 2 => {
+            let keys = entries.values()[0].clone().into_series();
+            let values = entries.values()[1].clone().into_series();
+            Ok((keys, values))
+        }
+        _ => Err(ErrorCode::BadDataValueType(format!(
+            "Expected the entries of map, but got {:?}",
+            entries.data_type()
+        ))),
+    }
+}
+
+/// Build the map array from the values, each of them is a list of the `(key, value)` structs.
+pub fn build_map_array(
+    values: &[DataValue],
+    key_type: &DataType,
+    value_type: &DataType,
+) -> Result<Series> {
+    let mut offsets = Vec::with_capacity(values.len() + 1);
+    let mut keys = vec![];
+    let mut items = vec![];
+    offsets.push(0i64);
+
+    for value in values {
+        match value {
+            DataValue::List(Some(entries), _) => {
+                for entry in entries {
+                    match entry {
+                        DataValue::Struct(kv) if kv.len() == 2 => {
+                            keys.push(kv[0].clone());
+                            items.push(match &kv[1] {
+                                DataValue::Null => DataValue::from(value_type),
+                                item => item.clone(),
+                            });
+                        }
+                        other => {
+                            return Err(ErrorCode::BadDataValueType(format!(
+                                "Unexpected map entry: {:?}",
+                                other
+                            )));
+                        }
+                    }
+                }
+            }
+            DataValue::List(None, _) | DataValue::Null => {}
+            other => {
+                return Err(ErrorCode::BadDataValueType(format!(
+                    "Unexpected type:{:?} for DataValue Map",
+                    other.data_type()
+                )));
+            }
+        }
+        offsets.push(keys.len() as i64);
+    }
+
+    let keys = DataValue::try_into_data_array(&keys, key_type)?;
+    let items = DataValue::try_into_data_array(&items, value_type)?;
+    let entries = map_entries_array(&keys, &items, key_type, value_type);
+
+    let validity = Bitmap::from_trusted_len_iter(values.iter().map(|v| !v.is_null()));
+    let map_type = DataType::Map(Box::new(key_type.clone()), Box::new(value_type.clone()));
+    let array = LargeListArray::from_data(
+        map_type.to_arrow(),
+        Buffer::from_slice(&offsets),
+        Arc::new(entries),
+        Some(validity),
+    );
+    Ok(DFListArray::new(array).into_series())
+}
+
+/// Replace the entries of the map array, the offsets and the validity are kept.
+pub fn map_array_with_entries(
+    array: &DFListArray,
+    keys: &Series,
+    values: &Series,
+    key_type: &DataType,
+    value_type: &DataType,
+) -> Series {
+    let entries = map_entries_array(keys, values, key_type, value_type);
+    let map_type = DataType::Map(Box::new(key_type.clone()), Box::new(value_type.clone()));
+    let array = LargeListArray::from_data(
+        map_type.to_arrow(),
+        array.inner().offsets().clone(),
+        Arc::new(entries),
+        array.inner().validity().cloned(),
+    );
+    DFListArray::new(array).into_series()
+}
+
+fn map_entries_array(
+    keys: &Series,
+    values: &Series,
+    key_type: &DataType,
+    value_type: &DataType,
+) -> StructArray {
+    let entries = map_entries_field(key_type, value_type);
+    StructArray::from_data(
+        entries.data_type().to_arrow(),
+        vec![keys.get_array_ref(), values.get_array_ref()],
+        None,
+    )
+}
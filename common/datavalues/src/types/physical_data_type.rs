@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use crate::map_entries_field;
 use crate::DataField;
 use crate::DataType;
 
@@ -54,8 +55,9 @@ impl From<DataType> for PhysicalDataType {
             DataType::Float64 => Float64,
             DataType::List(x) => List(x),
             DataType::Struct(x) => Struct(x),
-            DataType::String => String,
-            DataType::Interval(_) => Int64,
+            DataType::Map(key, value) => List(Box::new(map_entries_field(&key, &value))),
+            DataType::String | DataType::Variant => String,
+            DataType::Interval(_) | DataType::Decimal(_, _) | DataType::DateTime64(_, _) => Int64,
         }
     }
 }
@@ -0,0 +1,64 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::prelude::*;
+
+pub struct ListSerializer {
+    pub inner: DataType,
+}
+
+impl TypeSerializer for ListSerializer {
+    fn serialize_value(&self, value: &DataValue) -> Result<String> {
+        match value {
+            DataValue::List(None, _) | DataValue::Null => Ok("NULL".to_owned()),
+            DataValue::List(Some(items), _) => {
+                let serializer = self.inner.create_serializer();
+                let quoted = matches!(
+                    self.inner,
+                    DataType::String
+                        | DataType::Date16
+                        | DataType::Date32
+                        | DataType::DateTime32(_)
+                        | DataType::DateTime64(_, _)
+                );
+
+                let mut res = String::new();
+                res.push('[');
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        res.push(',');
+                    }
+                    let s = serializer.serialize_value(item)?;
+                    match quoted && !item.is_null() {
+                        true => res.push_str(&format!("'{}'", s)),
+                        false => res.push_str(&s),
+                    }
+                }
+                res.push(']');
+                Ok(res)
+            }
+            _ => Err(ErrorCode::BadBytes("Incorrect List value")),
+        }
+    }
+
+    fn serialize_column(&self, column: &DataColumn) -> Result<Vec<String>> {
+        let array = column.to_array()?;
+        (0..array.len())
+            .map(|i| self.serialize_value(&array.try_get(i)?))
+            .collect()
+    }
+}
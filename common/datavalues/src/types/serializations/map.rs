@@ -0,0 +1,76 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::prelude::*;
+
+pub struct MapSerializer {
+    pub key_type: DataType,
+    pub value_type: DataType,
+}
+
+impl MapSerializer {
+    fn serialize_item(data_type: &DataType, value: &DataValue) -> Result<String> {
+        let s = data_type.create_serializer().serialize_value(value)?;
+        let quoted = !value.is_null()
+            && matches!(
+                data_type,
+                DataType::String
+                    | DataType::Date16
+                    | DataType::Date32
+                    | DataType::DateTime32(_)
+                    | DataType::DateTime64(_, _)
+            );
+        match quoted {
+            true => Ok(format!("'{}'", s)),
+            false => Ok(s),
+        }
+    }
+}
+
+impl TypeSerializer for MapSerializer {
+    fn serialize_value(&self, value: &DataValue) -> Result<String> {
+        match value {
+            DataValue::List(None, _) | DataValue::Null => Ok("NULL".to_owned()),
+            DataValue::List(Some(entries), _) => {
+                let mut res = String::new();
+                res.push('{');
+                for (i, entry) in entries.iter().enumerate() {
+                    let kv = match entry {
+                        DataValue::Struct(kv) if kv.len() == 2 => kv,
+                        _ => return Err(ErrorCode::BadBytes("Incorrect Map entry")),
+                    };
+                    if i > 0 {
+                        res.push(',');
+                    }
+                    res.push_str(&Self::serialize_item(&self.key_type, &kv[0])?);
+                    res.push(':');
+                    res.push_str(&Self::serialize_item(&self.value_type, &kv[1])?);
+                }
+                res.push('}');
+                Ok(res)
+            }
+            _ => Err(ErrorCode::BadBytes("Incorrect Map value")),
+        }
+    }
+
+    fn serialize_column(&self, column: &DataColumn) -> Result<Vec<String>> {
+        let array = column.to_array()?;
+        (0..array.len())
+            .map(|i| self.serialize_value(&array.try_get(i)?))
+            .collect()
+    }
+}
@@ -44,6 +44,7 @@ impl TypeSerializer for StructSerializer {
                             | DataType::Date16
                             | DataType::Date32
                             | DataType::DateTime32(_)
+                            | DataType::DateTime64(_, _)
                     ) {
                         res.push_str(&format!("'{}'", s));
                     } else {
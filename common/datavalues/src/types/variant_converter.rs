@@ -0,0 +1,48 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use serde_json::Value as JsonValue;
+
+/// Parse the JSON text into a variant value.
+pub fn parse_variant(bytes: &[u8]) -> Result<JsonValue> {
+    serde_json::from_slice(bytes).map_err(|e| {
+        ErrorCode::BadBytes(format!(
+            "Cannot parse value '{}' to Variant, cause: {}",
+            String::from_utf8_lossy(bytes),
+            e
+        ))
+    })
+}
+
+/// Encode the variant value as the compact JSON text, which is how the Variant is stored.
+pub fn variant_to_bytes(value: &JsonValue) -> Vec<u8> {
+    // Serializing a `serde_json::Value` never fails.
+    serde_json::to_vec(value).unwrap()
+}
+
+/// Validate and normalize the JSON text to the stored form of the Variant.
+pub fn normalize_variant(bytes: &[u8]) -> Result<Vec<u8>> {
+    parse_variant(bytes).map(|v| variant_to_bytes(&v))
+}
+
+/// Render the variant value as a plain string, strings are unquoted and `null` becomes None.
+pub fn variant_to_text(value: &JsonValue) -> Option<String> {
+    match value {
+        JsonValue::Null => None,
+        JsonValue::String(s) => Some(s.clone()),
+        other => Some(other.to_string()),
+    }
+}
@@ -20,6 +20,7 @@ mod fill;
 mod group_hash;
 mod r#if;
 mod like;
+mod regexp;
 mod scatter;
 mod take;
 mod take_random;
@@ -0,0 +1,78 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+
+#[test]
+fn test_a_regexp_binary() -> Result<()> {
+    let strings = DFStringArray::new_from_slice(&["Hello", "Hello", "Hello", "abc123", "abc"]);
+    let patterns = DFStringArray::new_from_slice(&["^H", "^W", "l+", "\\d{3}$", "\\d{3}$"]);
+
+    let result1 = strings.a_regexp_binary(&patterns, |x| x).unwrap();
+    let vs1: Vec<_> = result1.into_no_null_iter().collect();
+    assert_eq!(vs1, [true, false, true, true, false]);
+
+    let result2 = strings.a_regexp_binary(&patterns, |x| !x).unwrap();
+    let vs2: Vec<_> = result2.into_no_null_iter().collect();
+    assert_eq!(vs2, [false, true, false, false, true]);
+
+    Ok(())
+}
+
+#[test]
+fn test_a_regexp_binary_scalar() -> Result<()> {
+    let strings = DFStringArray::new_from_slice(&["GET /index.html", "POST /login", "get /"]);
+
+    let result1 = strings.a_regexp_binary_scalar(b"^(GET|POST) /", |x| x)?;
+    let vs1: Vec<_> = result1.into_no_null_iter().collect();
+    assert_eq!(vs1, [true, true, false]);
+
+    let result2 = strings.a_regexp_binary_scalar(b"^(GET|POST) /", |x| !x)?;
+    let vs2: Vec<_> = result2.into_no_null_iter().collect();
+    assert_eq!(vs2, [false, false, true]);
+
+    let result = strings.a_regexp_binary_scalar(b"(", |x| x);
+    assert!(result.is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_build_regexp() -> Result<()> {
+    let re = build_regexp(b"^abc$", b"")?;
+    assert!(re.is_match(b"abc"));
+    assert!(!re.is_match(b"ABC"));
+
+    let re = build_regexp(b"^abc$", b"i")?;
+    assert!(re.is_match(b"ABC"));
+
+    // The rightmost option takes precedence.
+    let re = build_regexp(b"^abc$", b"ic")?;
+    assert!(!re.is_match(b"ABC"));
+
+    let re = build_regexp(b"^b$", b"m")?;
+    assert!(re.is_match(b"a\nb\nc"));
+
+    let re = build_regexp(b"a.c", b"n")?;
+    assert!(re.is_match(b"a\nc"));
+
+    let result = build_regexp(b"abc", b"x");
+    assert_eq!(
+        "Code: 6, displayText = Incorrect arguments to regular expression match type: x.",
+        result.unwrap_err().to_string()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,104 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_datavalues::Tz;
+use common_exception::Result;
+
+#[test]
+fn test_datetime64_converter() -> Result<()> {
+    assert_eq!(
+        datetime64_to_string(1630320462123, 3, &Tz::UTC),
+        "2021-08-30 10:47:42.123"
+    );
+    assert_eq!(
+        datetime64_to_string(1630320462000001, 6, &Tz::UTC),
+        "2021-08-30 10:47:42.000001"
+    );
+    assert_eq!(
+        datetime64_to_string(1630320462, 0, &Tz::UTC),
+        "2021-08-30 10:47:42"
+    );
+    assert_eq!(
+        datetime64_to_string(-1, 3, &Tz::UTC),
+        "1969-12-31 23:59:59.999"
+    );
+
+    let date_time = 1630320462123456789i64.to_date_time64(9, &Tz::UTC);
+    assert_eq!(
+        datetime64_from_date_time(&date_time, 9),
+        1630320462123456789
+    );
+    assert_eq!(datetime64_from_date_time(&date_time, 3), 1630320462123);
+    assert_eq!(datetime64_from_date_time(&date_time, 0), 1630320462);
+
+    assert_eq!(datetime64_rescale(1630320462123, 3, 6), 1630320462123000);
+    assert_eq!(datetime64_rescale(1630320462123, 3, 0), 1630320462);
+    assert_eq!(datetime64_rescale(-1, 3, 0), -1);
+
+    assert!(create_datetime64_type(10, None).is_err());
+    assert!(create_datetime64_type(3, Some("Mars/Olympus".to_string())).is_err());
+    assert_eq!(
+        create_datetime64_type(6, Some("Asia/Shanghai".to_string()))?,
+        DataType::DateTime64(6, Some("Asia/Shanghai".to_string()))
+    );
+    Ok(())
+}
+
+#[test]
+fn test_datetime64_deserializer() -> Result<()> {
+    let data_type = DataType::DateTime64(3, None);
+    let mut deserializer = data_type.create_deserializer(4)?;
+    deserializer.de_text("2021-08-30 10:47:42.123".as_bytes())?;
+    deserializer.de_text("2021-08-30 10:47:42".as_bytes())?;
+    deserializer.de_text("1630320462999".as_bytes())?;
+    deserializer.de_text("NULL".as_bytes())?;
+    assert!(deserializer.de_text("2021-08-30".as_bytes()).is_err());
+
+    let series = deserializer.finish_to_series();
+    assert_eq!(series.i64()?.collect_values(), vec![
+        Some(1630320462123),
+        Some(1630320462000),
+        Some(1630320462999),
+        None
+    ]);
+    Ok(())
+}
+
+#[test]
+fn test_datetime64_coercion() -> Result<()> {
+    let datetime64 = DataType::DateTime64(3, None);
+
+    assert_eq!(
+        compare_coercion(&datetime64, &DataType::DateTime64(6, None))?,
+        DataType::DateTime64(6, None)
+    );
+    assert_eq!(
+        compare_coercion(&datetime64, &DataType::DateTime32(None))?,
+        DataType::DateTime64(3, None)
+    );
+    assert_eq!(
+        compare_coercion(&DataType::Date16, &datetime64)?,
+        DataType::DateTime64(3, None)
+    );
+    assert_eq!(
+        compare_coercion(&datetime64, &DataType::String)?,
+        DataType::DateTime64(3, None)
+    );
+    assert_eq!(
+        merge_types(&datetime64, &DataType::DateTime32(None))?,
+        DataType::DateTime64(3, None)
+    );
+    Ok(())
+}
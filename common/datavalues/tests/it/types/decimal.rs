@@ -0,0 +1,98 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_datavalues::DataValueArithmeticOperator;
+use common_exception::Result;
+
+#[test]
+fn test_decimal_converter() -> Result<()> {
+    assert_eq!(decimal_from_str("12.345", 10, 2)?, 1235);
+    assert_eq!(decimal_from_str("-12.344", 10, 2)?, -1234);
+    assert_eq!(decimal_from_str("+.5", 10, 2)?, 50);
+    assert_eq!(decimal_from_str("7", 10, 0)?, 7);
+    assert_eq!(decimal_from_str("1.5e1", 10, 1)?, 150);
+    assert!(decimal_from_str("abc", 10, 2).is_err());
+    assert!(decimal_from_str("1.2.3", 10, 2).is_err());
+    assert!(decimal_from_str(".", 10, 2).is_err());
+
+    let err = decimal_from_str("1000", 5, 2).unwrap_err();
+    assert_eq!(err.code(), 49);
+    let err = decimal_from_str("99999999999999999999", 18, 0).unwrap_err();
+    assert_eq!(err.code(), 49);
+
+    assert_eq!(decimal_to_string(-1235, 2), "-12.35");
+    assert_eq!(decimal_to_string(1, 3), "0.001");
+    assert_eq!(decimal_to_string(42, 0), "42");
+
+    assert_eq!(decimal_rescale(1235, 2, 1, 10)?, 124);
+    assert_eq!(decimal_rescale(-1235, 2, 1, 10)?, -124);
+    assert_eq!(decimal_rescale(12, 0, 3, 10)?, 12000);
+    assert!(decimal_rescale(12, 0, 3, 4).is_err());
+
+    assert!(create_decimal_type(19, 2).is_err());
+    assert!(create_decimal_type(5, 6).is_err());
+    assert_eq!(create_decimal_type(18, 18)?, DataType::Decimal(18, 18));
+    Ok(())
+}
+
+#[test]
+fn test_decimal_coercion() -> Result<()> {
+    let decimal = DataType::Decimal(10, 2);
+
+    assert_eq!(
+        compare_coercion(&decimal, &DataType::Decimal(5, 4))?,
+        DataType::Decimal(12, 4)
+    );
+    assert_eq!(
+        compare_coercion(&decimal, &DataType::Int32)?,
+        DataType::Decimal(12, 2)
+    );
+    assert_eq!(
+        compare_coercion(&decimal, &DataType::Float32)?,
+        DataType::Float64
+    );
+    // Int64 takes all the 18 digits, it's only merged with the decimals without fraction
+    assert_eq!(merge_types(&DataType::Int64, &decimal)?, DataType::Float64);
+    assert_eq!(
+        compare_coercion(&DataType::Int64, &DataType::Decimal(18, 4))?,
+        DataType::Float64
+    );
+    assert_eq!(
+        compare_coercion(&DataType::Int64, &DataType::Decimal(10, 0))?,
+        DataType::Decimal(18, 0)
+    );
+
+    let op = DataValueArithmeticOperator::Mul;
+    assert_eq!(
+        decimal_arithmetic_coercion(&op, &decimal, &DataType::Decimal(5, 3))?,
+        DataType::Decimal(15, 5)
+    );
+    let op = DataValueArithmeticOperator::Div;
+    assert_eq!(
+        decimal_arithmetic_coercion(&op, &decimal, &DataType::Int8)?,
+        DataType::Decimal(18, 2)
+    );
+    assert!(decimal_arithmetic_coercion(&op, &decimal, &DataType::String).is_err());
+
+    // The decimal is stored as Int64 and keeps its precision and scale in the arrow metadata.
+    let field = DataField::new("a", decimal.clone(), true);
+    let arrow_field = field.to_arrow();
+    assert_eq!(
+        arrow_field.data_type(),
+        &common_arrow::arrow::datatypes::DataType::Int64
+    );
+    assert_eq!(DataField::from(&arrow_field).data_type(), &decimal);
+    Ok(())
+}
@@ -0,0 +1,94 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+
+fn map_value(entries: Vec<(DataValue, DataValue)>) -> DataValue {
+    let entries_type = map_entries_field(&DataType::String, &DataType::Int64)
+        .data_type()
+        .clone();
+    let entries = entries
+        .into_iter()
+        .map(|(k, v)| DataValue::Struct(vec![k, v]))
+        .collect();
+    DataValue::List(Some(entries), entries_type)
+}
+
+#[test]
+fn test_map_type() -> Result<()> {
+    let map_type = create_map_type(DataType::String, DataType::Int64)?;
+    assert_eq!(
+        map_type,
+        DataType::Map(Box::new(DataType::String), Box::new(DataType::Int64))
+    );
+    assert_eq!(format!("{:?}", map_type), "Map(String, Int64)");
+    assert!(create_map_type(DataType::Float64, DataType::Int64).is_err());
+    assert!(create_map_type(DataType::String, DataType::Null).is_err());
+
+    // The map is kept through the arrow field.
+    let field = DataField::new("m", map_type.clone(), true);
+    let arrow_field = field.to_arrow();
+    let field: DataField = (&arrow_field).into();
+    assert_eq!(field.data_type(), &map_type);
+
+    let entries = map_entries_field(&DataType::String, &DataType::Int64);
+    assert_eq!(
+        map_type.to_physical_type(),
+        PhysicalDataType::List(Box::new(entries.clone()))
+    );
+    assert!(map_entries_types(entries.data_type()).is_none());
+    assert_eq!(
+        map_entries_types(&DataType::List(Box::new(entries))),
+        Some((DataType::String, DataType::Int64))
+    );
+    Ok(())
+}
+
+#[test]
+fn test_build_map_array() -> Result<()> {
+    let values = vec![
+        map_value(vec![
+            (
+                DataValue::String(Some(b"a".to_vec())),
+                DataValue::Int64(Some(1)),
+            ),
+            (DataValue::String(Some(b"b".to_vec())), DataValue::Null),
+        ]),
+        DataValue::Null,
+        map_value(vec![]),
+    ];
+    let series = build_map_array(&values, &DataType::String, &DataType::Int64)?;
+    assert_eq!(series.len(), 3);
+    assert_eq!(series.null_count(), 1);
+
+    let (keys, items) = map_array_entries(series.list()?)?;
+    assert_eq!(keys.string()?.collect_values(), vec![
+        Some(b"a".to_vec()),
+        Some(b"b".to_vec())
+    ]);
+    assert_eq!(items.i64()?.collect_values(), vec![Some(1), None]);
+
+    let map_type = DataType::Map(Box::new(DataType::String), Box::new(DataType::Int64));
+    let serializer = map_type.create_serializer();
+    assert_eq!(serializer.serialize_column(&series.into())?, vec![
+        "{'a':1,'b':NULL}",
+        "NULL",
+        "{}"
+    ]);
+
+    let bad = vec![DataValue::Int64(Some(1))];
+    assert!(build_map_array(&bad, &DataType::String, &DataType::Int64).is_err());
+    Ok(())
+}
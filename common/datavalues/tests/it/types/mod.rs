@@ -12,4 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod date_time64;
+mod decimal;
+mod map;
 mod serializations;
+mod variant;
@@ -55,6 +55,18 @@ fn test_serializers() -> Result<()> {
                 "NULL".to_owned(),
             ],
         },
+        Test {
+            name: "datetime64",
+            data_type: DataType::DateTime64(3, None),
+            value: DataValue::Int64(Some(1630320462123)),
+            column: Series::new(vec![Some(1630320462123i64), Some(1637117572000i64), None]).into(),
+            val_str: "2021-08-30 10:47:42.123",
+            col_str: vec![
+                "2021-08-30 10:47:42.123".to_owned(),
+                "2021-11-17 02:52:52.000".to_owned(),
+                "NULL".to_owned(),
+            ],
+        },
         Test {
             name: "date32",
             data_type: DataType::Date32,
@@ -67,6 +79,14 @@ fn test_serializers() -> Result<()> {
                 "NULL".to_owned(),
             ],
         },
+        Test {
+            name: "decimal",
+            data_type: DataType::Decimal(10, 2),
+            value: DataValue::Int64(Some(-150)),
+            column: Series::new(vec![Some(12345i64), Some(5i64), None]).into(),
+            val_str: "-1.50",
+            col_str: vec!["123.45".to_owned(), "0.05".to_owned(), "NULL".to_owned()],
+        },
         Test {
             name: "string",
             data_type: DataType::String,
@@ -105,5 +125,19 @@ fn test_serializers() -> Result<()> {
         assert_eq!(&result, expect);
     }
 
+    {
+        let data_type = DataType::List(Box::new(DataField::new("item", DataType::String, true)));
+        let serializer = data_type.create_serializer();
+        let value = DataValue::List(
+            Some(vec![
+                DataValue::String(Some("a".as_bytes().to_vec())),
+                DataValue::String(None),
+            ]),
+            DataType::String,
+        );
+        let result = serializer.serialize_value(&value)?;
+        assert_eq!(&result, "['a',NULL]");
+    }
+
     Ok(())
 }
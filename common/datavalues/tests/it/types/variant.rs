@@ -0,0 +1,79 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use common_datavalues::prelude::*;
+use common_exception::Result;
+
+#[test]
+fn test_variant_converter() -> Result<()> {
+    assert_eq!(
+        normalize_variant(br#" {"b": [1, 2.5, null], "a": "x"} "#)?,
+        br#"{"a":"x","b":[1,2.5,null]}"#.to_vec()
+    );
+    assert!(normalize_variant(b"{\"a\":").is_err());
+    assert!(normalize_variant(b"abc").is_err());
+
+    let value = parse_variant(br#"{"a": {"b": "c"}}"#)?;
+    assert_eq!(
+        variant_to_text(&value),
+        Some(r#"{"a":{"b":"c"}}"#.to_string())
+    );
+    assert_eq!(
+        variant_to_text(&parse_variant(br#""str""#)?),
+        Some("str".to_string())
+    );
+    assert_eq!(
+        variant_to_text(&parse_variant(b"1.5")?),
+        Some("1.5".to_string())
+    );
+    assert_eq!(variant_to_text(&parse_variant(b"null")?), None);
+    Ok(())
+}
+
+#[test]
+fn test_variant_deserializer() -> Result<()> {
+    let mut deserializer = DataType::Variant.create_deserializer(4)?;
+    deserializer.de_text(br#"{"a": 1}"#)?;
+    deserializer.de_text(br#"[true, "x"]"#)?;
+    deserializer.de_text(b"NULL")?;
+    assert!(deserializer.de_text(b"{a: 1}").is_err());
+
+    let series = deserializer.finish_to_series();
+    assert_eq!(series.string()?.collect_values(), vec![
+        Some(br#"{"a":1}"#.to_vec()),
+        Some(br#"[true,"x"]"#.to_vec()),
+        None
+    ]);
+    Ok(())
+}
+
+#[test]
+fn test_variant_coercion() -> Result<()> {
+    assert_eq!(
+        compare_coercion(&DataType::Variant, &DataType::Variant)?,
+        DataType::Variant
+    );
+    assert_eq!(
+        compare_coercion(&DataType::Variant, &DataType::String)?,
+        DataType::String
+    );
+    assert_eq!(
+        compare_coercion(&DataType::UInt8, &DataType::Variant)?,
+        DataType::Float64
+    );
+    assert_eq!(
+        compare_coercion(&DataType::Variant, &DataType::Boolean)?,
+        DataType::Boolean
+    );
+    Ok(())
+}
@@ -277,6 +277,8 @@ build_exceptions! {
     // storage-api error codes
     ReadFileError(5001),
     BrokenChannel(5002),
+    TableHistoricalDataNotFound(5003),
+    TableCommitConflict(5004),
 
     // kv-api error codes
     UnknownKey(6000),
@@ -0,0 +1,179 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::alloc::Layout;
+use std::fmt;
+use std::sync::Arc;
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_io::prelude::*;
+
+use super::StateAddr;
+use crate::aggregates::aggregate_function_factory::AggregateFunctionDescription;
+use crate::aggregates::assert_unary_arguments;
+use crate::aggregates::AggregateFunction;
+use crate::aggregates::AggregateFunctionRef;
+
+struct AggregateAnyState {
+    value: Option<DataValue>,
+}
+
+impl AggregateAnyState {
+    #[inline(always)]
+    fn add(&mut self, value: DataValue, is_last: bool) {
+        if !value.is_null() && (is_last || self.value.is_none()) {
+            self.value = Some(value);
+        }
+    }
+
+    fn serialize(&self, writer: &mut BytesMut) -> Result<()> {
+        self.value.serialize_to_buf(writer)
+    }
+
+    fn deserialize(&mut self, reader: &mut &[u8]) -> Result<()> {
+        self.value = Option::<DataValue>::deserialize(reader)?;
+        Ok(())
+    }
+}
+
+/// Returns the first (`any`) or the last (`anyLast`) non-null value it meets.
+/// The order of the rows is not specified, so the result is arbitrary unless the input is sorted.
+#[derive(Clone)]
+pub struct AggregateAnyFunction {
+    display_name: String,
+    data_type: DataType,
+    is_last: bool,
+}
+
+impl AggregateFunction for AggregateAnyFunction {
+    fn name(&self) -> &str {
+        "AggregateAnyFunction"
+    }
+
+    fn return_type(&self) -> Result<DataType> {
+        Ok(self.data_type.clone())
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(true)
+    }
+
+    fn init_state(&self, place: StateAddr) {
+        place.write(|| AggregateAnyState { value: None });
+    }
+
+    fn state_layout(&self) -> Layout {
+        Layout::new::<AggregateAnyState>()
+    }
+
+    fn accumulate(&self, place: StateAddr, arrays: &[Series], input_rows: usize) -> Result<()> {
+        let state = place.get::<AggregateAnyState>();
+        if self.is_last {
+            for row in (0..input_rows).rev() {
+                let value = arrays[0].try_get(row)?;
+                if !value.is_null() {
+                    state.add(value, true);
+                    break;
+                }
+            }
+        } else {
+            for row in 0..input_rows {
+                if state.value.is_some() {
+                    break;
+                }
+                state.add(arrays[0].try_get(row)?, false);
+            }
+        }
+        Ok(())
+    }
+
+    fn accumulate_keys(
+        &self,
+        places: &[StateAddr],
+        offset: usize,
+        arrays: &[Series],
+        _input_rows: usize,
+    ) -> Result<()> {
+        for (row, place) in places.iter().enumerate() {
+            let place = place.next(offset);
+            let state = place.get::<AggregateAnyState>();
+            if self.is_last || state.value.is_none() {
+                state.add(arrays[0].try_get(row)?, self.is_last);
+            }
+        }
+        Ok(())
+    }
+
+    fn serialize(&self, place: StateAddr, writer: &mut BytesMut) -> Result<()> {
+        let state = place.get::<AggregateAnyState>();
+        state.serialize(writer)
+    }
+
+    fn deserialize(&self, place: StateAddr, reader: &mut &[u8]) -> Result<()> {
+        let state = place.get::<AggregateAnyState>();
+        state.deserialize(reader)
+    }
+
+    fn merge(&self, place: StateAddr, rhs: StateAddr) -> Result<()> {
+        let state = place.get::<AggregateAnyState>();
+        let rhs = rhs.get::<AggregateAnyState>();
+        if let Some(value) = &rhs.value {
+            state.add(value.clone(), self.is_last);
+        }
+        Ok(())
+    }
+
+    fn merge_result(&self, place: StateAddr) -> Result<DataValue> {
+        let state = place.get::<AggregateAnyState>();
+        match &state.value {
+            Some(value) => Ok(value.clone()),
+            None => Ok(DataValue::from(&self.data_type)),
+        }
+    }
+}
+
+impl fmt::Display for AggregateAnyFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+impl AggregateAnyFunction {
+    pub fn try_create(
+        display_name: &str,
+        arguments: Vec<DataField>,
+        is_last: bool,
+    ) -> Result<AggregateFunctionRef> {
+        assert_unary_arguments(display_name, arguments.len())?;
+
+        Ok(Arc::new(AggregateAnyFunction {
+            display_name: display_name.to_owned(),
+            data_type: arguments[0].data_type().clone(),
+            is_last,
+        }))
+    }
+}
+
+pub fn aggregate_any_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(|display_name, _params, arguments| {
+        AggregateAnyFunction::try_create(display_name, arguments, false)
+    }))
+}
+
+pub fn aggregate_any_last_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(|display_name, _params, arguments| {
+        AggregateAnyFunction::try_create(display_name, arguments, true)
+    }))
+}
@@ -0,0 +1,285 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::alloc::Layout;
+use std::fmt;
+use std::hash::Hasher;
+use std::io::Read;
+use std::sync::Arc;
+
+use bytes::BytesMut;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use twox_hash::XxHash64;
+
+use super::StateAddr;
+use crate::aggregates::aggregate_function_factory::AggregateFunctionDescription;
+use crate::aggregates::aggregator_common::assert_variadic_arguments;
+use crate::aggregates::AggregateFunction;
+
+/// The seed of the row hash, which must never change: the states serialized by other nodes,
+/// or by another version, are merged register by register.
+const HASH_SEED: u64 = 0;
+/// The number of bits of the hash used to choose the register.
+const PRECISION: u32 = 12;
+const REGISTERS: usize = 1 << PRECISION;
+
+/// HyperLogLog with 2^12 registers, the standard error is about 1.04 / sqrt(4096) = 1.6%.
+/// The state is always 4KB no matter how many distinct values are seen.
+pub struct AggregateApproxCountDistinctState {
+    registers: [u8; REGISTERS],
+}
+
+impl AggregateApproxCountDistinctState {
+    #[inline(always)]
+    fn add_hash(&mut self, hash: u64) {
+        let index = (hash >> (64 - PRECISION)) as usize;
+        // The sentinel bit bounds the rank when the remaining bits are all zero.
+        let rank = ((hash << PRECISION) | (1 << (PRECISION - 1))).leading_zeros() as u8 + 1;
+        if rank > self.registers[index] {
+            self.registers[index] = rank;
+        }
+    }
+
+    fn merge(&mut self, other: &Self) {
+        for (register, other) in self.registers.iter_mut().zip(other.registers.iter()) {
+            *register = (*register).max(*other);
+        }
+    }
+
+    fn estimate(&self) -> u64 {
+        let m = REGISTERS as f64;
+        let alpha = 0.7213 / (1.0 + 1.079 / m);
+
+        let mut sum = 0.0;
+        let mut zeros = 0;
+        for register in self.registers.iter() {
+            sum += 1.0 / (1u64 << register) as f64;
+            if *register == 0 {
+                zeros += 1;
+            }
+        }
+
+        let estimate = alpha * m * m / sum;
+        // Linear counting is more accurate for the small cardinalities.
+        if estimate <= 2.5 * m && zeros > 0 {
+            return (m * (m / zeros as f64).ln()).round() as u64;
+        }
+        estimate.round() as u64
+    }
+
+    fn serialize(&self, writer: &mut BytesMut) -> Result<()> {
+        writer.extend_from_slice(&self.registers);
+        Ok(())
+    }
+
+    fn deserialize(&mut self, reader: &mut &[u8]) -> Result<()> {
+        reader.read_exact(&mut self.registers)?;
+        Ok(())
+    }
+}
+
+/// The approximate `uniq`: `approx_count_distinct(x, ...)` or `uniqHLL12(x, ...)`.
+/// Unlike the distinct combinator, the state has a fixed size and merges register by register.
+#[derive(Clone)]
+pub struct AggregateApproxCountDistinctFunction {
+    display_name: String,
+}
+
+impl AggregateApproxCountDistinctFunction {
+    pub fn try_create(
+        display_name: &str,
+        _params: Vec<DataValue>,
+        arguments: Vec<DataField>,
+    ) -> Result<Arc<dyn AggregateFunction>> {
+        assert_variadic_arguments(display_name, arguments.len(), (1, 32))?;
+        Ok(Arc::new(AggregateApproxCountDistinctFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> AggregateFunctionDescription {
+        AggregateFunctionDescription::creator(Box::new(Self::try_create))
+    }
+
+    /// Downcasts the arguments once per block, to hash the native values of the rows.
+    fn hash_columns(arrays: &[Series]) -> Result<Vec<&dyn HashColumn>> {
+        arrays
+            .iter()
+            .map(|array| {
+                let column: &dyn HashColumn =
+                    match PhysicalDataType::from(array.data_type().clone()) {
+                        PhysicalDataType::Null => array.static_cast::<DFNullArray>(),
+                        PhysicalDataType::Boolean => array.static_cast::<DFBooleanArray>(),
+                        PhysicalDataType::String => array.static_cast::<DFStringArray>(),
+                        PhysicalDataType::UInt8 => array.static_cast::<DFUInt8Array>(),
+                        PhysicalDataType::UInt16 => array.static_cast::<DFUInt16Array>(),
+                        PhysicalDataType::UInt32 => array.static_cast::<DFUInt32Array>(),
+                        PhysicalDataType::UInt64 => array.static_cast::<DFUInt64Array>(),
+                        PhysicalDataType::Int8 => array.static_cast::<DFInt8Array>(),
+                        PhysicalDataType::Int16 => array.static_cast::<DFInt16Array>(),
+                        PhysicalDataType::Int32 => array.static_cast::<DFInt32Array>(),
+                        PhysicalDataType::Int64 => array.static_cast::<DFInt64Array>(),
+                        PhysicalDataType::Float32 => array.static_cast::<DFFloat32Array>(),
+                        PhysicalDataType::Float64 => array.static_cast::<DFFloat64Array>(),
+                        _ => {
+                            return Err(ErrorCode::BadDataValueType(format!(
+                                "Cannot count distinct values of type {:?}",
+                                array.data_type()
+                            )))
+                        }
+                    };
+                Ok(column)
+            })
+            .collect()
+    }
+
+    /// Hashes the row with XxHash64 of a fixed seed over the little-endian bytes of the values,
+    /// so the same value falls into the same register on every node.
+    fn hash_row(columns: &[&dyn HashColumn], row: usize) -> Option<u64> {
+        let mut hasher = XxHash64::with_seed(HASH_SEED);
+        for column in columns {
+            if !column.hash_value(row, &mut hasher) {
+                return None;
+            }
+        }
+        Some(hasher.finish())
+    }
+}
+
+/// Feeds the value of the row into the hasher, returns false if it is NULL.
+trait HashColumn {
+    fn hash_value(&self, row: usize, hasher: &mut XxHash64) -> bool;
+}
+
+impl<T: DFPrimitiveType> HashColumn for DFPrimitiveArray<T> {
+    fn hash_value(&self, row: usize, hasher: &mut XxHash64) -> bool {
+        if self.is_null(row) {
+            return false;
+        }
+        hasher.write(self.inner().value(row).to_le_bytes().as_ref());
+        true
+    }
+}
+
+impl HashColumn for DFNullArray {
+    fn hash_value(&self, _row: usize, _hasher: &mut XxHash64) -> bool {
+        false
+    }
+}
+
+impl HashColumn for DFBooleanArray {
+    fn hash_value(&self, row: usize, hasher: &mut XxHash64) -> bool {
+        if self.is_null(row) {
+            return false;
+        }
+        hasher.write(&[self.inner().value(row) as u8]);
+        true
+    }
+}
+
+impl HashColumn for DFStringArray {
+    fn hash_value(&self, row: usize, hasher: &mut XxHash64) -> bool {
+        if self.is_null(row) {
+            return false;
+        }
+        // the length keeps ('ab', 'c') and ('a', 'bc') apart
+        let value = self.inner().value(row);
+        hasher.write(&(value.len() as u64).to_le_bytes());
+        hasher.write(value);
+        true
+    }
+}
+
+impl AggregateFunction for AggregateApproxCountDistinctFunction {
+    fn name(&self) -> &str {
+        "AggregateApproxCountDistinctFunction"
+    }
+
+    fn return_type(&self) -> Result<DataType> {
+        Ok(DataType::UInt64)
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn init_state(&self, place: StateAddr) {
+        place.write(|| AggregateApproxCountDistinctState {
+            registers: [0; REGISTERS],
+        });
+    }
+
+    fn state_layout(&self) -> Layout {
+        Layout::new::<AggregateApproxCountDistinctState>()
+    }
+
+    fn accumulate(&self, place: StateAddr, arrays: &[Series], input_rows: usize) -> Result<()> {
+        let state = place.get::<AggregateApproxCountDistinctState>();
+        let columns = Self::hash_columns(arrays)?;
+        for row in 0..input_rows {
+            if let Some(hash) = Self::hash_row(&columns, row) {
+                state.add_hash(hash);
+            }
+        }
+        Ok(())
+    }
+
+    fn accumulate_keys(
+        &self,
+        places: &[StateAddr],
+        offset: usize,
+        arrays: &[Series],
+        _input_rows: usize,
+    ) -> Result<()> {
+        let columns = Self::hash_columns(arrays)?;
+        for (row, place) in places.iter().enumerate() {
+            if let Some(hash) = Self::hash_row(&columns, row) {
+                let place = place.next(offset);
+                let state = place.get::<AggregateApproxCountDistinctState>();
+                state.add_hash(hash);
+            }
+        }
+        Ok(())
+    }
+
+    fn serialize(&self, place: StateAddr, writer: &mut BytesMut) -> Result<()> {
+        let state = place.get::<AggregateApproxCountDistinctState>();
+        state.serialize(writer)
+    }
+
+    fn deserialize(&self, place: StateAddr, reader: &mut &[u8]) -> Result<()> {
+        let state = place.get::<AggregateApproxCountDistinctState>();
+        state.deserialize(reader)
+    }
+
+    fn merge(&self, place: StateAddr, rhs: StateAddr) -> Result<()> {
+        let state = place.get::<AggregateApproxCountDistinctState>();
+        let rhs = rhs.get::<AggregateApproxCountDistinctState>();
+        state.merge(rhs);
+        Ok(())
+    }
+
+    fn merge_result(&self, place: StateAddr) -> Result<DataValue> {
+        let state = place.get::<AggregateApproxCountDistinctState>();
+        Ok(DataValue::UInt64(Some(state.estimate())))
+    }
+}
+
+impl fmt::Display for AggregateApproxCountDistinctFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
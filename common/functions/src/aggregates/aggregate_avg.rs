@@ -28,6 +28,7 @@ use num::NumCast;
 use super::StateAddr;
 use crate::aggregates::aggregate_function_factory::AggregateFunctionDescription;
 use crate::aggregates::aggregator_common::assert_unary_arguments;
+use crate::aggregates::AggregateDecimalSumAvgFunction;
 use crate::aggregates::AggregateFunction;
 use crate::aggregates::AggregateFunctionRef;
 use crate::with_match_primitive_type;
@@ -186,6 +187,10 @@ pub fn try_create_aggregate_avg_function(
     assert_unary_arguments(display_name, arguments.len())?;
 
     let data_type = arguments[0].data_type();
+    if data_type.is_decimal() {
+        return AggregateDecimalSumAvgFunction::try_create(display_name, true, arguments);
+    }
+
     with_match_primitive_type!(data_type, |$T| {
         AggregateAvgFunction::<$T, <$T as DFPrimitiveType>::LargestType>::try_create(
             display_name,
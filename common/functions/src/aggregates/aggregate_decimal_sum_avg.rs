@@ -0,0 +1,187 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::alloc::Layout;
+use std::cmp;
+use std::fmt;
+use std::sync::Arc;
+
+use bytes::BytesMut;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_io::prelude::*;
+
+use crate::aggregates::AggregateFunction;
+use crate::aggregates::AggregateFunctionRef;
+use crate::aggregates::StateAddr;
+
+// The avg keeps more fraction digits than the input, same as MySQL.
+const AVG_EXTRA_SCALE: u8 = 4;
+
+struct AggregateDecimalSumAvgState {
+    pub value: i128,
+    pub count: u64,
+}
+
+impl AggregateDecimalSumAvgState {
+    #[inline(always)]
+    fn add(&mut self, value: i128, count: u64) {
+        self.value += value;
+        self.count += count;
+    }
+}
+
+/// The sum and the avg of decimals, the scaled values are added into an Int128 without
+/// losing precision, only the result is checked to fit in the max precision.
+#[derive(Clone)]
+pub struct AggregateDecimalSumAvgFunction {
+    display_name: String,
+    is_avg: bool,
+    scale: u8,
+}
+
+impl AggregateFunction for AggregateDecimalSumAvgFunction {
+    fn name(&self) -> &str {
+        match self.is_avg {
+            true => "AggregateDecimalAvgFunction",
+            false => "AggregateDecimalSumFunction",
+        }
+    }
+
+    fn return_type(&self) -> Result<DataType> {
+        Ok(DataType::Decimal(
+            MAX_DECIMAL_PRECISION,
+            self.result_scale(),
+        ))
+    }
+
+    // NULL over the empty input
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(true)
+    }
+
+    fn init_state(&self, place: StateAddr) {
+        place.write(|| AggregateDecimalSumAvgState { value: 0, count: 0 });
+    }
+
+    fn state_layout(&self) -> Layout {
+        Layout::new::<AggregateDecimalSumAvgState>()
+    }
+
+    fn accumulate(&self, place: StateAddr, arrays: &[Series], _input_rows: usize) -> Result<()> {
+        let state = place.get::<AggregateDecimalSumAvgState>();
+        for v in arrays[0].i64()?.into_iter().flatten() {
+            state.add(*v as i128, 1);
+        }
+        Ok(())
+    }
+
+    fn accumulate_keys(
+        &self,
+        places: &[StateAddr],
+        offset: usize,
+        arrays: &[Series],
+        _input_rows: usize,
+    ) -> Result<()> {
+        let array = arrays[0].i64()?;
+        for (value, place) in array.into_iter().zip(places.iter()) {
+            if let Some(v) = value {
+                let place = place.next(offset);
+                let state = place.get::<AggregateDecimalSumAvgState>();
+                state.add(*v as i128, 1);
+            }
+        }
+        Ok(())
+    }
+
+    fn serialize(&self, place: StateAddr, writer: &mut BytesMut) -> Result<()> {
+        let state = place.get::<AggregateDecimalSumAvgState>();
+        // the Int128 is written as its high and low halves
+        ((state.value >> 64) as i64).serialize_to_buf(writer)?;
+        (state.value as u64).serialize_to_buf(writer)?;
+        state.count.serialize_to_buf(writer)
+    }
+
+    fn deserialize(&self, place: StateAddr, reader: &mut &[u8]) -> Result<()> {
+        let state = place.get::<AggregateDecimalSumAvgState>();
+        let high = i64::deserialize(reader)?;
+        let low = u64::deserialize(reader)?;
+        state.value = ((high as i128) << 64) | low as i128;
+        state.count = u64::deserialize(reader)?;
+        Ok(())
+    }
+
+    fn merge(&self, place: StateAddr, rhs: StateAddr) -> Result<()> {
+        let state = place.get::<AggregateDecimalSumAvgState>();
+        let rhs = rhs.get::<AggregateDecimalSumAvgState>();
+        state.add(rhs.value, rhs.count);
+        Ok(())
+    }
+
+    fn merge_result(&self, place: StateAddr) -> Result<DataValue> {
+        let state = place.get::<AggregateDecimalSumAvgState>();
+        if state.count == 0 {
+            return Ok(DataValue::Int64(None));
+        }
+
+        let value = match self.is_avg {
+            true => {
+                let factor = decimal_factor(self.result_scale() - self.scale) as i128;
+                let value = state.value.checked_mul(factor).ok_or_else(|| {
+                    ErrorCode::Overflow(format!(
+                        "Decimal overflow, the value doesn't fit in the precision {}",
+                        MAX_DECIMAL_PRECISION
+                    ))
+                })?;
+                check_decimal(div_round(value, state.count as i128), MAX_DECIMAL_PRECISION)?
+            }
+            false => check_decimal(state.value, MAX_DECIMAL_PRECISION)?,
+        };
+        Ok(DataValue::Int64(Some(value)))
+    }
+}
+
+impl fmt::Display for AggregateDecimalSumAvgFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+impl AggregateDecimalSumAvgFunction {
+    pub fn try_create(
+        display_name: &str,
+        is_avg: bool,
+        arguments: Vec<DataField>,
+    ) -> Result<AggregateFunctionRef> {
+        match arguments[0].data_type() {
+            DataType::Decimal(_, scale) => Ok(Arc::new(Self {
+                display_name: display_name.to_owned(),
+                is_avg,
+                scale: *scale,
+            })),
+            data_type => Err(ErrorCode::BadDataValueType(format!(
+                "AggregateDecimalSumAvgFunction does not support type '{:?}'",
+                data_type
+            ))),
+        }
+    }
+
+    fn result_scale(&self) -> u8 {
+        match self.is_avg {
+            true => cmp::min(MAX_DECIMAL_PRECISION, self.scale + AVG_EXTRA_SCALE),
+            false => self.scale,
+        }
+    }
+}
@@ -0,0 +1,173 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::alloc::Layout;
+use std::fmt;
+use std::sync::Arc;
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_io::prelude::*;
+
+use super::StateAddr;
+use crate::aggregates::aggregate_function_factory::AggregateFunctionDescription;
+use crate::aggregates::assert_unary_arguments;
+use crate::aggregates::assert_variadic_params;
+use crate::aggregates::AggregateFunction;
+use crate::aggregates::AggregateFunctionRef;
+
+struct AggregateGroupArrayState {
+    values: Vec<DataValue>,
+}
+
+impl AggregateGroupArrayState {
+    #[inline(always)]
+    fn add(&mut self, value: DataValue, max_size: usize) {
+        if !value.is_null() && self.values.len() < max_size {
+            self.values.push(value);
+        }
+    }
+
+    fn serialize(&self, writer: &mut BytesMut) -> Result<()> {
+        writer.write_uvarint(self.values.len() as u64)?;
+        for value in self.values.iter() {
+            value.serialize_to_buf(writer)?;
+        }
+        Ok(())
+    }
+
+    fn deserialize(&mut self, reader: &mut &[u8]) -> Result<()> {
+        self.values.clear();
+
+        let size = reader.read_uvarint()?;
+        self.values.reserve(size as usize);
+        for _i in 0..size {
+            self.values.push(DataValue::deserialize(reader)?);
+        }
+        Ok(())
+    }
+}
+
+/// Collects the non-null values of the argument into an array, like `groupArray` of ClickHouse.
+/// The optional parameter limits the size of the array: `groupArray(N)(x)`.
+#[derive(Clone)]
+pub struct AggregateGroupArrayFunction {
+    display_name: String,
+    item_type: DataType,
+    max_size: usize,
+}
+
+impl AggregateFunction for AggregateGroupArrayFunction {
+    fn name(&self) -> &str {
+        "AggregateGroupArrayFunction"
+    }
+
+    fn return_type(&self) -> Result<DataType> {
+        Ok(create_list_type(self.item_type.clone()))
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn init_state(&self, place: StateAddr) {
+        place.write(|| AggregateGroupArrayState { values: vec![] });
+    }
+
+    fn state_layout(&self) -> Layout {
+        Layout::new::<AggregateGroupArrayState>()
+    }
+
+    fn accumulate(&self, place: StateAddr, arrays: &[Series], input_rows: usize) -> Result<()> {
+        let state = place.get::<AggregateGroupArrayState>();
+        for row in 0..input_rows {
+            state.add(arrays[0].try_get(row)?, self.max_size);
+        }
+        Ok(())
+    }
+
+    fn accumulate_keys(
+        &self,
+        places: &[StateAddr],
+        offset: usize,
+        arrays: &[Series],
+        _input_rows: usize,
+    ) -> Result<()> {
+        for (row, place) in places.iter().enumerate() {
+            let place = place.next(offset);
+            let state = place.get::<AggregateGroupArrayState>();
+            state.add(arrays[0].try_get(row)?, self.max_size);
+        }
+        Ok(())
+    }
+
+    fn serialize(&self, place: StateAddr, writer: &mut BytesMut) -> Result<()> {
+        let state = place.get::<AggregateGroupArrayState>();
+        state.serialize(writer)
+    }
+
+    fn deserialize(&self, place: StateAddr, reader: &mut &[u8]) -> Result<()> {
+        let state = place.get::<AggregateGroupArrayState>();
+        state.deserialize(reader)
+    }
+
+    fn merge(&self, place: StateAddr, rhs: StateAddr) -> Result<()> {
+        let state = place.get::<AggregateGroupArrayState>();
+        let rhs = rhs.get::<AggregateGroupArrayState>();
+        for value in rhs.values.iter() {
+            state.add(value.clone(), self.max_size);
+        }
+        Ok(())
+    }
+
+    fn merge_result(&self, place: StateAddr) -> Result<DataValue> {
+        let state = place.get::<AggregateGroupArrayState>();
+        Ok(DataValue::List(
+            Some(state.values.clone()),
+            self.item_type.clone(),
+        ))
+    }
+}
+
+impl fmt::Display for AggregateGroupArrayFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+impl AggregateGroupArrayFunction {
+    pub fn try_create(
+        display_name: &str,
+        params: Vec<DataValue>,
+        arguments: Vec<DataField>,
+    ) -> Result<AggregateFunctionRef> {
+        assert_variadic_params(display_name, params.len(), (0, 1))?;
+        assert_unary_arguments(display_name, arguments.len())?;
+
+        let max_size = match params.first() {
+            Some(param) => param.as_u64()? as usize,
+            None => usize::MAX,
+        };
+
+        Ok(Arc::new(AggregateGroupArrayFunction {
+            display_name: display_name.to_owned(),
+            item_type: arguments[0].data_type().clone(),
+            max_size,
+        }))
+    }
+}
+
+pub fn aggregate_group_array_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(AggregateGroupArrayFunction::try_create))
+}
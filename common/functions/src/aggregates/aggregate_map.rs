@@ -0,0 +1,332 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::alloc::Layout;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::fmt;
+use std::sync::Arc;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_io::prelude::*;
+
+use super::StateAddr;
+use crate::aggregates::aggregate_function_factory::AggregateFunctionDescription;
+use crate::aggregates::assert_unary_arguments;
+use crate::aggregates::AggregateFunction;
+use crate::aggregates::AggregateFunctionRef;
+
+struct AggregateMapState {
+    entries: HashMap<DataGroupValue, DataValue>,
+}
+
+impl AggregateMapState {
+    fn add(
+        &mut self,
+        op: &DataValueAggregateOperator,
+        key: &DataValue,
+        value: DataValue,
+    ) -> Result<()> {
+        if key.is_null() || value.is_null() {
+            return Ok(());
+        }
+
+        self.merge_entry(op, DataGroupValue::try_from(key)?, value)
+    }
+
+    fn merge_entry(
+        &mut self,
+        op: &DataValueAggregateOperator,
+        key: DataGroupValue,
+        value: DataValue,
+    ) -> Result<()> {
+        match self.entries.get_mut(&key) {
+            Some(current) => *current = merge_value(op, current, value)?,
+            None => {
+                self.entries.insert(key, value);
+            }
+        }
+        Ok(())
+    }
+
+    fn serialize(&self, writer: &mut BytesMut) -> Result<()> {
+        writer.write_uvarint(self.entries.len() as u64)?;
+        for (key, value) in self.entries.iter() {
+            DataValue::from(key).serialize_to_buf(writer)?;
+            value.serialize_to_buf(writer)?;
+        }
+        Ok(())
+    }
+
+    fn deserialize(&mut self, reader: &mut &[u8]) -> Result<()> {
+        self.entries.clear();
+
+        let size = reader.read_uvarint()?;
+        self.entries.reserve(size as usize);
+        for _i in 0..size {
+            let key = DataValue::deserialize(reader)?;
+            let value = DataValue::deserialize(reader)?;
+            self.entries.insert(DataGroupValue::try_from(&key)?, value);
+        }
+        Ok(())
+    }
+}
+
+/// The sum, the min and the max of the map values grouped by the keys, like `sumMap`
+/// of ClickHouse but the argument is a map. The keys of the result are sorted.
+#[derive(Clone)]
+pub struct AggregateMapFunction {
+    display_name: String,
+    op: DataValueAggregateOperator,
+    key_type: DataType,
+    value_type: DataType,
+}
+
+impl AggregateFunction for AggregateMapFunction {
+    fn name(&self) -> &str {
+        "AggregateMapFunction"
+    }
+
+    fn return_type(&self) -> Result<DataType> {
+        Ok(DataType::Map(
+            Box::new(self.key_type.clone()),
+            Box::new(self.value_type.clone()),
+        ))
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn init_state(&self, place: StateAddr) {
+        place.write(|| AggregateMapState {
+            entries: HashMap::new(),
+        });
+    }
+
+    fn state_layout(&self) -> Layout {
+        Layout::new::<AggregateMapState>()
+    }
+
+    fn accumulate(&self, place: StateAddr, arrays: &[Series], _input_rows: usize) -> Result<()> {
+        let state = place.get::<AggregateMapState>();
+        self.for_each_entry(&arrays[0], |_, key, value| state.add(&self.op, &key, value))
+    }
+
+    fn accumulate_keys(
+        &self,
+        places: &[StateAddr],
+        offset: usize,
+        arrays: &[Series],
+        _input_rows: usize,
+    ) -> Result<()> {
+        self.for_each_entry(&arrays[0], |row, key, value| {
+            let place = places[row].next(offset);
+            let state = place.get::<AggregateMapState>();
+            state.add(&self.op, &key, value)
+        })
+    }
+
+    fn serialize(&self, place: StateAddr, writer: &mut BytesMut) -> Result<()> {
+        let state = place.get::<AggregateMapState>();
+        state.serialize(writer)
+    }
+
+    fn deserialize(&self, place: StateAddr, reader: &mut &[u8]) -> Result<()> {
+        let state = place.get::<AggregateMapState>();
+        state.deserialize(reader)
+    }
+
+    fn merge(&self, place: StateAddr, rhs: StateAddr) -> Result<()> {
+        let state = place.get::<AggregateMapState>();
+        let rhs = rhs.get::<AggregateMapState>();
+        for (key, value) in rhs.entries.iter() {
+            state.merge_entry(&self.op, key.clone(), value.clone())?;
+        }
+        Ok(())
+    }
+
+    fn merge_result(&self, place: StateAddr) -> Result<DataValue> {
+        let state = place.get::<AggregateMapState>();
+        let mut entries = state
+            .entries
+            .iter()
+            .map(|(key, value)| (DataValue::from(key), value.clone()))
+            .collect::<Vec<_>>();
+
+        entries.sort_by(|a, b| compare_values(&a.0, &b.0));
+
+        let entries = entries
+            .into_iter()
+            .map(|(key, value)| DataValue::Struct(vec![key, value]))
+            .collect();
+        let entries_type = map_entries_field(&self.key_type, &self.value_type);
+        Ok(DataValue::List(
+            Some(entries),
+            entries_type.data_type().clone(),
+        ))
+    }
+}
+
+impl fmt::Display for AggregateMapFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+impl AggregateMapFunction {
+    pub fn try_create(
+        display_name: &str,
+        op: DataValueAggregateOperator,
+        arguments: Vec<DataField>,
+    ) -> Result<AggregateFunctionRef> {
+        assert_unary_arguments(display_name, arguments.len())?;
+
+        let (key_type, value_type) = match arguments[0].data_type() {
+            DataType::Map(key_type, value_type) => (key_type.as_ref(), value_type.as_ref()),
+            other => {
+                return Err(ErrorCode::BadDataValueType(format!(
+                    "{} expects a map argument, but got {:?}",
+                    display_name, other
+                )));
+            }
+        };
+
+        // The sum is widened like the sum of the numbers.
+        let value_type = match op {
+            DataValueAggregateOperator::Sum if value_type.is_unsigned_integer() => DataType::UInt64,
+            DataValueAggregateOperator::Sum if value_type.is_signed_integer() => DataType::Int64,
+            DataValueAggregateOperator::Sum if value_type.is_floating() => DataType::Float64,
+            DataValueAggregateOperator::Min | DataValueAggregateOperator::Max
+                if value_type.is_numeric()
+                    || value_type.is_date_or_date_time()
+                    || value_type == &DataType::String =>
+            {
+                value_type.clone()
+            }
+            _ => {
+                return Err(ErrorCode::BadDataValueType(format!(
+                    "{} does not support the value type '{:?}'",
+                    display_name, value_type
+                )));
+            }
+        };
+
+        Ok(Arc::new(AggregateMapFunction {
+            display_name: display_name.to_owned(),
+            op,
+            key_type: key_type.clone(),
+            value_type,
+        }))
+    }
+
+    /// Calls the function with the row, the key and the value of each entry of the maps.
+    fn for_each_entry<F>(&self, series: &Series, mut f: F) -> Result<()>
+    where F: FnMut(usize, DataValue, DataValue) -> Result<()> {
+        let array = series.list()?;
+        let (keys, values) = map_array_entries(array)?;
+        let offsets = array.inner().offsets().as_slice();
+
+        for row in 0..array.len() {
+            if array.is_null(row) {
+                continue;
+            }
+
+            for i in offsets[row] as usize..offsets[row + 1] as usize {
+                let value = values.try_get(i)?;
+                let value = match self.op {
+                    DataValueAggregateOperator::Sum => widen_value(&value, &self.value_type)?,
+                    _ => value,
+                };
+                f(row, keys.try_get(i)?, value)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+fn widen_value(value: &DataValue, data_type: &DataType) -> Result<DataValue> {
+    if value.is_null() {
+        return Ok(DataValue::from(data_type));
+    }
+
+    match data_type {
+        DataType::UInt64 => Ok(DataValue::UInt64(Some(value.as_u64()?))),
+        DataType::Int64 => Ok(DataValue::Int64(Some(value.as_i64()?))),
+        _ => Ok(DataValue::Float64(Some(value.as_f64()?))),
+    }
+}
+
+fn merge_value(
+    op: &DataValueAggregateOperator,
+    current: &DataValue,
+    value: DataValue,
+) -> Result<DataValue> {
+    match op {
+        DataValueAggregateOperator::Sum => match (current, &value) {
+            (DataValue::UInt64(Some(a)), DataValue::UInt64(Some(b))) => {
+                Ok(DataValue::UInt64(Some(a.wrapping_add(*b))))
+            }
+            (DataValue::Int64(Some(a)), DataValue::Int64(Some(b))) => {
+                Ok(DataValue::Int64(Some(a.wrapping_add(*b))))
+            }
+            (DataValue::Float64(Some(a)), DataValue::Float64(Some(b))) => {
+                Ok(DataValue::Float64(Some(a + b)))
+            }
+            _ => Err(ErrorCode::BadDataValueType(format!(
+                "Unexpected values to sum: {:?}, {:?}",
+                current, value
+            ))),
+        },
+        _ => match (compare_values(current, &value), op) {
+            (Ordering::Greater, DataValueAggregateOperator::Min)
+            | (Ordering::Less, DataValueAggregateOperator::Max) => Ok(value),
+            _ => Ok(current.clone()),
+        },
+    }
+}
+
+fn compare_values(a: &DataValue, b: &DataValue) -> Ordering {
+    match (a, b) {
+        (DataValue::String(Some(a)), DataValue::String(Some(b))) => a.cmp(b),
+        _ if a.is_unsigned_integer() => a.as_u64().ok().cmp(&b.as_u64().ok()),
+        _ if a.is_integer() => a.as_i64().ok().cmp(&b.as_i64().ok()),
+        _ => a
+            .as_f64()
+            .ok()
+            .partial_cmp(&b.as_f64().ok())
+            .unwrap_or(Ordering::Equal),
+    }
+}
+
+fn aggregate_map_function_desc(op: DataValueAggregateOperator) -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(move |display_name, _params, arguments| {
+        AggregateMapFunction::try_create(display_name, op.clone(), arguments)
+    }))
+}
+
+pub fn aggregate_sum_map_function_desc() -> AggregateFunctionDescription {
+    aggregate_map_function_desc(DataValueAggregateOperator::Sum)
+}
+
+pub fn aggregate_min_map_function_desc() -> AggregateFunctionDescription {
+    aggregate_map_function_desc(DataValueAggregateOperator::Min)
+}
+
+pub fn aggregate_max_map_function_desc() -> AggregateFunctionDescription {
+    aggregate_map_function_desc(DataValueAggregateOperator::Max)
+}
@@ -0,0 +1,289 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::alloc::Layout;
+use std::cmp::Ordering;
+use std::fmt;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_io::prelude::*;
+use num::cast::AsPrimitive;
+
+use super::StateAddr;
+use crate::aggregates::aggregate_function_factory::AggregateFunctionDescription;
+use crate::aggregates::assert_unary_arguments;
+use crate::aggregates::assert_variadic_params;
+use crate::aggregates::AggregateFunction;
+use crate::aggregates::AggregateFunctionRef;
+use crate::with_match_primitive_type;
+
+/// The level of the quantile from the parameters, such as `quantile(0.95)(x)`.
+pub fn get_quantile_level(
+    display_name: &str,
+    params: &[DataValue],
+    expected: (usize, usize),
+) -> Result<f64> {
+    assert_variadic_params(display_name, params.len(), expected)?;
+
+    let level = match params.first() {
+        Some(param) => param.as_f64()?,
+        None => 0.5,
+    };
+    if !(0.0..=1.0).contains(&level) {
+        return Err(ErrorCode::BadArguments(format!(
+            "The level of {} must be in [0, 1], but got {}",
+            display_name, level
+        )));
+    }
+    Ok(level)
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum QuantileKind {
+    /// The linear interpolation between the two closest values, the result is Float64.
+    Interpolated,
+    /// The first value whose cumulative distribution is not less than the level, the result has
+    /// the type of the argument.
+    Discrete,
+}
+
+struct AggregateQuantileState<T> {
+    values: Vec<T>,
+}
+
+impl<T> AggregateQuantileState<T>
+where T: DFPrimitiveType
+{
+    fn serialize(&self, writer: &mut BytesMut) -> Result<()> {
+        writer.write_uvarint(self.values.len() as u64)?;
+        for value in self.values.iter() {
+            value.serialize_to_buf(writer)?;
+        }
+        Ok(())
+    }
+
+    fn deserialize(&mut self, reader: &mut &[u8]) -> Result<()> {
+        self.values.clear();
+
+        let size = reader.read_uvarint()?;
+        self.values.reserve(size as usize);
+        for _i in 0..size {
+            self.values.push(T::deserialize(reader)?);
+        }
+        Ok(())
+    }
+}
+
+/// The exact quantiles, all the values are kept in the state and sorted at the end:
+/// - `quantile(level)(x)`, `median(x)` and `percentile_cont(level)(x)` interpolate linearly
+/// - `percentile_disc(level)(x)` is one of the values
+#[derive(Clone)]
+pub struct AggregateQuantileFunction<T> {
+    display_name: String,
+    arguments: Vec<DataField>,
+    level: f64,
+    kind: QuantileKind,
+    t: PhantomData<T>,
+}
+
+impl<T> AggregateFunction for AggregateQuantileFunction<T>
+where T: DFPrimitiveType + AsPrimitive<f64>
+{
+    fn name(&self) -> &str {
+        "AggregateQuantileFunction"
+    }
+
+    fn return_type(&self) -> Result<DataType> {
+        match self.kind {
+            QuantileKind::Interpolated => Ok(DataType::Float64),
+            QuantileKind::Discrete => Ok(self.arguments[0].data_type().clone()),
+        }
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn init_state(&self, place: StateAddr) {
+        place.write(|| AggregateQuantileState::<T> { values: vec![] });
+    }
+
+    fn state_layout(&self) -> Layout {
+        Layout::new::<AggregateQuantileState<T>>()
+    }
+
+    fn accumulate(&self, place: StateAddr, arrays: &[Series], _input_rows: usize) -> Result<()> {
+        let state = place.get::<AggregateQuantileState<T>>();
+        let array: &DFPrimitiveArray<T> = arrays[0].static_cast();
+
+        if array.null_count() == 0 {
+            state.values.extend(array.into_no_null_iter());
+        } else {
+            state.values.extend(array.iter().flatten());
+        }
+        Ok(())
+    }
+
+    fn accumulate_keys(
+        &self,
+        places: &[StateAddr],
+        offset: usize,
+        arrays: &[Series],
+        _input_rows: usize,
+    ) -> Result<()> {
+        let array: &DFPrimitiveArray<T> = arrays[0].static_cast();
+        array.iter().zip(places.iter()).for_each(|(value, place)| {
+            if let Some(value) = value {
+                let place = place.next(offset);
+                let state = place.get::<AggregateQuantileState<T>>();
+                state.values.push(*value);
+            }
+        });
+        Ok(())
+    }
+
+    fn serialize(&self, place: StateAddr, writer: &mut BytesMut) -> Result<()> {
+        let state = place.get::<AggregateQuantileState<T>>();
+        state.serialize(writer)
+    }
+
+    fn deserialize(&self, place: StateAddr, reader: &mut &[u8]) -> Result<()> {
+        let state = place.get::<AggregateQuantileState<T>>();
+        state.deserialize(reader)
+    }
+
+    fn merge(&self, place: StateAddr, rhs: StateAddr) -> Result<()> {
+        let state = place.get::<AggregateQuantileState<T>>();
+        let rhs = rhs.get::<AggregateQuantileState<T>>();
+        state.values.extend_from_slice(&rhs.values);
+        Ok(())
+    }
+
+    fn merge_result(&self, place: StateAddr) -> Result<DataValue> {
+        let state = place.get::<AggregateQuantileState<T>>();
+        if state.values.is_empty() {
+            return match self.kind {
+                QuantileKind::Interpolated => Ok(DataValue::Float64(None)),
+                QuantileKind::Discrete => Ok(DataValue::from(self.return_type()?)),
+            };
+        }
+
+        let values = &mut state.values;
+        values.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+
+        match self.kind {
+            QuantileKind::Interpolated => {
+                let position = self.level * (values.len() - 1) as f64;
+                let lower = position.floor() as usize;
+                let upper = position.ceil() as usize;
+
+                let lower_value: f64 = values[lower].as_();
+                let upper_value: f64 = values[upper].as_();
+                let value = lower_value + (upper_value - lower_value) * (position - lower as f64);
+                Ok(DataValue::Float64(Some(value)))
+            }
+            QuantileKind::Discrete => {
+                let index = (self.level * values.len() as f64).ceil() as usize;
+                let index = index.saturating_sub(1).min(values.len() - 1);
+                Ok(values[index].into())
+            }
+        }
+    }
+}
+
+impl<T> fmt::Display for AggregateQuantileFunction<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+fn try_create_aggregate_quantile_function(
+    display_name: &str,
+    arguments: Vec<DataField>,
+    level: f64,
+    kind: QuantileKind,
+) -> Result<AggregateFunctionRef> {
+    assert_unary_arguments(display_name, arguments.len())?;
+
+    let data_type = arguments[0].data_type().clone();
+    with_match_primitive_type!(&data_type, |$T| {
+        let func: AggregateFunctionRef = Arc::new(AggregateQuantileFunction::<$T> {
+            display_name: display_name.to_string(),
+            arguments,
+            level,
+            kind,
+            t: PhantomData,
+        });
+        Ok(func)
+    },
+
+    {
+        Err(ErrorCode::BadDataValueType(format!(
+            "{} does not support type '{:?}'",
+            display_name, data_type
+        )))
+    })
+}
+
+pub fn aggregate_quantile_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(|display_name, params, arguments| {
+        let level = get_quantile_level(display_name, &params, (0, 1))?;
+        try_create_aggregate_quantile_function(
+            display_name,
+            arguments,
+            level,
+            QuantileKind::Interpolated,
+        )
+    }))
+}
+
+pub fn aggregate_median_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(|display_name, params, arguments| {
+        let level = get_quantile_level(display_name, &params, (0, 0))?;
+        try_create_aggregate_quantile_function(
+            display_name,
+            arguments,
+            level,
+            QuantileKind::Interpolated,
+        )
+    }))
+}
+
+pub fn aggregate_percentile_cont_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(|display_name, params, arguments| {
+        let level = get_quantile_level(display_name, &params, (1, 1))?;
+        try_create_aggregate_quantile_function(
+            display_name,
+            arguments,
+            level,
+            QuantileKind::Interpolated,
+        )
+    }))
+}
+
+pub fn aggregate_percentile_disc_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(|display_name, params, arguments| {
+        let level = get_quantile_level(display_name, &params, (1, 1))?;
+        try_create_aggregate_quantile_function(
+            display_name,
+            arguments,
+            level,
+            QuantileKind::Discrete,
+        )
+    }))
+}
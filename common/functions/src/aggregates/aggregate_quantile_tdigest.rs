@@ -0,0 +1,309 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::alloc::Layout;
+use std::cmp::Ordering;
+use std::fmt;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_io::prelude::*;
+use num::cast::AsPrimitive;
+
+use super::StateAddr;
+use crate::aggregates::aggregate_function_factory::AggregateFunctionDescription;
+use crate::aggregates::aggregate_quantile::get_quantile_level;
+use crate::aggregates::assert_unary_arguments;
+use crate::aggregates::AggregateFunction;
+use crate::aggregates::AggregateFunctionRef;
+use crate::with_match_primitive_type;
+
+/// The larger the compression is, the more centroids are kept and the more accurate it is.
+const COMPRESSION: f64 = 100.0;
+/// The unmerged points are buffered and compressed together.
+const MAX_UNMERGED: usize = 500;
+
+#[derive(Clone, Copy, Debug)]
+struct Centroid {
+    mean: f64,
+    weight: f64,
+}
+
+/// The merging t-digest, the centroids near the tails are smaller so the extreme quantiles are
+/// more accurate, see "Computing Extremely Accurate Quantiles Using t-Digests" by Ted Dunning.
+struct AggregateTDigestState {
+    centroids: Vec<Centroid>,
+    unmerged: Vec<Centroid>,
+    min: f64,
+    max: f64,
+}
+
+impl AggregateTDigestState {
+    #[inline(always)]
+    fn add(&mut self, value: f64) {
+        if value.is_nan() {
+            return;
+        }
+        self.add_centroid(Centroid {
+            mean: value,
+            weight: 1.0,
+        });
+    }
+
+    fn add_centroid(&mut self, centroid: Centroid) {
+        self.min = self.min.min(centroid.mean);
+        self.max = self.max.max(centroid.mean);
+        self.unmerged.push(centroid);
+        if self.unmerged.len() >= MAX_UNMERGED {
+            self.compress();
+        }
+    }
+
+    fn merge(&mut self, other: &Self) {
+        for centroid in other.centroids.iter().chain(other.unmerged.iter()) {
+            self.add_centroid(*centroid);
+        }
+    }
+
+    /// Merges the adjacent centroids as long as the weight of the merged one is within the
+    /// bound `4 * total * q * (1 - q) / compression`, where q is the quantile of the centroid.
+    fn compress(&mut self) {
+        if self.unmerged.is_empty() {
+            return;
+        }
+
+        let mut centroids = std::mem::take(&mut self.unmerged);
+        centroids.append(&mut self.centroids);
+        centroids.sort_by(|a, b| a.mean.partial_cmp(&b.mean).unwrap_or(Ordering::Equal));
+
+        let total: f64 = centroids.iter().map(|c| c.weight).sum();
+        let mut merged = Vec::with_capacity(centroids.len());
+        let mut current = centroids[0];
+        let mut weight_so_far = 0.0;
+        for centroid in centroids.into_iter().skip(1) {
+            let proposed = current.weight + centroid.weight;
+            let q0 = weight_so_far / total;
+            let q2 = (weight_so_far + proposed) / total;
+            let limit = 4.0 * total * (q0 * (1.0 - q0)).min(q2 * (1.0 - q2)) / COMPRESSION;
+
+            if proposed <= limit {
+                current.mean += (centroid.mean - current.mean) * centroid.weight / proposed;
+                current.weight = proposed;
+            } else {
+                weight_so_far += current.weight;
+                merged.push(current);
+                current = centroid;
+            }
+        }
+        merged.push(current);
+        self.centroids = merged;
+    }
+
+    /// Interpolates between the centers of the adjacent centroids, the min and the max are the
+    /// bounds of the first and the last centroids.
+    fn quantile(&mut self, level: f64) -> Option<f64> {
+        self.compress();
+        if self.centroids.is_empty() {
+            return None;
+        }
+
+        let total: f64 = self.centroids.iter().map(|c| c.weight).sum();
+        let target = level * total;
+
+        let first = &self.centroids[0];
+        if target <= first.weight / 2.0 {
+            return Some(self.min + (first.mean - self.min) * target / (first.weight / 2.0));
+        }
+
+        let mut weight_so_far = first.weight / 2.0;
+        for pair in self.centroids.windows(2) {
+            let step = (pair[0].weight + pair[1].weight) / 2.0;
+            if target <= weight_so_far + step {
+                let ratio = (target - weight_so_far) / step;
+                return Some(pair[0].mean + (pair[1].mean - pair[0].mean) * ratio);
+            }
+            weight_so_far += step;
+        }
+
+        let last = &self.centroids[self.centroids.len() - 1];
+        let ratio = ((target - weight_so_far) / (last.weight / 2.0)).min(1.0);
+        Some(last.mean + (self.max - last.mean) * ratio)
+    }
+
+    fn serialize(&mut self, writer: &mut BytesMut) -> Result<()> {
+        self.compress();
+        writer.write_uvarint(self.centroids.len() as u64)?;
+        for centroid in self.centroids.iter() {
+            centroid.mean.serialize_to_buf(writer)?;
+            centroid.weight.serialize_to_buf(writer)?;
+        }
+        self.min.serialize_to_buf(writer)?;
+        self.max.serialize_to_buf(writer)
+    }
+
+    fn deserialize(&mut self, reader: &mut &[u8]) -> Result<()> {
+        self.centroids.clear();
+        self.unmerged.clear();
+
+        let size = reader.read_uvarint()?;
+        self.centroids.reserve(size as usize);
+        for _i in 0..size {
+            let mean = f64::deserialize(reader)?;
+            let weight = f64::deserialize(reader)?;
+            self.centroids.push(Centroid { mean, weight });
+        }
+        self.min = f64::deserialize(reader)?;
+        self.max = f64::deserialize(reader)?;
+        Ok(())
+    }
+}
+
+/// The approximate quantile with the mergeable t-digest state: `quantileTDigest(level)(x)`.
+#[derive(Clone)]
+pub struct AggregateQuantileTDigestFunction<T> {
+    display_name: String,
+    level: f64,
+    t: PhantomData<T>,
+}
+
+impl<T> AggregateFunction for AggregateQuantileTDigestFunction<T>
+where T: DFPrimitiveType + AsPrimitive<f64>
+{
+    fn name(&self) -> &str {
+        "AggregateQuantileTDigestFunction"
+    }
+
+    fn return_type(&self) -> Result<DataType> {
+        Ok(DataType::Float64)
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn init_state(&self, place: StateAddr) {
+        place.write(|| AggregateTDigestState {
+            centroids: vec![],
+            unmerged: vec![],
+            min: f64::INFINITY,
+            max: f64::NEG_INFINITY,
+        });
+    }
+
+    fn state_layout(&self) -> Layout {
+        Layout::new::<AggregateTDigestState>()
+    }
+
+    fn accumulate(&self, place: StateAddr, arrays: &[Series], _input_rows: usize) -> Result<()> {
+        let state = place.get::<AggregateTDigestState>();
+        let array: &DFPrimitiveArray<T> = arrays[0].static_cast();
+
+        if array.null_count() == 0 {
+            for value in array.into_no_null_iter() {
+                state.add(value.as_());
+            }
+        } else {
+            for value in array.iter().flatten() {
+                state.add(value.as_());
+            }
+        }
+        Ok(())
+    }
+
+    fn accumulate_keys(
+        &self,
+        places: &[StateAddr],
+        offset: usize,
+        arrays: &[Series],
+        _input_rows: usize,
+    ) -> Result<()> {
+        let array: &DFPrimitiveArray<T> = arrays[0].static_cast();
+        array.iter().zip(places.iter()).for_each(|(value, place)| {
+            if let Some(value) = value {
+                let place = place.next(offset);
+                let state = place.get::<AggregateTDigestState>();
+                state.add(value.as_());
+            }
+        });
+        Ok(())
+    }
+
+    fn serialize(&self, place: StateAddr, writer: &mut BytesMut) -> Result<()> {
+        let state = place.get::<AggregateTDigestState>();
+        state.serialize(writer)
+    }
+
+    fn deserialize(&self, place: StateAddr, reader: &mut &[u8]) -> Result<()> {
+        let state = place.get::<AggregateTDigestState>();
+        state.deserialize(reader)
+    }
+
+    fn merge(&self, place: StateAddr, rhs: StateAddr) -> Result<()> {
+        let state = place.get::<AggregateTDigestState>();
+        let rhs = rhs.get::<AggregateTDigestState>();
+        state.merge(rhs);
+        Ok(())
+    }
+
+    fn merge_result(&self, place: StateAddr) -> Result<DataValue> {
+        let state = place.get::<AggregateTDigestState>();
+        Ok(DataValue::Float64(state.quantile(self.level)))
+    }
+}
+
+impl<T> fmt::Display for AggregateQuantileTDigestFunction<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+impl<T> AggregateQuantileTDigestFunction<T>
+where T: DFPrimitiveType + AsPrimitive<f64>
+{
+    pub fn try_create(display_name: &str, level: f64) -> Result<AggregateFunctionRef> {
+        Ok(Arc::new(Self {
+            display_name: display_name.to_string(),
+            level,
+            t: PhantomData,
+        }))
+    }
+}
+
+pub fn try_create_aggregate_quantile_tdigest_function(
+    display_name: &str,
+    params: Vec<DataValue>,
+    arguments: Vec<DataField>,
+) -> Result<AggregateFunctionRef> {
+    assert_unary_arguments(display_name, arguments.len())?;
+    let level = get_quantile_level(display_name, &params, (0, 1))?;
+
+    let data_type = arguments[0].data_type();
+    with_match_primitive_type!(data_type, |$T| {
+        AggregateQuantileTDigestFunction::<$T>::try_create(display_name, level)
+    },
+
+    {
+        Err(ErrorCode::BadDataValueType(format!(
+            "AggregateQuantileTDigestFunction does not support type '{:?}'",
+            data_type
+        )))
+    })
+}
+
+pub fn aggregate_quantile_tdigest_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(try_create_aggregate_quantile_tdigest_function))
+}
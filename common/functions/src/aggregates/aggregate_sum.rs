@@ -29,6 +29,7 @@ use super::AggregateFunctionRef;
 use super::StateAddr;
 use crate::aggregates::aggregate_function_factory::AggregateFunctionDescription;
 use crate::aggregates::aggregator_common::assert_unary_arguments;
+use crate::aggregates::AggregateDecimalSumAvgFunction;
 use crate::aggregates::AggregateFunction;
 use crate::with_match_primitive_type;
 
@@ -201,6 +202,10 @@ pub fn try_create_aggregate_sum_function(
     assert_unary_arguments(display_name, arguments.len())?;
 
     let data_type = arguments[0].data_type();
+    if data_type.is_decimal() {
+        return AggregateDecimalSumAvgFunction::try_create(display_name, false, arguments);
+    }
+
     with_match_primitive_type!(data_type, |$T| {
         AggregateSumFunction::<$T, <$T as DFPrimitiveType>::LargestType>::try_create(
              display_name,
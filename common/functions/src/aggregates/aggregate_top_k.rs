@@ -0,0 +1,372 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::alloc::Layout;
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::fmt;
+use std::sync::Arc;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_io::prelude::*;
+
+use super::StateAddr;
+use crate::aggregates::aggregate_function_factory::AggregateFunctionDescription;
+use crate::aggregates::assert_unary_arguments;
+use crate::aggregates::assert_variadic_params;
+use crate::aggregates::AggregateFunction;
+use crate::aggregates::AggregateFunctionRef;
+
+const TOP_K_DEFAULT_SIZE: u64 = 10;
+const TOP_K_MAX_SIZE: u64 = 0xFFFFFF;
+/// More counters than K are kept, so the values near the K-th one are less likely to be evicted.
+const TOP_K_RESERVED_FACTOR: usize = 3;
+
+struct Counter {
+    key: DataGroupValue,
+    value: DataValue,
+    count: u64,
+    /// The upper bound of the overestimation of the count.
+    error: u64,
+    /// The order in which the values are monitored, the earlier one wins the tie of the counts.
+    seq: u64,
+}
+
+/// The space-saving sketch, see "Efficient Computation of Frequent and Top-k Elements in Data
+/// Streams" by Metwally et al. When all the counters are taken, the new value replaces the one
+/// with the minimal count and inherits that count as its error.
+///
+/// The counters are kept in a binary min-heap of the counts, so that the one with the minimal
+/// count is always on the top, the positions of the counters in the heap are tracked by keys.
+struct AggregateTopKState {
+    counters: Vec<Counter>,
+    positions: HashMap<DataGroupValue, usize>,
+    next_seq: u64,
+}
+
+impl AggregateTopKState {
+    fn add(&mut self, value: DataValue, capacity: usize) -> Result<()> {
+        if value.is_null() {
+            return Ok(());
+        }
+
+        let key = DataGroupValue::try_from(&value)?;
+        let seq = self.next_seq;
+        if let Some(position) = self.positions.get(&key) {
+            let position = *position;
+            self.counters[position].count += 1;
+            self.sift_down(position);
+            return Ok(());
+        }
+
+        self.next_seq += 1;
+        if self.counters.len() < capacity {
+            self.positions.insert(key.clone(), self.counters.len());
+            self.counters.push(Counter {
+                key,
+                value,
+                count: 1,
+                error: 0,
+                seq,
+            });
+            self.sift_up(self.counters.len() - 1);
+        } else if !self.counters.is_empty() {
+            let counter = &mut self.counters[0];
+            self.positions.remove(&counter.key);
+            self.positions.insert(key.clone(), 0);
+            *counter = Counter {
+                key,
+                value,
+                count: counter.count + 1,
+                error: counter.count,
+                seq,
+            };
+            self.sift_down(0);
+        }
+        Ok(())
+    }
+
+    fn sift_up(&mut self, mut position: usize) {
+        while position > 0 {
+            let parent = (position - 1) / 2;
+            if self.counters[position].count >= self.counters[parent].count {
+                break;
+            }
+            self.swap(position, parent);
+            position = parent;
+        }
+    }
+
+    fn sift_down(&mut self, mut position: usize) {
+        let len = self.counters.len();
+        loop {
+            let left = 2 * position + 1;
+            if left >= len {
+                break;
+            }
+            let right = left + 1;
+            let child = match right < len && self.counters[right].count < self.counters[left].count
+            {
+                true => right,
+                false => left,
+            };
+            if self.counters[child].count >= self.counters[position].count {
+                break;
+            }
+            self.swap(position, child);
+            position = child;
+        }
+    }
+
+    fn swap(&mut self, a: usize, b: usize) {
+        self.counters.swap(a, b);
+        for position in [a, b] {
+            if let Some(p) = self.positions.get_mut(&self.counters[position].key) {
+                *p = position;
+            }
+        }
+    }
+
+    /// The count of a value which is not monitored is at most the minimal count of a full sketch.
+    fn unmonitored_count(&self, capacity: usize) -> u64 {
+        match self.counters.len() < capacity {
+            true => 0,
+            false => self.counters.first().map(|c| c.count).unwrap_or(0),
+        }
+    }
+
+    /// Merges the sketches as described in "Mergeable Summaries" by Agarwal et al.
+    fn merge(&mut self, other: &Self, capacity: usize) {
+        let self_min = self.unmonitored_count(capacity);
+        let other_min = other.unmonitored_count(capacity);
+
+        for counter in self.counters.iter_mut() {
+            match other.positions.get(&counter.key) {
+                Some(position) => {
+                    counter.count += other.counters[*position].count;
+                    counter.error += other.counters[*position].error;
+                }
+                None => {
+                    counter.count += other_min;
+                    counter.error += other_min;
+                }
+            }
+        }
+
+        for counter in other.counters.iter() {
+            if !self.positions.contains_key(&counter.key) {
+                self.counters.push(Counter {
+                    key: counter.key.clone(),
+                    value: counter.value.clone(),
+                    count: counter.count + self_min,
+                    error: counter.error + self_min,
+                    seq: self.next_seq + counter.seq,
+                });
+            }
+        }
+        self.next_seq += other.next_seq;
+
+        self.truncate(capacity);
+    }
+
+    /// Keeps the `capacity` counters with the largest counts, in the ascending order of the
+    /// counts (the later monitored first on ties), which is a valid heap as well.
+    fn truncate(&mut self, capacity: usize) {
+        self.counters
+            .sort_by(|a, b| b.count.cmp(&a.count).then(a.seq.cmp(&b.seq)));
+        self.counters.truncate(capacity);
+        self.counters.reverse();
+        self.positions = self
+            .counters
+            .iter()
+            .enumerate()
+            .map(|(position, counter)| (counter.key.clone(), position))
+            .collect();
+    }
+
+    fn serialize(&self, writer: &mut BytesMut) -> Result<()> {
+        // in the monitored order, which is restored by the positions
+        let mut counters = self.counters.iter().collect::<Vec<_>>();
+        counters.sort_by_key(|counter| counter.seq);
+
+        writer.write_uvarint(counters.len() as u64)?;
+        for counter in counters {
+            counter.value.serialize_to_buf(writer)?;
+            counter.count.serialize_to_buf(writer)?;
+            counter.error.serialize_to_buf(writer)?;
+        }
+        Ok(())
+    }
+
+    fn deserialize(&mut self, reader: &mut &[u8]) -> Result<()> {
+        self.counters.clear();
+        self.positions.clear();
+
+        let size = reader.read_uvarint()?;
+        self.counters.reserve(size as usize);
+        self.positions.reserve(size as usize);
+        for position in 0..size as usize {
+            let value = DataValue::deserialize(reader)?;
+            let count = u64::deserialize(reader)?;
+            let error = u64::deserialize(reader)?;
+            let key = DataGroupValue::try_from(&value)?;
+
+            self.positions.insert(key.clone(), position);
+            self.counters.push(Counter {
+                key,
+                value,
+                count,
+                error,
+                seq: position as u64,
+            });
+        }
+        self.next_seq = size;
+
+        for position in (0..self.counters.len() / 2).rev() {
+            self.sift_down(position);
+        }
+        Ok(())
+    }
+}
+
+/// Returns an array of the approximately most frequent values in the descending order of their
+/// counts: `topK(N)(x)`, N is 10 by default.
+#[derive(Clone)]
+pub struct AggregateTopKFunction {
+    display_name: String,
+    item_type: DataType,
+    k: usize,
+    capacity: usize,
+}
+
+impl AggregateFunction for AggregateTopKFunction {
+    fn name(&self) -> &str {
+        "AggregateTopKFunction"
+    }
+
+    fn return_type(&self) -> Result<DataType> {
+        Ok(create_list_type(self.item_type.clone()))
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn init_state(&self, place: StateAddr) {
+        place.write(|| AggregateTopKState {
+            counters: vec![],
+            positions: HashMap::new(),
+            next_seq: 0,
+        });
+    }
+
+    fn state_layout(&self) -> Layout {
+        Layout::new::<AggregateTopKState>()
+    }
+
+    fn accumulate(&self, place: StateAddr, arrays: &[Series], input_rows: usize) -> Result<()> {
+        let state = place.get::<AggregateTopKState>();
+        for row in 0..input_rows {
+            state.add(arrays[0].try_get(row)?, self.capacity)?;
+        }
+        Ok(())
+    }
+
+    fn accumulate_keys(
+        &self,
+        places: &[StateAddr],
+        offset: usize,
+        arrays: &[Series],
+        _input_rows: usize,
+    ) -> Result<()> {
+        for (row, place) in places.iter().enumerate() {
+            let place = place.next(offset);
+            let state = place.get::<AggregateTopKState>();
+            state.add(arrays[0].try_get(row)?, self.capacity)?;
+        }
+        Ok(())
+    }
+
+    fn serialize(&self, place: StateAddr, writer: &mut BytesMut) -> Result<()> {
+        let state = place.get::<AggregateTopKState>();
+        state.serialize(writer)
+    }
+
+    fn deserialize(&self, place: StateAddr, reader: &mut &[u8]) -> Result<()> {
+        let state = place.get::<AggregateTopKState>();
+        state.deserialize(reader)
+    }
+
+    fn merge(&self, place: StateAddr, rhs: StateAddr) -> Result<()> {
+        let state = place.get::<AggregateTopKState>();
+        let rhs = rhs.get::<AggregateTopKState>();
+        state.merge(rhs, self.capacity);
+        Ok(())
+    }
+
+    fn merge_result(&self, place: StateAddr) -> Result<DataValue> {
+        let state = place.get::<AggregateTopKState>();
+        state.truncate(self.capacity);
+
+        let values = state
+            .counters
+            .iter()
+            .rev()
+            .take(self.k)
+            .map(|counter| counter.value.clone())
+            .collect::<Vec<_>>();
+        Ok(DataValue::List(Some(values), self.item_type.clone()))
+    }
+}
+
+impl fmt::Display for AggregateTopKFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+impl AggregateTopKFunction {
+    pub fn try_create(
+        display_name: &str,
+        params: Vec<DataValue>,
+        arguments: Vec<DataField>,
+    ) -> Result<AggregateFunctionRef> {
+        assert_variadic_params(display_name, params.len(), (0, 1))?;
+        assert_unary_arguments(display_name, arguments.len())?;
+
+        let k = match params.first() {
+            Some(param) => param.as_u64()?,
+            None => TOP_K_DEFAULT_SIZE,
+        };
+        if k == 0 || k > TOP_K_MAX_SIZE {
+            return Err(ErrorCode::BadArguments(format!(
+                "The parameter of {} must be in [1, {}], but got {}",
+                display_name, TOP_K_MAX_SIZE, k
+            )));
+        }
+
+        Ok(Arc::new(AggregateTopKFunction {
+            display_name: display_name.to_owned(),
+            item_type: arguments[0].data_type().clone(),
+            k: k as usize,
+            capacity: k as usize * TOP_K_RESERVED_FACTOR,
+        }))
+    }
+}
+
+pub fn aggregate_top_k_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(AggregateTopKFunction::try_create))
+}
@@ -12,17 +12,31 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use crate::aggregates::aggregate_any::aggregate_any_function_desc;
+use crate::aggregates::aggregate_any::aggregate_any_last_function_desc;
 use crate::aggregates::aggregate_arg_min_max::aggregate_arg_max_function_desc;
 use crate::aggregates::aggregate_arg_min_max::aggregate_arg_min_function_desc;
 use crate::aggregates::aggregate_avg::aggregate_avg_function_desc;
 use crate::aggregates::aggregate_covariance::aggregate_covariance_population_desc;
 use crate::aggregates::aggregate_covariance::aggregate_covariance_sample_desc;
 use crate::aggregates::aggregate_function_factory::AggregateFunctionFactory;
+use crate::aggregates::aggregate_group_array::aggregate_group_array_function_desc;
+use crate::aggregates::aggregate_group_uniq_array::aggregate_group_uniq_array_function_desc;
+use crate::aggregates::aggregate_map::aggregate_max_map_function_desc;
+use crate::aggregates::aggregate_map::aggregate_min_map_function_desc;
+use crate::aggregates::aggregate_map::aggregate_sum_map_function_desc;
 use crate::aggregates::aggregate_min_max::aggregate_max_function_desc;
 use crate::aggregates::aggregate_min_max::aggregate_min_function_desc;
+use crate::aggregates::aggregate_quantile::aggregate_median_function_desc;
+use crate::aggregates::aggregate_quantile::aggregate_percentile_cont_function_desc;
+use crate::aggregates::aggregate_quantile::aggregate_percentile_disc_function_desc;
+use crate::aggregates::aggregate_quantile::aggregate_quantile_function_desc;
+use crate::aggregates::aggregate_quantile_tdigest::aggregate_quantile_tdigest_function_desc;
 use crate::aggregates::aggregate_stddev_pop::aggregate_stddev_pop_function_desc;
 use crate::aggregates::aggregate_sum::aggregate_sum_function_desc;
+use crate::aggregates::aggregate_top_k::aggregate_top_k_function_desc;
 use crate::aggregates::aggregate_window_funnel::aggregate_window_funnel_function_desc;
+use crate::aggregates::AggregateApproxCountDistinctFunction;
 use crate::aggregates::AggregateCountFunction;
 use crate::aggregates::AggregateDistinctCombinator;
 use crate::aggregates::AggregateIfCombinator;
@@ -44,8 +58,29 @@ impl Aggregators {
         factory.register("stddev_pop", aggregate_stddev_pop_function_desc());
         factory.register("windowFunnel", aggregate_window_funnel_function_desc());
         factory.register("uniq", AggregateDistinctCombinator::uniq_desc());
+        factory.register(
+            "approx_count_distinct",
+            AggregateApproxCountDistinctFunction::desc(),
+        );
+        factory.register("uniqHLL12", AggregateApproxCountDistinctFunction::desc());
         factory.register("covar_samp", aggregate_covariance_sample_desc());
         factory.register("covar_pop", aggregate_covariance_population_desc());
+        factory.register("sumMap", aggregate_sum_map_function_desc());
+        factory.register("minMap", aggregate_min_map_function_desc());
+        factory.register("maxMap", aggregate_max_map_function_desc());
+        factory.register("groupArray", aggregate_group_array_function_desc());
+        factory.register("groupUniqArray", aggregate_group_uniq_array_function_desc());
+        factory.register("topK", aggregate_top_k_function_desc());
+        factory.register("any", aggregate_any_function_desc());
+        factory.register("anyLast", aggregate_any_last_function_desc());
+        factory.register("quantile", aggregate_quantile_function_desc());
+        factory.register("median", aggregate_median_function_desc());
+        factory.register(
+            "quantileTDigest",
+            aggregate_quantile_tdigest_function_desc(),
+        );
+        factory.register("percentile_cont", aggregate_percentile_cont_function_desc());
+        factory.register("percentile_disc", aggregate_percentile_disc_function_desc());
     }
 
     pub fn register_combinator(factory: &mut AggregateFunctionFactory) {
@@ -70,3 +70,17 @@ pub fn assert_variadic_arguments<D: Display>(
     }
     Ok(())
 }
+
+pub fn assert_variadic_params<D: Display>(
+    name: D,
+    actual: usize,
+    expected: (usize, usize),
+) -> Result<()> {
+    if actual < expected.0 || actual > expected.1 {
+        return Err(ErrorCode::NumberArgumentsNotMatch(format!(
+            "{} expect to have [{}, {}] parameters, but got {}",
+            name, expected.0, expected.1, actual
+        )));
+    }
+    Ok(())
+}
@@ -20,6 +20,7 @@ use common_datavalues::DataSchema;
 use common_datavalues::DataValueArithmeticOperator;
 use common_exception::Result;
 
+use crate::scalars::arithmetics::arithmetic_decimal::DecimalArithmetic;
 use crate::scalars::dates::IntervalFunctionFactory;
 use crate::scalars::function_factory::FunctionFactory;
 use crate::scalars::ArithmeticDivFunction;
@@ -76,6 +77,9 @@ impl Function for ArithmeticFunction {
         if args[0].is_date_or_date_time() || args[1].is_date_or_date_time() {
             return datetime_arithmetic_coercion(&self.op, &args[0], &args[1]);
         }
+        if args[0].is_decimal() || args[1].is_decimal() {
+            return decimal_arithmetic_coercion(&self.op, &args[0], &args[1]);
+        }
         numerical_arithmetic_coercion(&self.op, &args[0], &args[1])
     }
 
@@ -84,6 +88,15 @@ impl Function for ArithmeticFunction {
     }
 
     fn eval(&self, columns: &DataColumnsWithField, _input_rows: usize) -> Result<DataColumn> {
+        if columns.iter().any(|c| c.data_type().is_decimal()) {
+            let args = columns
+                .iter()
+                .map(|f| f.data_type().clone())
+                .collect::<Vec<_>>();
+            let data_type = self.return_type(&args)?;
+            return DecimalArithmetic::eval(&self.op, columns, &data_type);
+        }
+
         let result: DataColumn = {
             // Some logic type need DateType information, try arithmetic on column with field first.
             if let Some(f) = IntervalFunctionFactory::try_get_arithmetic_func(columns) {
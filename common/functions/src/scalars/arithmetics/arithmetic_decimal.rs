@@ -0,0 +1,134 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::columns::DataColumn;
+use common_datavalues::prelude::*;
+use common_datavalues::DataValueArithmeticOperator;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+// Decimals are scaled Int64 values, the arithmetic is done on i128 so that the intermediate
+// results can't overflow before they are checked against the precision of the result type.
+pub struct DecimalArithmetic;
+
+impl DecimalArithmetic {
+    pub fn eval(
+        op: &DataValueArithmeticOperator,
+        columns: &DataColumnsWithField,
+        result_type: &DataType,
+    ) -> Result<DataColumn> {
+        // Decimal mixed with float is computed as Float64.
+        if result_type.is_floating() {
+            let columns = columns
+                .iter()
+                .map(Self::to_float_column)
+                .collect::<Result<Vec<_>>>()?;
+            return match columns.len() {
+                1 => columns[0].unary_arithmetic(op.clone()),
+                _ => columns[0].arithmetic(op.clone(), &columns[1]),
+            };
+        }
+
+        if columns.len() == 1 {
+            let array = Self::to_decimal_array(&columns[0])?;
+            let result: DFInt64Array = match op {
+                DataValueArithmeticOperator::Minus => array.apply(|v| -v),
+                _ => array,
+            };
+            return Ok(result.into());
+        }
+
+        let lhs_scale = Self::scale(columns[0].data_type());
+        let rhs_scale = Self::scale(columns[1].data_type());
+        let lhs = Self::to_decimal_array(&columns[0])?;
+        let rhs = Self::to_decimal_array(&columns[1])?;
+
+        let (precision, scale) = match result_type {
+            DataType::Decimal(precision, scale) => (*precision, *scale),
+            _ => (MAX_DECIMAL_PRECISION, 0),
+        };
+
+        // Align both sides to the same scale for the additive operators.
+        let common_scale = std::cmp::max(lhs_scale, rhs_scale);
+        let lhs_factor = decimal_factor(common_scale - lhs_scale) as i128;
+        let rhs_factor = decimal_factor(common_scale - rhs_scale) as i128;
+
+        let compute = |a: i64, b: i64| -> Result<i128> {
+            let (a, b) = (a as i128, b as i128);
+            if b == 0
+                && matches!(
+                    op,
+                    DataValueArithmeticOperator::Div
+                        | DataValueArithmeticOperator::IntDiv
+                        | DataValueArithmeticOperator::Modulo
+                )
+            {
+                return Err(ErrorCode::BadArguments("Division by zero"));
+            }
+
+            Ok(match op {
+                DataValueArithmeticOperator::Plus => a * lhs_factor + b * rhs_factor,
+                DataValueArithmeticOperator::Minus => a * lhs_factor - b * rhs_factor,
+                DataValueArithmeticOperator::Mul => a * b,
+                DataValueArithmeticOperator::Div => {
+                    let factor = decimal_factor(rhs_scale + scale - lhs_scale) as i128;
+                    div_round(a * factor, b)
+                }
+                DataValueArithmeticOperator::IntDiv => (a * lhs_factor) / (b * rhs_factor),
+                DataValueArithmeticOperator::Modulo => (a * lhs_factor) % (b * rhs_factor),
+            })
+        };
+
+        let result = lhs
+            .into_iter()
+            .zip(rhs.into_iter())
+            .map(|(a, b)| match (a, b) {
+                (Some(a), Some(b)) => Ok(Some(check_decimal(compute(*a, *b)?, precision)?)),
+                _ => Ok(None),
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(DFInt64Array::new_from_opt_iter(result.into_iter()).into())
+    }
+
+    fn scale(data_type: &DataType) -> u8 {
+        match data_type {
+            DataType::Decimal(_, scale) => *scale,
+            _ => 0,
+        }
+    }
+
+    // The scaled Int64 values of a decimal column, integers have the scale 0.
+    fn to_decimal_array(column: &DataColumnWithField) -> Result<DFInt64Array> {
+        let array = column.column().to_array()?;
+        let array = match column.data_type() {
+            DataType::Decimal(_, _) => array,
+            _ => array.cast_with_type(&DataType::Int64)?,
+        };
+        Ok(array.i64()?.clone())
+    }
+
+    fn to_float_column(column: &DataColumnWithField) -> Result<DataColumn> {
+        match column.data_type() {
+            DataType::Decimal(_, scale) => {
+                let array = column.column().to_array()?;
+                let array = array
+                    .i64()?
+                    .apply_cast_numeric(|v| decimal_to_f64(v, *scale));
+                Ok(array.into())
+            }
+            _ => Ok(column.column().clone()),
+        }
+    }
+}
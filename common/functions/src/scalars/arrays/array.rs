@@ -0,0 +1,105 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::maps::cast_to_series;
+use crate::scalars::Function;
+
+/// Creates an array from the arguments, `array(1, 2, 3)`. The items are cast to the
+/// common type of the arguments.
+#[derive(Clone)]
+pub struct ArrayFunction {
+    display_name: String,
+}
+
+impl ArrayFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(ArrayFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic())
+    }
+}
+
+impl Function for ArrayFunction {
+    fn name(&self) -> &str {
+        "array"
+    }
+
+    fn num_arguments(&self) -> usize {
+        0
+    }
+
+    fn variadic_arguments(&self) -> Option<(usize, usize)> {
+        Some((1, usize::MAX))
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        match aggregate_types(args)? {
+            DataType::Null => Err(ErrorCode::IllegalDataType(format!(
+                "Function {} can't infer the item type from NULL",
+                self.display_name
+            ))),
+            item_type => Ok(create_list_type(item_type)),
+        }
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let args = columns
+            .iter()
+            .map(|c| c.data_type().clone())
+            .collect::<Vec<_>>();
+        let item_type = match self.return_type(&args)? {
+            DataType::List(field) => field.data_type().clone(),
+            _ => unreachable!(),
+        };
+
+        let items = columns
+            .iter()
+            .map(|column| cast_to_series(column, &item_type, input_rows))
+            .collect::<Result<Vec<_>>>()?;
+
+        let arrays = (0..input_rows)
+            .map(|row| {
+                let values = items
+                    .iter()
+                    .map(|item| item.try_get(row))
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(DataValue::List(Some(values), item_type.clone()))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(build_list_array(&arrays, &item_type)?.into())
+    }
+}
+
+impl fmt::Display for ArrayFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
@@ -0,0 +1,126 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::maps::cast_to_series;
+use crate::scalars::Function;
+
+/// Returns the item of the array by the index, `arrayElement(arr, n)` or `arr[n]`.
+/// The index starts from 1, the negative index counts from the end of the array,
+/// NULL is returned if the index is out of range.
+#[derive(Clone)]
+pub struct ArrayElementFunction {
+    display_name: String,
+}
+
+impl ArrayElementFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(ArrayElementFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic())
+    }
+
+    pub(crate) fn item_type(args: &[DataType]) -> Result<DataType> {
+        let item_type = match &args[0] {
+            DataType::List(field) => field.data_type().clone(),
+            other => {
+                return Err(ErrorCode::IllegalDataType(format!(
+                    "Expected array, but got {}",
+                    other
+                )));
+            }
+        };
+
+        if !args[1].is_integer() && args[1] != DataType::Null {
+            return Err(ErrorCode::IllegalDataType(format!(
+                "Expected the index of array is integer, but got {}",
+                args[1]
+            )));
+        }
+        Ok(item_type)
+    }
+}
+
+impl Function for ArrayElementFunction {
+    fn name(&self) -> &str {
+        "arrayElement"
+    }
+
+    fn num_arguments(&self) -> usize {
+        2
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        Self::item_type(args)
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(true)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        array_element(&columns[0], &columns[1], input_rows)
+    }
+}
+
+impl fmt::Display for ArrayElementFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+pub(crate) fn array_element(
+    array: &DataColumnWithField,
+    index: &DataColumnWithField,
+    input_rows: usize,
+) -> Result<DataColumn> {
+    let args = [array.data_type().clone(), index.data_type().clone()];
+    let item_type = ArrayElementFunction::item_type(&args)?;
+
+    let series = array.column().to_array()?;
+    let array = series.list()?;
+    let items = list_array_items(array);
+    let offsets = array.inner().offsets().as_slice();
+    let indices = cast_to_series(index, &DataType::Int64, input_rows)?;
+    let indices = indices.i64()?;
+
+    let mut result = Vec::with_capacity(input_rows);
+    for (row, index) in indices.into_iter().enumerate() {
+        let (start, end) = (offsets[row], offsets[row + 1]);
+        let position = match index {
+            Some(index) if !array.is_null(row) && *index > 0 => Some(start + index - 1),
+            Some(index) if !array.is_null(row) && *index < 0 => Some(end + index),
+            _ => None,
+        };
+        match position {
+            Some(position) if position >= start && position < end => {
+                result.push(items.try_get(position as usize)?)
+            }
+            _ => result.push(DataValue::from(&item_type)),
+        }
+    }
+    Ok(DataValue::try_into_data_array(&result, &item_type)?.into())
+}
@@ -0,0 +1,110 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::maps::cast_to_series;
+use crate::scalars::Function;
+
+/// Checks whether the array contains the value, `has(arr, x)`.
+#[derive(Clone)]
+pub struct ArrayHasFunction {
+    display_name: String,
+}
+
+impl ArrayHasFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(ArrayHasFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic().bool_function())
+    }
+}
+
+impl Function for ArrayHasFunction {
+    fn name(&self) -> &str {
+        "has"
+    }
+
+    fn num_arguments(&self) -> usize {
+        2
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        match &args[0] {
+            DataType::List(_) => Ok(DataType::Boolean),
+            other => Err(ErrorCode::IllegalDataType(format!(
+                "Expected array, but got {}",
+                other
+            ))),
+        }
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(true)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let item_type = match columns[0].data_type() {
+            DataType::List(field) => field.data_type().clone(),
+            other => {
+                return Err(ErrorCode::IllegalDataType(format!(
+                    "Expected array, but got {}",
+                    other
+                )));
+            }
+        };
+
+        let series = columns[0].column().to_array()?;
+        let array = series.list()?;
+        let items = list_array_items(array);
+        let offsets = array.inner().offsets().as_slice();
+        let targets = cast_to_series(&columns[1], &item_type, input_rows)?;
+
+        let mut result = Vec::with_capacity(input_rows);
+        for row in 0..input_rows {
+            if array.is_null(row) {
+                result.push(None);
+                continue;
+            }
+
+            let target = targets.try_get(row)?;
+            let mut found = false;
+            for i in offsets[row] as usize..offsets[row + 1] as usize {
+                if items.try_get(i)? == target {
+                    found = true;
+                    break;
+                }
+            }
+            result.push(Some(found));
+        }
+        Ok(DFBooleanArray::new_from_opt_iter(result.into_iter()).into())
+    }
+}
+
+impl fmt::Display for ArrayHasFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
@@ -0,0 +1,104 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::Function;
+
+/// Unnests the array into the rows, `arrayJoin(arr)` or `unnest(arr)`. Each item of the array
+/// becomes a row, and the other columns of the row are replicated by the expression executor,
+/// the NULL and the empty arrays produce no rows.
+#[derive(Clone)]
+pub struct ArrayJoinFunction {
+    display_name: String,
+}
+
+impl ArrayJoinFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(ArrayJoinFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().array_join())
+    }
+
+    /// Returns the items of the arrays and the row index of each item.
+    pub fn unnest(column: &DataColumn, input_rows: usize) -> Result<(Series, Vec<usize>)> {
+        let series = column.to_array()?;
+        let array = series.list()?;
+        let offsets = array.inner().offsets().as_slice();
+
+        let mut rows = Vec::with_capacity(input_rows);
+        let mut positions = Vec::with_capacity(input_rows);
+        for row in 0..input_rows {
+            if array.is_null(row) {
+                continue;
+            }
+
+            for position in offsets[row] as usize..offsets[row + 1] as usize {
+                rows.push(row);
+                positions.push(position);
+            }
+        }
+
+        let items = list_array_items(array).take_iter(&mut positions.into_iter())?;
+        Ok((items, rows))
+    }
+}
+
+impl Function for ArrayJoinFunction {
+    fn name(&self) -> &str {
+        "arrayJoin"
+    }
+
+    fn num_arguments(&self) -> usize {
+        1
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        match &args[0] {
+            DataType::List(field) => Ok(field.data_type().clone()),
+            other => Err(ErrorCode::IllegalDataType(format!(
+                "Expected array, but got {}",
+                other
+            ))),
+        }
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(true)
+    }
+
+    // The result has more rows than the input, so it's only evaluated by the executor which
+    // replicates the other columns.
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let (items, _) = Self::unnest(columns[0].column(), input_rows)?;
+        Ok(items.into())
+    }
+}
+
+impl fmt::Display for ArrayJoinFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
@@ -0,0 +1,217 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_arrow::arrow::bitmap::Bitmap;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum LambdaKind {
+    Map,
+    Filter,
+}
+
+/// The items of the arrays lined up for the lambda, the rows with a NULL array are skipped.
+struct LambdaLayout {
+    offsets: Vec<i64>,
+    validity: Bitmap,
+    positions: Vec<Vec<usize>>,
+}
+
+/// The higher-order functions taking a lambda as the first argument, `arrayMap(x -> x + 1, arr)`
+/// and `arrayFilter(x -> x > 1, arr)`. The parameters of the lambda are bound to the items of
+/// the arrays in order, the lambda is evaluated by the expression executor over the flattened
+/// items, and the result is assembled into the arrays again.
+#[derive(Clone)]
+pub struct ArrayLambdaFunction {
+    display_name: String,
+    kind: LambdaKind,
+}
+
+impl ArrayLambdaFunction {
+    pub fn check(name: &str) -> bool {
+        matches!(name.to_lowercase().as_str(), "arraymap" | "arrayfilter")
+    }
+
+    pub fn try_create(display_name: &str) -> Result<ArrayLambdaFunction> {
+        let kind = match display_name.to_lowercase().as_str() {
+            "arraymap" => LambdaKind::Map,
+            "arrayfilter" => LambdaKind::Filter,
+            _ => {
+                return Err(ErrorCode::UnknownFunction(format!(
+                    "Unsupported lambda function: {}",
+                    display_name
+                )));
+            }
+        };
+
+        Ok(ArrayLambdaFunction {
+            display_name: display_name.to_string(),
+            kind,
+        })
+    }
+
+    /// The types of the lambda parameters, which are the item types of the arrays.
+    pub fn param_types(&self, args: &[DataType]) -> Result<Vec<DataType>> {
+        if args.is_empty() {
+            return Err(ErrorCode::NumberArgumentsNotMatch(format!(
+                "Function {} expects at least one array",
+                self.display_name
+            )));
+        }
+
+        args.iter()
+            .map(|arg| match arg {
+                DataType::List(field) => Ok(field.data_type().clone()),
+                other => Err(ErrorCode::IllegalDataType(format!(
+                    "Function {} expects array, but got {}",
+                    self.display_name, other
+                ))),
+            })
+            .collect()
+    }
+
+    pub fn return_type(&self, args: &[DataType], lambda_type: &DataType) -> Result<DataType> {
+        self.param_types(args)?;
+        match self.kind {
+            LambdaKind::Map => Ok(create_list_type(lambda_type.clone())),
+            LambdaKind::Filter if lambda_type == &DataType::Boolean => Ok(args[0].clone()),
+            LambdaKind::Filter => Err(ErrorCode::IllegalDataType(format!(
+                "The lambda of {} must return boolean, but got {}",
+                self.display_name, lambda_type
+            ))),
+        }
+    }
+
+    /// The flattened items of the arrays, which are the arguments of the lambda.
+    pub fn lambda_arguments(
+        &self,
+        arrays: &[DataColumn],
+        input_rows: usize,
+    ) -> Result<Vec<Series>> {
+        let arrays = Self::to_list_series(arrays)?;
+        let layout = self.layout(&arrays, input_rows)?;
+        arrays
+            .iter()
+            .zip(layout.positions.into_iter())
+            .map(|(array, positions)| {
+                list_array_items(array.list()?).take_iter(&mut positions.into_iter())
+            })
+            .collect()
+    }
+
+    /// Assemble the result of the lambda into the arrays.
+    pub fn eval(
+        &self,
+        arrays: &[DataColumn],
+        lambda_result: &DataColumnWithField,
+        input_rows: usize,
+    ) -> Result<DataColumn> {
+        let arrays = Self::to_list_series(arrays)?;
+        let layout = self.layout(&arrays, input_rows)?;
+        let values = lambda_result.column().to_array()?;
+
+        match self.kind {
+            LambdaKind::Map => Ok(build_list_array_from_offsets(
+                layout.offsets,
+                &values,
+                lambda_result.data_type(),
+                Some(layout.validity),
+            )
+            .into()),
+            LambdaKind::Filter => {
+                let predicate = values
+                    .bool()?
+                    .into_iter()
+                    .map(|v| v.unwrap_or(false))
+                    .collect::<Vec<_>>();
+                let mut offsets = Vec::with_capacity(layout.offsets.len());
+                let mut positions = vec![];
+                offsets.push(0i64);
+                for row in 0..input_rows {
+                    let (start, end) = (layout.offsets[row], layout.offsets[row + 1]);
+                    for i in start as usize..end as usize {
+                        if predicate[i] {
+                            positions.push(layout.positions[0][i]);
+                        }
+                    }
+                    offsets.push(positions.len() as i64);
+                }
+
+                let array = arrays[0].list()?;
+                let item_type = array.sub_data_type().clone();
+                let items = list_array_items(array).take_iter(&mut positions.into_iter())?;
+                Ok(build_list_array_from_offsets(
+                    offsets,
+                    &items,
+                    &item_type,
+                    Some(layout.validity),
+                )
+                .into())
+            }
+        }
+    }
+
+    fn to_list_series(arrays: &[DataColumn]) -> Result<Vec<Series>> {
+        arrays.iter().map(|array| array.to_array()).collect()
+    }
+
+    fn layout(&self, arrays: &[Series], input_rows: usize) -> Result<LambdaLayout> {
+        let arrays = arrays
+            .iter()
+            .map(|array| array.list())
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut offsets = Vec::with_capacity(input_rows + 1);
+        let mut validity = Vec::with_capacity(input_rows);
+        let mut positions = vec![vec![]; arrays.len()];
+        offsets.push(0i64);
+
+        for row in 0..input_rows {
+            let is_valid = arrays.iter().all(|array| !array.is_null(row));
+            if is_valid {
+                let first = arrays[0].inner().offsets().as_slice();
+                let len = first[row + 1] - first[row];
+                for (array, positions) in arrays.iter().zip(positions.iter_mut()) {
+                    let array_offsets = array.inner().offsets().as_slice();
+                    let (start, end) = (array_offsets[row], array_offsets[row + 1]);
+                    if end - start != len {
+                        return Err(ErrorCode::BadArguments(format!(
+                            "The arrays passed to {} must have the same size",
+                            self.display_name
+                        )));
+                    }
+                    positions.extend(start as usize..end as usize);
+                }
+            }
+            validity.push(is_valid);
+            offsets.push(positions[0].len() as i64);
+        }
+
+        Ok(LambdaLayout {
+            offsets,
+            validity: Bitmap::from_trusted_len_iter(validity.into_iter()),
+            positions,
+        })
+    }
+}
+
+impl fmt::Display for ArrayLambdaFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
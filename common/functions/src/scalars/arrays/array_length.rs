@@ -0,0 +1,85 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::Function;
+use crate::scalars::LengthFunction;
+
+/// Returns the number of the items in the array, the length of any other argument is taken
+/// by `LengthFunction` on its text.
+#[derive(Clone)]
+pub struct ArrayLengthFunction {
+    display_name: String,
+}
+
+impl ArrayLengthFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(ArrayLengthFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic())
+    }
+}
+
+impl Function for ArrayLengthFunction {
+    fn name(&self) -> &str {
+        "length"
+    }
+
+    fn num_arguments(&self) -> usize {
+        1
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        match &args[0] {
+            DataType::List(_) => Ok(DataType::UInt64),
+            _ => LengthFunction::try_create(&self.display_name)?.return_type(args),
+        }
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(true)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        if !matches!(columns[0].data_type(), DataType::List(_)) {
+            return LengthFunction::try_create(&self.display_name)?.eval(columns, input_rows);
+        }
+
+        let series = columns[0].column().to_array()?;
+        let array = series.list()?;
+        let offsets = array.inner().offsets().as_slice();
+        let it = (0..array.len()).map(|row| match array.is_null(row) {
+            true => None,
+            false => Some((offsets[row + 1] - offsets[row]) as u64),
+        });
+        Ok(DFUInt64Array::new_from_opt_iter(it).into())
+    }
+}
+
+impl fmt::Display for ArrayLengthFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
@@ -0,0 +1,182 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::Function;
+
+pub type ComparisonInFunction = InFunction<false>;
+pub type ComparisonNotInFunction = InFunction<true>;
+
+/// `x IN (v1, v2, ...)` and `x IN (subquery)`.
+///
+/// Constant values (including the list produced by a subquery) are collected into a hash set
+/// of serialized keys, non-constant values are compared row by row.
+///
+/// NULL follows the three-valued logic: the result is NULL if the left operand is NULL, or if
+/// no value matches but some of the values are NULL.
+#[derive(Clone)]
+pub struct InFunction<const NEGATED: bool>;
+
+impl<const NEGATED: bool> InFunction<NEGATED> {
+    pub fn try_create_func(_display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(InFunction::<NEGATED>))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        let negative_function = if NEGATED { "in" } else { "not_in" };
+        FunctionDescription::creator(Box::new(Self::try_create_func)).features(
+            FunctionFeatures::default()
+                .deterministic()
+                .negative_function(negative_function)
+                .bool_function(),
+        )
+    }
+}
+
+impl<const NEGATED: bool> Function for InFunction<NEGATED> {
+    fn name(&self) -> &str {
+        if NEGATED {
+            "NotInFunction"
+        } else {
+            "InFunction"
+        }
+    }
+
+    fn variadic_arguments(&self) -> Option<(usize, usize)> {
+        Some((2, usize::MAX))
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        common_type(args)?;
+        Ok(DataType::Boolean)
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(true)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let types = columns
+            .iter()
+            .map(|c| c.column().data_type())
+            .collect::<Vec<_>>();
+        let data_type = common_type(&types)?;
+
+        if data_type == DataType::Null {
+            return Ok(DataColumn::Constant(DataValue::Boolean(None), input_rows));
+        }
+
+        let mut set = HashSet::new();
+        let mut set_has_null = false;
+        let mut rows_values = vec![];
+        for column in &columns[1..] {
+            match column.column() {
+                DataColumn::Constant(DataValue::List(values, value_type), _) => {
+                    let values = values
+                        .iter()
+                        .flatten()
+                        .filter(|v| {
+                            set_has_null |= v.is_null();
+                            !v.is_null()
+                        })
+                        .cloned()
+                        .collect::<Vec<_>>();
+                    let series = DataValue::try_into_data_array(&values, value_type)?;
+                    let keys = serialize_keys(&series.cast_with_type(&data_type)?)?;
+                    set.extend(keys.into_iter().flatten());
+                }
+                DataColumn::Constant(value, _) if value.is_null() => set_has_null = true,
+                DataColumn::Constant(value, _) => {
+                    let keys = serialize_keys(&value.to_array()?.cast_with_type(&data_type)?)?;
+                    set.extend(keys.into_iter().flatten());
+                }
+                DataColumn::Array(array) => {
+                    rows_values.push(serialize_keys(&array.cast_with_type(&data_type)?)?);
+                }
+            }
+        }
+
+        let keys = serialize_keys(&columns[0].column().to_array()?.cast_with_type(&data_type)?)?;
+        let result = keys
+            .iter()
+            .enumerate()
+            .map(|(row, key)| {
+                let key = key.as_ref()?;
+                if set.contains(key)
+                    || rows_values
+                        .iter()
+                        .any(|values| values[row].as_ref() == Some(key))
+                {
+                    return Some(!NEGATED);
+                }
+
+                let has_null =
+                    set_has_null || rows_values.iter().any(|values| values[row].is_none());
+                match has_null {
+                    true => None,
+                    false => Some(NEGATED),
+                }
+            })
+            .collect::<DFBooleanArray>();
+        Ok(result.into())
+    }
+}
+
+impl<const NEGATED: bool> fmt::Display for InFunction<NEGATED> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if NEGATED {
+            write!(f, "NOT IN")
+        } else {
+            write!(f, "IN")
+        }
+    }
+}
+
+/// The type which the left operand and all the values are compared as.
+fn common_type(args: &[DataType]) -> Result<DataType> {
+    let mut common_type = args[0].clone();
+    for value_type in &args[1..] {
+        let value_type = match value_type {
+            DataType::List(field) => field.data_type().clone(),
+            DataType::Struct(_) => {
+                return Err(ErrorCode::BadArguments(
+                    "IN subquery must return exactly one column",
+                ));
+            }
+            other => other.clone(),
+        };
+        common_type = compare_coercion(&common_type, &value_type)?;
+    }
+    Ok(common_type)
+}
+
+/// Serialize each row to a key, NULL rows have no key.
+fn serialize_keys(series: &Series) -> Result<Vec<Option<Vec<u8>>>> {
+    let mut keys = vec![Vec::new(); series.len()];
+    series.serialize(&mut keys)?;
+
+    Ok(keys
+        .into_iter()
+        .enumerate()
+        .map(|(row, key)| if series.is_null(row) { None } else { Some(key) })
+        .collect())
+}
@@ -19,22 +19,31 @@ use super::interval_function::MonthsArithmeticFunction;
 use super::interval_function::SecondsArithmeticFunction;
 use super::now::NowFunction;
 use super::number_function::ToMondayFunction;
+use super::DateDiffFunction;
+use super::DateTruncFunction;
+use super::FormatDateTimeFunction;
+use super::FromUnixTimeFunction;
+use super::ParseDateTimeBestEffortFunction;
 use super::RoundFunction;
+use super::StrToDateFunction;
 use super::ToDayOfMonthFunction;
 use super::ToDayOfWeekFunction;
 use super::ToDayOfYearFunction;
 use super::ToHourFunction;
 use super::ToMinuteFunction;
 use super::ToMonthFunction;
+use super::ToQuarterFunction;
 use super::ToSecondFunction;
 use super::ToStartOfISOYearFunction;
 use super::ToStartOfMonthFunction;
 use super::ToStartOfQuarterFunction;
 use super::ToStartOfWeekFunction;
 use super::ToStartOfYearFunction;
+use super::ToUnixTimestampFunction;
 use super::ToYYYYMMDDFunction;
 use super::ToYYYYMMDDhhmmssFunction;
 use super::ToYYYYMMFunction;
+use super::ToYearFunction;
 use super::TodayFunction;
 use super::TomorrowFunction;
 use super::YesterdayFunction;
@@ -116,6 +125,8 @@ impl DateFunction {
         factory.register("toStartOfQuarter", ToStartOfQuarterFunction::desc());
         factory.register("toStartOfWeek", ToStartOfWeekFunction::desc());
         factory.register("toStartOfMonth", ToStartOfMonthFunction::desc());
+        factory.register("toYear", ToYearFunction::desc());
+        factory.register("toQuarter", ToQuarterFunction::desc());
         factory.register("toMonth", ToMonthFunction::desc());
         factory.register("toDayOfYear", ToDayOfYearFunction::desc());
         factory.register("toDayOfMonth", ToDayOfMonthFunction::desc());
@@ -125,6 +136,19 @@ impl DateFunction {
         factory.register("toSecond", ToSecondFunction::desc());
         factory.register("toMonday", ToMondayFunction::desc());
 
+        // formatting and parsing
+        factory.register("formatDateTime", FormatDateTimeFunction::desc());
+        factory.register("date_format", FormatDateTimeFunction::mysql_desc());
+        factory.register(
+            "parseDateTimeBestEffort",
+            ParseDateTimeBestEffortFunction::desc(),
+        );
+        factory.register("str_to_date", StrToDateFunction::desc());
+        factory.register("toUnixTimestamp", ToUnixTimestampFunction::desc());
+        factory.register("fromUnixTime", FromUnixTimeFunction::desc());
+        factory.register("dateDiff", DateDiffFunction::desc());
+        factory.register("date_trunc", DateTruncFunction::desc());
+
         // rounders
         factory.register("toStartOfSecond", Self::round_function_creator(1));
         factory.register("toStartOfMinute", Self::round_function_creator(60));
@@ -0,0 +1,98 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use super::date_unit::DateUnit;
+use super::timezone::timezone_of_argument;
+use super::timezone::to_date_times;
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::Function;
+
+/// `dateDiff(unit, start, end[, timezone])` is the difference of the unit numbers of the wall
+/// clock times in the timezone, such as `dateDiff('day', '2021-12-01 23:00:00', '2021-12-02 01:00:00')`
+/// is 1.
+#[derive(Clone, Debug)]
+pub struct DateDiffFunction {
+    display_name: String,
+}
+
+impl DateDiffFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(DateDiffFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic())
+    }
+}
+
+impl Function for DateDiffFunction {
+    fn name(&self) -> &str {
+        self.display_name.as_str()
+    }
+
+    fn variadic_arguments(&self) -> Option<(usize, usize)> {
+        Some((3, 4))
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        for arg in &args[1..3] {
+            if !arg.is_date_or_date_time() && arg != &DataType::String {
+                return Err(ErrorCode::IllegalDataType(format!(
+                    "Illegal type {:?} of argument of function {}, it should be a date, a date time or a string",
+                    arg, self.display_name
+                )));
+            }
+        }
+        Ok(DataType::Int64)
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, _input_rows: usize) -> Result<DataColumn> {
+        let unit = DateUnit::try_from_column(&columns[0], &self.display_name)?;
+        let tz = timezone_of_argument(columns.get(3), columns[1].data_type())?;
+        let starts = to_date_times(&columns[1], &tz)?;
+        let ends = to_date_times(&columns[2], &tz)?;
+
+        let values = starts
+            .iter()
+            .zip(ends.iter())
+            .map(|(start, end)| match (start, end) {
+                (Some(start), Some(end)) => Some(
+                    unit.relative_number(&end.naive_local())
+                        - unit.relative_number(&start.naive_local()),
+                ),
+                _ => None,
+            });
+        Ok(DFInt64Array::new_from_opt_iter(values).into())
+    }
+}
+
+impl fmt::Display for DateDiffFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
@@ -0,0 +1,122 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::chrono::NaiveDate;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use super::date_unit::DateUnit;
+use super::timezone::from_wall_clock;
+use super::timezone::timezone_of_type;
+use super::timezone::to_date_times;
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::Function;
+
+/// `date_trunc(unit, time)` truncates the wall clock time in the timezone of the time to the start
+/// of the unit, the result has the type of the time.
+#[derive(Clone, Debug)]
+pub struct DateTruncFunction {
+    display_name: String,
+}
+
+impl DateTruncFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(DateTruncFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic().monotonicity())
+    }
+}
+
+impl Function for DateTruncFunction {
+    fn name(&self) -> &str {
+        self.display_name.as_str()
+    }
+
+    fn num_arguments(&self) -> usize {
+        2
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        if !args[1].is_date_or_date_time() {
+            return Err(ErrorCode::IllegalDataType(format!(
+                "Illegal type {:?} of the second argument of function {}, it should be a date or a date time",
+                args[1], self.display_name
+            )));
+        }
+        Ok(args[1].clone())
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, _input_rows: usize) -> Result<DataColumn> {
+        let unit = DateUnit::try_from_column(&columns[0], &self.display_name)?;
+        let data_type = columns[1].data_type();
+        let tz = timezone_of_type(data_type)?;
+
+        let truncated = to_date_times(&columns[1], &tz)?.into_iter().map(|v| {
+            v.and_then(|date_time| from_wall_clock(&tz, &unit.truncate(&date_time.naive_local())))
+        });
+
+        let epoch = NaiveDate::from_ymd(1970, 1, 1);
+        let column: DataColumn = match data_type {
+            DataType::Date16 => DFUInt16Array::new_from_opt_iter(truncated.map(|v| {
+                v.map(|v| v.naive_local().date().signed_duration_since(epoch).num_days() as u16)
+            }))
+            .into(),
+            DataType::Date32 => DFInt32Array::new_from_opt_iter(truncated.map(|v| {
+                v.map(|v| v.naive_local().date().signed_duration_since(epoch).num_days() as i32)
+            }))
+            .into(),
+            DataType::DateTime32(_) => DFUInt32Array::new_from_opt_iter(
+                truncated.map(|v| v.map(|v| v.timestamp() as u32)),
+            )
+            .into(),
+            DataType::DateTime64(precision, _) => {
+                let ticks_per_second = datetime64_ticks_per_second(*precision);
+                let nanos_per_tick = datetime64_ticks_per_second(MAX_DATETIME64_PRECISION - *precision);
+                DFInt64Array::new_from_opt_iter(truncated.map(|v| {
+                    v.map(|v| {
+                        v.timestamp() * ticks_per_second
+                            + v.timestamp_subsec_nanos() as i64 / nanos_per_tick
+                    })
+                }))
+                .into()
+            }
+            other => {
+                return Err(ErrorCode::IllegalDataType(format!(
+                    "Illegal type {:?} of the second argument of function {}, it should be a date or a date time",
+                    other, self.display_name
+                )))
+            }
+        };
+        Ok(column)
+    }
+}
+
+impl fmt::Display for DateTruncFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
@@ -0,0 +1,104 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::chrono::Datelike;
+use common_datavalues::chrono::Duration;
+use common_datavalues::chrono::NaiveDate;
+use common_datavalues::chrono::NaiveDateTime;
+use common_datavalues::chrono::Timelike;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+/// The unit of `dateDiff` and `date_trunc`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DateUnit {
+    Second,
+    Minute,
+    Hour,
+    Day,
+    Week,
+    Month,
+    Quarter,
+    Year,
+}
+
+impl DateUnit {
+    /// The unit is a case insensitive constant string, such as `'day'` or `'DAYS'`.
+    pub fn try_from_column(column: &DataColumnWithField, display_name: &str) -> Result<DateUnit> {
+        let unit = match column.column() {
+            DataColumn::Constant(DataValue::String(Some(unit)), _) => {
+                String::from_utf8_lossy(unit).to_lowercase()
+            }
+            _ => {
+                return Err(ErrorCode::BadArguments(format!(
+                    "The first argument of function {} must be a constant string of the unit",
+                    display_name
+                )))
+            }
+        };
+
+        match unit.trim_end_matches('s') {
+            "second" => Ok(DateUnit::Second),
+            "minute" => Ok(DateUnit::Minute),
+            "hour" => Ok(DateUnit::Hour),
+            "day" => Ok(DateUnit::Day),
+            "week" => Ok(DateUnit::Week),
+            "month" => Ok(DateUnit::Month),
+            "quarter" => Ok(DateUnit::Quarter),
+            "year" => Ok(DateUnit::Year),
+            _ => Err(ErrorCode::BadArguments(format!(
+                "Unsupported unit {} of function {}, it should be one of second, minute, hour, day, week, month, quarter and year",
+                unit, display_name
+            ))),
+        }
+    }
+
+    /// The number of the units since the epoch of the wall clock time, the weeks start on Monday.
+    pub fn relative_number(&self, date_time: &NaiveDateTime) -> i64 {
+        let seconds = date_time.timestamp();
+        let days = seconds.div_euclid(24 * 3600);
+        match self {
+            DateUnit::Second => seconds,
+            DateUnit::Minute => seconds.div_euclid(60),
+            DateUnit::Hour => seconds.div_euclid(3600),
+            DateUnit::Day => days,
+            // 1970-01-01 is Thursday.
+            DateUnit::Week => (days + 3).div_euclid(7),
+            DateUnit::Month => date_time.year() as i64 * 12 + date_time.month0() as i64,
+            DateUnit::Quarter => date_time.year() as i64 * 4 + date_time.month0() as i64 / 3,
+            DateUnit::Year => date_time.year() as i64,
+        }
+    }
+
+    /// Truncates the wall clock time to the start of the unit, the weeks start on Monday.
+    pub fn truncate(&self, date_time: &NaiveDateTime) -> NaiveDateTime {
+        let date = date_time.date();
+        match self {
+            DateUnit::Second => date_time.with_nanosecond(0).unwrap_or(*date_time),
+            DateUnit::Minute => date.and_hms(date_time.hour(), date_time.minute(), 0),
+            DateUnit::Hour => date.and_hms(date_time.hour(), 0, 0),
+            DateUnit::Day => date.and_hms(0, 0, 0),
+            DateUnit::Week => {
+                let days = date.weekday().num_days_from_monday() as i64;
+                (date - Duration::days(days)).and_hms(0, 0, 0)
+            }
+            DateUnit::Month => NaiveDate::from_ymd(date.year(), date.month(), 1).and_hms(0, 0, 0),
+            DateUnit::Quarter => {
+                NaiveDate::from_ymd(date.year(), date.month0() / 3 * 3 + 1, 1).and_hms(0, 0, 0)
+            }
+            DateUnit::Year => NaiveDate::from_ymd(date.year(), 1, 1).and_hms(0, 0, 0),
+        }
+    }
+}
@@ -0,0 +1,327 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+use std::fmt::Write;
+
+use common_datavalues::chrono::format::Item;
+use common_datavalues::chrono::format::StrftimeItems;
+use common_datavalues::chrono::DateTime;
+use common_datavalues::chrono::Datelike;
+use common_datavalues::chrono::TimeZone;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use super::timezone::timezone_of_argument;
+use super::timezone::to_date_times;
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::Function;
+
+/// The ClickHouse specifiers which have the same meaning in chrono, `%Q` is handled by ourselves.
+const CLICKHOUSE_SPECIFIERS: &str = "CdDeFGgHIjmMnpRStTuVwyY%";
+
+enum FormatPiece {
+    Items(Vec<Item<'static>>),
+    Quarter,
+    DayOfMonthWithSuffix,
+}
+
+/// The compiled date time format, it's compiled once and applied to every row.
+pub struct DateTimeFormat {
+    pieces: Vec<FormatPiece>,
+}
+
+impl DateTimeFormat {
+    /// The ClickHouse style format of `formatDateTime`, such as `%Y-%m-%d %H:%M:%S`.
+    pub fn try_create_clickhouse(format: &str) -> Result<DateTimeFormat> {
+        let mut pieces = vec![];
+        let mut strftime = String::new();
+        let mut chars = format.chars();
+        while let Some(c) = chars.next() {
+            if c != '%' {
+                strftime.push(c);
+                continue;
+            }
+
+            match chars.next() {
+                Some('Q') => {
+                    Self::flush(&mut pieces, &mut strftime)?;
+                    pieces.push(FormatPiece::Quarter);
+                }
+                Some(c) if CLICKHOUSE_SPECIFIERS.contains(c) => {
+                    strftime.push('%');
+                    strftime.push(c);
+                }
+                Some(c) => {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "Unsupported format specifier %{} in the date time format: {}",
+                        c, format
+                    )))
+                }
+                None => {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "Sign '%' is the last in the date time format: {}",
+                        format
+                    )))
+                }
+            }
+        }
+
+        Self::flush(&mut pieces, &mut strftime)?;
+        Ok(DateTimeFormat { pieces })
+    }
+
+    /// The MySQL style format of `date_format`, such as `%Y-%m-%d %H:%i:%s`.
+    pub fn try_create_mysql(format: &str) -> Result<DateTimeFormat> {
+        let mut pieces = vec![];
+        let mut strftime = String::new();
+        let mut chars = format.chars();
+        while let Some(c) = chars.next() {
+            match c {
+                '%' => match chars.next() {
+                    Some('D') => {
+                        Self::flush(&mut pieces, &mut strftime)?;
+                        pieces.push(FormatPiece::DayOfMonthWithSuffix);
+                    }
+                    Some(c) => push_mysql_specifier(&mut strftime, c),
+                    None => strftime.push_str("%%"),
+                },
+                c => strftime.push(c),
+            }
+        }
+
+        Self::flush(&mut pieces, &mut strftime)?;
+        Ok(DateTimeFormat { pieces })
+    }
+
+    fn flush(pieces: &mut Vec<FormatPiece>, strftime: &mut String) -> Result<()> {
+        if !strftime.is_empty() {
+            pieces.push(FormatPiece::Items(compile_strftime(strftime)?));
+            strftime.clear();
+        }
+        Ok(())
+    }
+
+    pub fn format<T>(&self, date_time: &DateTime<T>, buffer: &mut String)
+    where
+        T: TimeZone,
+        T::Offset: fmt::Display,
+    {
+        for piece in &self.pieces {
+            match piece {
+                FormatPiece::Items(items) => {
+                    let _ = write!(buffer, "{}", date_time.format_with_items(items.iter()));
+                }
+                FormatPiece::Quarter => {
+                    let _ = write!(buffer, "{}", date_time.month0() / 3 + 1);
+                }
+                FormatPiece::DayOfMonthWithSuffix => {
+                    let day = date_time.day();
+                    let suffix = match (day % 10, day % 100) {
+                        (_, 11..=13) => "th",
+                        (1, _) => "st",
+                        (2, _) => "nd",
+                        (3, _) => "rd",
+                        _ => "th",
+                    };
+                    let _ = write!(buffer, "{}{}", day, suffix);
+                }
+            }
+        }
+    }
+}
+
+/// Translates the MySQL specifier into the chrono one, the unknown specifier is the character itself.
+pub fn push_mysql_specifier(strftime: &mut String, specifier: char) {
+    let translated = match specifier {
+        'a' => "%a",
+        'b' => "%b",
+        'c' => "%-m",
+        'd' => "%d",
+        'e' => "%-d",
+        'f' => "%6f",
+        'H' => "%H",
+        'h' | 'I' => "%I",
+        'i' => "%M",
+        'j' => "%j",
+        'k' => "%-H",
+        'l' => "%-I",
+        'M' => "%B",
+        'm' => "%m",
+        'p' => "%p",
+        'r' => "%I:%M:%S %p",
+        'S' | 's' => "%S",
+        'T' => "%H:%M:%S",
+        'U' => "%U",
+        'u' => "%W",
+        'v' => "%V",
+        'W' => "%A",
+        'w' => "%w",
+        'x' => "%G",
+        'Y' => "%Y",
+        'y' => "%y",
+        '%' => "%%",
+        c => {
+            strftime.push(c);
+            return;
+        }
+    };
+    strftime.push_str(translated);
+}
+
+/// Compiles the chrono format, the items own the literals so that they can be kept in the function.
+pub fn compile_strftime(strftime: &str) -> Result<Vec<Item<'static>>> {
+    StrftimeItems::new(strftime)
+        .map(|item| match item {
+            Item::Literal(s) => Ok(Item::OwnedLiteral(s.into())),
+            Item::OwnedLiteral(s) => Ok(Item::OwnedLiteral(s)),
+            Item::Space(s) => Ok(Item::OwnedSpace(s.into())),
+            Item::OwnedSpace(s) => Ok(Item::OwnedSpace(s)),
+            Item::Numeric(numeric, pad) => Ok(Item::Numeric(numeric, pad)),
+            Item::Fixed(fixed) => Ok(Item::Fixed(fixed)),
+            Item::Error => Err(ErrorCode::BadArguments(format!(
+                "Invalid date time format: {}",
+                strftime
+            ))),
+        })
+        .collect()
+}
+
+/// The format argument must be a constant string, it's compiled once for all the rows.
+fn format_argument(column: &DataColumnWithField) -> Result<String> {
+    match column.column() {
+        DataColumn::Constant(DataValue::String(Some(format)), _) => {
+            Ok(String::from_utf8_lossy(format).to_string())
+        }
+        _ => Err(ErrorCode::BadArguments(
+            "The format argument must be a constant string",
+        )),
+    }
+}
+
+/// Formats the date like column by the format in the timezone.
+pub fn format_date_times(
+    column: &DataColumnWithField,
+    format: &DateTimeFormat,
+    tz_column: Option<&DataColumnWithField>,
+) -> Result<DataColumn> {
+    let tz = timezone_of_argument(tz_column, column.data_type())?;
+    let date_times = to_date_times(column, &tz)?;
+
+    let mut builder = StringArrayBuilder::with_capacity(date_times.len());
+    let mut buffer = String::new();
+    for date_time in date_times {
+        match date_time {
+            Some(date_time) => {
+                buffer.clear();
+                format.format(&date_time, &mut buffer);
+                builder.append_value(buffer.as_bytes());
+            }
+            None => builder.append_null(),
+        }
+    }
+    Ok(builder.finish().into())
+}
+
+#[derive(Clone, Copy, Debug)]
+enum FormatStyle {
+    ClickHouse,
+    MySQL,
+}
+
+/// `formatDateTime(time, format[, timezone])` formats with the ClickHouse specifiers,
+/// `date_format(time, format)` formats with the MySQL specifiers.
+#[derive(Clone, Debug)]
+pub struct FormatDateTimeFunction {
+    display_name: String,
+    style: FormatStyle,
+}
+
+impl FormatDateTimeFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(FormatDateTimeFunction {
+            display_name: display_name.to_string(),
+            style: FormatStyle::ClickHouse,
+        }))
+    }
+
+    pub fn try_create_mysql(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(FormatDateTimeFunction {
+            display_name: display_name.to_string(),
+            style: FormatStyle::MySQL,
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic())
+    }
+
+    pub fn mysql_desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create_mysql))
+            .features(FunctionFeatures::default().deterministic())
+    }
+}
+
+impl Function for FormatDateTimeFunction {
+    fn name(&self) -> &str {
+        self.display_name.as_str()
+    }
+
+    fn variadic_arguments(&self) -> Option<(usize, usize)> {
+        match self.style {
+            FormatStyle::ClickHouse => Some((2, 3)),
+            FormatStyle::MySQL => Some((2, 2)),
+        }
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        if !args[0].is_date_or_date_time() && args[0] != DataType::String {
+            return Err(ErrorCode::IllegalDataType(format!(
+                "Illegal type {:?} of the first argument of function {}, it should be a date, a date time or a string",
+                args[0], self.display_name
+            )));
+        }
+        for arg in &args[1..] {
+            if !matches!(arg, DataType::String | DataType::Null) {
+                return Err(ErrorCode::IllegalDataType(format!(
+                    "Illegal type {:?} of argument of function {}, expected string",
+                    arg, self.display_name
+                )));
+            }
+        }
+        Ok(DataType::String)
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(true)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, _input_rows: usize) -> Result<DataColumn> {
+        let format = format_argument(&columns[1])?;
+        let format = match self.style {
+            FormatStyle::ClickHouse => DateTimeFormat::try_create_clickhouse(&format)?,
+            FormatStyle::MySQL => DateTimeFormat::try_create_mysql(&format)?,
+        };
+        format_date_times(&columns[0], &format, columns.get(2))
+    }
+}
+
+impl fmt::Display for FormatDateTimeFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
@@ -192,9 +192,10 @@ impl IntervalFunctionFactory {
         let mut date_datetime_opt = None;
         columns.iter().for_each(|column| match column.data_type() {
             DataType::Interval(_) => interval_opt = Some(column),
-            DataType::Date16 | DataType::Date32 | DataType::DateTime32(_) => {
-                date_datetime_opt = Some(column)
-            }
+            DataType::Date16
+            | DataType::Date32
+            | DataType::DateTime32(_)
+            | DataType::DateTime64(_, _) => date_datetime_opt = Some(column),
             _ => {}
         });
 
@@ -237,6 +238,8 @@ impl IntervalFunctionFactory {
     //   4. interval_month_plus_minus_date16 --------- Interval(YearMonth) +/-  Date16
     //   5. interval_month_plus_minus_date32 --------- Interval(YearMonth) +/-  Date32
     //   6. interval_month_plus_minus_datetime32 ----- Interval(YearMonth) +/-  DateTime32
+    //   7. interval_daytime_plus_minus_datetime64 --- Interval(DayTime)   +/-  DateTime64
+    //   8. interval_month_plus_minus_datetime64 ----- Interval(YearMonth) +/-  DateTime64
 
     fn get_interval_arithmetic_func(
         interval: &DataType,
@@ -247,12 +250,14 @@ impl IntervalFunctionFactory {
                 DataType::Date16 => Self::interval_month_plus_minus_date16,
                 DataType::Date32 => Self::interval_month_plus_minus_date32,
                 DataType::DateTime32(_) => Self::interval_month_plus_minus_datetime32,
+                DataType::DateTime64(_, _) => Self::interval_month_plus_minus_datetime64,
                 _ => unreachable!(),
             },
             DataType::Interval(IntervalUnit::DayTime) => match date_datetime {
                 DataType::Date16 => Self::interval_daytime_plus_minus_date16,
                 DataType::Date32 => Self::interval_daytime_plus_minus_date32,
                 DataType::DateTime32(_) => Self::interval_daytime_plus_minus_datetime32,
+                DataType::DateTime64(_, _) => Self::interval_daytime_plus_minus_datetime64,
                 _ => unreachable!(),
             },
             _ => unreachable!(),
@@ -332,6 +337,29 @@ impl IntervalFunctionFactory {
         Ok(res.into())
     }
 
+    fn interval_daytime_plus_minus_datetime64(
+        op: &DataValueArithmeticOperator,
+        a: &DataColumnWithField,
+        b: &DataColumnWithField,
+    ) -> Result<DataColumn> {
+        let (interval, datetime) = Self::validate_input(op, a, b)?;
+        let ticks_per_second = Self::ticks_per_second(datetime.data_type());
+        let res = Self::interval_operation(
+            interval.column().to_array()?.i64()?,
+            datetime.column().to_array()?.i64()?,
+            |ms: &i64, ticks: &i64| {
+                let delta = *ms * ticks_per_second / 1000;
+                let r = match op {
+                    DataValueArithmeticOperator::Plus => *ticks + delta,
+                    DataValueArithmeticOperator::Minus => *ticks - delta,
+                    _ => unreachable!(),
+                };
+                Ok(r)
+            },
+        )?;
+        Ok(res.into())
+    }
+
     fn interval_month_plus_minus_date16(
         op: &DataValueArithmeticOperator,
         a: &DataColumnWithField,
@@ -359,6 +387,14 @@ impl IntervalFunctionFactory {
         Self::month_i64_plus_minus_datetime32(op, interval, datetime, 1)
     }
 
+    fn interval_month_plus_minus_datetime64(
+        op: &DataValueArithmeticOperator,
+        a: &DataColumnWithField,
+        b: &DataColumnWithField,
+    ) -> Result<DataColumn> {
+        Self::month_plus_minus_datetime64(op, a, b, 1)
+    }
+
     //  End of interval typed arithmetic functions.
     //////////////////////////////////////////////////////////////////////////////////
 
@@ -405,6 +441,7 @@ impl IntervalFunctionFactory {
                 DataType::Int64 => Self::month_i64_plus_minus_datetime32,
                 _ => unreachable!(),
             },
+            DataType::DateTime64(_, _) => Self::month_plus_minus_datetime64,
             _ => unreachable!(),
         }
     }
@@ -438,6 +475,41 @@ impl IntervalFunctionFactory {
     crate::define_month_plus_minus_datetime32!(month_u16_plus_minus_datetime32, u16);
     crate::define_month_plus_minus_datetime32!(month_u8_plus_minus_datetime32, u8);
 
+    // datetime64 function, the months of any integer type are cast to Int64, and the
+    // sub-second ticks are kept as they are.
+    fn month_plus_minus_datetime64(
+        op: &DataValueArithmeticOperator,
+        a: &DataColumnWithField,
+        b: &DataColumnWithField,
+        mul: i64,
+    ) -> Result<DataColumn> {
+        let (interval_months, datetime64) = Self::validate_input(op, a, b)?;
+        let ticks_per_second = Self::ticks_per_second(datetime64.data_type());
+        let months = interval_months
+            .column()
+            .to_array()?
+            .cast_with_type(&DataType::Int64)?;
+
+        let res = Self::interval_operation(
+            months.i64()?,
+            datetime64.column().to_array()?.i64()?,
+            |months: &i64, ticks: &i64| {
+                let dt = Self::seconds_to_datetime(ticks.div_euclid(ticks_per_second))?;
+                let new_dt = match op {
+                    DataValueArithmeticOperator::Plus => {
+                        Self::datetime_plus_signed_months(&dt, *months * mul)?
+                    }
+                    DataValueArithmeticOperator::Minus => {
+                        Self::datetime_plus_signed_months(&dt, -*months * mul)?
+                    }
+                    _ => unreachable!(),
+                };
+                Ok(new_dt.timestamp() * ticks_per_second + ticks.rem_euclid(ticks_per_second))
+            },
+        )?;
+        Ok(res.into())
+    }
+
     //  End of months integer arithmetic functions
     //////////////////////////////////////////////////////////////////////////////////
 
@@ -484,6 +556,7 @@ impl IntervalFunctionFactory {
                 DataType::Int64 => Self::time_secs_i64_plus_minus_datetime32,
                 _ => unreachable!(),
             },
+            DataType::DateTime64(_, _) => Self::time_secs_plus_minus_datetime64,
             _ => unreachable!(),
         }
     }
@@ -517,9 +590,45 @@ impl IntervalFunctionFactory {
     crate::define_time_secs_plus_minus_datetime32!(time_secs_u16_plus_minus_datetime32, u16);
     crate::define_time_secs_plus_minus_datetime32!(time_secs_u8_plus_minus_datetime32, u8);
 
+    // datetime64 function, the seconds of any integer type are cast to Int64.
+    fn time_secs_plus_minus_datetime64(
+        op: &DataValueArithmeticOperator,
+        interval: &DataColumnWithField,
+        datetime: &DataColumnWithField,
+        mul: i64,
+    ) -> Result<DataColumn> {
+        let ticks_per_second = Self::ticks_per_second(datetime.data_type());
+        let seconds = interval
+            .column()
+            .to_array()?
+            .cast_with_type(&DataType::Int64)?;
+
+        let res = Self::interval_operation(
+            seconds.i64()?,
+            datetime.column().to_array()?.i64()?,
+            |secs: &i64, ticks: &i64| {
+                let r = match op {
+                    DataValueArithmeticOperator::Plus => *ticks + *secs * mul * ticks_per_second,
+                    DataValueArithmeticOperator::Minus => *ticks - *secs * mul * ticks_per_second,
+                    _ => unreachable!(),
+                };
+                Ok(r)
+            },
+        )?;
+        Ok(res.into())
+    }
+
     // End of seconds integer arithmetic functions
     //////////////////////////////////////////////////////////////////////////////////
 
+    // A private helper function to get the ticks per second of a DateTime64 column
+    fn ticks_per_second(data_type: &DataType) -> i64 {
+        match data_type {
+            DataType::DateTime64(precision, _) => datetime64_ticks_per_second(*precision),
+            _ => 1,
+        }
+    }
+
     // A private helper function for validate operator, returns a tuple of
     // (interval|integer, date16|date32|datetime32)
     fn validate_input<'a>(
@@ -13,14 +13,24 @@
 // limitations under the License.
 
 mod date;
+mod date_diff;
+mod date_trunc;
+mod date_unit;
+mod format_date_time;
 mod interval_function;
 mod now;
 mod number_function;
+mod parse_date_time;
 mod round_function;
 mod simple_date;
+mod timezone;
+mod unix_timestamp;
 mod week_date;
 
 pub use date::DateFunction;
+pub use date_diff::DateDiffFunction;
+pub use date_trunc::DateTruncFunction;
+pub use format_date_time::FormatDateTimeFunction;
 pub use interval_function::IntervalArithmeticFunction;
 pub use interval_function::IntervalFunctionFactory;
 pub use interval_function::MonthsArithmeticFunction;
@@ -32,6 +42,7 @@ pub use number_function::ToHourFunction;
 pub use number_function::ToMinuteFunction;
 pub use number_function::ToMondayFunction;
 pub use number_function::ToMonthFunction;
+pub use number_function::ToQuarterFunction;
 pub use number_function::ToSecondFunction;
 pub use number_function::ToStartOfISOYearFunction;
 pub use number_function::ToStartOfMonthFunction;
@@ -40,8 +51,13 @@ pub use number_function::ToStartOfYearFunction;
 pub use number_function::ToYYYYMMDDFunction;
 pub use number_function::ToYYYYMMDDhhmmssFunction;
 pub use number_function::ToYYYYMMFunction;
+pub use number_function::ToYearFunction;
+pub use parse_date_time::ParseDateTimeBestEffortFunction;
+pub use parse_date_time::StrToDateFunction;
 pub use round_function::RoundFunction;
 pub use simple_date::TodayFunction;
 pub use simple_date::TomorrowFunction;
 pub use simple_date::YesterdayFunction;
+pub use unix_timestamp::FromUnixTimeFunction;
+pub use unix_timestamp::ToUnixTimestampFunction;
 pub use week_date::ToStartOfWeekFunction;
@@ -24,6 +24,8 @@ use common_datavalues::prelude::*;
 use common_exception::ErrorCode;
 use common_exception::Result;
 
+use super::timezone::timezone_of_type;
+use super::timezone::to_wall_clock;
 use crate::scalars::function_factory::FunctionDescription;
 use crate::scalars::function_factory::FunctionFeatures;
 use crate::scalars::Function;
@@ -194,6 +196,46 @@ impl NumberResultFunction<u16> for ToStartOfMonth {
     }
 }
 
+#[derive(Clone)]
+pub struct ToYear;
+
+impl NumberResultFunction<u16> for ToYear {
+    const IS_DETERMINISTIC: bool = true;
+    const MAYBE_MONOTONIC: bool = true;
+
+    fn return_type() -> Result<DataType> {
+        Ok(DataType::UInt16)
+    }
+    fn to_number(value: DateTime<Utc>) -> u16 {
+        value.year() as u16
+    }
+
+    fn to_constant_value(value: DateTime<Utc>) -> DataValue {
+        DataValue::UInt16(Some(Self::to_number(value)))
+    }
+}
+
+#[derive(Clone)]
+pub struct ToQuarter;
+
+impl NumberResultFunction<u8> for ToQuarter {
+    const IS_DETERMINISTIC: bool = true;
+
+    // ToQuarter is NOT a monotonic function in general, unless the time range is within the same year.
+    const MAYBE_MONOTONIC: bool = false;
+
+    fn return_type() -> Result<DataType> {
+        Ok(DataType::UInt8)
+    }
+    fn to_number(value: DateTime<Utc>) -> u8 {
+        (value.month0() / 3 + 1) as u8
+    }
+
+    fn to_constant_value(value: DateTime<Utc>) -> DataValue {
+        DataValue::UInt8(Some(Self::to_number(value)))
+    }
+}
+
 #[derive(Clone)]
 pub struct ToMonth;
 
@@ -452,9 +494,11 @@ where
                     Ok(result.into())
                 }
             }
+            // The numbers of the date times are the ones of the wall clock time in their timezone.
             DataType::DateTime32(_) => {
+                let tz = timezone_of_type(data_type)?;
                 if let DataColumn::Constant(v, _) = columns[0].column() {
-                    let date_time = Utc.timestamp(v.as_u64()? as i64, 0_u32);
+                    let date_time = to_wall_clock(v.as_u64()?.to_date_time(&tz));
                     let constant_result = T::to_constant_value(date_time);
                     Ok(DataColumn::Constant(constant_result, input_rows))
                 } else {
@@ -462,7 +506,26 @@ where
                         .to_array()?
                         .u32()?
                         .apply_cast_numeric(|v| {
-                            let date_time = Utc.timestamp(v as i64, 0_u32);
+                            let date_time = to_wall_clock(v.to_date_time(&tz));
+                            T::to_number(date_time)
+                        }
+                        );
+                    Ok(result.into())
+                }
+            }
+            DataType::DateTime64(precision, _) => {
+                let precision = *precision;
+                let tz = timezone_of_type(data_type)?;
+                if let DataColumn::Constant(v, _) = columns[0].column() {
+                    let date_time = to_wall_clock(v.as_i64()?.to_date_time64(precision, &tz));
+                    let constant_result = T::to_constant_value(date_time);
+                    Ok(DataColumn::Constant(constant_result, input_rows))
+                } else {
+                    let result = columns[0].column()
+                        .to_array()?
+                        .i64()?
+                        .apply_cast_numeric(|v| {
+                            let date_time = to_wall_clock(v.to_date_time64(precision, &tz));
                             T::to_number(date_time)
                         }
                         );
@@ -470,7 +533,7 @@ where
                 }
             }
             other => Result::Err(ErrorCode::IllegalDataType(format!(
-                "Illegal type {:?} of argument of function {}.Should be a date16/data32 or a dateTime32",
+                "Illegal type {:?} of argument of function {}.Should be a date16/data32 or a dateTime32/dateTime64",
                 other,
                 self.name()))),
         }?;
@@ -507,6 +570,8 @@ pub type ToStartOfYearFunction = NumberFunction<ToStartOfYear, u16>;
 pub type ToStartOfQuarterFunction = NumberFunction<ToStartOfQuarter, u16>;
 pub type ToStartOfMonthFunction = NumberFunction<ToStartOfMonth, u16>;
 
+pub type ToYearFunction = NumberFunction<ToYear, u16>;
+pub type ToQuarterFunction = NumberFunction<ToQuarter, u8>;
 pub type ToMonthFunction = NumberFunction<ToMonth, u8>;
 pub type ToDayOfYearFunction = NumberFunction<ToDayOfYear, u16>;
 pub type ToDayOfMonthFunction = NumberFunction<ToDayOfMonth, u8>;
@@ -0,0 +1,300 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::chrono::format::parse;
+use common_datavalues::chrono::format::Item;
+use common_datavalues::chrono::format::Parsed;
+use common_datavalues::chrono::DateTime;
+use common_datavalues::chrono::NaiveDate;
+use common_datavalues::chrono::NaiveDateTime;
+use common_datavalues::chrono::NaiveTime;
+use common_datavalues::chrono::TimeZone;
+use common_datavalues::chrono::Utc;
+use common_datavalues::prelude::*;
+use common_datavalues::Tz;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use super::format_date_time::compile_strftime;
+use super::format_date_time::push_mysql_specifier;
+use super::timezone::from_wall_clock;
+use super::timezone::timezone_of_argument;
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::Function;
+
+/// The date time formats with the offset, such as `2021-12-01 10:20:30 +0800`.
+const OFFSET_DATE_TIME_FORMATS: &[&str] = &[
+    "%Y-%m-%d %H:%M:%S%.f%:z",
+    "%Y-%m-%d %H:%M:%S%.f %z",
+    "%Y-%m-%dT%H:%M:%S%.f%z",
+    "%Y/%m/%d %H:%M:%S%.f %z",
+];
+
+/// The date time formats of the wall clock time.
+const DATE_TIME_FORMATS: &[&str] = &[
+    "%Y-%m-%d %H:%M:%S%.f",
+    "%Y-%m-%dT%H:%M:%S%.f",
+    "%Y-%m-%d %H:%M",
+    "%Y-%m-%dT%H:%M",
+    "%Y/%m/%d %H:%M:%S%.f",
+    "%Y/%m/%d %H:%M",
+    "%d/%m/%Y %H:%M:%S%.f",
+    "%d.%m.%Y %H:%M:%S%.f",
+    "%d %b %Y %H:%M:%S%.f",
+    "%b %d %Y %H:%M:%S%.f",
+    "%b %d, %Y %H:%M:%S%.f",
+];
+
+/// The date formats, the time is the midnight.
+const DATE_FORMATS: &[&str] = &[
+    "%Y-%m-%d",
+    "%Y/%m/%d",
+    "%d/%m/%Y",
+    "%d.%m.%Y",
+    "%d-%b-%Y",
+    "%d %b %Y",
+    "%b %d %Y",
+    "%b %d, %Y",
+];
+
+/// Parses the date time in many common formats, the value without the offset is the wall clock
+/// time in the timezone:
+/// - the unix timestamp, `YYYYMMDD` and `YYYYMMDDhhmmss`
+/// - ISO 8601 and RFC 3339, such as `2021-12-01T10:20:30.123+08:00` and `2021-12-01T10:20:30Z`
+/// - RFC 2822, such as `Wed, 01 Dec 2021 10:20:30 +0800`
+/// - the dates and the date times such as `2021/12/01 10:20`, `01.12.2021` and `Dec 1, 2021`
+pub fn parse_date_time_best_effort(value: &[u8], tz: &Tz) -> Option<DateTime<Tz>> {
+    let value = std::str::from_utf8(value).ok()?.trim();
+    if value.is_empty() {
+        return None;
+    }
+
+    if value.bytes().all(|b| b.is_ascii_digit()) {
+        return match value.len() {
+            8 => NaiveDate::parse_from_str(value, "%Y%m%d")
+                .ok()
+                .and_then(|date| from_wall_clock(tz, &date.and_hms(0, 0, 0))),
+            14 => NaiveDateTime::parse_from_str(value, "%Y%m%d%H%M%S")
+                .ok()
+                .and_then(|date_time| from_wall_clock(tz, &date_time)),
+            _ => value
+                .parse::<i64>()
+                .ok()
+                .and_then(|seconds| tz.timestamp_opt(seconds, 0).single()),
+        };
+    }
+
+    if let Ok(date_time) = DateTime::parse_from_rfc3339(value) {
+        return Some(date_time.with_timezone(tz));
+    }
+    if let Ok(date_time) = DateTime::parse_from_rfc2822(value) {
+        return Some(date_time.with_timezone(tz));
+    }
+    for format in OFFSET_DATE_TIME_FORMATS {
+        if let Ok(date_time) = DateTime::parse_from_str(value, format) {
+            return Some(date_time.with_timezone(tz));
+        }
+    }
+
+    // The trailing `Z` means UTC.
+    if let Some(utc) = value.strip_suffix('Z') {
+        return DATE_TIME_FORMATS
+            .iter()
+            .find_map(|format| NaiveDateTime::parse_from_str(utc, format).ok())
+            .map(|date_time| Utc.from_utc_datetime(&date_time).with_timezone(tz));
+    }
+
+    DATE_TIME_FORMATS
+        .iter()
+        .find_map(|format| NaiveDateTime::parse_from_str(value, format).ok())
+        .or_else(|| {
+            DATE_FORMATS
+                .iter()
+                .find_map(|format| NaiveDate::parse_from_str(value, format).ok())
+                .map(|date| date.and_hms(0, 0, 0))
+        })
+        .and_then(|date_time| from_wall_clock(tz, &date_time))
+}
+
+/// Parses the date time by the compiled MySQL format, the time is the midnight if the format has
+/// only the date.
+fn parse_date_time_by_format(value: &[u8], items: &[Item<'static>]) -> Option<NaiveDateTime> {
+    let value = std::str::from_utf8(value).ok()?;
+    let mut parsed = Parsed::new();
+    parse(&mut parsed, value, items.iter()).ok()?;
+
+    let date = parsed.to_naive_date().ok()?;
+    let time = parsed
+        .to_naive_time()
+        .unwrap_or_else(|_| NaiveTime::from_hms(0, 0, 0));
+    Some(date.and_time(time))
+}
+
+/// `parseDateTimeBestEffort(string[, timezone])` parses the date time in many common formats,
+/// the unparsable string is NULL.
+#[derive(Clone, Debug)]
+pub struct ParseDateTimeBestEffortFunction {
+    display_name: String,
+}
+
+impl ParseDateTimeBestEffortFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(ParseDateTimeBestEffortFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic())
+    }
+}
+
+impl Function for ParseDateTimeBestEffortFunction {
+    fn name(&self) -> &str {
+        self.display_name.as_str()
+    }
+
+    fn variadic_arguments(&self) -> Option<(usize, usize)> {
+        Some((1, 2))
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        check_string_arguments(&self.display_name, args)?;
+        Ok(DataType::DateTime32(None))
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(true)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, _input_rows: usize) -> Result<DataColumn> {
+        let tz = timezone_of_argument(columns.get(1), &DataType::String)?;
+        let array = columns[0].column().cast_with_type(&DataType::String)?;
+        let array = array.to_array()?;
+        let values = array
+            .string()?
+            .into_iter()
+            .map(|v| v.and_then(|v| parse_date_time_best_effort(v, &tz)))
+            .map(|v| v.map(|date_time| date_time.timestamp() as u32));
+        Ok(DFUInt32Array::new_from_opt_iter(values).into())
+    }
+}
+
+impl fmt::Display for ParseDateTimeBestEffortFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+/// `str_to_date(string, format)` parses the date time by the MySQL format in UTC, the unparsable
+/// string is NULL.
+#[derive(Clone, Debug)]
+pub struct StrToDateFunction {
+    display_name: String,
+}
+
+impl StrToDateFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(StrToDateFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic())
+    }
+}
+
+impl Function for StrToDateFunction {
+    fn name(&self) -> &str {
+        self.display_name.as_str()
+    }
+
+    fn num_arguments(&self) -> usize {
+        2
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        check_string_arguments(&self.display_name, args)?;
+        Ok(DataType::DateTime32(None))
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(true)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, _input_rows: usize) -> Result<DataColumn> {
+        let format = match columns[1].column() {
+            DataColumn::Constant(DataValue::String(Some(format)), _) => {
+                String::from_utf8_lossy(format).to_string()
+            }
+            _ => {
+                return Err(ErrorCode::BadArguments(
+                    "The format argument must be a constant string",
+                ))
+            }
+        };
+
+        let mut strftime = String::with_capacity(format.len());
+        let mut chars = format.chars();
+        while let Some(c) = chars.next() {
+            match c {
+                '%' => match chars.next() {
+                    Some('D') => {
+                        return Err(ErrorCode::BadArguments(format!(
+                            "Unsupported format specifier %D in function {}",
+                            self.display_name
+                        )))
+                    }
+                    Some(c) => push_mysql_specifier(&mut strftime, c),
+                    None => strftime.push_str("%%"),
+                },
+                c => strftime.push(c),
+            }
+        }
+        let items = compile_strftime(&strftime)?;
+
+        let array = columns[0].column().cast_with_type(&DataType::String)?;
+        let array = array.to_array()?;
+        let values = array
+            .string()?
+            .into_iter()
+            .map(|v| v.and_then(|v| parse_date_time_by_format(v, &items)))
+            .map(|v| v.map(|date_time| date_time.timestamp() as u32));
+        Ok(DFUInt32Array::new_from_opt_iter(values).into())
+    }
+}
+
+impl fmt::Display for StrToDateFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+fn check_string_arguments(display_name: &str, args: &[DataType]) -> Result<()> {
+    for arg in args {
+        if !matches!(arg, DataType::String | DataType::Null) {
+            return Err(ErrorCode::IllegalDataType(format!(
+                "Illegal type {:?} of argument of function {}, expected string",
+                arg, display_name
+            )));
+        }
+    }
+    Ok(())
+}
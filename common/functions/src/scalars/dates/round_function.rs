@@ -43,6 +43,13 @@ impl RoundFunction {
     fn execute(&self, time: u32) -> u32 {
         time / self.round * self.round
     }
+
+    // Round the ticks of DateTime64, the sub-second digits are always dropped.
+    #[inline]
+    fn execute_ticks(&self, ticks: i64, precision: u32) -> i64 {
+        let round = self.round as i64 * datetime64_ticks_per_second(precision);
+        ticks.div_euclid(round) * round
+    }
 }
 
 impl Function for RoundFunction {
@@ -53,6 +60,7 @@ impl Function for RoundFunction {
     fn return_type(&self, args: &[DataType]) -> Result<DataType> {
         match args[0] {
             DataType::DateTime32(_) => Ok(DataType::DateTime32(None)),
+            DataType::DateTime64(precision, _) => Ok(DataType::DateTime64(precision, None)),
             _ => Err(ErrorCode::BadDataValueType(format!(
                 "Function {} must have a DateTime type as argument, but got {}",
                 self.display_name, args[0],
@@ -65,6 +73,24 @@ impl Function for RoundFunction {
     }
 
     fn eval(&self, columns: &DataColumnsWithField, _input_rows: usize) -> Result<DataColumn> {
+        if let DataType::DateTime64(precision, _) = columns[0].data_type() {
+            let precision = *precision;
+            return match columns[0].column() {
+                DataColumn::Array(array) => {
+                    let array = array.i64()?;
+                    let arr = array.apply(|x| self.execute_ticks(x, precision));
+                    Ok(DataColumn::Array(arr.into_series()))
+                }
+                DataColumn::Constant(v, rows) => {
+                    if v.is_null() {
+                        return Ok(DataColumn::Constant(DataValue::Int64(None), *rows));
+                    }
+                    let value = self.execute_ticks(v.as_i64()?, precision);
+                    Ok(DataColumn::Constant(DataValue::Int64(Some(value)), *rows))
+                }
+            };
+        }
+
         match columns[0].column() {
             DataColumn::Array(array) => {
                 let array = array.u32()?;
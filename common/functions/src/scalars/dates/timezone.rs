@@ -0,0 +1,111 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::chrono::DateTime;
+use common_datavalues::chrono::NaiveDateTime;
+use common_datavalues::chrono::TimeZone;
+use common_datavalues::chrono::Utc;
+use common_datavalues::prelude::*;
+use common_datavalues::Tz;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use super::parse_date_time::parse_date_time_best_effort;
+
+/// Parses the timezone name, such as `UTC` or `Asia/Shanghai`.
+pub fn parse_timezone(tz: &str) -> Result<Tz> {
+    tz.parse::<Tz>()
+        .map_err(|_| ErrorCode::BadArguments(format!("Unknown timezone: {}", tz)))
+}
+
+/// The timezone of `DateTime32`/`DateTime64`, it's UTC if the type has no timezone.
+pub fn timezone_of_type(data_type: &DataType) -> Result<Tz> {
+    match data_type {
+        DataType::DateTime32(Some(tz)) | DataType::DateTime64(_, Some(tz)) => parse_timezone(tz),
+        _ => Ok(Tz::UTC),
+    }
+}
+
+/// The timezone given by the optional constant string argument, or the timezone of `data_type`.
+pub fn timezone_of_argument(
+    column: Option<&DataColumnWithField>,
+    data_type: &DataType,
+) -> Result<Tz> {
+    match column {
+        None => timezone_of_type(data_type),
+        Some(column) => match column.column() {
+            DataColumn::Constant(DataValue::String(Some(tz)), _) => {
+                parse_timezone(&String::from_utf8_lossy(tz))
+            }
+            _ => Err(ErrorCode::BadArguments(
+                "The timezone argument must be a constant string",
+            )),
+        },
+    }
+}
+
+/// The wall clock time in the timezone, the calendar fields of the result are the local ones.
+pub fn to_wall_clock(date_time: DateTime<Tz>) -> DateTime<Utc> {
+    Utc.from_utc_datetime(&date_time.naive_local())
+}
+
+/// The date time of the wall clock time in the timezone, the earliest one if it's ambiguous.
+pub fn from_wall_clock(tz: &Tz, date_time: &NaiveDateTime) -> Option<DateTime<Tz>> {
+    tz.from_local_datetime(date_time).earliest()
+}
+
+/// Converts the date like column into the date times in the timezone, the dates are the midnight
+/// in the timezone and the strings are parsed in the best effort way.
+pub fn to_date_times(column: &DataColumnWithField, tz: &Tz) -> Result<Vec<Option<DateTime<Tz>>>> {
+    let array = column.column().to_array()?;
+    let from_days = |days: i64| {
+        let date = days.to_date(&Tz::UTC).naive_utc();
+        from_wall_clock(tz, &date.and_hms(0, 0, 0))
+    };
+
+    let date_times = match column.data_type() {
+        DataType::Date16 => array
+            .u16()?
+            .into_iter()
+            .map(|v| v.and_then(|v| from_days(*v as i64)))
+            .collect(),
+        DataType::Date32 => array
+            .i32()?
+            .into_iter()
+            .map(|v| v.and_then(|v| from_days(*v as i64)))
+            .collect(),
+        DataType::DateTime32(_) => array
+            .u32()?
+            .into_iter()
+            .map(|v| v.map(|v| v.to_date_time(tz)))
+            .collect(),
+        DataType::DateTime64(precision, _) => array
+            .i64()?
+            .into_iter()
+            .map(|v| v.map(|v| v.to_date_time64(*precision, tz)))
+            .collect(),
+        DataType::String => array
+            .string()?
+            .into_iter()
+            .map(|v| v.and_then(|v| parse_date_time_best_effort(v, tz)))
+            .collect(),
+        other => {
+            return Err(ErrorCode::IllegalDataType(format!(
+                "Illegal type {:?} of the date time argument, it should be a date, a date time or a string",
+                other
+            )))
+        }
+    };
+    Ok(date_times)
+}
@@ -0,0 +1,174 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::chrono::TimeZone;
+use common_datavalues::chrono::Utc;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use super::format_date_time::DateTimeFormat;
+use super::timezone::timezone_of_argument;
+use super::timezone::to_date_times;
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::Function;
+
+/// `toUnixTimestamp(time[, timezone])` is the seconds since the epoch, the dates and the strings
+/// are in the timezone.
+#[derive(Clone, Debug)]
+pub struct ToUnixTimestampFunction {
+    display_name: String,
+}
+
+impl ToUnixTimestampFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(ToUnixTimestampFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic().monotonicity())
+    }
+}
+
+impl Function for ToUnixTimestampFunction {
+    fn name(&self) -> &str {
+        self.display_name.as_str()
+    }
+
+    fn variadic_arguments(&self) -> Option<(usize, usize)> {
+        Some((1, 2))
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        if !args[0].is_date_or_date_time() && args[0] != DataType::String {
+            return Err(ErrorCode::IllegalDataType(format!(
+                "Illegal type {:?} of the first argument of function {}, it should be a date, a date time or a string",
+                args[0], self.display_name
+            )));
+        }
+        Ok(DataType::UInt32)
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, _input_rows: usize) -> Result<DataColumn> {
+        let tz = timezone_of_argument(columns.get(1), columns[0].data_type())?;
+        let values = to_date_times(&columns[0], &tz)?
+            .into_iter()
+            .map(|v| v.map(|date_time| date_time.timestamp() as u32));
+        Ok(DFUInt32Array::new_from_opt_iter(values).into())
+    }
+}
+
+impl fmt::Display for ToUnixTimestampFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+/// `fromUnixTime(seconds)` is the date time of the unix timestamp,
+/// `fromUnixTime(seconds, format)` formats it by the MySQL format in UTC.
+#[derive(Clone, Debug)]
+pub struct FromUnixTimeFunction {
+    display_name: String,
+}
+
+impl FromUnixTimeFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(FromUnixTimeFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic())
+    }
+}
+
+impl Function for FromUnixTimeFunction {
+    fn name(&self) -> &str {
+        self.display_name.as_str()
+    }
+
+    fn variadic_arguments(&self) -> Option<(usize, usize)> {
+        Some((1, 2))
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        if !args[0].is_numeric() && args[0] != DataType::Null {
+            return Err(ErrorCode::IllegalDataType(format!(
+                "Illegal type {:?} of the first argument of function {}, expected number",
+                args[0], self.display_name
+            )));
+        }
+
+        match args.len() {
+            1 => Ok(DataType::DateTime32(None)),
+            _ => Ok(DataType::String),
+        }
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, _input_rows: usize) -> Result<DataColumn> {
+        let seconds = columns[0].column().cast_with_type(&DataType::Int64)?;
+        if columns.len() == 1 {
+            return seconds.cast_with_type(&DataType::UInt32);
+        }
+
+        let format = match columns[1].column() {
+            DataColumn::Constant(DataValue::String(Some(format)), _) => {
+                DateTimeFormat::try_create_mysql(&String::from_utf8_lossy(format))?
+            }
+            _ => {
+                return Err(ErrorCode::BadArguments(
+                    "The format argument must be a constant string",
+                ))
+            }
+        };
+
+        let array = seconds.to_array()?;
+        let array = array.i64()?;
+        let mut builder = StringArrayBuilder::with_capacity(array.len());
+        let mut buffer = String::new();
+        for seconds in array.into_iter() {
+            match seconds.and_then(|seconds| Utc.timestamp_opt(*seconds, 0).single()) {
+                Some(date_time) => {
+                    buffer.clear();
+                    format.format(&date_time, &mut buffer);
+                    builder.append_value(buffer.as_bytes());
+                }
+                None => builder.append_null(),
+            }
+        }
+        Ok(builder.finish().into())
+    }
+}
+
+impl fmt::Display for FromUnixTimeFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
@@ -22,6 +22,7 @@ use common_datavalues::chrono::Duration;
 use common_datavalues::chrono::TimeZone;
 use common_datavalues::chrono::Utc;
 use common_datavalues::prelude::*;
+use common_datavalues::Tz;
 use common_exception::ErrorCode;
 use common_exception::Result;
 
@@ -189,8 +190,26 @@ where
                     Ok(result.into())
                 }
             },
+            DataType::DateTime64(precision, _) => {
+                let precision = *precision;
+                if let DataColumn::Constant(v, _) = columns[0].column() {
+                    let date_time = v.as_i64()?.to_date_time64(precision, &Tz::UTC).with_timezone(&Utc);
+                    let constant_result = T::to_constant_value(date_time, mode);
+                    Ok(DataColumn::Constant(constant_result, input_rows))
+                } else {
+                    let result = columns[0].column()
+                        .to_array()?
+                        .i64()?
+                        .apply_cast_numeric(|v| {
+                            let date_time = v.to_date_time64(precision, &Tz::UTC).with_timezone(&Utc);
+                            T::to_number(date_time, mode)
+                        }
+                        );
+                    Ok(result.into())
+                }
+            },
             other => Result::Err(ErrorCode::IllegalDataType(format!(
-                "Illegal type {:?} of argument of function {}.Should be a date16/data32 or a dateTime32",
+                "Illegal type {:?} of argument of function {}.Should be a date16/data32 or a dateTime32/dateTime64",
                 other,
                 self.name()))),
         }?;
@@ -22,17 +22,42 @@ use common_datavalues::chrono::NaiveDateTime;
 use common_datavalues::chrono::TimeZone;
 use common_datavalues::chrono::Utc;
 use common_datavalues::columns::DataColumn;
+use common_datavalues::prelude::datetime64_from_date_time;
+use common_datavalues::prelude::datetime64_rescale;
+use common_datavalues::prelude::datetime64_ticks_per_second;
+use common_datavalues::prelude::datetime64_to_string;
+use common_datavalues::prelude::decimal_factor;
+use common_datavalues::prelude::decimal_from_f64;
+use common_datavalues::prelude::decimal_from_str;
+use common_datavalues::prelude::decimal_rescale;
+use common_datavalues::prelude::decimal_to_f64;
+use common_datavalues::prelude::decimal_to_string;
+use common_datavalues::prelude::map_array_entries;
+use common_datavalues::prelude::map_array_with_entries;
+use common_datavalues::prelude::parse_variant;
+use common_datavalues::prelude::variant_to_bytes;
+use common_datavalues::prelude::variant_to_text;
 use common_datavalues::prelude::ArrayApply;
+use common_datavalues::prelude::DFBooleanArray;
+use common_datavalues::prelude::DFFloat64Array;
 use common_datavalues::prelude::DFInt32Array;
+use common_datavalues::prelude::DFInt64Array;
 use common_datavalues::prelude::DFStringArray;
 use common_datavalues::prelude::DFUInt16Array;
 use common_datavalues::prelude::DFUInt32Array;
+use common_datavalues::prelude::DataColumnWithField;
 use common_datavalues::prelude::DataColumnsWithField;
+use common_datavalues::prelude::DateConverter;
+use common_datavalues::prelude::NewDataArray;
+use common_datavalues::prelude::Series;
 use common_datavalues::series::IntoSeries;
+use common_datavalues::DataField;
 use common_datavalues::DataSchema;
 use common_datavalues::DataType;
+use common_datavalues::Tz;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use serde_json::Value as JsonValue;
 
 use crate::scalars::Function;
 use crate::with_match_primitive_type;
@@ -82,6 +107,29 @@ impl Function for CastFunction {
         ));
 
         let array = match (columns[0].data_type(), &self.cast_type) {
+            // Map to Map, the keys and the values are casted respectively
+            (DataType::Map(_, _), DataType::Map(_, _)) => {
+                cast_map(&series, columns[0].data_type(), &self.cast_type)
+            }
+
+            // Variant to others and others to Variant
+            (DataType::Variant, _) => cast_from_variant(&series, &self.cast_type),
+            (_, DataType::Variant) => cast_to_variant(&series, columns[0].data_type()),
+
+            // Decimal to others and others to Decimal
+            (DataType::Decimal(_, scale), _) => cast_from_decimal(&series, *scale, &self.cast_type),
+            (_, DataType::Decimal(precision, scale)) => {
+                cast_to_decimal(&series, columns[0].data_type(), *precision, *scale)
+            }
+
+            // DateTime64 to others and others to DateTime64
+            (DataType::DateTime64(precision, _), _) => {
+                cast_from_datetime64(&series, *precision, &self.cast_type)
+            }
+            (_, DataType::DateTime64(precision, _)) => {
+                cast_to_datetime64(&series, columns[0].data_type(), *precision)
+            }
+
             // Date/DateTime to others
             (DataType::Date16, _) => with_match_primitive_type!(&self.cast_type, |$T| {
                 series.cast_with_type(&self.cast_type)
@@ -114,7 +162,17 @@ impl Function for CastFunction {
                match &self.cast_type {
                 Date16 => Ok(arr.apply_cast_numeric(|v| (v as i64 / 24/ 3600) as u16).into_series()),
                 Date32 => Ok(arr.apply_cast_numeric(|v| (v as i64 / 24/ 3600) as i32).into_series()),
-                String => Ok(DFStringArray::from_iter(arr.into_iter().map(|v| v.map(|x| datetime_to_string( Utc.timestamp(*x as i64, 0_u32), TIME_FMT))) ).into_series()),
+                // The timezone doesn't change the time, only the way it's shown.
+                DateTime32(_) => Ok(series.clone()),
+                String => {
+                    let tz = match columns[0].data_type() {
+                        DataType::DateTime32(Some(tz)) => tz.parse::<Tz>().map_err(|_| {
+                            ErrorCode::BadArguments(format!("Unknown timezone: {}", tz))
+                        })?,
+                        _ => Tz::UTC,
+                    };
+                    Ok(DFStringArray::from_iter(arr.into_iter().map(|v| v.map(|x| x.to_date_time(&tz).format(TIME_FMT).to_string())) ).into_series())
+                },
                 _ =>  Err(error)
                }
             }),
@@ -182,7 +240,326 @@ impl fmt::Display for CastFunction {
     }
 }
 
+fn cast_from_decimal(series: &Series, from_scale: u8, cast_type: &DataType) -> Result<Series> {
+    let arr = series.i64()?;
+    match cast_type {
+        DataType::Decimal(precision, scale) => {
+            let values = arr
+                .into_iter()
+                .map(|v| match v {
+                    Some(v) => decimal_rescale(*v, from_scale, *scale, *precision).map(Some),
+                    None => Ok(None),
+                })
+                .collect::<Result<Vec<_>>>()?;
+            Ok(DFInt64Array::new_from_opt_iter(values.into_iter()).into_series())
+        }
+        DataType::String => {
+            let it = arr
+                .into_iter()
+                .map(|v| v.map(|x| decimal_to_string(*x, from_scale)));
+            Ok(DFStringArray::from_iter(it).into_series())
+        }
+        data_type if data_type.is_floating() => arr
+            .apply_cast_numeric(|v| decimal_to_f64(v, from_scale))
+            .into_series()
+            .cast_with_type(data_type),
+        // The fraction digits are truncated, like the cast from float to integer.
+        data_type if data_type.is_integer() => {
+            let factor = decimal_factor(from_scale);
+            arr.apply(|v| v / factor)
+                .into_series()
+                .cast_with_type(data_type)
+        }
+        _ => Err(ErrorCode::BadDataValueType(format!(
+            "Unsupported cast_with_type from array: {:?} into data_type: {:?}",
+            series, cast_type,
+        ))),
+    }
+}
+
+fn cast_to_decimal(
+    series: &Series,
+    from_type: &DataType,
+    precision: u8,
+    scale: u8,
+) -> Result<Series> {
+    let values = match from_type {
+        DataType::Null => vec![None; series.len()],
+        DataType::String => series
+            .string()?
+            .into_iter()
+            .map(|v| match v {
+                Some(v) => {
+                    let v =
+                        std::str::from_utf8(v).map_err(|e| ErrorCode::BadBytes(e.to_string()))?;
+                    decimal_from_str(v, precision, scale).map(Some)
+                }
+                None => Ok(None),
+            })
+            .collect::<Result<Vec<_>>>()?,
+        data_type if data_type.is_floating() => series
+            .cast_with_type(&DataType::Float64)?
+            .f64()?
+            .into_iter()
+            .map(|v| match v {
+                Some(v) => decimal_from_f64(*v, precision, scale).map(Some),
+                None => Ok(None),
+            })
+            .collect::<Result<Vec<_>>>()?,
+        data_type if data_type.is_integer() => series
+            .cast_with_type(&DataType::Int64)?
+            .i64()?
+            .into_iter()
+            .map(|v| match v {
+                Some(v) => decimal_rescale(*v, 0, scale, precision).map(Some),
+                None => Ok(None),
+            })
+            .collect::<Result<Vec<_>>>()?,
+        _ => {
+            return Err(ErrorCode::BadDataValueType(format!(
+                "Unsupported cast_with_type from array: {:?} into data_type: Decimal({}, {})",
+                series, precision, scale,
+            )))
+        }
+    };
+    Ok(DFInt64Array::new_from_opt_iter(values.into_iter()).into_series())
+}
+
+fn cast_from_datetime64(series: &Series, precision: u32, cast_type: &DataType) -> Result<Series> {
+    let arr = series.i64()?;
+    let seconds_per_day = 24 * 3600;
+    let ticks_per_second = datetime64_ticks_per_second(precision);
+    match cast_type {
+        DataType::DateTime64(to_precision, _) => Ok(arr
+            .apply(|v| datetime64_rescale(v, precision, *to_precision))
+            .into_series()),
+        DataType::DateTime32(_) => Ok(arr
+            .apply_cast_numeric(|v| v.div_euclid(ticks_per_second) as u32)
+            .into_series()),
+        DataType::Date16 => Ok(arr
+            .apply_cast_numeric(|v| (v.div_euclid(ticks_per_second) / seconds_per_day) as u16)
+            .into_series()),
+        DataType::Date32 => Ok(arr
+            .apply_cast_numeric(|v| {
+                v.div_euclid(ticks_per_second).div_euclid(seconds_per_day) as i32
+            })
+            .into_series()),
+        DataType::String => {
+            let it = arr
+                .into_iter()
+                .map(|v| v.map(|x| datetime64_to_string(*x, precision, &Tz::UTC)));
+            Ok(DFStringArray::from_iter(it).into_series())
+        }
+        // The numbers are the ticks, like DateTime32 to numbers are the seconds.
+        data_type if data_type.is_numeric() => series.cast_with_type(data_type),
+        _ => Err(ErrorCode::BadDataValueType(format!(
+            "Unsupported cast_with_type from array: {:?} into data_type: {:?}",
+            series, cast_type,
+        ))),
+    }
+}
+
+fn cast_to_datetime64(series: &Series, from_type: &DataType, precision: u32) -> Result<Series> {
+    let seconds_per_day = 24 * 3600;
+    let ticks_per_second = datetime64_ticks_per_second(precision);
+    let values: Vec<Option<i64>> = match from_type {
+        DataType::Null => vec![None; series.len()],
+        DataType::String => series
+            .string()?
+            .into_iter()
+            .map(|v| {
+                v.and_then(string_to_datetime64)
+                    .map(|t| datetime64_from_date_time(&Utc.from_utc_datetime(&t), precision))
+            })
+            .collect(),
+        DataType::DateTime32(_) => series
+            .u32()?
+            .into_iter()
+            .map(|v| v.map(|x| *x as i64 * ticks_per_second))
+            .collect(),
+        DataType::Date16 => series
+            .u16()?
+            .into_iter()
+            .map(|v| v.map(|x| *x as i64 * seconds_per_day * ticks_per_second))
+            .collect(),
+        DataType::Date32 => series
+            .i32()?
+            .into_iter()
+            .map(|v| v.map(|x| *x as i64 * seconds_per_day * ticks_per_second))
+            .collect(),
+        // The numbers are the ticks, like the numbers to DateTime32 are the seconds.
+        data_type if data_type.is_numeric() => {
+            return series.cast_with_type(&DataType::Int64);
+        }
+        _ => {
+            return Err(ErrorCode::BadDataValueType(format!(
+                "Unsupported cast_with_type from array: {:?} into data_type: DateTime64({})",
+                series, precision,
+            )))
+        }
+    };
+    Ok(DFInt64Array::new_from_opt_iter(values.into_iter()).into_series())
+}
+
+fn cast_from_variant(series: &Series, cast_type: &DataType) -> Result<Series> {
+    let values = series
+        .string()?
+        .into_iter()
+        .map(|v| v.map(parse_variant).transpose())
+        .collect::<Result<Vec<_>>>()?;
+
+    let error = |v: &JsonValue| {
+        ErrorCode::BadDataValueType(format!(
+            "Cannot cast Variant value '{}' to {:?}",
+            v, cast_type
+        ))
+    };
+
+    match cast_type {
+        DataType::String => {
+            let it = values.iter().map(|v| v.as_ref().and_then(variant_to_text));
+            Ok(DFStringArray::from_iter(it).into_series())
+        }
+        DataType::Boolean => {
+            let values = values
+                .iter()
+                .map(|v| match v {
+                    None | Some(JsonValue::Null) => Ok(None),
+                    Some(JsonValue::Bool(b)) => Ok(Some(*b)),
+                    Some(JsonValue::String(s)) if s.eq_ignore_ascii_case("true") => Ok(Some(true)),
+                    Some(JsonValue::String(s)) if s.eq_ignore_ascii_case("false") => {
+                        Ok(Some(false))
+                    }
+                    Some(other) => Err(error(other)),
+                })
+                .collect::<Result<Vec<_>>>()?;
+            Ok(DFBooleanArray::new_from_opt_iter(values.into_iter()).into_series())
+        }
+        // Integers are converted through Int64 to keep all the digits, the fraction is truncated.
+        data_type if data_type.is_integer() => {
+            let values = values
+                .iter()
+                .map(|v| match v {
+                    None | Some(JsonValue::Null) => Ok(None),
+                    Some(JsonValue::Bool(b)) => Ok(Some(*b as i64)),
+                    Some(JsonValue::Number(n)) => n
+                        .as_i64()
+                        .or_else(|| n.as_f64().map(|f| f as i64))
+                        .map(Some)
+                        .ok_or_else(|| error(v.as_ref().unwrap())),
+                    Some(JsonValue::String(s)) => s
+                        .trim()
+                        .parse::<i64>()
+                        .ok()
+                        .or_else(|| s.trim().parse::<f64>().ok().map(|f| f as i64))
+                        .map(Some)
+                        .ok_or_else(|| error(v.as_ref().unwrap())),
+                    Some(other) => Err(error(other)),
+                })
+                .collect::<Result<Vec<_>>>()?;
+            DFInt64Array::new_from_opt_iter(values.into_iter())
+                .into_series()
+                .cast_with_type(data_type)
+        }
+        data_type if data_type.is_floating() => {
+            let values = values
+                .iter()
+                .map(|v| match v {
+                    None | Some(JsonValue::Null) => Ok(None),
+                    Some(JsonValue::Bool(b)) => Ok(Some(*b as i64 as f64)),
+                    Some(JsonValue::Number(n)) => Ok(n.as_f64()),
+                    Some(JsonValue::String(s)) => s
+                        .trim()
+                        .parse::<f64>()
+                        .map(Some)
+                        .map_err(|_| error(v.as_ref().unwrap())),
+                    Some(other) => Err(error(other)),
+                })
+                .collect::<Result<Vec<_>>>()?;
+            DFFloat64Array::new_from_opt_iter(values.into_iter())
+                .into_series()
+                .cast_with_type(data_type)
+        }
+        _ => Err(ErrorCode::BadDataValueType(format!(
+            "Unsupported cast_with_type from array: {:?} into data_type: {:?}",
+            series, cast_type,
+        ))),
+    }
+}
+
+fn cast_to_variant(series: &Series, from_type: &DataType) -> Result<Series> {
+    let values: Vec<Option<JsonValue>> = match from_type {
+        DataType::Null => vec![None; series.len()],
+        // The string is the JSON text, like the `parse_json` function.
+        DataType::String => series
+            .string()?
+            .into_iter()
+            .map(|v| v.map(parse_variant).transpose())
+            .collect::<Result<Vec<_>>>()?,
+        DataType::Boolean => series
+            .bool()?
+            .into_iter()
+            .map(|v| v.map(JsonValue::from))
+            .collect(),
+        DataType::Decimal(_, scale) => series
+            .i64()?
+            .into_iter()
+            .map(|v| v.map(|x| JsonValue::from(decimal_to_f64(*x, *scale))))
+            .collect(),
+        data_type if data_type.is_floating() => series
+            .cast_with_type(&DataType::Float64)?
+            .f64()?
+            .into_iter()
+            .map(|v| v.map(|x| JsonValue::from(*x)))
+            .collect(),
+        data_type if data_type.is_unsigned_integer() => series
+            .cast_with_type(&DataType::UInt64)?
+            .u64()?
+            .into_iter()
+            .map(|v| v.map(|x| JsonValue::from(*x)))
+            .collect(),
+        data_type if data_type.is_signed_integer() => series
+            .cast_with_type(&DataType::Int64)?
+            .i64()?
+            .into_iter()
+            .map(|v| v.map(|x| JsonValue::from(*x)))
+            .collect(),
+        _ => {
+            return Err(ErrorCode::BadDataValueType(format!(
+                "Unsupported cast_with_type from array: {:?} into data_type: Variant",
+                series,
+            )))
+        }
+    };
+
+    let it = values.iter().map(|v| v.as_ref().map(variant_to_bytes));
+    Ok(DFStringArray::from_iter(it).into_series())
+}
+
 #[inline]
+fn cast_map(series: &Series, from_type: &DataType, cast_type: &DataType) -> Result<Series> {
+    let (from_key, from_value, key_type, value_type) = match (from_type, cast_type) {
+        (DataType::Map(a, b), DataType::Map(c, d)) => (a, b, c, d),
+        _ => unreachable!(),
+    };
+
+    let array = series.list()?;
+    let (keys, values) = map_array_entries(array)?;
+    let keys = cast_map_entries(&keys, from_key, key_type)?;
+    let values = cast_map_entries(&values, from_value, value_type)?;
+    Ok(map_array_with_entries(
+        array, &keys, &values, key_type, value_type,
+    ))
+}
+
+fn cast_map_entries(series: &Series, from_type: &DataType, cast_type: &DataType) -> Result<Series> {
+    let field = DataField::new("entries", from_type.clone(), true);
+    let column = DataColumnWithField::new(series.clone().into(), field);
+    CastFunction::create("cast".to_string(), cast_type.clone())?
+        .eval(&[column], series.len())?
+        .to_array()
+}
+
 fn datetime_to_string(date: DateTime<Utc>, fmt: &str) -> String {
     date.format(fmt).to_string()
 }
@@ -195,6 +572,13 @@ fn string_to_datetime(date_str: impl AsRef<[u8]>) -> Option<NaiveDateTime> {
     s.and_then(|c| NaiveDateTime::parse_from_str(c, "%Y-%m-%d %H:%M:%S").ok())
 }
 
+// The fractional seconds are optional, like `2021-08-30 10:47:42.123`
+#[inline]
+fn string_to_datetime64(date_str: impl AsRef<[u8]>) -> Option<NaiveDateTime> {
+    let s = std::str::from_utf8(date_str.as_ref()).ok();
+    s.and_then(|c| NaiveDateTime::parse_from_str(c, "%Y-%m-%d %H:%M:%S%.f").ok())
+}
+
 #[inline]
 fn string_to_date(date_str: impl AsRef<[u8]>) -> Option<NaiveDate> {
     let s = std::str::from_utf8(date_str.as_ref()).ok();
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 use common_datavalues::DataType;
+use common_datavalues::DEFAULT_DATETIME64_PRECISION;
 
 use crate::scalars::function_factory::FactoryCreator;
 use crate::scalars::function_factory::FunctionDescription;
@@ -63,5 +64,9 @@ impl ToCastFunction {
             "toDateTime32",
             Self::cast_function_creator(DataType::DateTime32(None)),
         );
+        factory.register(
+            "toDateTime64",
+            Self::cast_function_creator(DataType::DateTime64(DEFAULT_DATETIME64_PRECISION, None)),
+        );
     }
 }
@@ -20,15 +20,18 @@ use common_exception::Result;
 use lazy_static::lazy_static;
 
 use crate::scalars::ArithmeticFunction;
+use crate::scalars::ArrayClassFunction;
 use crate::scalars::ComparisonFunction;
 use crate::scalars::ConditionalFunction;
 use crate::scalars::DateFunction;
 use crate::scalars::Function;
 use crate::scalars::HashesFunction;
 use crate::scalars::LogicFunction;
+use crate::scalars::MapClassFunction;
 use crate::scalars::MathsFunction;
 use crate::scalars::NullableFunction;
 use crate::scalars::OtherFunction;
+use crate::scalars::SemiStructuredFunction;
 use crate::scalars::StringFunction;
 use crate::scalars::ToCastFunction;
 use crate::scalars::TupleClassFunction;
@@ -43,6 +46,7 @@ pub struct FunctionFeatures {
     pub is_bool_func: bool,
     pub is_context_func: bool,
     pub maybe_monotonic: bool,
+    pub is_array_join: bool,
 }
 
 impl FunctionFeatures {
@@ -53,6 +57,7 @@ impl FunctionFeatures {
             is_bool_func: false,
             is_context_func: false,
             maybe_monotonic: false,
+            is_array_join: false,
         }
     }
 
@@ -80,6 +85,12 @@ impl FunctionFeatures {
         self.maybe_monotonic = true;
         self
     }
+
+    /// The function unnests the array into the rows, the other columns are replicated.
+    pub fn array_join(mut self) -> FunctionFeatures {
+        self.is_array_join = true;
+        self
+    }
 }
 
 pub struct FunctionDescription {
@@ -123,6 +134,9 @@ lazy_static! {
         OtherFunction::register(&mut function_factory);
         MathsFunction::register(&mut function_factory);
         TupleClassFunction::register(&mut function_factory);
+        SemiStructuredFunction::register(&mut function_factory);
+        MapClassFunction::register(&mut function_factory);
+        ArrayClassFunction::register(&mut function_factory);
 
         Arc::new(function_factory)
     };
@@ -0,0 +1,118 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use super::map_element::cast_to_series;
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::Function;
+
+/// Creates a map from the key-value pairs, `map(k1, v1, k2, v2, ...)`.
+#[derive(Clone)]
+pub struct MapFunction {
+    display_name: String,
+}
+
+impl MapFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(MapFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic())
+    }
+}
+
+impl Function for MapFunction {
+    fn name(&self) -> &str {
+        "map"
+    }
+
+    fn num_arguments(&self) -> usize {
+        0
+    }
+
+    fn variadic_arguments(&self) -> Option<(usize, usize)> {
+        Some((2, usize::MAX))
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        if args.len() % 2 != 0 {
+            return Err(ErrorCode::NumberArgumentsNotMatch(format!(
+                "Function {} expects the key-value pairs, but got {} arguments",
+                self.display_name,
+                args.len()
+            )));
+        }
+
+        let keys = args.iter().step_by(2).cloned().collect::<Vec<_>>();
+        let values = args.iter().skip(1).step_by(2).cloned().collect::<Vec<_>>();
+        create_map_type(aggregate_types(&keys)?, aggregate_types(&values)?)
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let args = columns
+            .iter()
+            .map(|c| c.data_type().clone())
+            .collect::<Vec<_>>();
+        let (key_type, value_type) = match self.return_type(&args)? {
+            DataType::Map(key_type, value_type) => (*key_type, *value_type),
+            _ => unreachable!(),
+        };
+
+        let mut pairs = Vec::with_capacity(columns.len() / 2);
+        for pair in columns.chunks(2) {
+            let key = cast_to_series(&pair[0], &key_type, input_rows)?;
+            let value = cast_to_series(&pair[1], &value_type, input_rows)?;
+            pairs.push((key, value));
+        }
+
+        let entries_type = map_entries_field(&key_type, &value_type)
+            .data_type()
+            .clone();
+        let maps = (0..input_rows)
+            .map(|row| {
+                let entries = pairs
+                    .iter()
+                    .map(|(key, value)| {
+                        Ok(DataValue::Struct(vec![
+                            key.try_get(row)?,
+                            value.try_get(row)?,
+                        ]))
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(DataValue::List(Some(entries), entries_type.clone()))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(build_map_array(&maps, &key_type, &value_type)?.into())
+    }
+}
+
+impl fmt::Display for MapFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
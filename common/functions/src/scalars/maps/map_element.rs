@@ -0,0 +1,72 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::scalars::CastFunction;
+
+/// Looks up the key in each map, NULL is returned if the key is not found.
+pub(crate) fn map_element(
+    map: &DataColumnWithField,
+    key: &DataColumnWithField,
+    input_rows: usize,
+) -> Result<DataColumn> {
+    let (key_type, value_type) = match map.data_type() {
+        DataType::Map(key_type, value_type) => (key_type.as_ref(), value_type.as_ref()),
+        other => {
+            return Err(ErrorCode::IllegalDataType(format!(
+                "Expected map, but got {}",
+                other
+            )));
+        }
+    };
+
+    let series = map.column().to_array()?;
+    let array = series.list()?;
+    let (keys, values) = map_array_entries(array)?;
+    let offsets = array.inner().offsets().as_slice();
+    let targets = cast_to_series(key, key_type, input_rows)?;
+
+    let mut result = Vec::with_capacity(input_rows);
+    for row in 0..input_rows {
+        let target = targets.try_get(row)?;
+        let mut value = DataValue::from(value_type);
+        if !array.is_null(row) && !target.is_null() {
+            for i in offsets[row] as usize..offsets[row + 1] as usize {
+                if keys.try_get(i)? == target {
+                    value = values.try_get(i)?;
+                    break;
+                }
+            }
+        }
+        result.push(value);
+    }
+    Ok(DataValue::try_into_data_array(&result, value_type)?.into())
+}
+
+pub(crate) fn cast_to_series(
+    column: &DataColumnWithField,
+    data_type: &DataType,
+    input_rows: usize,
+) -> Result<Series> {
+    if column.data_type() == data_type {
+        return column.column().to_array();
+    }
+
+    CastFunction::create("cast".to_string(), data_type.clone())?
+        .eval(&[column.clone()], input_rows)?
+        .to_array()
+}
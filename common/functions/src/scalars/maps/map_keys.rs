@@ -0,0 +1,91 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::Function;
+
+/// Returns the keys of the map as an array.
+#[derive(Clone)]
+pub struct MapKeysFunction {
+    display_name: String,
+}
+
+impl MapKeysFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(MapKeysFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic())
+    }
+}
+
+impl Function for MapKeysFunction {
+    fn name(&self) -> &str {
+        "mapKeys"
+    }
+
+    fn num_arguments(&self) -> usize {
+        1
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        match &args[0] {
+            DataType::Map(key_type, _) => Ok(DataType::List(Box::new(DataField::new(
+                "item",
+                key_type.as_ref().clone(),
+                true,
+            )))),
+            other => Err(ErrorCode::IllegalDataType(format!(
+                "Expected map, but got {}",
+                other
+            ))),
+        }
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, _input_rows: usize) -> Result<DataColumn> {
+        let data_type = self.return_type(&[columns[0].data_type().clone()])?;
+        let series = columns[0].column().to_array()?;
+        let array = series.list()?;
+        let (keys, _) = map_array_entries(array)?;
+
+        let array = LargeListArray::from_data(
+            data_type.to_arrow(),
+            array.inner().offsets().clone(),
+            keys.get_array_ref(),
+            array.inner().validity().cloned(),
+        );
+        Ok(DFListArray::new(array).into_series().into())
+    }
+}
+
+impl fmt::Display for MapKeysFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
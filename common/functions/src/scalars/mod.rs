@@ -13,6 +13,7 @@
 // limitations under the License.
 
 mod arithmetics;
+mod arrays;
 mod comparisons;
 mod conditionals;
 mod dates;
@@ -25,14 +26,17 @@ mod function_literal;
 mod function_monotonic;
 mod hashes;
 mod logics;
+mod maps;
 mod maths;
 mod nullables;
 mod others;
+mod semi_structureds;
 mod strings;
 mod tuples;
 mod udfs;
 
 pub use arithmetics::*;
+pub use arrays::*;
 pub use comparisons::*;
 pub use conditionals::*;
 pub use dates::*;
@@ -45,9 +49,11 @@ pub use function_literal::LiteralFunction;
 pub use function_monotonic::Monotonicity;
 pub use hashes::*;
 pub use logics::*;
+pub use maps::*;
 pub use maths::*;
 pub use nullables::*;
 pub use others::*;
+pub use semi_structureds::*;
 pub use strings::*;
 pub use tuples::*;
 pub use udfs::*;
@@ -0,0 +1,89 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::Function;
+
+/// Returns NULL if the string is a valid JSON document, otherwise the parsing error.
+#[derive(Clone)]
+pub struct CheckJsonFunction {
+    display_name: String,
+}
+
+impl CheckJsonFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(CheckJsonFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic())
+    }
+}
+
+impl Function for CheckJsonFunction {
+    fn name(&self) -> &str {
+        "check_json"
+    }
+
+    fn num_arguments(&self) -> usize {
+        1
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        if !matches!(
+            args[0],
+            DataType::String | DataType::Variant | DataType::Null
+        ) {
+            return Err(ErrorCode::IllegalDataType(format!(
+                "Expected string or variant or null, but got {}",
+                args[0]
+            )));
+        }
+        Ok(DataType::String)
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(true)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        if columns[0].data_type() == &DataType::Null {
+            return Ok(DataColumn::Constant(DataValue::Null, input_rows));
+        }
+
+        let column = columns[0].column().cast_with_type(&DataType::String)?;
+        let series = column.to_minimal_array()?;
+        let it = series.string()?.into_iter().map(|v| {
+            v.and_then(|v| serde_json::from_slice::<serde_json::Value>(v).err())
+                .map(|e| e.to_string())
+        });
+        let column: DataColumn = DFStringArray::from_iter(it).into();
+        Ok(column.resize_constant(input_rows))
+    }
+}
+
+impl fmt::Display for CheckJsonFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
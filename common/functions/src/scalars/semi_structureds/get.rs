@@ -0,0 +1,137 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use super::json_path::get_by_path;
+use super::json_path::json_values;
+use super::json_path::JsonPathElem;
+use crate::scalars::arrays::array_element;
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::maps::map_element;
+use crate::scalars::ArrayElementFunction;
+use crate::scalars::Function;
+
+/// Extracts the value of the object by the key or the value of the array by the index,
+/// `v['a'][0]` is rewritten into `get(get(v, 'a'), 0)`. The value of the map is looked up
+/// by the key in the same way, and the item of the array is got by the index starting from 1.
+#[derive(Clone)]
+pub struct GetFunction {
+    display_name: String,
+}
+
+impl GetFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(GetFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic())
+    }
+}
+
+impl Function for GetFunction {
+    fn name(&self) -> &str {
+        "get"
+    }
+
+    fn num_arguments(&self) -> usize {
+        2
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        match &args[0] {
+            DataType::Map(_, value_type) => return Ok(value_type.as_ref().clone()),
+            DataType::List(_) => return ArrayElementFunction::item_type(args),
+            _ => {}
+        }
+
+        if !matches!(
+            args[0],
+            DataType::String | DataType::Variant | DataType::Null
+        ) {
+            return Err(ErrorCode::IllegalDataType(format!(
+                "Expected parameter 1 is string or variant or map or array or null, but got {}",
+                args[0]
+            )));
+        }
+
+        if !args[1].is_unsigned_integer() && !matches!(args[1], DataType::String | DataType::Null) {
+            return Err(ErrorCode::IllegalDataType(format!(
+                "Expected parameter 2 is unsigned integer or string or null, but got {}",
+                args[1]
+            )));
+        }
+        Ok(DataType::Variant)
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(true)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        match columns[0].data_type() {
+            DataType::Map(_, _) => return map_element(&columns[0], &columns[1], input_rows),
+            DataType::List(_) => return array_element(&columns[0], &columns[1], input_rows),
+            _ => {}
+        }
+
+        let values = json_values(&columns[0], input_rows)?;
+        let paths: Vec<Option<Vec<JsonPathElem>>> = match columns[1].data_type() {
+            data_type if data_type.is_unsigned_integer() => columns[1]
+                .column()
+                .cast_with_type(&DataType::UInt64)?
+                .to_array()?
+                .u64()?
+                .into_iter()
+                .map(|v| v.map(|i| vec![JsonPathElem::Index(*i as usize)]))
+                .collect(),
+            _ => {
+                // The key is the whole string, so it's not parsed as a path.
+                let keys = columns[1]
+                    .column()
+                    .cast_with_type(&DataType::String)?
+                    .to_array()?;
+                keys.string()?
+                    .into_iter()
+                    .map(|v| {
+                        v.map(|k| vec![JsonPathElem::Key(String::from_utf8_lossy(k).to_string())])
+                    })
+                    .collect()
+            }
+        };
+
+        let it = values
+            .iter()
+            .zip(paths.iter())
+            .map(|(value, path)| match (value, path) {
+                (Some(value), Some(path)) => get_by_path(value, path).map(variant_to_bytes),
+                _ => None,
+            });
+        Ok(DFStringArray::from_iter(it).into())
+    }
+}
+
+impl fmt::Display for GetFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
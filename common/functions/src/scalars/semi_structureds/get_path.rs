@@ -0,0 +1,120 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use super::json_path::get_by_path;
+use super::json_path::json_paths;
+use super::json_path::json_values;
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::Function;
+
+/// Extracts the value by the path like `a.b[0]`, `get_path` returns the Variant and
+/// `json_extract_path_text` returns the String.
+#[derive(Clone)]
+pub struct GetPathFunction {
+    display_name: String,
+    as_text: bool,
+}
+
+impl GetPathFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(GetPathFunction {
+            display_name: display_name.to_string(),
+            as_text: false,
+        }))
+    }
+
+    pub fn try_create_text(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(GetPathFunction {
+            display_name: display_name.to_string(),
+            as_text: true,
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic())
+    }
+
+    pub fn text_desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create_text))
+            .features(FunctionFeatures::default().deterministic())
+    }
+}
+
+impl Function for GetPathFunction {
+    fn name(&self) -> &str {
+        match self.as_text {
+            true => "json_extract_path_text",
+            false => "get_path",
+        }
+    }
+
+    fn num_arguments(&self) -> usize {
+        2
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        if !matches!(
+            args[0],
+            DataType::String | DataType::Variant | DataType::Null
+        ) {
+            return Err(ErrorCode::IllegalDataType(format!(
+                "Expected parameter 1 is string or variant or null, but got {}",
+                args[0]
+            )));
+        }
+
+        if !matches!(args[1], DataType::String | DataType::Null) {
+            return Err(ErrorCode::IllegalDataType(format!(
+                "Expected parameter 2 is string or null, but got {}",
+                args[1]
+            )));
+        }
+
+        match self.as_text {
+            true => Ok(DataType::String),
+            false => Ok(DataType::Variant),
+        }
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(true)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let values = json_values(&columns[0], input_rows)?;
+        let paths = json_paths(&columns[1], input_rows)?;
+
+        let it = values.iter().zip(paths.iter()).map(|(value, path)| {
+            let value = get_by_path(value.as_ref()?, path.as_ref()?)?;
+            match self.as_text {
+                true => variant_to_text(value).map(String::into_bytes),
+                false => Some(variant_to_bytes(value)),
+            }
+        });
+        Ok(DFStringArray::from_iter(it).into())
+    }
+}
+
+impl fmt::Display for GetPathFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
@@ -0,0 +1,151 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use serde_json::Value as JsonValue;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum JsonPathElem {
+    Key(String),
+    Index(usize),
+}
+
+/// Parse the path like `a.b[0]["c"]` into the elements, the keys can be quoted by
+/// double quotes after a dot or by single or double quotes inside the brackets.
+pub fn parse_json_path(path: &[u8]) -> Result<Vec<JsonPathElem>> {
+    let error = || {
+        ErrorCode::BadArguments(format!(
+            "Invalid JSON path: '{}'",
+            String::from_utf8_lossy(path)
+        ))
+    };
+
+    let path = std::str::from_utf8(path).map_err(|_| error())?;
+    let chars = path.chars().collect::<Vec<_>>();
+    let mut elems = vec![];
+    let mut pos = 0;
+    while pos < chars.len() {
+        match chars[pos] {
+            '[' => {
+                let end = find(&chars, pos + 1, ']').ok_or_else(error)?;
+                let inner = chars[pos + 1..end].iter().collect::<String>();
+                let inner = inner.trim();
+                let elem = match unquote(inner) {
+                    Some(key) => JsonPathElem::Key(key),
+                    None => JsonPathElem::Index(inner.parse::<usize>().map_err(|_| error())?),
+                };
+                elems.push(elem);
+                pos = end + 1;
+            }
+            '.' if !elems.is_empty() => {
+                pos += 1;
+                if chars.get(pos) == Some(&'"') {
+                    let end = find(&chars, pos + 1, '"').ok_or_else(error)?;
+                    elems.push(JsonPathElem::Key(chars[pos + 1..end].iter().collect()));
+                    pos = end + 1;
+                } else {
+                    let end = find_key_end(&chars, pos);
+                    if end == pos {
+                        return Err(error());
+                    }
+                    elems.push(JsonPathElem::Key(chars[pos..end].iter().collect()));
+                    pos = end;
+                }
+            }
+            '"' if elems.is_empty() => {
+                let end = find(&chars, pos + 1, '"').ok_or_else(error)?;
+                elems.push(JsonPathElem::Key(chars[pos + 1..end].iter().collect()));
+                pos = end + 1;
+            }
+            _ if elems.is_empty() => {
+                let end = find_key_end(&chars, pos);
+                if end == pos {
+                    return Err(error());
+                }
+                elems.push(JsonPathElem::Key(chars[pos..end].iter().collect()));
+                pos = end;
+            }
+            _ => return Err(error()),
+        }
+    }
+
+    if elems.is_empty() {
+        return Err(error());
+    }
+    Ok(elems)
+}
+
+/// Get the inner value by the path, returns None if any element of the path is missing.
+pub fn get_by_path<'a>(value: &'a JsonValue, path: &[JsonPathElem]) -> Option<&'a JsonValue> {
+    path.iter().try_fold(value, |value, elem| match elem {
+        JsonPathElem::Key(key) => value.as_object()?.get(key),
+        JsonPathElem::Index(index) => value.as_array()?.get(*index),
+    })
+}
+
+/// The JSON documents of the String or Variant column, Null column is treated as all NULLs.
+pub fn json_values(column: &DataColumnWithField, rows: usize) -> Result<Vec<Option<JsonValue>>> {
+    if column.data_type() == &DataType::Null {
+        return Ok(vec![None; rows]);
+    }
+
+    let series = column
+        .column()
+        .cast_with_type(&DataType::String)?
+        .to_array()?;
+    series
+        .string()?
+        .into_iter()
+        .map(|v| v.map(parse_variant).transpose())
+        .collect()
+}
+
+/// The parsed paths of the String column, the constant path is parsed only once.
+pub fn json_paths(
+    column: &DataColumnWithField,
+    rows: usize,
+) -> Result<Vec<Option<Vec<JsonPathElem>>>> {
+    match column.column().cast_with_type(&DataType::String)? {
+        DataColumn::Constant(DataValue::String(path), _) => {
+            let path = path.map(|p| parse_json_path(&p)).transpose()?;
+            Ok(vec![path; rows])
+        }
+        DataColumn::Constant(_, _) => Ok(vec![None; rows]),
+        DataColumn::Array(series) => series
+            .string()?
+            .into_iter()
+            .map(|v| v.map(parse_json_path).transpose())
+            .collect(),
+    }
+}
+
+fn find(chars: &[char], start: usize, target: char) -> Option<usize> {
+    (start..chars.len()).find(|i| chars[*i] == target)
+}
+
+fn find_key_end(chars: &[char], start: usize) -> usize {
+    (start..chars.len())
+        .find(|i| chars[*i] == '.' || chars[*i] == '[')
+        .unwrap_or(chars.len())
+}
+
+fn unquote(s: &str) -> Option<String> {
+    let quoted = s.len() >= 2
+        && ((s.starts_with('\'') && s.ends_with('\'')) || (s.starts_with('"') && s.ends_with('"')));
+    match quoted {
+        true => Some(s[1..s.len() - 1].to_string()),
+        false => None,
+    }
+}
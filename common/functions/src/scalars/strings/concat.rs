@@ -0,0 +1,190 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::Function;
+
+/// Concatenates the strings, returns NULL if any argument is NULL.
+#[derive(Clone)]
+pub struct ConcatFunction {
+    display_name: String,
+}
+
+impl ConcatFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(ConcatFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic())
+    }
+}
+
+impl Function for ConcatFunction {
+    fn name(&self) -> &str {
+        "concat"
+    }
+
+    fn variadic_arguments(&self) -> Option<(usize, usize)> {
+        Some((1, usize::MAX - 1))
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        check_string_arguments(args)?;
+        Ok(DataType::String)
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(true)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let series = string_series(columns)?;
+        let arrays = series
+            .iter()
+            .map(|series| series.string())
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut builder = StringArrayBuilder::with_capacity(input_rows);
+        let mut buf = Vec::new();
+        for row in 0..input_rows {
+            if arrays.iter().any(|array| array.is_null(row)) {
+                builder.append_null();
+                continue;
+            }
+
+            buf.clear();
+            for array in arrays.iter() {
+                buf.extend_from_slice(array.inner().value(row));
+            }
+            builder.append_value(&buf);
+        }
+        Ok(builder.finish().into())
+    }
+}
+
+impl fmt::Display for ConcatFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+/// Concatenates the strings with the separator, the NULL arguments after the separator are
+/// skipped, returns NULL if the separator is NULL.
+#[derive(Clone)]
+pub struct ConcatWsFunction {
+    display_name: String,
+}
+
+impl ConcatWsFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(ConcatWsFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic())
+    }
+}
+
+impl Function for ConcatWsFunction {
+    fn name(&self) -> &str {
+        "concat_ws"
+    }
+
+    fn variadic_arguments(&self) -> Option<(usize, usize)> {
+        Some((2, usize::MAX - 1))
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        check_string_arguments(args)?;
+        Ok(DataType::String)
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(true)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let series = string_series(columns)?;
+        let arrays = series
+            .iter()
+            .map(|series| series.string())
+            .collect::<Result<Vec<_>>>()?;
+        let (separator, arrays) = (arrays[0], &arrays[1..]);
+
+        let mut builder = StringArrayBuilder::with_capacity(input_rows);
+        let mut buf = Vec::new();
+        for row in 0..input_rows {
+            if separator.is_null(row) {
+                builder.append_null();
+                continue;
+            }
+
+            buf.clear();
+            let mut is_first = true;
+            for array in arrays.iter().filter(|array| !array.is_null(row)) {
+                if !is_first {
+                    buf.extend_from_slice(separator.inner().value(row));
+                }
+                buf.extend_from_slice(array.inner().value(row));
+                is_first = false;
+            }
+            builder.append_value(&buf);
+        }
+        Ok(builder.finish().into())
+    }
+}
+
+impl fmt::Display for ConcatWsFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+fn check_string_arguments(args: &[DataType]) -> Result<()> {
+    for arg in args {
+        if !arg.is_numeric() && arg != &DataType::String && arg != &DataType::Null {
+            return Err(ErrorCode::IllegalDataType(format!(
+                "Expected string or number or null, but got {}",
+                arg
+            )));
+        }
+    }
+    Ok(())
+}
+
+fn string_series(columns: &DataColumnsWithField) -> Result<Vec<Series>> {
+    columns
+        .iter()
+        .map(|column| {
+            column
+                .column()
+                .cast_with_type(&DataType::String)?
+                .to_array()
+        })
+        .collect()
+}
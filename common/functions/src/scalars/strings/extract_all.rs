@@ -0,0 +1,119 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_arrow::arrow::bitmap::Bitmap;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+
+use super::regexp::cast_regexp_columns;
+use super::regexp::check_regexp_arguments;
+use super::regexp::string_value;
+use super::regexp::ConstantRegexp;
+use super::regexp::RegexpCache;
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::Function;
+
+/// extractAll(haystack, pattern), returns an array of all the substrings matching the pattern.
+/// If the pattern has capture groups, the first group of each match is returned instead.
+#[derive(Clone)]
+pub struct ExtractAllFunction {
+    display_name: String,
+    constant_regexp: ConstantRegexp,
+}
+
+impl ExtractAllFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(ExtractAllFunction {
+            display_name: display_name.to_string(),
+            constant_regexp: ConstantRegexp::default(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic())
+    }
+}
+
+impl Function for ExtractAllFunction {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn num_arguments(&self) -> usize {
+        2
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        check_regexp_arguments(args, &[])?;
+        Ok(create_list_type(DataType::String))
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(true)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let columns = cast_regexp_columns(columns, &[])?;
+        let mut cache = RegexpCache::try_create(&self.constant_regexp, &columns[1], None)?;
+
+        let (strings, patterns) = (columns[0].to_array()?, columns[1].to_array()?);
+        let (strings, patterns) = (strings.string()?, patterns.string()?);
+
+        let mut items = StringArrayBuilder::with_capacity(input_rows);
+        let mut offsets = Vec::with_capacity(input_rows + 1);
+        let mut validity = Vec::with_capacity(input_rows);
+        offsets.push(0i64);
+        for row in 0..input_rows {
+            let mut count = 0;
+            match (string_value(strings, row), string_value(patterns, row)) {
+                (Some(s), Some(pattern)) => {
+                    let re = cache.get(pattern, b"")?;
+                    // The group 0 is the whole match, it's used if there is no capture group.
+                    let group = match re.captures_len() {
+                        1 => 0,
+                        _ => 1,
+                    };
+                    for caps in re.captures_iter(s) {
+                        if let Some(m) = caps.get(group) {
+                            items.append_value(m.as_bytes());
+                            count += 1;
+                        }
+                    }
+                    validity.push(true);
+                }
+                _ => validity.push(false),
+            }
+            offsets.push(offsets[row] + count);
+        }
+
+        let items = items.finish().into_series();
+        Ok(build_list_array_from_offsets(
+            offsets,
+            &items,
+            &DataType::String,
+            Some(Bitmap::from_trusted_len_iter(validity.into_iter())),
+        )
+        .into())
+    }
+}
+
+impl fmt::Display for ExtractAllFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
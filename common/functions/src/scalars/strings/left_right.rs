@@ -0,0 +1,147 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::Function;
+use crate::scalars::Monotonicity;
+
+pub type LeftFunction = LeftRightFunction<true>;
+pub type RightFunction = LeftRightFunction<false>;
+
+/// Returns the leftmost or the rightmost `len` characters of the string.
+#[derive(Clone)]
+pub struct LeftRightFunction<const IS_LEFT: bool> {
+    display_name: String,
+}
+
+impl<const IS_LEFT: bool> LeftRightFunction<IS_LEFT> {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(LeftRightFunction::<IS_LEFT> {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        let mut features = FunctionFeatures::default().deterministic();
+
+        // The prefix of the larger string is not less than the prefix of the smaller one.
+        if IS_LEFT {
+            features = features.monotonicity();
+        }
+
+        FunctionDescription::creator(Box::new(Self::try_create)).features(features)
+    }
+}
+
+impl<const IS_LEFT: bool> Function for LeftRightFunction<IS_LEFT> {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn num_arguments(&self) -> usize {
+        2
+    }
+
+    fn get_monotonicity(&self, args: &[Monotonicity]) -> Result<Monotonicity> {
+        if IS_LEFT && args[1].is_constant {
+            return Ok(Monotonicity::clone_without_range(&args[0]));
+        }
+        Ok(Monotonicity::default())
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        if !args[0].is_numeric() && args[0] != DataType::String && args[0] != DataType::Null {
+            return Err(ErrorCode::IllegalDataType(format!(
+                "Expected parameter 1 is string or number or null, but got {}",
+                args[0]
+            )));
+        }
+
+        if !args[1].is_integer() && args[1] != DataType::Null {
+            return Err(ErrorCode::IllegalDataType(format!(
+                "Expected parameter 2 is integer or null, but got {}",
+                args[1]
+            )));
+        }
+
+        Ok(DataType::String)
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(true)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let strings = columns[0]
+            .column()
+            .cast_with_type(&DataType::String)?
+            .to_array()?;
+        let lens = columns[1]
+            .column()
+            .cast_with_type(&DataType::Int64)?
+            .to_array()?;
+
+        let mut builder = StringArrayBuilder::with_capacity(input_rows);
+        for (s, len) in strings.string()?.into_iter().zip(lens.i64()?.into_iter()) {
+            match (s, len) {
+                (Some(s), Some(len)) if *len <= 0 => builder.append_value(&s[..0]),
+                (Some(s), Some(len)) if IS_LEFT => {
+                    builder.append_value(&s[..utf8_prefix_len(s, *len as usize)])
+                }
+                (Some(s), Some(len)) => {
+                    let count = utf8_char_count(s);
+                    let skip = count.saturating_sub(*len as usize);
+                    builder.append_value(&s[utf8_prefix_len(s, skip)..])
+                }
+                _ => builder.append_null(),
+            }
+        }
+        Ok(builder.finish().into())
+    }
+}
+
+impl<const IS_LEFT: bool> fmt::Display for LeftRightFunction<IS_LEFT> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+/// The number of the characters, the bytes are counted if it's not valid UTF-8.
+#[inline]
+pub(crate) fn utf8_char_count(s: &[u8]) -> usize {
+    match std::str::from_utf8(s) {
+        Ok(s) => s.chars().count(),
+        Err(_) => s.len(),
+    }
+}
+
+/// The byte length of the first `n` characters, the bytes are counted if it's not valid UTF-8.
+#[inline]
+pub(crate) fn utf8_prefix_len(s: &[u8], n: usize) -> usize {
+    match std::str::from_utf8(s) {
+        Ok(v) => v
+            .char_indices()
+            .nth(n)
+            .map(|(idx, _)| idx)
+            .unwrap_or(s.len()),
+        Err(_) => n.min(s.len()),
+    }
+}
@@ -0,0 +1,112 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use super::NumberResultFunction;
+use super::String2NumberFunction;
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::Function;
+
+/// Returns the length of the string in bytes, the numbers are measured by their text.
+#[derive(Clone)]
+pub struct LengthFunction {
+    display_name: String,
+}
+
+impl LengthFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(LengthFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic())
+    }
+}
+
+impl Function for LengthFunction {
+    fn name(&self) -> &str {
+        "length"
+    }
+
+    fn num_arguments(&self) -> usize {
+        1
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        match &args[0] {
+            DataType::String | DataType::Null => Ok(DataType::UInt64),
+            other if other.is_numeric() => Ok(DataType::UInt64),
+            other => Err(ErrorCode::IllegalDataType(format!(
+                "Expected string or numeric or null, but got {}",
+                other
+            ))),
+        }
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(true)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        if columns[0].data_type() == &DataType::Null {
+            return Ok(DataColumn::Constant(DataValue::UInt64(None), input_rows));
+        }
+
+        let column: DataColumn = columns[0]
+            .column()
+            .cast_with_type(&DataType::String)?
+            .to_minimal_array()?
+            .string()?
+            .apply_cast_numeric(|s| s.len() as u64)
+            .into();
+        Ok(column.resize_constant(input_rows))
+    }
+}
+
+impl fmt::Display for LengthFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+#[derive(Clone)]
+pub struct CharLength {}
+
+impl NumberResultFunction<u64> for CharLength {
+    const IS_DETERMINISTIC: bool = true;
+    const MAYBE_MONOTONIC: bool = false;
+
+    fn return_type() -> Result<DataType> {
+        Ok(DataType::UInt64)
+    }
+
+    /// The number of the characters, the bytes are counted if it's not valid UTF-8.
+    fn to_number(value: &[u8]) -> u64 {
+        match std::str::from_utf8(value) {
+            Ok(s) => s.chars().count() as u64,
+            Err(_) => value.len() as u64,
+        }
+    }
+}
+
+pub type CharLengthFunction = String2NumberFunction<CharLength, u64>;
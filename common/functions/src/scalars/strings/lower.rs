@@ -0,0 +1,53 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::string2string::String2StringFunction;
+use super::string2string::StringOperator;
+
+#[derive(Clone, Default)]
+pub struct Lower {
+    buf: Vec<u8>,
+}
+
+impl StringOperator for Lower {
+    #[inline]
+    fn apply<'a>(&'a mut self, s: &'a [u8]) -> Option<&'a [u8]> {
+        self.buf.clear();
+        match std::str::from_utf8(s) {
+            Ok(s) => self.buf.extend_from_slice(s.to_lowercase().as_bytes()),
+            Err(_) => self.buf.extend(s.iter().map(|c| c.to_ascii_lowercase())),
+        }
+        Some(&self.buf)
+    }
+}
+
+#[derive(Clone, Default)]
+pub struct Upper {
+    buf: Vec<u8>,
+}
+
+impl StringOperator for Upper {
+    #[inline]
+    fn apply<'a>(&'a mut self, s: &'a [u8]) -> Option<&'a [u8]> {
+        self.buf.clear();
+        match std::str::from_utf8(s) {
+            Ok(s) => self.buf.extend_from_slice(s.to_uppercase().as_bytes()),
+            Err(_) => self.buf.extend(s.iter().map(|c| c.to_ascii_uppercase())),
+        }
+        Some(&self.buf)
+    }
+}
+
+pub type LowerFunction = String2StringFunction<Lower>;
+pub type UpperFunction = String2StringFunction<Upper>;
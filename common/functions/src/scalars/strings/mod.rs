@@ -14,13 +14,29 @@
 
 mod ascii;
 mod base_64;
+mod concat;
+mod extract_all;
 mod field;
 mod hex;
 mod insert;
+mod left_right;
+mod length;
 mod locate;
+mod lower;
 mod oct;
+mod pad;
 mod quote;
+mod regexp;
+mod regexp_instr;
+mod regexp_like;
+mod regexp_replace;
+mod regexp_substr;
 mod repeat;
+mod replace;
+mod reverse;
+mod space;
+mod split_part;
+mod starts_with;
 mod string;
 mod string2number;
 mod string2string;
@@ -31,14 +47,35 @@ mod unhex;
 pub use ascii::AsciiFunction;
 pub use base_64::Base64DecodeFunction;
 pub use base_64::Base64EncodeFunction;
+pub use concat::ConcatFunction;
+pub use concat::ConcatWsFunction;
+pub use extract_all::ExtractAllFunction;
 pub use field::FieldFunction;
 pub use insert::InsertFunction;
+pub use left_right::LeftFunction;
+pub use left_right::RightFunction;
+pub use length::CharLengthFunction;
+pub use length::LengthFunction;
 pub use locate::InstrFunction;
 pub use locate::LocateFunction;
 pub use locate::PositionFunction;
+pub use lower::LowerFunction;
+pub use lower::UpperFunction;
 pub use oct::OctFunction;
+pub use pad::LeftPadFunction;
+pub use pad::RightPadFunction;
 pub use quote::QuoteFunction;
+pub use regexp_instr::RegexpInStrFunction;
+pub use regexp_like::RegexpLikeFunction;
+pub use regexp_replace::RegexpReplaceFunction;
+pub use regexp_substr::RegexpSubStrFunction;
 pub use repeat::RepeatFunction;
+pub use replace::ReplaceFunction;
+pub use reverse::ReverseFunction;
+pub use space::SpaceFunction;
+pub use split_part::SplitPartFunction;
+pub use starts_with::EndsWithFunction;
+pub use starts_with::StartsWithFunction;
 pub use string::StringFunction;
 pub use string2number::NumberResultFunction;
 pub use string2number::String2NumberFunction;
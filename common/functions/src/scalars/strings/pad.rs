@@ -0,0 +1,164 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use itertools::izip;
+
+use super::left_right::utf8_char_count;
+use super::left_right::utf8_prefix_len;
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::Function;
+
+const MAX_PAD_LENGTH: i64 = 1000000;
+
+pub type LeftPadFunction = PadFunction<true>;
+pub type RightPadFunction = PadFunction<false>;
+
+/// Pads the string to `len` characters with the pad string on the left or the right.
+/// The string longer than `len` is truncated to `len` characters.
+#[derive(Clone)]
+pub struct PadFunction<const IS_LEFT: bool> {
+    display_name: String,
+}
+
+impl<const IS_LEFT: bool> PadFunction<IS_LEFT> {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(PadFunction::<IS_LEFT> {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic())
+    }
+}
+
+impl<const IS_LEFT: bool> Function for PadFunction<IS_LEFT> {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn num_arguments(&self) -> usize {
+        3
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        if !args[1].is_integer() && args[1] != DataType::Null {
+            return Err(ErrorCode::IllegalDataType(format!(
+                "Expected parameter 2 is integer or null, but got {}",
+                args[1]
+            )));
+        }
+
+        for arg in [&args[0], &args[2]] {
+            if !arg.is_numeric() && arg != &DataType::String && arg != &DataType::Null {
+                return Err(ErrorCode::IllegalDataType(format!(
+                    "Expected string or number or null, but got {}",
+                    arg
+                )));
+            }
+        }
+
+        Ok(DataType::String)
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(true)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let strings = columns[0]
+            .column()
+            .cast_with_type(&DataType::String)?
+            .to_array()?;
+        let lens = columns[1]
+            .column()
+            .cast_with_type(&DataType::Int64)?
+            .to_array()?;
+        let pads = columns[2]
+            .column()
+            .cast_with_type(&DataType::String)?
+            .to_array()?;
+
+        let mut builder = StringArrayBuilder::with_capacity(input_rows);
+        let mut buf = Vec::new();
+        for (s, len, pad) in izip!(strings.string()?, lens.i64()?, pads.string()?) {
+            match (s, len, pad) {
+                (Some(s), Some(len), Some(pad)) => {
+                    buf.clear();
+                    match Self::pad(s, *len, pad, &mut buf)? {
+                        true => builder.append_value(&buf),
+                        false => builder.append_null(),
+                    }
+                }
+                _ => builder.append_null(),
+            }
+        }
+        Ok(builder.finish().into())
+    }
+}
+
+impl<const IS_LEFT: bool> PadFunction<IS_LEFT> {
+    /// Returns false if the result is NULL, which happens when the length is negative or
+    /// the pad string is empty but the string must be padded.
+    fn pad(s: &[u8], len: i64, pad: &[u8], buf: &mut Vec<u8>) -> Result<bool> {
+        if len < 0 {
+            return Ok(false);
+        }
+
+        if len > MAX_PAD_LENGTH {
+            return Err(ErrorCode::BadArguments(format!(
+                "Too large length to pad: ({}), maximum is: {}",
+                len, MAX_PAD_LENGTH
+            )));
+        }
+
+        let len = len as usize;
+        let count = utf8_char_count(s);
+        if len <= count {
+            buf.extend_from_slice(&s[..utf8_prefix_len(s, len)]);
+            return Ok(true);
+        }
+
+        let pad_count = utf8_char_count(pad);
+        if pad_count == 0 {
+            return Ok(false);
+        }
+
+        if !IS_LEFT {
+            buf.extend_from_slice(s);
+        }
+        let padding = len - count;
+        for _ in 0..padding / pad_count {
+            buf.extend_from_slice(pad);
+        }
+        buf.extend_from_slice(&pad[..utf8_prefix_len(pad, padding % pad_count)]);
+        if IS_LEFT {
+            buf.extend_from_slice(s);
+        }
+        Ok(true)
+    }
+}
+
+impl<const IS_LEFT: bool> fmt::Display for PadFunction<IS_LEFT> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
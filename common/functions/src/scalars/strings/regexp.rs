@@ -0,0 +1,184 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use once_cell::sync::OnceCell;
+use regex::bytes::Regex;
+
+use super::left_right::utf8_char_count;
+use super::left_right::utf8_prefix_len;
+
+/// The regular expression compiled from the constant pattern and match type, it's kept by the
+/// function so that it's compiled once instead of once per block.
+#[derive(Clone, Default)]
+pub(crate) struct ConstantRegexp {
+    cell: OnceCell<(Vec<u8>, Vec<u8>, Regex)>,
+}
+
+/// Compiled regular expressions of a function evaluation, the constant pattern and match type
+/// are taken from the `ConstantRegexp`, the others are cached by the match type and pattern.
+pub(crate) struct RegexpCache<'a> {
+    constant: Option<&'a Regex>,
+    cache: HashMap<Vec<u8>, HashMap<Vec<u8>, Regex>>,
+}
+
+impl<'a> RegexpCache<'a> {
+    /// The columns should be cast to String already.
+    pub fn try_create(
+        constant: &'a ConstantRegexp,
+        pattern: &DataColumn,
+        match_type: Option<&DataColumn>,
+    ) -> Result<Self> {
+        let empty = DataColumn::Constant(DataValue::String(Some(vec![])), 1);
+        let constant = match (pattern, match_type.unwrap_or(&empty)) {
+            (
+                DataColumn::Constant(DataValue::String(Some(pattern)), _),
+                DataColumn::Constant(DataValue::String(Some(match_type)), _),
+            ) => {
+                let (constant_pattern, constant_match_type, re) =
+                    constant.cell.get_or_try_init(|| {
+                        let re = build_regexp(pattern, match_type)?;
+                        Result::Ok((pattern.clone(), match_type.clone(), re))
+                    })?;
+                // a different constant goes to the cache, it's compiled once per evaluation
+                match constant_pattern == pattern && constant_match_type == match_type {
+                    true => Some(re),
+                    false => None,
+                }
+            }
+            _ => None,
+        };
+
+        Ok(RegexpCache {
+            constant,
+            cache: HashMap::new(),
+        })
+    }
+
+    pub fn get(&mut self, pattern: &[u8], match_type: &[u8]) -> Result<&Regex> {
+        if let Some(re) = self.constant {
+            return Ok(re);
+        }
+
+        match self.cache.get_mut(match_type) {
+            Some(patterns) => {
+                if !patterns.contains_key(pattern) {
+                    patterns.insert(pattern.to_vec(), build_regexp(pattern, match_type)?);
+                }
+            }
+            None => {
+                let re = build_regexp(pattern, match_type)?;
+                let patterns = HashMap::from([(pattern.to_vec(), re)]);
+                self.cache.insert(match_type.to_vec(), patterns);
+            }
+        }
+        Ok(&self.cache[match_type][pattern])
+    }
+}
+
+/// Check the arguments of the regular expression functions, the arguments at `integer_args`
+/// positions should be integers and the others should be strings.
+pub(crate) fn check_regexp_arguments(args: &[DataType], integer_args: &[usize]) -> Result<()> {
+    for (i, arg) in args.iter().enumerate() {
+        if integer_args.contains(&i) {
+            if !arg.is_integer() && arg != &DataType::Null {
+                return Err(ErrorCode::IllegalDataType(format!(
+                    "Expected parameter {} is integer or null, but got {}",
+                    i + 1,
+                    arg
+                )));
+            }
+        } else if !arg.is_numeric() && arg != &DataType::String && arg != &DataType::Null {
+            return Err(ErrorCode::IllegalDataType(format!(
+                "Expected string or number or null, but got {}",
+                arg
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Cast the arguments at `integer_args` positions to Int64 and the others to String.
+pub(crate) fn cast_regexp_columns(
+    columns: &DataColumnsWithField,
+    integer_args: &[usize],
+) -> Result<Vec<DataColumn>> {
+    columns
+        .iter()
+        .enumerate()
+        .map(|(i, column)| match integer_args.contains(&i) {
+            true => column.column().cast_with_type(&DataType::Int64),
+            false => column.column().cast_with_type(&DataType::String),
+        })
+        .collect()
+}
+
+#[inline]
+pub(crate) fn string_value(array: &DFStringArray, row: usize) -> Option<&[u8]> {
+    match array.is_null(row) {
+        true => None,
+        false => Some(array.inner().value(row)),
+    }
+}
+
+#[inline]
+pub(crate) fn integer_value(array: &DFInt64Array, row: usize) -> Option<i64> {
+    match array.is_null(row) {
+        true => None,
+        false => Some(array.inner().value(row)),
+    }
+}
+
+/// The optional arguments default to `default` if they are not given.
+#[inline]
+pub(crate) fn optional_integer_value(
+    array: Option<&DFInt64Array>,
+    row: usize,
+    default: i64,
+) -> Option<i64> {
+    match array {
+        Some(array) => integer_value(array, row),
+        None => Some(default),
+    }
+}
+
+#[inline]
+pub(crate) fn optional_string_value(array: Option<&DFStringArray>, row: usize) -> Option<&[u8]> {
+    match array {
+        Some(array) => string_value(array, row),
+        None => Some(&[]),
+    }
+}
+
+/// Convert the 1-based character position to the byte offset, returns None if it's out of range.
+#[inline]
+pub(crate) fn position_to_offset(s: &[u8], position: i64) -> Result<Option<usize>> {
+    if position < 1 {
+        return Err(ErrorCode::BadArguments(format!(
+            "Index out of bounds in regular expression search: {}",
+            position
+        )));
+    }
+
+    let position = (position - 1) as usize;
+    let offset = utf8_prefix_len(s, position);
+    match offset == s.len() && utf8_char_count(s) < position {
+        true => Ok(None),
+        false => Ok(Some(offset)),
+    }
+}
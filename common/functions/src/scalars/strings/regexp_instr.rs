@@ -0,0 +1,159 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use regex::bytes::Regex;
+
+use super::left_right::utf8_char_count;
+use super::regexp::cast_regexp_columns;
+use super::regexp::check_regexp_arguments;
+use super::regexp::optional_integer_value;
+use super::regexp::optional_string_value;
+use super::regexp::position_to_offset;
+use super::regexp::string_value;
+use super::regexp::ConstantRegexp;
+use super::regexp::RegexpCache;
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::Function;
+
+/// regexp_instr(expr, pattern[, position[, occurrence[, return_option[, match_type]]]]),
+/// returns the 1-based character index of the matched substring, 0 if there is no match.
+/// The search starts at `position`, and the `occurrence`th match is returned. If `return_option`
+/// is 1, the index of the character following the matched substring is returned.
+#[derive(Clone)]
+pub struct RegexpInStrFunction {
+    display_name: String,
+    constant_regexp: ConstantRegexp,
+}
+
+impl RegexpInStrFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(RegexpInStrFunction {
+            display_name: display_name.to_string(),
+            constant_regexp: ConstantRegexp::default(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic())
+    }
+}
+
+impl Function for RegexpInStrFunction {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn variadic_arguments(&self) -> Option<(usize, usize)> {
+        Some((2, 6))
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        check_regexp_arguments(args, &[2, 3, 4])?;
+        Ok(DataType::UInt64)
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(true)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let columns = cast_regexp_columns(columns, &[2, 3, 4])?;
+        let mut cache =
+            RegexpCache::try_create(&self.constant_regexp, &columns[1], columns.get(5))?;
+
+        let series = columns
+            .iter()
+            .map(|column| column.to_array())
+            .collect::<Result<Vec<_>>>()?;
+        let (strings, patterns) = (series[0].string()?, series[1].string()?);
+        let positions = series.get(2).map(|series| series.i64()).transpose()?;
+        let occurrences = series.get(3).map(|series| series.i64()).transpose()?;
+        let return_options = series.get(4).map(|series| series.i64()).transpose()?;
+        let match_types = series.get(5).map(|series| series.string()).transpose()?;
+
+        let values = (0..input_rows)
+            .map(|row| {
+                let (s, pattern, position, occurrence, return_option, match_type) = match (
+                    string_value(strings, row),
+                    string_value(patterns, row),
+                    optional_integer_value(positions, row, 1),
+                    optional_integer_value(occurrences, row, 1),
+                    optional_integer_value(return_options, row, 0),
+                    optional_string_value(match_types, row),
+                ) {
+                    (Some(s), Some(p), Some(pos), Some(occur), Some(ro), Some(mt)) => {
+                        (s, p, pos, occur, ro, mt)
+                    }
+                    _ => return Ok(None),
+                };
+
+                if return_option != 0 && return_option != 1 {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "Incorrect arguments to {}: return_option must be 0 or 1, but got {}",
+                        self.display_name, return_option
+                    )));
+                }
+
+                let re = cache.get(pattern, match_type)?;
+                Ok(Some(regexp_instr(
+                    s,
+                    re,
+                    position,
+                    occurrence,
+                    return_option == 1,
+                )?))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(DFUInt64Array::new_from_opt_iter(values.into_iter()).into())
+    }
+}
+
+impl fmt::Display for RegexpInStrFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+#[inline]
+fn regexp_instr(
+    s: &[u8],
+    re: &Regex,
+    position: i64,
+    occurrence: i64,
+    return_end: bool,
+) -> Result<u64> {
+    let offset = match position_to_offset(s, position)? {
+        Some(offset) => offset,
+        None => return Ok(0),
+    };
+
+    let occurrence = occurrence.max(1) as usize;
+    match re.find_iter(&s[offset..]).nth(occurrence - 1) {
+        Some(m) => {
+            let end = match return_end {
+                true => offset + m.end(),
+                false => offset + m.start(),
+            };
+            Ok(utf8_char_count(&s[..end]) as u64 + 1)
+        }
+        None => Ok(0),
+    }
+}
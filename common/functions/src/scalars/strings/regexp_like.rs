@@ -0,0 +1,102 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+
+use super::regexp::cast_regexp_columns;
+use super::regexp::check_regexp_arguments;
+use super::regexp::optional_string_value;
+use super::regexp::string_value;
+use super::regexp::ConstantRegexp;
+use super::regexp::RegexpCache;
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::Function;
+
+/// regexp_like(expr, pattern[, match_type]), returns whether the string matches the pattern.
+#[derive(Clone)]
+pub struct RegexpLikeFunction {
+    display_name: String,
+    constant_regexp: ConstantRegexp,
+}
+
+impl RegexpLikeFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(RegexpLikeFunction {
+            display_name: display_name.to_string(),
+            constant_regexp: ConstantRegexp::default(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic().bool_function())
+    }
+}
+
+impl Function for RegexpLikeFunction {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn variadic_arguments(&self) -> Option<(usize, usize)> {
+        Some((2, 3))
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        check_regexp_arguments(args, &[])?;
+        Ok(DataType::Boolean)
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(true)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let columns = cast_regexp_columns(columns, &[])?;
+        let mut cache =
+            RegexpCache::try_create(&self.constant_regexp, &columns[1], columns.get(2))?;
+
+        let series = columns
+            .iter()
+            .map(|column| column.to_array())
+            .collect::<Result<Vec<_>>>()?;
+        let (strings, patterns) = (series[0].string()?, series[1].string()?);
+        let match_types = series.get(2).map(|series| series.string()).transpose()?;
+
+        let values = (0..input_rows)
+            .map(|row| {
+                let (s, pattern, match_type) = match (
+                    string_value(strings, row),
+                    string_value(patterns, row),
+                    optional_string_value(match_types, row),
+                ) {
+                    (Some(s), Some(pattern), Some(match_type)) => (s, pattern, match_type),
+                    _ => return Ok(None),
+                };
+                Ok(Some(cache.get(pattern, match_type)?.is_match(s)))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(DFBooleanArray::new_from_opt_iter(values.into_iter()).into())
+    }
+}
+
+impl fmt::Display for RegexpLikeFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
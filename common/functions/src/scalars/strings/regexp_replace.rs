@@ -0,0 +1,157 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use regex::bytes::Regex;
+
+use super::regexp::cast_regexp_columns;
+use super::regexp::check_regexp_arguments;
+use super::regexp::optional_integer_value;
+use super::regexp::optional_string_value;
+use super::regexp::position_to_offset;
+use super::regexp::string_value;
+use super::regexp::ConstantRegexp;
+use super::regexp::RegexpCache;
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::Function;
+
+/// regexp_replace(expr, pattern, replacement[, position[, occurrence[, match_type]]]), replaces
+/// the substrings matching the pattern from `position` with the replacement, all of them are
+/// replaced if `occurrence` is 0, otherwise only the `occurrence`th one is replaced.
+/// The replacement can refer to the capture groups by `$1` or `${name}`.
+#[derive(Clone)]
+pub struct RegexpReplaceFunction {
+    display_name: String,
+    constant_regexp: ConstantRegexp,
+}
+
+impl RegexpReplaceFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(RegexpReplaceFunction {
+            display_name: display_name.to_string(),
+            constant_regexp: ConstantRegexp::default(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic())
+    }
+}
+
+impl Function for RegexpReplaceFunction {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn variadic_arguments(&self) -> Option<(usize, usize)> {
+        Some((3, 6))
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        check_regexp_arguments(args, &[3, 4])?;
+        Ok(DataType::String)
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(true)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let columns = cast_regexp_columns(columns, &[3, 4])?;
+        let mut cache =
+            RegexpCache::try_create(&self.constant_regexp, &columns[1], columns.get(5))?;
+
+        let series = columns
+            .iter()
+            .map(|column| column.to_array())
+            .collect::<Result<Vec<_>>>()?;
+        let (strings, patterns) = (series[0].string()?, series[1].string()?);
+        let replacements = series[2].string()?;
+        let positions = series.get(3).map(|series| series.i64()).transpose()?;
+        let occurrences = series.get(4).map(|series| series.i64()).transpose()?;
+        let match_types = series.get(5).map(|series| series.string()).transpose()?;
+
+        let mut builder = StringArrayBuilder::with_capacity(input_rows);
+        let mut buf = Vec::new();
+        for row in 0..input_rows {
+            match (
+                string_value(strings, row),
+                string_value(patterns, row),
+                string_value(replacements, row),
+                optional_integer_value(positions, row, 1),
+                optional_integer_value(occurrences, row, 0),
+                optional_string_value(match_types, row),
+            ) {
+                (Some(s), Some(pattern), Some(repl), Some(pos), Some(occur), Some(match_type)) => {
+                    let re = cache.get(pattern, match_type)?;
+                    buf.clear();
+                    regexp_replace(s, re, repl, pos, occur, &mut buf)?;
+                    builder.append_value(&buf);
+                }
+                _ => builder.append_null(),
+            }
+        }
+        Ok(builder.finish().into())
+    }
+}
+
+impl fmt::Display for RegexpReplaceFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+#[inline]
+fn regexp_replace(
+    s: &[u8],
+    re: &Regex,
+    repl: &[u8],
+    position: i64,
+    occurrence: i64,
+    buf: &mut Vec<u8>,
+) -> Result<()> {
+    let offset = match position_to_offset(s, position)? {
+        Some(offset) => offset,
+        None => {
+            buf.extend_from_slice(s);
+            return Ok(());
+        }
+    };
+
+    let occurrence = occurrence.max(0) as usize;
+    let mut last = offset;
+    buf.extend_from_slice(&s[..offset]);
+    for (i, caps) in re.captures_iter(&s[offset..]).enumerate() {
+        if occurrence != 0 && i + 1 < occurrence {
+            continue;
+        }
+
+        // Safe to unwrap because the group 0 is always the whole match.
+        let m = caps.get(0).unwrap();
+        buf.extend_from_slice(&s[last..offset + m.start()]);
+        caps.expand(repl, buf);
+        last = offset + m.end();
+
+        if occurrence != 0 {
+            break;
+        }
+    }
+    buf.extend_from_slice(&s[last..]);
+    Ok(())
+}
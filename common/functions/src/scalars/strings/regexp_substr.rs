@@ -0,0 +1,130 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use regex::bytes::Regex;
+
+use super::regexp::cast_regexp_columns;
+use super::regexp::check_regexp_arguments;
+use super::regexp::optional_integer_value;
+use super::regexp::optional_string_value;
+use super::regexp::position_to_offset;
+use super::regexp::string_value;
+use super::regexp::ConstantRegexp;
+use super::regexp::RegexpCache;
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::Function;
+
+/// regexp_substr(expr, pattern[, position[, occurrence[, match_type]]]), returns the
+/// `occurrence`th substring matching the pattern from `position`, NULL if there is no match.
+#[derive(Clone)]
+pub struct RegexpSubStrFunction {
+    display_name: String,
+    constant_regexp: ConstantRegexp,
+}
+
+impl RegexpSubStrFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(RegexpSubStrFunction {
+            display_name: display_name.to_string(),
+            constant_regexp: ConstantRegexp::default(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic())
+    }
+}
+
+impl Function for RegexpSubStrFunction {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn variadic_arguments(&self) -> Option<(usize, usize)> {
+        Some((2, 5))
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        check_regexp_arguments(args, &[2, 3])?;
+        Ok(DataType::String)
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(true)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let columns = cast_regexp_columns(columns, &[2, 3])?;
+        let mut cache =
+            RegexpCache::try_create(&self.constant_regexp, &columns[1], columns.get(4))?;
+
+        let series = columns
+            .iter()
+            .map(|column| column.to_array())
+            .collect::<Result<Vec<_>>>()?;
+        let (strings, patterns) = (series[0].string()?, series[1].string()?);
+        let positions = series.get(2).map(|series| series.i64()).transpose()?;
+        let occurrences = series.get(3).map(|series| series.i64()).transpose()?;
+        let match_types = series.get(4).map(|series| series.string()).transpose()?;
+
+        let mut builder = StringArrayBuilder::with_capacity(input_rows);
+        for row in 0..input_rows {
+            match (
+                string_value(strings, row),
+                string_value(patterns, row),
+                optional_integer_value(positions, row, 1),
+                optional_integer_value(occurrences, row, 1),
+                optional_string_value(match_types, row),
+            ) {
+                (Some(s), Some(pattern), Some(position), Some(occurrence), Some(match_type)) => {
+                    let re = cache.get(pattern, match_type)?;
+                    builder.append_option(regexp_substr(s, re, position, occurrence)?);
+                }
+                _ => builder.append_null(),
+            }
+        }
+        Ok(builder.finish().into())
+    }
+}
+
+impl fmt::Display for RegexpSubStrFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+#[inline]
+fn regexp_substr<'a>(
+    s: &'a [u8],
+    re: &Regex,
+    position: i64,
+    occurrence: i64,
+) -> Result<Option<&'a [u8]>> {
+    let offset = match position_to_offset(s, position)? {
+        Some(offset) => offset,
+        None => return Ok(None),
+    };
+
+    let occurrence = occurrence.max(1) as usize;
+    Ok(re
+        .find_iter(&s[offset..])
+        .nth(occurrence - 1)
+        .map(|m| m.as_bytes()))
+}
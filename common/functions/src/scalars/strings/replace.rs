@@ -0,0 +1,126 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use itertools::izip;
+
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::Function;
+
+/// Replaces all the occurrences of `from` in the string with `to`.
+#[derive(Clone)]
+pub struct ReplaceFunction {
+    display_name: String,
+}
+
+impl ReplaceFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(ReplaceFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic())
+    }
+}
+
+impl Function for ReplaceFunction {
+    fn name(&self) -> &str {
+        "replace"
+    }
+
+    fn num_arguments(&self) -> usize {
+        3
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        for arg in args {
+            if !arg.is_numeric() && arg != &DataType::String && arg != &DataType::Null {
+                return Err(ErrorCode::IllegalDataType(format!(
+                    "Expected string or number or null, but got {}",
+                    arg
+                )));
+            }
+        }
+        Ok(DataType::String)
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(true)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let strings = columns[0]
+            .column()
+            .cast_with_type(&DataType::String)?
+            .to_array()?;
+        let froms = columns[1]
+            .column()
+            .cast_with_type(&DataType::String)?
+            .to_array()?;
+        let tos = columns[2]
+            .column()
+            .cast_with_type(&DataType::String)?
+            .to_array()?;
+
+        let mut builder = StringArrayBuilder::with_capacity(input_rows);
+        let mut buf = Vec::new();
+        for (s, from, to) in izip!(strings.string()?, froms.string()?, tos.string()?) {
+            match (s, from, to) {
+                (Some(s), Some(from), Some(to)) => {
+                    buf.clear();
+                    replace(s, from, to, &mut buf);
+                    builder.append_value(&buf);
+                }
+                _ => builder.append_null(),
+            }
+        }
+        Ok(builder.finish().into())
+    }
+}
+
+impl fmt::Display for ReplaceFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+#[inline]
+fn replace(s: &[u8], from: &[u8], to: &[u8], buf: &mut Vec<u8>) {
+    if from.is_empty() {
+        buf.extend_from_slice(s);
+        return;
+    }
+
+    let mut last = 0;
+    let mut idx = 0;
+    while idx + from.len() <= s.len() {
+        if &s[idx..idx + from.len()] == from {
+            buf.extend_from_slice(&s[last..idx]);
+            buf.extend_from_slice(to);
+            idx += from.len();
+            last = idx;
+        } else {
+            idx += 1;
+        }
+    }
+    buf.extend_from_slice(&s[last..]);
+}
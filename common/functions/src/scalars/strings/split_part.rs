@@ -0,0 +1,146 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use itertools::izip;
+
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::Function;
+
+/// Splits the string by the delimiter and returns the part at the position, which starts
+/// from 1 and counts from the end if it's negative. The empty string is returned if the
+/// position is out of range.
+#[derive(Clone)]
+pub struct SplitPartFunction {
+    display_name: String,
+}
+
+impl SplitPartFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(SplitPartFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic())
+    }
+}
+
+impl Function for SplitPartFunction {
+    fn name(&self) -> &str {
+        "split_part"
+    }
+
+    fn num_arguments(&self) -> usize {
+        3
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        for arg in [&args[0], &args[1]] {
+            if !arg.is_numeric() && arg != &DataType::String && arg != &DataType::Null {
+                return Err(ErrorCode::IllegalDataType(format!(
+                    "Expected string or number or null, but got {}",
+                    arg
+                )));
+            }
+        }
+
+        if !args[2].is_integer() && args[2] != DataType::Null {
+            return Err(ErrorCode::IllegalDataType(format!(
+                "Expected parameter 3 is integer or null, but got {}",
+                args[2]
+            )));
+        }
+
+        Ok(DataType::String)
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(true)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let strings = columns[0]
+            .column()
+            .cast_with_type(&DataType::String)?
+            .to_array()?;
+        let delimiters = columns[1]
+            .column()
+            .cast_with_type(&DataType::String)?
+            .to_array()?;
+        let positions = columns[2]
+            .column()
+            .cast_with_type(&DataType::Int64)?
+            .to_array()?;
+
+        let mut builder = StringArrayBuilder::with_capacity(input_rows);
+        for (s, delimiter, position) in
+            izip!(strings.string()?, delimiters.string()?, positions.i64()?)
+        {
+            match (s, delimiter, position) {
+                (Some(s), Some(delimiter), Some(position)) => {
+                    builder.append_value(split_part(s, delimiter, *position))
+                }
+                _ => builder.append_null(),
+            }
+        }
+        Ok(builder.finish().into())
+    }
+}
+
+impl fmt::Display for SplitPartFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+#[inline]
+fn split_part<'a>(s: &'a [u8], delimiter: &[u8], position: i64) -> &'a [u8] {
+    if delimiter.is_empty() {
+        return match position {
+            1 | -1 => s,
+            _ => &s[..0],
+        };
+    }
+
+    let mut parts = vec![];
+    let mut start = 0;
+    let mut idx = 0;
+    while idx + delimiter.len() <= s.len() {
+        if &s[idx..idx + delimiter.len()] == delimiter {
+            parts.push(&s[start..idx]);
+            idx += delimiter.len();
+            start = idx;
+        } else {
+            idx += 1;
+        }
+    }
+    parts.push(&s[start..]);
+
+    let index = match position {
+        0 => None,
+        p if p > 0 => Some(p as usize - 1),
+        p => parts.len().checked_sub(p.unsigned_abs() as usize),
+    };
+    index
+        .and_then(|index| parts.get(index).copied())
+        .unwrap_or(&s[..0])
+}
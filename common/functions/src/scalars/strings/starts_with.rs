@@ -0,0 +1,99 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::Function;
+
+pub type StartsWithFunction = StartsEndsWithFunction<true>;
+pub type EndsWithFunction = StartsEndsWithFunction<false>;
+
+/// Checks whether the string starts or ends with the other string.
+#[derive(Clone)]
+pub struct StartsEndsWithFunction<const IS_STARTS: bool> {
+    display_name: String,
+}
+
+impl<const IS_STARTS: bool> StartsEndsWithFunction<IS_STARTS> {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(StartsEndsWithFunction::<IS_STARTS> {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic().bool_function())
+    }
+}
+
+impl<const IS_STARTS: bool> Function for StartsEndsWithFunction<IS_STARTS> {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn num_arguments(&self) -> usize {
+        2
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        for arg in args {
+            if !arg.is_numeric() && arg != &DataType::String && arg != &DataType::Null {
+                return Err(ErrorCode::IllegalDataType(format!(
+                    "Expected string or number or null, but got {}",
+                    arg
+                )));
+            }
+        }
+        Ok(DataType::Boolean)
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(true)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, _input_rows: usize) -> Result<DataColumn> {
+        let strings = columns[0]
+            .column()
+            .cast_with_type(&DataType::String)?
+            .to_array()?;
+        let patterns = columns[1]
+            .column()
+            .cast_with_type(&DataType::String)?
+            .to_array()?;
+
+        let it = strings
+            .string()?
+            .into_iter()
+            .zip(patterns.string()?.into_iter())
+            .map(|(s, pattern)| match (s, pattern) {
+                (Some(s), Some(pattern)) if IS_STARTS => Some(s.starts_with(pattern)),
+                (Some(s), Some(pattern)) => Some(s.ends_with(pattern)),
+                _ => None,
+            });
+        Ok(DFBooleanArray::new_from_opt_iter(it).into())
+    }
+}
+
+impl<const IS_STARTS: bool> fmt::Display for StartsEndsWithFunction<IS_STARTS> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
@@ -16,20 +16,41 @@ use crate::scalars::function_factory::FunctionFactory;
 use crate::scalars::AsciiFunction;
 use crate::scalars::Base64DecodeFunction;
 use crate::scalars::Base64EncodeFunction;
+use crate::scalars::CharLengthFunction;
+use crate::scalars::ConcatFunction;
+use crate::scalars::ConcatWsFunction;
+use crate::scalars::EndsWithFunction;
+use crate::scalars::ExtractAllFunction;
 use crate::scalars::FieldFunction;
 use crate::scalars::HexFunction;
 use crate::scalars::InsertFunction;
 use crate::scalars::InstrFunction;
 use crate::scalars::LTrimFunction;
+use crate::scalars::LeftFunction;
+use crate::scalars::LeftPadFunction;
+use crate::scalars::LengthFunction;
 use crate::scalars::LocateFunction;
+use crate::scalars::LowerFunction;
 use crate::scalars::OctFunction;
 use crate::scalars::PositionFunction;
 use crate::scalars::QuoteFunction;
 use crate::scalars::RTrimFunction;
+use crate::scalars::RegexpInStrFunction;
+use crate::scalars::RegexpLikeFunction;
+use crate::scalars::RegexpReplaceFunction;
+use crate::scalars::RegexpSubStrFunction;
 use crate::scalars::RepeatFunction;
+use crate::scalars::ReplaceFunction;
+use crate::scalars::ReverseFunction;
+use crate::scalars::RightFunction;
+use crate::scalars::RightPadFunction;
+use crate::scalars::SpaceFunction;
+use crate::scalars::SplitPartFunction;
+use crate::scalars::StartsWithFunction;
 use crate::scalars::SubstringFunction;
 use crate::scalars::TrimFunction;
 use crate::scalars::UnhexFunction;
+use crate::scalars::UpperFunction;
 
 #[derive(Clone)]
 pub struct StringFunction;
@@ -53,5 +74,29 @@ impl StringFunction {
         factory.register("instr", InstrFunction::desc());
         factory.register("insert", InsertFunction::desc());
         factory.register("field", FieldFunction::desc());
+        factory.register("lower", LowerFunction::desc());
+        factory.register("lcase", LowerFunction::desc());
+        factory.register("upper", UpperFunction::desc());
+        factory.register("ucase", UpperFunction::desc());
+        factory.register("octet_length", LengthFunction::desc());
+        factory.register("char_length", CharLengthFunction::desc());
+        factory.register("character_length", CharLengthFunction::desc());
+        factory.register("concat", ConcatFunction::desc());
+        factory.register("concat_ws", ConcatWsFunction::desc());
+        factory.register("replace", ReplaceFunction::desc());
+        factory.register("reverse", ReverseFunction::desc());
+        factory.register("left", LeftFunction::desc());
+        factory.register("right", RightFunction::desc());
+        factory.register("lpad", LeftPadFunction::desc());
+        factory.register("rpad", RightPadFunction::desc());
+        factory.register("split_part", SplitPartFunction::desc());
+        factory.register("starts_with", StartsWithFunction::desc());
+        factory.register("ends_with", EndsWithFunction::desc());
+        factory.register("space", SpaceFunction::desc());
+        factory.register("regexp_like", RegexpLikeFunction::desc());
+        factory.register("regexp_instr", RegexpInStrFunction::desc());
+        factory.register("regexp_substr", RegexpSubStrFunction::desc());
+        factory.register("regexp_replace", RegexpReplaceFunction::desc());
+        factory.register("extractAll", ExtractAllFunction::desc());
     }
 }
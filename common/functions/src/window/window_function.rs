@@ -0,0 +1,93 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::DataType;
+use common_datavalues::DataValue;
+use common_exception::Result;
+
+use crate::aggregates::AggregateFunctionRef;
+
+/// The functions that can be used with an `OVER` clause.
+///
+/// The ranking and navigation functions are evaluated by the window transform directly,
+/// the aggregate functions are evaluated over the window frame of each row.
+#[derive(Clone)]
+pub enum WindowFunction {
+    RowNumber,
+    Rank,
+    DenseRank,
+    /// The value of the row `offset` rows before the current row of the partition.
+    Lag {
+        offset: usize,
+        default: DataValue,
+        data_type: DataType,
+    },
+    /// The value of the row `offset` rows after the current row of the partition.
+    Lead {
+        offset: usize,
+        default: DataValue,
+        data_type: DataType,
+    },
+    /// The value of the first row of the window frame.
+    FirstValue {
+        data_type: DataType,
+    },
+    /// The value of the last row of the window frame.
+    LastValue {
+        data_type: DataType,
+    },
+    Aggregate(AggregateFunctionRef),
+}
+
+impl WindowFunction {
+    pub fn return_type(&self) -> Result<DataType> {
+        match self {
+            WindowFunction::RowNumber | WindowFunction::Rank | WindowFunction::DenseRank => {
+                Ok(DataType::UInt64)
+            }
+            WindowFunction::Lag { data_type, .. }
+            | WindowFunction::Lead { data_type, .. }
+            | WindowFunction::FirstValue { data_type }
+            | WindowFunction::LastValue { data_type } => Ok(data_type.clone()),
+            WindowFunction::Aggregate(func) => func.return_type(),
+        }
+    }
+
+    /// Whether the result depends on the window frame of the row.
+    pub fn is_framed(&self) -> bool {
+        matches!(
+            self,
+            WindowFunction::FirstValue { .. }
+                | WindowFunction::LastValue { .. }
+                | WindowFunction::Aggregate(_)
+        )
+    }
+}
+
+impl fmt::Display for WindowFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            WindowFunction::RowNumber => write!(f, "row_number"),
+            WindowFunction::Rank => write!(f, "rank"),
+            WindowFunction::DenseRank => write!(f, "dense_rank"),
+            WindowFunction::Lag { .. } => write!(f, "lag"),
+            WindowFunction::Lead { .. } => write!(f, "lead"),
+            WindowFunction::FirstValue { .. } => write!(f, "first_value"),
+            WindowFunction::LastValue { .. } => write!(f, "last_value"),
+            WindowFunction::Aggregate(func) => write!(f, "{}", func),
+        }
+    }
+}
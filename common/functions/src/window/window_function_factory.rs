@@ -0,0 +1,144 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::DataField;
+use common_datavalues::DataType;
+use common_datavalues::DataValue;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::aggregates::assert_arguments;
+use crate::aggregates::assert_unary_arguments;
+use crate::aggregates::AggregateFunctionFactory;
+use crate::window::WindowFunction;
+
+const RANKING_FUNCTIONS: [&str; 3] = ["row_number", "rank", "dense_rank"];
+const NAVIGATION_FUNCTIONS: [&str; 4] = ["lag", "lead", "first_value", "last_value"];
+
+pub struct WindowFunctionFactory;
+
+static FACTORY: WindowFunctionFactory = WindowFunctionFactory;
+
+impl WindowFunctionFactory {
+    pub fn instance() -> &'static WindowFunctionFactory {
+        &FACTORY
+    }
+
+    /// Whether `name` can be used with an `OVER` clause.
+    pub fn check(&self, name: impl AsRef<str>) -> bool {
+        self.check_non_aggregate(name.as_ref())
+            || AggregateFunctionFactory::instance().check(name.as_ref())
+    }
+
+    /// Whether `name` is a window function which is not an aggregate function,
+    /// such functions are only allowed with an `OVER` clause.
+    pub fn check_non_aggregate(&self, name: impl AsRef<str>) -> bool {
+        let lowercase_name = name.as_ref().to_lowercase();
+        RANKING_FUNCTIONS.contains(&lowercase_name.as_str())
+            || NAVIGATION_FUNCTIONS.contains(&lowercase_name.as_str())
+    }
+
+    pub fn get(
+        &self,
+        name: impl AsRef<str>,
+        params: Vec<DataValue>,
+        arguments: Vec<DataField>,
+    ) -> Result<WindowFunction> {
+        let origin = name.as_ref();
+        match origin.to_lowercase().as_str() {
+            "row_number" => {
+                assert_arguments(origin, arguments.len(), 0)?;
+                Ok(WindowFunction::RowNumber)
+            }
+            "rank" => {
+                assert_arguments(origin, arguments.len(), 0)?;
+                Ok(WindowFunction::Rank)
+            }
+            "dense_rank" => {
+                assert_arguments(origin, arguments.len(), 0)?;
+                Ok(WindowFunction::DenseRank)
+            }
+            "lag" => {
+                let (offset, default, data_type) = Self::offset_args(origin, params, arguments)?;
+                Ok(WindowFunction::Lag {
+                    offset,
+                    default,
+                    data_type,
+                })
+            }
+            "lead" => {
+                let (offset, default, data_type) = Self::offset_args(origin, params, arguments)?;
+                Ok(WindowFunction::Lead {
+                    offset,
+                    default,
+                    data_type,
+                })
+            }
+            "first_value" => {
+                assert_unary_arguments(origin, arguments.len())?;
+                Ok(WindowFunction::FirstValue {
+                    data_type: arguments[0].data_type().clone(),
+                })
+            }
+            "last_value" => {
+                assert_unary_arguments(origin, arguments.len())?;
+                Ok(WindowFunction::LastValue {
+                    data_type: arguments[0].data_type().clone(),
+                })
+            }
+            _ => {
+                let factory = AggregateFunctionFactory::instance();
+                Ok(WindowFunction::Aggregate(
+                    factory.get(origin, params, arguments)?,
+                ))
+            }
+        }
+    }
+
+    // lag(expr [, offset [, default]]), the offset and the default value are the parameters.
+    fn offset_args(
+        name: &str,
+        params: Vec<DataValue>,
+        arguments: Vec<DataField>,
+    ) -> Result<(usize, DataValue, DataType)> {
+        assert_unary_arguments(name, arguments.len())?;
+        if params.len() > 2 {
+            return Err(ErrorCode::NumberArgumentsNotMatch(format!(
+                "{} expect to have [1, 3] arguments, but got {}",
+                name,
+                params.len() + 1
+            )));
+        }
+
+        let data_type = arguments[0].data_type().clone();
+        let offset = match params.get(0) {
+            None => 1,
+            Some(offset) => match offset.as_i64() {
+                Ok(v) if v >= 0 => v as usize,
+                _ => {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "The offset of {} must be a non-negative integer, but got {}",
+                        name, offset
+                    )));
+                }
+            },
+        };
+        let default = match params.get(1) {
+            None => DataValue::from(&data_type),
+            Some(default) if default.is_null() => DataValue::from(&data_type),
+            Some(default) => default.to_array()?.cast_with_type(&data_type)?.try_get(0)?,
+        };
+        Ok((offset, default, data_type))
+    }
+}
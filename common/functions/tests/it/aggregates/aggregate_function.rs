@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use bumpalo::Bump;
+use bytes::BytesMut;
 use common_datavalues::prelude::*;
 use common_exception::Result;
 use common_functions::aggregates::*;
@@ -153,6 +154,28 @@ fn test_aggregate_function() -> Result<()> {
             expect: DataValue::UInt64(Some(4)),
             error: "",
         },
+        Test {
+            name: "approx_count_distinct-passed",
+            eval_nums: 2,
+            params: vec![],
+            args: vec![args[0].clone()],
+            display: "approx_count_distinct",
+            func_name: "approx_count_distinct",
+            arrays: vec![arrays[0].clone()],
+            expect: DataValue::UInt64(Some(4)),
+            error: "",
+        },
+        Test {
+            name: "uniqHLL12-passed",
+            eval_nums: 1,
+            params: vec![],
+            args: vec![args[0].clone()],
+            display: "uniqHLL12",
+            func_name: "uniqHLL12",
+            arrays: vec![arrays[0].clone()],
+            expect: DataValue::UInt64(Some(4)),
+            error: "",
+        },
         Test {
             name: "std-passed",
             eval_nums: 1,
@@ -229,6 +252,172 @@ fn test_aggregate_function() -> Result<()> {
             expect: DataValue::UInt8(Some(3)),
             error: "",
         },
+        Test {
+            name: "groupArray-passed",
+            eval_nums: 1,
+            params: vec![],
+            args: vec![args[0].clone()],
+            display: "groupArray",
+            func_name: "groupArray",
+            arrays: vec![arrays[0].clone()],
+            expect: DataValue::List(
+                Some(vec![
+                    DataValue::Int64(Some(4)),
+                    DataValue::Int64(Some(3)),
+                    DataValue::Int64(Some(2)),
+                    DataValue::Int64(Some(1)),
+                ]),
+                DataType::Int64,
+            ),
+            error: "",
+        },
+        Test {
+            name: "groupArray-max-size-passed",
+            eval_nums: 2,
+            params: vec![DataValue::UInt64(Some(2))],
+            args: vec![args[0].clone()],
+            display: "groupArray",
+            func_name: "groupArray",
+            arrays: vec![arrays[0].clone()],
+            expect: DataValue::List(
+                Some(vec![DataValue::Int64(Some(4)), DataValue::Int64(Some(3))]),
+                DataType::Int64,
+            ),
+            error: "",
+        },
+        Test {
+            name: "median-passed",
+            eval_nums: 1,
+            params: vec![],
+            args: vec![args[0].clone()],
+            display: "median",
+            func_name: "median",
+            arrays: vec![arrays[0].clone()],
+            expect: DataValue::Float64(Some(2.5)),
+            error: "",
+        },
+        Test {
+            name: "quantile-passed",
+            eval_nums: 2,
+            params: vec![DataValue::Float64(Some(0.75))],
+            args: vec![args[0].clone()],
+            display: "quantile",
+            func_name: "quantile",
+            arrays: vec![arrays[0].clone()],
+            expect: DataValue::Float64(Some(3.25)),
+            error: "",
+        },
+        Test {
+            name: "quantile-bad-level-failed",
+            eval_nums: 1,
+            params: vec![DataValue::Float64(Some(1.5))],
+            args: vec![args[0].clone()],
+            display: "quantile",
+            func_name: "quantile",
+            arrays: vec![arrays[0].clone()],
+            expect: DataValue::Null,
+            error: "Code: 6, displayText = The level of quantile must be in [0, 1], but got 1.5.",
+        },
+        Test {
+            name: "quantileTDigest-passed",
+            eval_nums: 1,
+            params: vec![DataValue::Float64(Some(0.5))],
+            args: vec![args[0].clone()],
+            display: "quantileTDigest",
+            func_name: "quantileTDigest",
+            arrays: vec![arrays[0].clone()],
+            expect: DataValue::Float64(Some(2.5)),
+            error: "",
+        },
+        Test {
+            name: "percentile_cont-passed",
+            eval_nums: 1,
+            params: vec![DataValue::Float64(Some(0.5))],
+            args: vec![args[0].clone()],
+            display: "percentile_cont",
+            func_name: "percentile_cont",
+            arrays: vec![arrays[0].clone()],
+            expect: DataValue::Float64(Some(2.5)),
+            error: "",
+        },
+        Test {
+            name: "percentile_disc-passed",
+            eval_nums: 1,
+            params: vec![DataValue::Float64(Some(0.5))],
+            args: vec![args[0].clone()],
+            display: "percentile_disc",
+            func_name: "percentile_disc",
+            arrays: vec![arrays[0].clone()],
+            expect: DataValue::Int64(Some(2)),
+            error: "",
+        },
+        Test {
+            name: "groupUniqArray-passed",
+            eval_nums: 2,
+            params: vec![],
+            args: vec![args[0].clone()],
+            display: "groupUniqArray",
+            func_name: "groupUniqArray",
+            arrays: vec![arrays[0].clone()],
+            expect: DataValue::List(
+                Some(vec![
+                    DataValue::Int64(Some(4)),
+                    DataValue::Int64(Some(3)),
+                    DataValue::Int64(Some(2)),
+                    DataValue::Int64(Some(1)),
+                ]),
+                DataType::Int64,
+            ),
+            error: "",
+        },
+        Test {
+            name: "topK-passed",
+            eval_nums: 2,
+            params: vec![DataValue::UInt64(Some(2))],
+            args: vec![args[0].clone()],
+            display: "topK",
+            func_name: "topK",
+            arrays: vec![arrays[0].clone()],
+            expect: DataValue::List(
+                Some(vec![DataValue::Int64(Some(4)), DataValue::Int64(Some(3))]),
+                DataType::Int64,
+            ),
+            error: "",
+        },
+        Test {
+            name: "topK-zero-failed",
+            eval_nums: 1,
+            params: vec![DataValue::UInt64(Some(0))],
+            args: vec![args[0].clone()],
+            display: "topK",
+            func_name: "topK",
+            arrays: vec![arrays[0].clone()],
+            expect: DataValue::Null,
+            error:
+                "Code: 6, displayText = The parameter of topK must be in [1, 16777215], but got 0.",
+        },
+        Test {
+            name: "any-passed",
+            eval_nums: 2,
+            params: vec![],
+            args: vec![args[0].clone()],
+            display: "any",
+            func_name: "any",
+            arrays: vec![arrays[0].clone()],
+            expect: DataValue::Int64(Some(4)),
+            error: "",
+        },
+        Test {
+            name: "anyLast-passed",
+            eval_nums: 2,
+            params: vec![],
+            args: vec![args[0].clone()],
+            display: "anyLast",
+            func_name: "anyLast",
+            arrays: vec![arrays[0].clone()],
+            expect: DataValue::Int64(Some(1)),
+            error: "",
+        },
     ];
 
     for t in tests {
@@ -413,6 +602,17 @@ fn test_aggregate_function_with_grpup_by() -> Result<()> {
             expect: vec![DataValue::UInt64(Some(2)), DataValue::UInt64(Some(2))],
             error: "",
         },
+        Test {
+            name: "approx_count_distinct-passed",
+            eval_nums: 1,
+            params: vec![],
+            args: vec![args[0].clone()],
+            display: "approx_count_distinct",
+            func_name: "approx_count_distinct",
+            arrays: vec![arrays[0].clone()],
+            expect: vec![DataValue::UInt64(Some(2)), DataValue::UInt64(Some(2))],
+            error: "",
+        },
         Test {
             name: "std-passed",
             eval_nums: 1,
@@ -490,6 +690,84 @@ fn test_aggregate_function_with_grpup_by() -> Result<()> {
             expect: vec![DataValue::UInt8(Some(2)), DataValue::UInt8(Some(1))],
             error: "",
         },
+        Test {
+            name: "median-passed",
+            eval_nums: 1,
+            params: vec![],
+            args: vec![args[0].clone()],
+            display: "median",
+            func_name: "median",
+            arrays: vec![arrays[0].clone()],
+            expect: vec![DataValue::Float64(Some(3.0)), DataValue::Float64(Some(2.0))],
+            error: "",
+        },
+        Test {
+            name: "percentile_disc-passed",
+            eval_nums: 1,
+            params: vec![DataValue::Float64(Some(0.9))],
+            args: vec![args[0].clone()],
+            display: "percentile_disc",
+            func_name: "percentile_disc",
+            arrays: vec![arrays[0].clone()],
+            expect: vec![DataValue::Int64(Some(4)), DataValue::Int64(Some(3))],
+            error: "",
+        },
+        Test {
+            name: "groupUniqArray-passed",
+            eval_nums: 2,
+            params: vec![],
+            args: vec![args[0].clone()],
+            display: "groupUniqArray",
+            func_name: "groupUniqArray",
+            arrays: vec![arrays[0].clone()],
+            expect: vec![
+                DataValue::List(
+                    Some(vec![DataValue::Int64(Some(4)), DataValue::Int64(Some(2))]),
+                    DataType::Int64,
+                ),
+                DataValue::List(
+                    Some(vec![DataValue::Int64(Some(3)), DataValue::Int64(Some(1))]),
+                    DataType::Int64,
+                ),
+            ],
+            error: "",
+        },
+        Test {
+            name: "topK-passed",
+            eval_nums: 1,
+            params: vec![DataValue::UInt64(Some(1))],
+            args: vec![args[0].clone()],
+            display: "topK",
+            func_name: "topK",
+            arrays: vec![arrays[0].clone()],
+            expect: vec![
+                DataValue::List(Some(vec![DataValue::Int64(Some(4))]), DataType::Int64),
+                DataValue::List(Some(vec![DataValue::Int64(Some(3))]), DataType::Int64),
+            ],
+            error: "",
+        },
+        Test {
+            name: "any-passed",
+            eval_nums: 1,
+            params: vec![],
+            args: vec![args[0].clone()],
+            display: "any",
+            func_name: "any",
+            arrays: vec![arrays[0].clone()],
+            expect: vec![DataValue::Int64(Some(4)), DataValue::Int64(Some(3))],
+            error: "",
+        },
+        Test {
+            name: "anyLast-passed",
+            eval_nums: 1,
+            params: vec![],
+            args: vec![args[0].clone()],
+            display: "anyLast",
+            func_name: "anyLast",
+            arrays: vec![arrays[0].clone()],
+            expect: vec![DataValue::Int64(Some(2)), DataValue::Int64(Some(1))],
+            error: "",
+        },
     ];
 
     for t in tests {
@@ -639,6 +917,17 @@ fn test_aggregate_function_on_empty_data() -> Result<()> {
             expect: DataValue::UInt64(Some(0)),
             error: "",
         },
+        Test {
+            name: "approx_count_distinct-passed",
+            eval_nums: 1,
+            params: vec![],
+            args: vec![args[0].clone()],
+            display: "approx_count_distinct",
+            func_name: "approx_count_distinct",
+            arrays: vec![arrays[0].clone()],
+            expect: DataValue::UInt64(Some(0)),
+            error: "",
+        },
         Test {
             name: "std-passed",
             eval_nums: 1,
@@ -694,6 +983,61 @@ fn test_aggregate_function_on_empty_data() -> Result<()> {
             expect: DataValue::Float64(Some(f64::INFINITY)),
             error: "",
         },
+        Test {
+            name: "median-passed",
+            eval_nums: 1,
+            params: vec![],
+            args: vec![args[0].clone()],
+            display: "median",
+            func_name: "median",
+            arrays: vec![arrays[0].clone()],
+            expect: DataValue::Float64(None),
+            error: "",
+        },
+        Test {
+            name: "quantileTDigest-passed",
+            eval_nums: 1,
+            params: vec![],
+            args: vec![args[0].clone()],
+            display: "quantileTDigest",
+            func_name: "quantileTDigest",
+            arrays: vec![arrays[0].clone()],
+            expect: DataValue::Float64(None),
+            error: "",
+        },
+        Test {
+            name: "groupUniqArray-passed",
+            eval_nums: 1,
+            params: vec![],
+            args: vec![args[0].clone()],
+            display: "groupUniqArray",
+            func_name: "groupUniqArray",
+            arrays: vec![arrays[0].clone()],
+            expect: DataValue::List(Some(vec![]), DataType::Int64),
+            error: "",
+        },
+        Test {
+            name: "topK-passed",
+            eval_nums: 1,
+            params: vec![],
+            args: vec![args[0].clone()],
+            display: "topK",
+            func_name: "topK",
+            arrays: vec![arrays[0].clone()],
+            expect: DataValue::List(Some(vec![]), DataType::Int64),
+            error: "",
+        },
+        Test {
+            name: "any-passed",
+            eval_nums: 1,
+            params: vec![],
+            args: vec![args[0].clone()],
+            display: "any",
+            func_name: "any",
+            arrays: vec![arrays[0].clone()],
+            expect: DataValue::Int64(None),
+            error: "",
+        },
     ];
 
     for t in tests {
@@ -731,6 +1075,162 @@ fn test_aggregate_function_on_empty_data() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_quantile_with_serialization() -> Result<()> {
+    let arena = Bump::new();
+
+    // Two halves of 1..=10000 are accumulated on two nodes and merged on a third one.
+    let arrays = vec![
+        Series::new((1..=10000).step_by(2).collect::<Vec<u64>>()),
+        Series::new((2..=10000).step_by(2).collect::<Vec<u64>>()),
+    ];
+    let args = vec![DataField::new("a", DataType::UInt64, false)];
+
+    let factory = AggregateFunctionFactory::instance();
+    let run_test = |func_name: &'static str, level: f64| -> Result<f64> {
+        let func = factory.get(
+            func_name,
+            vec![DataValue::Float64(Some(level))],
+            args.clone(),
+        )?;
+        let mut buffers = vec![];
+        for array in &arrays {
+            let addr = arena.alloc_layout(func.state_layout());
+            func.init_state(addr.into());
+            func.accumulate(addr.into(), &[array.clone()], array.len())?;
+
+            let mut buffer = BytesMut::new();
+            func.serialize(addr.into(), &mut buffer)?;
+            buffers.push(buffer);
+        }
+
+        let addr = arena.alloc_layout(func.state_layout());
+        func.init_state(addr.into());
+        for buffer in &buffers {
+            let rhs = arena.alloc_layout(func.state_layout());
+            func.init_state(rhs.into());
+            func.deserialize(rhs.into(), &mut buffer.as_ref())?;
+            func.merge(addr.into(), rhs.into())?;
+        }
+
+        match func.merge_result(addr.into())? {
+            DataValue::Float64(Some(val)) => Ok(val),
+            v => unreachable!("unexpected value: {:?}", v),
+        }
+    };
+
+    let r = run_test("quantile", 0.99)?;
+    approx_eq!(f64, 9900.01, r, epsilon = 0.000001);
+
+    for level in [0.5, 0.95, 0.99] {
+        let r = run_test("quantileTDigest", level)?;
+        let expect = 1.0 + level * 9999.0;
+        assert!((r - expect).abs() < expect * 0.01, "{}: {}", level, r);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_approx_count_distinct_with_serialization() -> Result<()> {
+    let arena = Bump::new();
+
+    // 0..60000 and 40000..100000 are accumulated on two nodes and merged on a third one.
+    let arrays = vec![
+        Series::new((0..60000).collect::<Vec<u64>>()),
+        Series::new((40000..100000).collect::<Vec<u64>>()),
+    ];
+    let args = vec![DataField::new("a", DataType::UInt64, false)];
+
+    let factory = AggregateFunctionFactory::instance();
+    let func = factory.get("approx_count_distinct", vec![], args)?;
+
+    let mut buffers = vec![];
+    for array in &arrays {
+        let addr = arena.alloc_layout(func.state_layout());
+        func.init_state(addr.into());
+        func.accumulate(addr.into(), &[array.clone()], array.len())?;
+
+        let mut buffer = BytesMut::new();
+        func.serialize(addr.into(), &mut buffer)?;
+        buffers.push(buffer);
+    }
+
+    let addr = arena.alloc_layout(func.state_layout());
+    func.init_state(addr.into());
+    for buffer in &buffers {
+        let rhs = arena.alloc_layout(func.state_layout());
+        func.init_state(rhs.into());
+        func.deserialize(rhs.into(), &mut buffer.as_ref())?;
+        func.merge(addr.into(), rhs.into())?;
+    }
+
+    match func.merge_result(addr.into())? {
+        DataValue::UInt64(Some(val)) => {
+            let error = (val as f64 - 100000.0).abs() / 100000.0;
+            assert!(error < 0.05, "estimate: {}", val);
+        }
+        v => unreachable!("unexpected value: {:?}", v),
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_top_k_with_serialization() -> Result<()> {
+    let arena = Bump::new();
+
+    // 1, 2 and 3 take 50%, 20% and 10% of the rows, the rest are spread over 1000 values.
+    let values = (0..100000u64)
+        .map(|i| match i % 10 {
+            0..=4 => 1,
+            5 | 6 => 2,
+            7 => 3,
+            _ => 1000 + i % 1000,
+        })
+        .collect::<Vec<_>>();
+    let arrays = vec![
+        Series::new(values[..50000].to_vec()),
+        Series::new(values[50000..].to_vec()),
+    ];
+    let args = vec![DataField::new("a", DataType::UInt64, false)];
+
+    let factory = AggregateFunctionFactory::instance();
+    let func = factory.get("topK", vec![DataValue::UInt64(Some(3))], args)?;
+
+    let mut buffers = vec![];
+    for array in &arrays {
+        let addr = arena.alloc_layout(func.state_layout());
+        func.init_state(addr.into());
+        func.accumulate(addr.into(), &[array.clone()], array.len())?;
+
+        let mut buffer = BytesMut::new();
+        func.serialize(addr.into(), &mut buffer)?;
+        buffers.push(buffer);
+    }
+
+    let addr = arena.alloc_layout(func.state_layout());
+    func.init_state(addr.into());
+    for buffer in &buffers {
+        let rhs = arena.alloc_layout(func.state_layout());
+        func.init_state(rhs.into());
+        func.deserialize(rhs.into(), &mut buffer.as_ref())?;
+        func.merge(addr.into(), rhs.into())?;
+    }
+
+    let expect = DataValue::List(
+        Some(vec![
+            DataValue::UInt64(Some(1)),
+            DataValue::UInt64(Some(2)),
+            DataValue::UInt64(Some(3)),
+        ]),
+        DataType::UInt64,
+    );
+    assert_eq!(expect, func.merge_result(addr.into())?);
+
+    Ok(())
+}
+
 #[test]
 fn test_covariance_with_comparable_data_sets() -> Result<()> {
     let arena = Bump::new();
@@ -455,3 +455,105 @@ fn test_arithmetic_date_interval() -> Result<()> {
     }
     Ok(())
 }
+
+#[test]
+fn test_arithmetic_decimal() -> Result<()> {
+    struct Test {
+        name: &'static str,
+        op: DataValueArithmeticOperator,
+        arg_names: Vec<&'static str>,
+        expect_type: DataType,
+        expect: DataColumn,
+        error: &'static str,
+    }
+
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", DataType::Decimal(10, 2), false),
+        DataField::new("b", DataType::Decimal(5, 1), false),
+        DataField::new("c", DataType::Int32, false),
+        DataField::new("d", DataType::Float64, false),
+    ]);
+
+    // a: [1.25, -3.50, 0.10], b: [0.5, 2.0, 0.0], c: [2, 3, 4], d: [0.5, 0.5, 0.5]
+    let columns = vec![
+        Series::new(vec![125i64, -350, 10]).into(),
+        Series::new(vec![5i64, 20, 0]).into(),
+        Series::new(vec![2i32, 3, 4]).into(),
+        Series::new(vec![0.5f64, 0.5, 0.5]).into(),
+    ];
+
+    let tests = vec![
+        Test {
+            name: "decimal-plus-decimal",
+            op: DataValueArithmeticOperator::Plus,
+            arg_names: vec!["a", "b"],
+            expect_type: DataType::Decimal(11, 2),
+            expect: Series::new(vec![175i64, -150, 10]).into(),
+            error: "",
+        },
+        Test {
+            name: "decimal-minus-int",
+            op: DataValueArithmeticOperator::Minus,
+            arg_names: vec!["a", "c"],
+            expect_type: DataType::Decimal(13, 2),
+            expect: Series::new(vec![-75i64, -650, -390]).into(),
+            error: "",
+        },
+        Test {
+            name: "decimal-mul-decimal",
+            op: DataValueArithmeticOperator::Mul,
+            arg_names: vec!["a", "b"],
+            expect_type: DataType::Decimal(15, 3),
+            expect: Series::new(vec![625i64, -7000, 0]).into(),
+            error: "",
+        },
+        Test {
+            name: "decimal-div-int",
+            op: DataValueArithmeticOperator::Div,
+            arg_names: vec!["a", "c"],
+            expect_type: DataType::Decimal(18, 2),
+            expect: Series::new(vec![63i64, -117, 3]).into(),
+            error: "",
+        },
+        Test {
+            name: "decimal-plus-float",
+            op: DataValueArithmeticOperator::Plus,
+            arg_names: vec!["a", "d"],
+            expect_type: DataType::Float64,
+            expect: Series::new(vec![1.75f64, -3.0, 0.6]).into(),
+            error: "",
+        },
+        Test {
+            name: "decimal-div-zero",
+            op: DataValueArithmeticOperator::Div,
+            arg_names: vec!["a", "b"],
+            expect_type: DataType::Decimal(18, 2),
+            expect: Series::new(vec![0i64]).into(),
+            error: "Code: 6, displayText = Division by zero.",
+        },
+    ];
+
+    for t in tests {
+        let func = ArithmeticFunction::try_create_func(t.op.clone())?;
+
+        let mut args = vec![];
+        let mut arg_columns = vec![];
+        for name in t.arg_names {
+            let (index, field) = schema.column_with_name(name).unwrap();
+            args.push(field.data_type().clone());
+            arg_columns.push(DataColumnWithField::new(
+                columns[index].clone(),
+                field.clone(),
+            ));
+        }
+
+        let actual_type = func.return_type(&args)?;
+        assert_eq!(t.expect_type, actual_type, "{}", t.name);
+
+        match func.eval(&arg_columns, 3) {
+            Ok(v) => assert_eq!(v, t.expect, "{}", t.name),
+            Err(e) => assert_eq!(t.error, e.to_string(), "{}", t.name),
+        }
+    }
+    Ok(())
+}
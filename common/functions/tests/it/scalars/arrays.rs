@@ -0,0 +1,197 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_functions::scalars::*;
+
+fn int_list(values: Option<Vec<i64>>) -> DataValue {
+    DataValue::List(
+        values.map(|v| v.into_iter().map(|v| DataValue::Int64(Some(v))).collect()),
+        DataType::Int64,
+    )
+}
+
+// [1, 2, 3], [], NULL, [4]
+fn array_column() -> Result<DataColumnWithField> {
+    let values = vec![
+        int_list(Some(vec![1, 2, 3])),
+        int_list(Some(vec![])),
+        int_list(None),
+        int_list(Some(vec![4])),
+    ];
+    let array = build_list_array(&values, &DataType::Int64)?;
+    Ok(DataColumnWithField::new(
+        array.into(),
+        DataField::new("arr", create_list_type(DataType::Int64), true),
+    ))
+}
+
+#[test]
+fn test_array_function() -> Result<()> {
+    let func = ArrayFunction::try_create("array")?;
+    assert_eq!(
+        func.return_type(&[DataType::UInt8, DataType::Int64])?,
+        create_list_type(DataType::Int64)
+    );
+    assert!(func.return_type(&[DataType::Null]).is_err());
+
+    let args = vec![
+        DataColumnWithField::new(
+            Series::new(vec![1u8, 2]).into(),
+            DataField::new("a", DataType::UInt8, false),
+        ),
+        DataColumnWithField::new(
+            DataColumn::Constant(DataValue::Int64(Some(10)), 2),
+            DataField::new("b", DataType::Int64, false),
+        ),
+    ];
+    let column = func.eval(&args, 2)?;
+    let serializer = create_list_type(DataType::Int64).create_serializer();
+    assert_eq!(serializer.serialize_column(&column)?, vec![
+        "[1,10]", "[2,10]"
+    ]);
+    Ok(())
+}
+
+#[test]
+fn test_array_length_function() -> Result<()> {
+    let func = ArrayLengthFunction::try_create("length")?;
+    let column = func.eval(&[array_column()?], 4)?.to_array()?;
+    assert_eq!(column.try_get(0)?, DataValue::UInt64(Some(3)));
+    assert_eq!(column.try_get(1)?, DataValue::UInt64(Some(0)));
+    assert_eq!(column.try_get(2)?, DataValue::UInt64(None));
+    assert_eq!(column.try_get(3)?, DataValue::UInt64(Some(1)));
+
+    // the strings are measured in bytes, the numbers by their text
+    assert_eq!(func.return_type(&[DataType::String])?, DataType::UInt64);
+    assert_eq!(func.return_type(&[DataType::Int64])?, DataType::UInt64);
+    let strings = DataColumnWithField::new(
+        Series::new(vec!["ab", "你好"]).into(),
+        DataField::new("s", DataType::String, false),
+    );
+    let column = func.eval(&[strings], 2)?.to_array()?;
+    assert_eq!(column.try_get(0)?, DataValue::UInt64(Some(2)));
+    assert_eq!(column.try_get(1)?, DataValue::UInt64(Some(6)));
+    let numbers = DataColumnWithField::new(
+        Series::new(vec![123i64, -5]).into(),
+        DataField::new("n", DataType::Int64, false),
+    );
+    let column = func.eval(&[numbers], 2)?.to_array()?;
+    assert_eq!(column.try_get(0)?, DataValue::UInt64(Some(3)));
+    assert_eq!(column.try_get(1)?, DataValue::UInt64(Some(2)));
+    Ok(())
+}
+
+#[test]
+fn test_array_element_function() -> Result<()> {
+    let func = ArrayElementFunction::try_create("arrayElement")?;
+    assert_eq!(
+        func.return_type(&[create_list_type(DataType::Int64), DataType::UInt8])?,
+        DataType::Int64
+    );
+    assert!(func
+        .return_type(&[create_list_type(DataType::Int64), DataType::String])
+        .is_err());
+
+    let index = |index: i64| {
+        DataColumnWithField::new(
+            DataColumn::Constant(DataValue::Int64(Some(index)), 4),
+            DataField::new("index", DataType::Int64, false),
+        )
+    };
+
+    // The index starts from 1, the negative index counts from the end.
+    let column = func.eval(&[array_column()?, index(1)], 4)?.to_array()?;
+    assert_eq!(column.try_get(0)?, DataValue::Int64(Some(1)));
+    assert_eq!(column.try_get(1)?, DataValue::Int64(None));
+    assert_eq!(column.try_get(2)?, DataValue::Int64(None));
+    assert_eq!(column.try_get(3)?, DataValue::Int64(Some(4)));
+
+    let column = func.eval(&[array_column()?, index(-1)], 4)?.to_array()?;
+    assert_eq!(column.try_get(0)?, DataValue::Int64(Some(3)));
+
+    let column = func.eval(&[array_column()?, index(0)], 4)?.to_array()?;
+    assert_eq!(column.try_get(0)?, DataValue::Int64(None));
+    Ok(())
+}
+
+#[test]
+fn test_array_has_function() -> Result<()> {
+    let func = ArrayHasFunction::try_create("has")?;
+    let needle = DataColumnWithField::new(
+        DataColumn::Constant(DataValue::UInt8(Some(4)), 4),
+        DataField::new("needle", DataType::UInt8, false),
+    );
+    let column = func.eval(&[array_column()?, needle], 4)?.to_array()?;
+    assert_eq!(column.try_get(0)?, DataValue::Boolean(Some(false)));
+    assert_eq!(column.try_get(1)?, DataValue::Boolean(Some(false)));
+    assert_eq!(column.try_get(2)?, DataValue::Boolean(None));
+    assert_eq!(column.try_get(3)?, DataValue::Boolean(Some(true)));
+    Ok(())
+}
+
+#[test]
+fn test_array_join_unnest() -> Result<()> {
+    let (items, rows) = ArrayJoinFunction::unnest(array_column()?.column(), 4)?;
+    assert_eq!(rows, vec![0, 0, 0, 3]);
+    assert_eq!(items.len(), 4);
+    assert_eq!(items.try_get(2)?, DataValue::Int64(Some(3)));
+    assert_eq!(items.try_get(3)?, DataValue::Int64(Some(4)));
+    Ok(())
+}
+
+#[test]
+fn test_array_lambda_function() -> Result<()> {
+    let arrays = vec![array_column()?.column().clone()];
+    let args = vec![create_list_type(DataType::Int64)];
+
+    // arrayMap(x -> x * 10, arr)
+    let func = ArrayLambdaFunction::try_create("arrayMap")?;
+    assert_eq!(func.param_types(&args)?, vec![DataType::Int64]);
+    assert_eq!(
+        func.return_type(&args, &DataType::Float64)?,
+        create_list_type(DataType::Float64)
+    );
+
+    let params = func.lambda_arguments(&arrays, 4)?;
+    assert_eq!(params.len(), 1);
+    assert_eq!(params[0].len(), 4);
+
+    let lambda_result = DataColumnWithField::new(
+        Series::new(vec![10i64, 20, 30, 40]).into(),
+        DataField::new("lambda", DataType::Int64, false),
+    );
+    let column = func.eval(&arrays, &lambda_result, 4)?;
+    let serializer = create_list_type(DataType::Int64).create_serializer();
+    assert_eq!(serializer.serialize_column(&column)?, vec![
+        "[10,20,30]",
+        "[]",
+        "NULL",
+        "[40]"
+    ]);
+
+    // arrayFilter(x -> x != 2, arr)
+    let func = ArrayLambdaFunction::try_create("arrayFilter")?;
+    assert!(func.return_type(&args, &DataType::Int64).is_err());
+
+    let lambda_result = DataColumnWithField::new(
+        Series::new(vec![true, false, true, true]).into(),
+        DataField::new("lambda", DataType::Boolean, false),
+    );
+    let column = func.eval(&arrays, &lambda_result, 4)?;
+    assert_eq!(serializer.serialize_column(&column)?, vec![
+        "[1,3]", "[]", "NULL", "[4]"
+    ]);
+    Ok(())
+}
@@ -141,6 +141,32 @@ fn test_comparison_function() -> Result<()> {
             expect: Series::new(vec![false, false, false, true]),
             error: "",
         },
+        Test {
+            name: "regexp-passed",
+            display: "REGEXP",
+            nullable: false,
+            func: ComparisonRegexpFunction::try_create_func("")?,
+            arg_names: vec!["a", "b"],
+            columns: vec![
+                Series::new(vec!["abc", "abd", "abe", "abf"]).into(),
+                Series::new(vec!["^a", "b.$", "^abe$", "^b"]).into(),
+            ],
+            expect: Series::new(vec![true, true, true, false]),
+            error: "",
+        },
+        Test {
+            name: "not-regexp-passed",
+            display: "NOT REGEXP",
+            nullable: false,
+            func: ComparisonNotRegexpFunction::try_create_func("")?,
+            arg_names: vec!["a", "b"],
+            columns: vec![
+                Series::new(vec!["abc", "abd", "abe", "abf"]).into(),
+                Series::new(vec!["^a", "b.$", "^abe$", "^b"]).into(),
+            ],
+            expect: Series::new(vec![false, false, false, true]),
+            error: "",
+        },
     ];
 
     for t in tests {
@@ -188,3 +214,170 @@ fn test_comparison_function() -> Result<()> {
     }
     Ok(())
 }
+
+#[test]
+fn test_in_function() -> Result<()> {
+    struct Test {
+        name: &'static str,
+        func: Box<dyn Function>,
+        columns: Vec<DataColumn>,
+        expect: Series,
+    }
+
+    let subquery_result = DataValue::List(
+        Some(vec![DataValue::Int32(Some(2)), DataValue::Int32(Some(4))]),
+        DataType::Int32,
+    );
+
+    let tests = vec![
+        Test {
+            name: "in-list-passed",
+            func: ComparisonInFunction::try_create_func("")?,
+            columns: vec![
+                Series::new(vec![1i64, 2, 3, 4]).into(),
+                DataColumn::Constant(DataValue::Int64(Some(2)), 4),
+                DataColumn::Constant(DataValue::UInt8(Some(3)), 4),
+                DataColumn::Constant(DataValue::Null, 4),
+            ],
+            expect: Series::new(vec![None, Some(true), Some(true), None]),
+        },
+        Test {
+            name: "not-in-list-with-null-passed",
+            func: ComparisonNotInFunction::try_create_func("")?,
+            columns: vec![
+                Series::new(vec![1i64, 2, 3, 4]).into(),
+                DataColumn::Constant(DataValue::Int64(Some(2)), 4),
+                DataColumn::Constant(DataValue::Null, 4),
+            ],
+            expect: Series::new(vec![None, Some(false), None, None]),
+        },
+        Test {
+            name: "in-null-key-passed",
+            func: ComparisonInFunction::try_create_func("")?,
+            columns: vec![
+                Series::new(vec![Some(1i64), None]).into(),
+                DataColumn::Constant(DataValue::Int64(Some(1)), 2),
+            ],
+            expect: Series::new(vec![Some(true), None]),
+        },
+        Test {
+            name: "not-in-null-key-passed",
+            func: ComparisonNotInFunction::try_create_func("")?,
+            columns: vec![
+                Series::new(vec![Some(1i64), None, Some(2)]).into(),
+                DataColumn::Constant(DataValue::Int64(Some(1)), 3),
+            ],
+            expect: Series::new(vec![Some(false), None, Some(true)]),
+        },
+        Test {
+            name: "in-column-with-null-passed",
+            func: ComparisonInFunction::try_create_func("")?,
+            columns: vec![
+                Series::new(vec![1i64, 2, 3]).into(),
+                Series::new(vec![Some(1i64), None, Some(4)]).into(),
+            ],
+            expect: Series::new(vec![Some(true), None, Some(false)]),
+        },
+        Test {
+            name: "not-in-list-passed",
+            func: ComparisonNotInFunction::try_create_func("")?,
+            columns: vec![
+                Series::new(vec![1i64, 2, 3, 4]).into(),
+                DataColumn::Constant(DataValue::Int64(Some(2)), 4),
+                DataColumn::Constant(DataValue::UInt8(Some(3)), 4),
+            ],
+            expect: Series::new(vec![true, false, false, true]),
+        },
+        Test {
+            name: "in-column-passed",
+            func: ComparisonInFunction::try_create_func("")?,
+            columns: vec![
+                Series::new(vec![1i64, 2, 3, 4]).into(),
+                Series::new(vec![1i64, 3, 2, 4]).into(),
+                DataColumn::Constant(DataValue::Int64(Some(3)), 4),
+            ],
+            expect: Series::new(vec![true, false, true, true]),
+        },
+        Test {
+            name: "in-string-passed",
+            func: ComparisonInFunction::try_create_func("")?,
+            columns: vec![
+                Series::new(vec!["abc", "abd", "abe"]).into(),
+                DataColumn::Constant(DataValue::String(Some(b"abe".to_vec())), 3),
+                DataColumn::Constant(DataValue::String(Some(b"abc".to_vec())), 3),
+            ],
+            expect: Series::new(vec![true, false, true]),
+        },
+        Test {
+            name: "in-subquery-passed",
+            func: ComparisonInFunction::try_create_func("")?,
+            columns: vec![
+                Series::new(vec![1i64, 2, 3, 4]).into(),
+                DataColumn::Constant(subquery_result.clone(), 4),
+            ],
+            expect: Series::new(vec![false, true, false, true]),
+        },
+        Test {
+            name: "not-in-subquery-passed",
+            func: ComparisonNotInFunction::try_create_func("")?,
+            columns: vec![
+                Series::new(vec![1i64, 2, 3, 4]).into(),
+                DataColumn::Constant(subquery_result, 4),
+            ],
+            expect: Series::new(vec![true, false, true, false]),
+        },
+        Test {
+            name: "not-in-subquery-with-null-passed",
+            func: ComparisonNotInFunction::try_create_func("")?,
+            columns: vec![
+                Series::new(vec![1i64, 2, 3, 4]).into(),
+                DataColumn::Constant(
+                    DataValue::List(
+                        Some(vec![DataValue::Int32(Some(2)), DataValue::Int32(None)]),
+                        DataType::Int32,
+                    ),
+                    4,
+                ),
+            ],
+            expect: Series::new(vec![None, Some(false), None, None]),
+        },
+    ];
+
+    for t in tests {
+        let rows = t.columns[0].len();
+        let columns: Vec<DataColumnWithField> = t
+            .columns
+            .iter()
+            .enumerate()
+            .map(|(i, c)| {
+                let field = DataField::new(&format!("c{}", i), c.data_type(), false);
+                DataColumnWithField::new(c.clone(), field)
+            })
+            .collect();
+
+        let v = t.func.eval(&columns, rows)?;
+        assert_eq!(DataType::Boolean, v.data_type(), "{}", t.name);
+        assert_eq!(
+            t.expect.to_values()?,
+            v.to_array()?.to_values()?,
+            "{}",
+            t.name
+        );
+    }
+
+    let func = ComparisonNotInFunction::try_create_func("")?;
+    assert!(func.nullable(&DataSchema::empty())?);
+
+    let multi_columns = DataValue::Struct(vec![
+        DataValue::List(Some(vec![]), DataType::Int32),
+        DataValue::List(Some(vec![]), DataType::Int32),
+    ]);
+    let func = ComparisonInFunction::try_create_func("")?;
+    let result = func.return_type(&[DataType::Int64, multi_columns.data_type()]);
+    assert_eq!(
+        "Code: 6, displayText = IN subquery must return exactly one column.",
+        result.unwrap_err().to_string()
+    );
+
+    Ok(())
+}
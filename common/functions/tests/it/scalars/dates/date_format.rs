@@ -0,0 +1,265 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_functions::scalars::*;
+
+#[test]
+fn test_date_format_functions() -> Result<()> {
+    struct Test {
+        name: &'static str,
+        func: Box<dyn Function>,
+        args: Vec<DataColumnWithField>,
+        input_rows: usize,
+        expect: DataColumn,
+        error: &'static str,
+    }
+
+    let date_time_arg = |values: &[u32], tz: Option<&str>| {
+        DataColumnWithField::new(
+            Series::new(values).into(),
+            DataField::new(
+                "t",
+                DataType::DateTime32(tz.map(|tz| tz.to_string())),
+                false,
+            ),
+        )
+    };
+    let date_arg = |values: &[u16]| {
+        DataColumnWithField::new(
+            Series::new(values).into(),
+            DataField::new("d", DataType::Date16, false),
+        )
+    };
+    let string_arg = |values: &[&str]| {
+        DataColumnWithField::new(
+            Series::new(values).into(),
+            DataField::new("s", DataType::String, false),
+        )
+    };
+    let const_arg = |value: &str, rows: usize| {
+        DataColumnWithField::new(
+            DataColumn::Constant(DataValue::String(Some(value.as_bytes().to_vec())), rows),
+            DataField::new("c", DataType::String, false),
+        )
+    };
+
+    // 2021-12-01 04:00:30 UTC
+    let time = 1638331230_u32;
+    let tests = vec![
+        Test {
+            name: "formatDateTime-passed",
+            func: FormatDateTimeFunction::try_create("formatDateTime")?,
+            args: vec![
+                date_time_arg(&[0, time], None),
+                const_arg("%Y-%m-%d %H:%M:%S Q%Q", 2),
+            ],
+            input_rows: 2,
+            expect: Series::new(["1970-01-01 00:00:00 Q1", "2021-12-01 04:00:30 Q4"]).into(),
+            error: "",
+        },
+        Test {
+            name: "formatDateTime-type-timezone-passed",
+            func: FormatDateTimeFunction::try_create("formatDateTime")?,
+            args: vec![
+                date_time_arg(&[time], Some("Asia/Shanghai")),
+                const_arg("%F %T", 1),
+            ],
+            input_rows: 1,
+            expect: Series::new(["2021-12-01 12:00:30"]).into(),
+            error: "",
+        },
+        Test {
+            name: "formatDateTime-argument-timezone-passed",
+            func: FormatDateTimeFunction::try_create("formatDateTime")?,
+            args: vec![
+                date_time_arg(&[time], Some("Asia/Shanghai")),
+                const_arg("%F %T", 1),
+                const_arg("America/New_York", 1),
+            ],
+            input_rows: 1,
+            expect: Series::new(["2021-11-30 23:00:30"]).into(),
+            error: "",
+        },
+        Test {
+            name: "formatDateTime-bad-specifier-failed",
+            func: FormatDateTimeFunction::try_create("formatDateTime")?,
+            args: vec![date_time_arg(&[time], None), const_arg("%K", 1)],
+            input_rows: 1,
+            expect: Series::new([""]).into(),
+            error: "Code: 6, displayText = Unsupported format specifier %K in the date time format: %K.",
+        },
+        Test {
+            name: "date_format-passed",
+            func: FormatDateTimeFunction::try_create_mysql("date_format")?,
+            args: vec![
+                date_time_arg(&[time], None),
+                const_arg("%W %D %M %Y %H:%i:%s", 1),
+            ],
+            input_rows: 1,
+            expect: Series::new(["Wednesday 1st December 2021 04:00:30"]).into(),
+            error: "",
+        },
+        Test {
+            name: "parseDateTimeBestEffort-passed",
+            func: ParseDateTimeBestEffortFunction::try_create("parseDateTimeBestEffort")?,
+            args: vec![string_arg(&[
+                "2021-12-01 04:00:30",
+                "2021-12-01T12:00:30+08:00",
+                "Wed, 01 Dec 2021 04:00:30 +0000",
+                "20211201",
+                "bad",
+            ])],
+            input_rows: 5,
+            expect: Series::new([
+                Some(time),
+                Some(time),
+                Some(time),
+                Some(1638316800_u32),
+                None,
+            ])
+            .into(),
+            error: "",
+        },
+        Test {
+            name: "str_to_date-passed",
+            func: StrToDateFunction::try_create("str_to_date")?,
+            args: vec![
+                string_arg(&["01/12/2021 04:00:30", "01/12/2021", "2021"]),
+                const_arg("%d/%m/%Y %H:%i:%s", 3),
+            ],
+            input_rows: 3,
+            expect: Series::new([Some(time), None, None]).into(),
+            error: "",
+        },
+        Test {
+            name: "toUnixTimestamp-passed",
+            func: ToUnixTimestampFunction::try_create("toUnixTimestamp")?,
+            args: vec![
+                string_arg(&["2021-12-01 12:00:30"]),
+                const_arg("Asia/Shanghai", 1),
+            ],
+            input_rows: 1,
+            expect: Series::new([time]).into(),
+            error: "",
+        },
+        Test {
+            name: "fromUnixTime-passed",
+            func: FromUnixTimeFunction::try_create("fromUnixTime")?,
+            args: vec![
+                DataColumnWithField::new(
+                    Series::new([time as i64]).into(),
+                    DataField::new("i", DataType::Int64, false),
+                ),
+                const_arg("%Y-%m-%d %T", 1),
+            ],
+            input_rows: 1,
+            expect: Series::new(["2021-12-01 04:00:30"]).into(),
+            error: "",
+        },
+        Test {
+            name: "dateDiff-passed",
+            func: DateDiffFunction::try_create("dateDiff")?,
+            args: vec![
+                const_arg("day", 2),
+                date_time_arg(&[time, time], None),
+                date_time_arg(&[1638575999, 1638316799], None),
+            ],
+            input_rows: 2,
+            expect: Series::new([2_i64, -1]).into(),
+            error: "",
+        },
+        Test {
+            name: "dateDiff-timezone-passed",
+            func: DateDiffFunction::try_create("dateDiff")?,
+            args: vec![
+                const_arg("month", 1),
+                date_time_arg(&[1638316799], None),
+                date_time_arg(&[time], None),
+                const_arg("America/New_York", 1),
+            ],
+            input_rows: 1,
+            expect: Series::new([0_i64]).into(),
+            error: "",
+        },
+        Test {
+            name: "dateDiff-bad-unit-failed",
+            func: DateDiffFunction::try_create("dateDiff")?,
+            args: vec![
+                const_arg("fortnight", 1),
+                date_time_arg(&[time], None),
+                date_time_arg(&[time], None),
+            ],
+            input_rows: 1,
+            expect: Series::new([0_i64]).into(),
+            error: "Code: 6, displayText = Unsupported unit fortnight of function dateDiff, it should be one of second, minute, hour, day, week, month, quarter and year.",
+        },
+        Test {
+            name: "date_trunc-passed",
+            func: DateTruncFunction::try_create("date_trunc")?,
+            args: vec![const_arg("month", 1), date_time_arg(&[time], None)],
+            input_rows: 1,
+            expect: Series::new([1638316800_u32]).into(),
+            error: "",
+        },
+        Test {
+            name: "date_trunc-timezone-passed",
+            func: DateTruncFunction::try_create("date_trunc")?,
+            args: vec![
+                const_arg("day", 1),
+                date_time_arg(&[time], Some("Asia/Shanghai")),
+            ],
+            input_rows: 1,
+            expect: Series::new([1638288000_u32]).into(),
+            error: "",
+        },
+        Test {
+            name: "date_trunc-week-passed",
+            func: DateTruncFunction::try_create("date_trunc")?,
+            args: vec![const_arg("week", 1), date_arg(&[18962])],
+            input_rows: 1,
+            expect: Series::new([18960_u16]).into(),
+            error: "",
+        },
+        Test {
+            name: "toYear-timezone-passed",
+            func: ToYearFunction::try_create("toYear")?,
+            args: vec![date_time_arg(&[1640966400], Some("Asia/Shanghai"))],
+            input_rows: 1,
+            expect: Series::new([2022_u16]).into(),
+            error: "",
+        },
+        Test {
+            name: "toQuarter-passed",
+            func: ToQuarterFunction::try_create("toQuarter")?,
+            args: vec![date_time_arg(&[1640966400, time], None)],
+            input_rows: 2,
+            expect: Series::new([4_u8, 4]).into(),
+            error: "",
+        },
+    ];
+
+    for t in tests {
+        let func = t.func;
+        if let Err(e) = func.eval(&t.args, t.input_rows) {
+            assert_eq!(t.error, e.to_string(), "{}", t.name);
+            continue;
+        }
+
+        let v = &(func.eval(&t.args, t.input_rows)?);
+        assert_eq!(v.to_values()?, t.expect.to_values()?, "case: {}", t.name);
+    }
+    Ok(())
+}
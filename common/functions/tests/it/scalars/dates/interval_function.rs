@@ -221,3 +221,63 @@ fn test_add_subtract_seconds() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_add_datetime64() -> Result<()> {
+    let dt_to_millis =
+        |dt: &str| -> i64 { DateTime::parse_from_rfc3339(dt).unwrap().timestamp_millis() };
+
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("datetime64", DataType::DateTime64(3, None), false),
+        DataField::new("u8", DataType::UInt8, false),
+        DataField::new("i32", DataType::Int32, false),
+    ]);
+
+    let blocks = DataBlock::create_by_array(schema.clone(), vec![
+        Series::new(vec![dt_to_millis("2020-02-29T23:59:59.123Z")]),
+        Series::new(vec![12_u8]),
+        Series::new(vec![-1_i32]),
+    ]);
+
+    let column = |col_name: &str| -> DataColumnWithField {
+        DataColumnWithField::new(
+            blocks.try_column_by_name(col_name).unwrap().clone(),
+            schema.field_with_name(col_name).unwrap().clone(),
+        )
+    };
+
+    // The sub-second ticks are kept.
+    let add_months =
+        MonthsArithmeticFunction::try_create("addMonths", DataValueArithmeticOperator::Plus, 1)?;
+    {
+        let mut expects: Vec<i64> = Vec::new();
+        for c in ["u8", "i32"] {
+            let col = add_months.eval(&[column("datetime64"), column(c)], 1)?;
+            let raw = col.to_array()?.i64()?.inner().values().as_slice().to_vec();
+            assert_eq!(raw.len(), 1);
+            expects.push(raw[0]);
+        }
+        assert_eq!(expects, vec![
+            dt_to_millis("2021-02-28T23:59:59.123Z"),
+            dt_to_millis("2020-01-29T23:59:59.123Z"),
+        ]);
+    }
+
+    let add_seconds =
+        SecondsArithmeticFunction::try_create("addSeconds", DataValueArithmeticOperator::Plus, 1)?;
+    {
+        let mut expects: Vec<i64> = Vec::new();
+        for c in ["u8", "i32"] {
+            let col = add_seconds.eval(&[column(c), column("datetime64")], 1)?;
+            let raw = col.to_array()?.i64()?.inner().values().as_slice().to_vec();
+            assert_eq!(raw.len(), 1);
+            expects.push(raw[0]);
+        }
+        assert_eq!(expects, vec![
+            dt_to_millis("2020-03-01T00:00:11.123Z"),
+            dt_to_millis("2020-02-29T23:59:58.123Z"),
+        ]);
+    }
+
+    Ok(())
+}
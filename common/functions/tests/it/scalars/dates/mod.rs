@@ -13,5 +13,6 @@
 // limitations under the License.
 
 mod date;
+mod date_format;
 mod date_function;
 mod interval_function;
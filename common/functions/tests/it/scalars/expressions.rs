@@ -132,6 +132,148 @@ fn test_cast_function() -> Result<()> {
             expect: Series::new(vec!["2021-03-05 01:01:01", "2021-10-24 10:10:10"]),
             error: "",
         },
+        Test {
+            name: "cast-string-to-decimal-passed",
+            display: "CAST",
+            nullable: false,
+            columns: vec![Series::new(vec!["1.5", "-0.125", "12", "1e2"]).into()],
+            column_types: vec![DataType::String],
+            func: CastFunction::create("cast".to_string(), DataType::Decimal(10, 2)),
+            expect: Series::new(vec![150i64, -13, 1200, 10000]),
+            error: "",
+        },
+        Test {
+            name: "cast-int32-to-decimal-passed",
+            display: "CAST",
+            nullable: false,
+            columns: vec![Series::new(vec![1i32, -20]).into()],
+            column_types: vec![DataType::Int32],
+            func: CastFunction::create("cast".to_string(), DataType::Decimal(5, 3)),
+            expect: Series::new(vec![1000i64, -20000]),
+            error: "",
+        },
+        Test {
+            name: "cast-decimal-to-decimal-passed",
+            display: "CAST",
+            nullable: false,
+            columns: vec![Series::new(vec![12345i64, -12355]).into()],
+            column_types: vec![DataType::Decimal(10, 3)],
+            func: CastFunction::create("cast".to_string(), DataType::Decimal(10, 1)),
+            expect: Series::new(vec![123i64, -124]),
+            error: "",
+        },
+        Test {
+            name: "cast-decimal-to-string-passed",
+            display: "CAST",
+            nullable: false,
+            columns: vec![Series::new(vec![150i64, -5, 0]).into()],
+            column_types: vec![DataType::Decimal(10, 2)],
+            func: CastFunction::create("cast".to_string(), DataType::String),
+            expect: Series::new(vec!["1.50", "-0.05", "0.00"]),
+            error: "",
+        },
+        Test {
+            name: "cast-decimal-to-float64-passed",
+            display: "CAST",
+            nullable: false,
+            columns: vec![Series::new(vec![150i64, -5]).into()],
+            column_types: vec![DataType::Decimal(10, 2)],
+            func: CastFunction::create("cast".to_string(), DataType::Float64),
+            expect: Series::new(vec![1.5f64, -0.05]),
+            error: "",
+        },
+        Test {
+            name: "cast-string-to-datetime64-passed",
+            display: "CAST",
+            nullable: false,
+            columns: vec![
+                Series::new(vec!["2021-08-30 10:47:42.123", "2021-08-30 10:47:42"]).into(),
+            ],
+            column_types: vec![DataType::String],
+            func: CastFunction::create("cast".to_string(), DataType::DateTime64(3, None)),
+            expect: Series::new(vec![1630320462123i64, 1630320462000]),
+            error: "",
+        },
+        Test {
+            name: "cast-datetime64-to-string-passed",
+            display: "CAST",
+            nullable: false,
+            columns: vec![Series::new(vec![1630320462123i64, -1]).into()],
+            column_types: vec![DataType::DateTime64(3, None)],
+            func: CastFunction::create("cast".to_string(), DataType::String),
+            expect: Series::new(vec!["2021-08-30 10:47:42.123", "1969-12-31 23:59:59.999"]),
+            error: "",
+        },
+        Test {
+            name: "cast-datetime64-to-datetime64-passed",
+            display: "CAST",
+            nullable: false,
+            columns: vec![Series::new(vec![1630320462123456i64]).into()],
+            column_types: vec![DataType::DateTime64(6, None)],
+            func: CastFunction::create("cast".to_string(), DataType::DateTime64(3, None)),
+            expect: Series::new(vec![1630320462123i64]),
+            error: "",
+        },
+        Test {
+            name: "cast-datetime64-to-datetime32-passed",
+            display: "CAST",
+            nullable: false,
+            columns: vec![Series::new(vec![1630320462123i64]).into()],
+            column_types: vec![DataType::DateTime64(3, None)],
+            func: CastFunction::create("cast".to_string(), DataType::DateTime32(None)),
+            expect: Series::new(vec![1630320462u32]),
+            error: "",
+        },
+        Test {
+            name: "cast-date16-to-datetime64-passed",
+            display: "CAST",
+            nullable: false,
+            columns: vec![Series::new(vec![18869u16]).into()],
+            column_types: vec![DataType::Date16],
+            func: CastFunction::create("cast".to_string(), DataType::DateTime64(3, None)),
+            expect: Series::new(vec![1630281600000i64]),
+            error: "",
+        },
+        Test {
+            name: "cast-string-to-variant-passed",
+            display: "CAST",
+            nullable: false,
+            columns: vec![Series::new(vec![r#"{"b": 1, "a": "x"}"#, "[1, 2.5]"]).into()],
+            column_types: vec![DataType::String],
+            func: CastFunction::create("cast".to_string(), DataType::Variant),
+            expect: Series::new(vec![r#"{"a":"x","b":1}"#, "[1,2.5]"]),
+            error: "",
+        },
+        Test {
+            name: "cast-variant-to-string-passed",
+            display: "CAST",
+            nullable: false,
+            columns: vec![Series::new(vec![r#""str""#, r#"{"a":1}"#, "1.5"]).into()],
+            column_types: vec![DataType::Variant],
+            func: CastFunction::create("cast".to_string(), DataType::String),
+            expect: Series::new(vec!["str", r#"{"a":1}"#, "1.5"]),
+            error: "",
+        },
+        Test {
+            name: "cast-variant-to-int32-passed",
+            display: "CAST",
+            nullable: false,
+            columns: vec![Series::new(vec!["1", "2.9", r#""-3""#, "true"]).into()],
+            column_types: vec![DataType::Variant],
+            func: CastFunction::create("cast".to_string(), DataType::Int32),
+            expect: Series::new(vec![1i32, 2, -3, 1]),
+            error: "",
+        },
+        Test {
+            name: "cast-int64-to-variant-passed",
+            display: "CAST",
+            nullable: false,
+            columns: vec![Series::new(vec![1i64, -2]).into()],
+            column_types: vec![DataType::Int64],
+            func: CastFunction::create("cast".to_string(), DataType::Variant),
+            expect: Series::new(vec!["1", "-2"]),
+            error: "",
+        },
     ];
 
     for t in tests {
@@ -0,0 +1,131 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_functions::scalars::*;
+
+fn map_type() -> DataType {
+    DataType::Map(Box::new(DataType::String), Box::new(DataType::Int64))
+}
+
+// {'a':1,'b':10}, {'a':2,'b':20}
+fn map_column() -> Result<DataColumnWithField> {
+    let func = MapFunction::try_create("map")?;
+    let args = vec![
+        DataColumnWithField::new(
+            Series::new(vec!["a", "a"]).into(),
+            DataField::new("k1", DataType::String, false),
+        ),
+        DataColumnWithField::new(
+            Series::new(vec![1i64, 2]).into(),
+            DataField::new("v1", DataType::Int64, false),
+        ),
+        DataColumnWithField::new(
+            DataColumn::Constant(DataValue::String(Some(b"b".to_vec())), 2),
+            DataField::new("k2", DataType::String, false),
+        ),
+        DataColumnWithField::new(
+            Series::new(vec![10i64, 20]).into(),
+            DataField::new("v2", DataType::Int64, false),
+        ),
+    ];
+    let column = func.eval(&args, 2)?;
+    Ok(DataColumnWithField::new(
+        column,
+        DataField::new("m", map_type(), false),
+    ))
+}
+
+#[test]
+fn test_map_function() -> Result<()> {
+    let func = MapFunction::try_create("map")?;
+    assert_eq!(
+        func.return_type(&[
+            DataType::String,
+            DataType::UInt8,
+            DataType::String,
+            DataType::Int64
+        ])?,
+        map_type()
+    );
+    assert!(func.return_type(&[DataType::String]).is_err());
+    assert!(func
+        .return_type(&[DataType::Float64, DataType::Int64])
+        .is_err());
+
+    let column = map_column()?;
+    let serializer = map_type().create_serializer();
+    assert_eq!(serializer.serialize_column(column.column())?, vec![
+        "{'a':1,'b':10}",
+        "{'a':2,'b':20}"
+    ]);
+    Ok(())
+}
+
+#[test]
+fn test_map_keys_values_function() -> Result<()> {
+    let args = vec![map_column()?];
+
+    let func = MapKeysFunction::try_create("mapKeys")?;
+    assert_eq!(
+        func.return_type(&[map_type()])?,
+        DataType::List(Box::new(DataField::new("item", DataType::String, true)))
+    );
+    assert!(func.return_type(&[DataType::String]).is_err());
+    let keys = func.eval(&args, 2)?.to_array()?;
+    assert_eq!(
+        keys.try_get(1)?,
+        DataValue::List(
+            Some(vec![
+                DataValue::String(Some(b"a".to_vec())),
+                DataValue::String(Some(b"b".to_vec()))
+            ]),
+            DataType::String
+        )
+    );
+
+    let func = MapValuesFunction::try_create("mapValues")?;
+    let values = func.eval(&args, 2)?.to_array()?;
+    assert_eq!(
+        values.try_get(0)?,
+        DataValue::List(
+            Some(vec![DataValue::Int64(Some(1)), DataValue::Int64(Some(10))]),
+            DataType::Int64
+        )
+    );
+    Ok(())
+}
+
+#[test]
+fn test_get_map_element_function() -> Result<()> {
+    let func = GetFunction::try_create("get")?;
+    assert_eq!(
+        func.return_type(&[map_type(), DataType::String])?,
+        DataType::Int64
+    );
+
+    let key = |key: &str| {
+        DataColumnWithField::new(
+            DataColumn::Constant(DataValue::String(Some(key.as_bytes().to_vec())), 2),
+            DataField::new("k", DataType::String, false),
+        )
+    };
+    let result = func.eval(&[map_column()?, key("b")], 2)?.to_array()?;
+    assert_eq!(result.i64()?.collect_values(), vec![Some(10), Some(20)]);
+
+    // The missing key is NULL.
+    let result = func.eval(&[map_column()?, key("c")], 2)?.to_array()?;
+    assert_eq!(result.i64()?.collect_values(), vec![None, None]);
+    Ok(())
+}
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 mod arithmetics;
+mod arrays;
 mod comparisons;
 mod conditionals;
 mod dates;
@@ -20,9 +21,11 @@ mod expressions;
 mod function_column;
 mod hashes;
 mod logics;
+mod maps;
 mod maths;
 mod nullables;
 mod others;
+mod semi_structureds;
 mod strings;
 mod tuples;
 mod udfs;
@@ -0,0 +1,169 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_functions::scalars::*;
+
+fn variant_column(values: &[&str]) -> DataColumnWithField {
+    DataColumnWithField::new(
+        Series::new(values.to_vec()).into(),
+        DataField::new("v", DataType::Variant, true),
+    )
+}
+
+fn string_constant(value: &str, rows: usize) -> DataColumnWithField {
+    DataColumnWithField::new(
+        DataColumn::Constant(DataValue::String(Some(value.as_bytes().to_vec())), rows),
+        DataField::new("s", DataType::String, false),
+    )
+}
+
+fn string_values(column: &DataColumn) -> Result<Vec<Option<String>>> {
+    let series = column.to_array()?;
+    Ok(series
+        .string()?
+        .into_iter()
+        .map(|v| v.map(|v| String::from_utf8_lossy(v).to_string()))
+        .collect())
+}
+
+#[test]
+fn test_parse_json_function() -> Result<()> {
+    let func = ParseJsonFunction::try_create("parse_json")?;
+    assert_eq!(func.return_type(&[DataType::String])?, DataType::Variant);
+    assert!(func.return_type(&[DataType::Int32]).is_err());
+
+    let args = vec![DataColumnWithField::new(
+        Series::new(vec![r#"{"b": 1, "a": [true, null]}"#, r#" "str" "#]).into(),
+        DataField::new("s", DataType::String, false),
+    )];
+    let result = func.eval(&args, 2)?;
+    assert_eq!(string_values(&result)?, vec![
+        Some(r#"{"a":[true,null],"b":1}"#.to_string()),
+        Some(r#""str""#.to_string()),
+    ]);
+
+    let args = vec![string_constant("{a: 1}", 1)];
+    assert!(func.eval(&args, 1).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_check_json_function() -> Result<()> {
+    let func = CheckJsonFunction::try_create("check_json")?;
+    let args = vec![DataColumnWithField::new(
+        Series::new(vec![r#"{"a": 1}"#, "{a: 1}"]).into(),
+        DataField::new("s", DataType::String, false),
+    )];
+    let result = string_values(&func.eval(&args, 2)?)?;
+    assert_eq!(result[0], None);
+    assert!(result[1].is_some());
+    Ok(())
+}
+
+#[test]
+fn test_get_function() -> Result<()> {
+    let func = GetFunction::try_create("get")?;
+    assert_eq!(
+        func.return_type(&[DataType::Variant, DataType::String])?,
+        DataType::Variant
+    );
+    assert!(func
+        .return_type(&[DataType::Variant, DataType::Float64])
+        .is_err());
+
+    let args = vec![
+        variant_column(&[r#"{"a":{"b":1}}"#, r#"{"x":2}"#, "[1,2]"]),
+        string_constant("a", 3),
+    ];
+    let result = func.eval(&args, 3)?;
+    assert_eq!(string_values(&result)?, vec![
+        Some(r#"{"b":1}"#.to_string()),
+        None,
+        None
+    ]);
+
+    let args = vec![
+        variant_column(&["[10,20]", "[30]"]),
+        DataColumnWithField::new(
+            DataColumn::Constant(DataValue::UInt8(Some(1)), 2),
+            DataField::new("i", DataType::UInt8, false),
+        ),
+    ];
+    let result = func.eval(&args, 2)?;
+    assert_eq!(string_values(&result)?, vec![Some("20".to_string()), None]);
+    Ok(())
+}
+
+#[test]
+fn test_get_path_function() -> Result<()> {
+    let value = r#"{"a":{"b":[1,{"c":"d"}]},"e f":true}"#;
+
+    let func = GetPathFunction::try_create("get_path")?;
+    let args = vec![
+        variant_column(&[value, "1"]),
+        string_constant("a.b[1].c", 2),
+    ];
+    let result = func.eval(&args, 2)?;
+    assert_eq!(string_values(&result)?, vec![
+        Some(r#""d""#.to_string()),
+        None
+    ]);
+
+    let args = vec![variant_column(&[value]), string_constant(r#"["e f"]"#, 1)];
+    let result = func.eval(&args, 1)?;
+    assert_eq!(string_values(&result)?, vec![Some("true".to_string())]);
+
+    let func = GetPathFunction::try_create_text("json_extract_path_text")?;
+    assert_eq!(
+        func.return_type(&[DataType::String, DataType::String])?,
+        DataType::String
+    );
+    let args = vec![
+        DataColumnWithField::new(
+            Series::new(vec![value, value]).into(),
+            DataField::new("s", DataType::String, false),
+        ),
+        DataColumnWithField::new(
+            Series::new(vec!["a.b[1].c", "a.b"]).into(),
+            DataField::new("p", DataType::String, false),
+        ),
+    ];
+    let result = func.eval(&args, 2)?;
+    assert_eq!(string_values(&result)?, vec![
+        Some("d".to_string()),
+        Some(r#"[1,{"c":"d"}]"#.to_string())
+    ]);
+    Ok(())
+}
+
+#[test]
+fn test_parse_json_path() -> Result<()> {
+    assert_eq!(parse_json_path(br#"a.b[0]["c d"].e"#)?, vec![
+        JsonPathElem::Key("a".to_string()),
+        JsonPathElem::Key("b".to_string()),
+        JsonPathElem::Index(0),
+        JsonPathElem::Key("c d".to_string()),
+        JsonPathElem::Key("e".to_string()),
+    ]);
+    assert_eq!(parse_json_path(b"[1]['x']")?, vec![
+        JsonPathElem::Index(1),
+        JsonPathElem::Key("x".to_string()),
+    ]);
+
+    for path in ["", "a..b", "a[x]", "a[1", ".a", "a.b c["] {
+        assert!(parse_json_path(path.as_bytes()).is_err(), "{}", path);
+    }
+    Ok(())
+}
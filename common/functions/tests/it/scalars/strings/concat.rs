@@ -0,0 +1,161 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_functions::scalars::*;
+
+#[test]
+fn test_concat_function() -> Result<()> {
+    struct Test {
+        name: &'static str,
+        func: Box<dyn Function>,
+        args: Vec<DataColumnWithField>,
+        input_rows: usize,
+        expect: DataColumn,
+        error: &'static str,
+    }
+
+    let tests = vec![
+        Test {
+            name: "concat-passed",
+            func: ConcatFunction::try_create("concat")?,
+            args: vec![
+                DataColumnWithField::new(
+                    Series::new(["ab", "c", ""]).into(),
+                    DataField::new("a", DataType::String, false),
+                ),
+                DataColumnWithField::new(
+                    DataColumn::Constant(DataValue::String(Some(b"-".to_vec())), 3),
+                    DataField::new("b", DataType::String, false),
+                ),
+                DataColumnWithField::new(
+                    Series::new([1_u64, 22_u64, 333_u64]).into(),
+                    DataField::new("c", DataType::UInt64, false),
+                ),
+            ],
+            input_rows: 3,
+            expect: Series::new(["ab-1", "c-22", "-333"]).into(),
+            error: "",
+        },
+        Test {
+            name: "concat-null-passed",
+            func: ConcatFunction::try_create("concat")?,
+            args: vec![
+                DataColumnWithField::new(
+                    Series::new([Some("a"), None]).into(),
+                    DataField::new("a", DataType::String, true),
+                ),
+                DataColumnWithField::new(
+                    Series::new(["b", "c"]).into(),
+                    DataField::new("b", DataType::String, false),
+                ),
+            ],
+            input_rows: 2,
+            expect: Series::new([Some("ab"), None]).into(),
+            error: "",
+        },
+        Test {
+            name: "concat_ws-passed",
+            func: ConcatWsFunction::try_create("concat_ws")?,
+            args: vec![
+                DataColumnWithField::new(
+                    Series::new([Some(","), Some("--"), None]).into(),
+                    DataField::new("sep", DataType::String, true),
+                ),
+                DataColumnWithField::new(
+                    Series::new([Some("a"), None, Some("a")]).into(),
+                    DataField::new("a", DataType::String, true),
+                ),
+                DataColumnWithField::new(
+                    Series::new(["b", "b", "b"]).into(),
+                    DataField::new("b", DataType::String, false),
+                ),
+            ],
+            input_rows: 3,
+            expect: Series::new([Some("a,b"), Some("b"), None]).into(),
+            error: "",
+        },
+        Test {
+            name: "replace-passed",
+            func: ReplaceFunction::try_create("replace")?,
+            args: vec![
+                DataColumnWithField::new(
+                    Series::new(["aXbXc", "abc", "abc"]).into(),
+                    DataField::new("s", DataType::String, false),
+                ),
+                DataColumnWithField::new(
+                    Series::new(["X", "", "bc"]).into(),
+                    DataField::new("from", DataType::String, false),
+                ),
+                DataColumnWithField::new(
+                    Series::new(["--", "Z", ""]).into(),
+                    DataField::new("to", DataType::String, false),
+                ),
+            ],
+            input_rows: 3,
+            expect: Series::new(["a--b--c", "abc", "a"]).into(),
+            error: "",
+        },
+        Test {
+            name: "split_part-passed",
+            func: SplitPartFunction::try_create("split_part")?,
+            args: vec![
+                DataColumnWithField::new(
+                    DataColumn::Constant(DataValue::String(Some(b"a,b,c".to_vec())), 4),
+                    DataField::new("s", DataType::String, false),
+                ),
+                DataColumnWithField::new(
+                    DataColumn::Constant(DataValue::String(Some(b",".to_vec())), 4),
+                    DataField::new("delimiter", DataType::String, false),
+                ),
+                DataColumnWithField::new(
+                    Series::new([1_i64, 3, -1, 4]).into(),
+                    DataField::new("pos", DataType::Int64, false),
+                ),
+            ],
+            input_rows: 4,
+            expect: Series::new(["a", "c", "c", ""]).into(),
+            error: "",
+        },
+        Test {
+            name: "concat-number-arguments-failed",
+            func: ConcatFunction::try_create("concat")?,
+            args: vec![DataColumnWithField::new(
+                Series::new([true]).into(),
+                DataField::new("a", DataType::Boolean, false),
+            )],
+            input_rows: 1,
+            expect: Series::new([""]).into(),
+            error: "Code: 7, displayText = Expected string or number or null, but got Boolean.",
+        },
+    ];
+
+    for t in tests {
+        let func = t.func;
+        let args = t
+            .args
+            .iter()
+            .map(|arg| arg.field().data_type().clone())
+            .collect::<Vec<_>>();
+        if let Err(e) = func.return_type(&args) {
+            assert_eq!(t.error, e.to_string(), "{}", t.name);
+            continue;
+        }
+
+        let v = &(func.eval(&t.args, t.input_rows)?);
+        assert_eq!(v.to_values()?, t.expect.to_values()?, "case: {}", t.name);
+    }
+    Ok(())
+}
@@ -12,6 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod concat;
 mod locate;
+mod pad;
+mod regexp;
 mod substring;
 mod trim;
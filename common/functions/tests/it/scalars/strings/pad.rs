@@ -0,0 +1,120 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_functions::scalars::*;
+
+#[test]
+fn test_pad_function() -> Result<()> {
+    struct Test {
+        name: &'static str,
+        func: Box<dyn Function>,
+        args: Vec<DataColumnWithField>,
+        input_rows: usize,
+        expect: DataColumn,
+        error: &'static str,
+    }
+
+    let string_arg = |values: &[&str]| {
+        DataColumnWithField::new(
+            Series::new(values).into(),
+            DataField::new("s", DataType::String, false),
+        )
+    };
+    let len_arg = |values: &[i64]| {
+        DataColumnWithField::new(
+            Series::new(values).into(),
+            DataField::new("len", DataType::Int64, false),
+        )
+    };
+
+    let tests = vec![
+        Test {
+            name: "lpad-passed",
+            func: LeftPadFunction::try_create("lpad")?,
+            args: vec![
+                string_arg(&["hi", "hello", "ab"]),
+                len_arg(&[5, 3, 1]),
+                string_arg(&["xy", "?", "?"]),
+            ],
+            input_rows: 3,
+            expect: Series::new(["xyxhi", "hel", "a"]).into(),
+            error: "",
+        },
+        Test {
+            name: "rpad-passed",
+            func: RightPadFunction::try_create("rpad")?,
+            args: vec![
+                string_arg(&["hi", "hello", "你好"]),
+                len_arg(&[5, 3, 3]),
+                string_arg(&["xy", "?", "啊"]),
+            ],
+            input_rows: 3,
+            expect: Series::new(["hixyx", "hel", "你好啊"]).into(),
+            error: "",
+        },
+        Test {
+            name: "lpad-negative-length-passed",
+            func: LeftPadFunction::try_create("lpad")?,
+            args: vec![string_arg(&["hi"]), len_arg(&[-1]), string_arg(&["x"])],
+            input_rows: 1,
+            expect: Series::new([None::<&str>]).into(),
+            error: "",
+        },
+        Test {
+            name: "lpad-too-long-failed",
+            func: LeftPadFunction::try_create("lpad")?,
+            args: vec![string_arg(&["hi"]), len_arg(&[1000001]), string_arg(&["x"])],
+            input_rows: 1,
+            expect: Series::new([""]).into(),
+            error:
+                "Code: 6, displayText = Too large length to pad: (1000001), maximum is: 1000000.",
+        },
+        Test {
+            name: "left-passed",
+            func: LeftFunction::try_create("left")?,
+            args: vec![
+                string_arg(&["hello", "你好世界", "abc"]),
+                len_arg(&[2, 3, -1]),
+            ],
+            input_rows: 3,
+            expect: Series::new(["he", "你好世", ""]).into(),
+            error: "",
+        },
+        Test {
+            name: "right-passed",
+            func: RightFunction::try_create("right")?,
+            args: vec![
+                string_arg(&["hello", "你好世界", "abc"]),
+                len_arg(&[2, 3, 10]),
+            ],
+            input_rows: 3,
+            expect: Series::new(["lo", "好世界", "abc"]).into(),
+            error: "",
+        },
+    ];
+
+    for t in tests {
+        let func = t.func;
+        if let Err(e) = func.eval(&t.args, t.input_rows) {
+            assert_eq!(t.error, e.to_string(), "{}", t.name);
+            continue;
+        }
+
+        let v = &(func.eval(&t.args, t.input_rows)?);
+        assert_eq!(v.to_values()?, t.expect.to_values()?, "case: {}", t.name);
+    }
+    Ok(())
+}
@@ -0,0 +1,232 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_functions::scalars::*;
+
+#[test]
+fn test_regexp_functions() -> Result<()> {
+    struct Test {
+        name: &'static str,
+        func: Box<dyn Function>,
+        args: Vec<DataColumnWithField>,
+        input_rows: usize,
+        expect: DataColumn,
+        error: &'static str,
+    }
+
+    let string_arg = |values: &[&str]| {
+        DataColumnWithField::new(
+            Series::new(values).into(),
+            DataField::new("s", DataType::String, false),
+        )
+    };
+    let const_arg = |value: &str, rows: usize| {
+        DataColumnWithField::new(
+            DataColumn::Constant(DataValue::String(Some(value.as_bytes().to_vec())), rows),
+            DataField::new("pattern", DataType::String, false),
+        )
+    };
+    let int_arg = |values: &[i64]| {
+        DataColumnWithField::new(
+            Series::new(values).into(),
+            DataField::new("i", DataType::Int64, false),
+        )
+    };
+
+    let tests = vec![
+        Test {
+            name: "regexp_like-passed",
+            func: RegexpLikeFunction::try_create("regexp_like")?,
+            args: vec![
+                string_arg(&["GET /index", "post /login", "PUT /"]),
+                const_arg("^(GET|POST) ", 3),
+            ],
+            input_rows: 3,
+            expect: Series::new([true, false, false]).into(),
+            error: "",
+        },
+        Test {
+            name: "regexp_like-match-type-passed",
+            func: RegexpLikeFunction::try_create("regexp_like")?,
+            args: vec![
+                string_arg(&["GET /index", "post /login", "PUT /"]),
+                string_arg(&["^(get|post) ", "^(get|post) ", "^(get|post) "]),
+                const_arg("i", 3),
+            ],
+            input_rows: 3,
+            expect: Series::new([true, true, false]).into(),
+            error: "",
+        },
+        Test {
+            name: "regexp_like-bad-match-type-failed",
+            func: RegexpLikeFunction::try_create("regexp_like")?,
+            args: vec![string_arg(&["abc"]), const_arg("a", 1), const_arg("x", 1)],
+            input_rows: 1,
+            expect: Series::new([false]).into(),
+            error:
+                "Code: 6, displayText = Incorrect arguments to regular expression match type: x.",
+        },
+        Test {
+            name: "regexp_instr-passed",
+            func: RegexpInStrFunction::try_create("regexp_instr")?,
+            args: vec![
+                string_arg(&["dog cat dog", "dog cat dog", "dog cat dog", "你好 dog"]),
+                const_arg("dog", 4),
+                int_arg(&[1, 2, 1, 1]),
+                int_arg(&[1, 1, 3, 1]),
+            ],
+            input_rows: 4,
+            expect: Series::new([1_u64, 9, 0, 4]).into(),
+            error: "",
+        },
+        Test {
+            name: "regexp_instr-return-end-passed",
+            func: RegexpInStrFunction::try_create("regexp_instr")?,
+            args: vec![
+                string_arg(&["dog cat dog"]),
+                const_arg("cat", 1),
+                int_arg(&[1]),
+                int_arg(&[1]),
+                int_arg(&[1]),
+            ],
+            input_rows: 1,
+            expect: Series::new([8_u64]).into(),
+            error: "",
+        },
+        Test {
+            name: "regexp_substr-passed",
+            func: RegexpSubStrFunction::try_create("regexp_substr")?,
+            args: vec![
+                string_arg(&["abc def ghi", "abc def ghi", "abc"]),
+                const_arg("[a-z]+", 3),
+                int_arg(&[1, 1, 1]),
+                int_arg(&[1, 3, 2]),
+            ],
+            input_rows: 3,
+            expect: Series::new([Some("abc"), Some("ghi"), None]).into(),
+            error: "",
+        },
+        Test {
+            name: "regexp_replace-passed",
+            func: RegexpReplaceFunction::try_create("regexp_replace")?,
+            args: vec![
+                string_arg(&["a b c", "a b c", "2021-12-01"]),
+                string_arg(&["b|c", " ", "(\\d+)-(\\d+)-(\\d+)"]),
+                string_arg(&["X", "", "$3/$2/$1"]),
+            ],
+            input_rows: 3,
+            expect: Series::new(["a X X", "abc", "01/12/2021"]).into(),
+            error: "",
+        },
+        Test {
+            name: "regexp_replace-occurrence-passed",
+            func: RegexpReplaceFunction::try_create("regexp_replace")?,
+            args: vec![
+                string_arg(&["abc abc abc", "abc abc abc"]),
+                const_arg("abc", 2),
+                const_arg("X", 2),
+                int_arg(&[1, 2]),
+                int_arg(&[2, 1]),
+            ],
+            input_rows: 2,
+            expect: Series::new(["abc X abc", "abc X abc"]).into(),
+            error: "",
+        },
+        Test {
+            name: "regexp_substr-bad-position-failed",
+            func: RegexpSubStrFunction::try_create("regexp_substr")?,
+            args: vec![string_arg(&["abc"]), const_arg("a", 1), int_arg(&[0])],
+            input_rows: 1,
+            expect: Series::new([""]).into(),
+            error: "Code: 6, displayText = Index out of bounds in regular expression search: 0.",
+        },
+    ];
+
+    for t in tests {
+        let func = t.func;
+        if let Err(e) = func.eval(&t.args, t.input_rows) {
+            assert_eq!(t.error, e.to_string(), "{}", t.name);
+            continue;
+        }
+
+        let v = &(func.eval(&t.args, t.input_rows)?);
+        assert_eq!(v.to_values()?, t.expect.to_values()?, "case: {}", t.name);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_extract_all_function() -> Result<()> {
+    let func = ExtractAllFunction::try_create("extractAll")?;
+    let args = vec![
+        DataColumnWithField::new(
+            Series::new([Some("a=1, b=22"), Some("none"), None]).into(),
+            DataField::new("s", DataType::String, true),
+        ),
+        DataColumnWithField::new(
+            DataColumn::Constant(DataValue::String(Some(b"[a-z]=(\\d+)".to_vec())), 3),
+            DataField::new("pattern", DataType::String, false),
+        ),
+    ];
+
+    let return_type = func.return_type(&[DataType::String, DataType::String])?;
+    assert_eq!(return_type, create_list_type(DataType::String));
+
+    let v = func.eval(&args, 3)?;
+    let string = |s: &str| DataValue::String(Some(s.as_bytes().to_vec()));
+    assert_eq!(v.to_values()?, vec![
+        DataValue::List(Some(vec![string("1"), string("22")]), DataType::String),
+        DataValue::List(Some(vec![]), DataType::String),
+        DataValue::List(None, DataType::String),
+    ]);
+    Ok(())
+}
+
+#[test]
+fn test_regexp_constant_pattern_across_blocks() -> Result<()> {
+    let func = RegexpLikeFunction::try_create("regexp_like")?;
+    let args = |pattern: &str| {
+        vec![
+            DataColumnWithField::new(
+                Series::new(["abc", "xyz"]).into(),
+                DataField::new("s", DataType::String, false),
+            ),
+            DataColumnWithField::new(
+                DataColumn::Constant(DataValue::String(Some(pattern.as_bytes().to_vec())), 2),
+                DataField::new("pattern", DataType::String, false),
+            ),
+        ]
+    };
+
+    // the first constant is kept by the function, the others are still compiled
+    for (pattern, expect) in [
+        ("^a", [true, false]),
+        ("^x", [false, true]),
+        ("^a", [true, false]),
+    ] {
+        let v = func.eval(&args(pattern), 2)?;
+        assert_eq!(
+            v.to_values()?,
+            vec![
+                DataValue::Boolean(Some(expect[0])),
+                DataValue::Boolean(Some(expect[1]))
+            ],
+            "pattern: {}",
+            pattern
+        );
+    }
+    Ok(())
+}
@@ -34,6 +34,8 @@ use common_meta_types::MetaId;
 use common_meta_types::TableIdent;
 use common_meta_types::TableInfo;
 use common_meta_types::TableMeta;
+use common_meta_types::UpdateTableMetaReply;
+use common_meta_types::UpdateTableMetaReq;
 use common_meta_types::UpsertTableOptionReply;
 use common_meta_types::UpsertTableOptionReq;
 
@@ -94,6 +96,11 @@ impl MetaApi for MetaEmbedded {
         sm.upsert_table_option(req).await
     }
 
+    async fn update_table_meta(&self, req: UpdateTableMetaReq) -> Result<UpdateTableMetaReply> {
+        let sm = self.inner.lock().await;
+        sm.update_table_meta(req).await
+    }
+
     fn name(&self) -> String {
         "meta-embedded".to_string()
     }
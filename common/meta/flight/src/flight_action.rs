@@ -37,6 +37,8 @@ use common_meta_types::MGetKVActionReply;
 use common_meta_types::MetaId;
 use common_meta_types::PrefixListReply;
 use common_meta_types::TableInfo;
+use common_meta_types::UpdateTableMetaReply;
+use common_meta_types::UpdateTableMetaReq;
 use common_meta_types::UpsertKVAction;
 use common_meta_types::UpsertKVActionReply;
 use common_meta_types::UpsertTableOptionReply;
@@ -69,6 +71,7 @@ pub enum MetaFlightAction {
     GetTableExt(GetTableExtReq),
     ListTables(FlightReq<ListTableReq>),
     CommitTable(FlightReq<UpsertTableOptionReq>),
+    UpdateTableMeta(FlightReq<UpdateTableMetaReq>),
 
     UpsertKV(UpsertKVAction),
     GetKV(GetKVAction),
@@ -187,6 +190,10 @@ impl RequestFor for FlightReq<UpsertTableOptionReq> {
     type Reply = UpsertTableOptionReply;
 }
 
+impl RequestFor for FlightReq<UpdateTableMetaReq> {
+    type Reply = UpdateTableMetaReply;
+}
+
 impl RequestFor for FlightReq<ListTableReq> {
     type Reply = Vec<Arc<TableInfo>>;
 }
@@ -587,6 +587,35 @@ impl StateMachine {
                     Some(sv),
                 )))
             }
+
+            Cmd::UpdateTableMeta(ref req) => {
+                let prev = self.tables().get(&req.table_id)?;
+
+                // Same as upsert-options, prev to be None is not allowed.
+                let prev = prev.ok_or_else(|| {
+                    ErrorCode::UnknownTableId(format!("table_id:{}", req.table_id))
+                })?;
+
+                if req.seq.match_seq(&prev).is_err() {
+                    let res = AppliedState::TableMeta(Change::new(Some(prev.clone()), Some(prev)));
+                    return Ok(res);
+                }
+
+                let new_seq = self.incr_seq(Tables::NAME).await?;
+                let sv = SeqV {
+                    seq: new_seq,
+                    meta: prev.meta.clone(),
+                    data: req.new_table_meta.clone(),
+                };
+
+                self.tables().insert(&req.table_id, &sv).await?;
+
+                Ok(AppliedState::TableMeta(Change::new_with_id(
+                    req.table_id,
+                    Some(prev),
+                    Some(sv),
+                )))
+            }
         }
     }
 
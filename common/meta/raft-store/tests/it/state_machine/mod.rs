@@ -40,6 +40,7 @@ use common_meta_types::MatchSeq;
 use common_meta_types::Operation;
 use common_meta_types::SeqV;
 use common_meta_types::TableMeta;
+use common_meta_types::UpdateTableMetaReq;
 use common_meta_types::UpsertTableOptionReq;
 use common_tracing::tracing;
 use maplit::btreeset;
@@ -329,6 +330,103 @@ async fn test_state_machine_apply_upsert_table_option() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_state_machine_apply_update_table_meta() -> anyhow::Result<()> {
+    let (_log_guards, ut_span) = init_raft_store_ut!();
+    let _ent = ut_span.enter();
+
+    let tc = new_raft_test_context();
+    let m = StateMachine::open(&tc.raft_config, 1).await?;
+
+    tracing::info!("--- prepare a table");
+    m.apply_cmd(&Cmd::CreateDatabase {
+        name: "db1".to_string(),
+        engine: "default".to_string(),
+    })
+    .await?;
+
+    let resp = m
+        .apply_cmd(&Cmd::CreateTable {
+            db_name: "db1".to_string(),
+            table_name: "tb1".to_string(),
+            table_meta: Default::default(),
+        })
+        .await?;
+
+    let mut ch: Change<TableMeta, u64> = resp.try_into().unwrap();
+    let table_id = ch.ident.take().unwrap();
+    let result = ch.result.unwrap();
+    let version = result.seq;
+
+    let new_table_meta = TableMeta {
+        engine: "FUSE".to_string(),
+        options: hashmap! {
+            "a".to_string() => "A".to_string(),
+        },
+        ..Default::default()
+    };
+
+    tracing::info!("--- update with invalid table_id");
+    {
+        let resp = m
+            .apply_cmd(&Cmd::UpdateTableMeta(UpdateTableMetaReq {
+                table_id: 0,
+                seq: MatchSeq::Exact(version),
+                new_table_meta: new_table_meta.clone(),
+            }))
+            .await;
+
+        let err = resp.unwrap_err();
+
+        assert_eq!(ErrorCode::UnknownTableIdCode(), err.code());
+    }
+
+    tracing::info!("--- update with mismatched seq wont update anything");
+    {
+        let resp = m
+            .apply_cmd(&Cmd::UpdateTableMeta(UpdateTableMetaReq {
+                table_id,
+                seq: MatchSeq::Exact(version - 1),
+                new_table_meta: new_table_meta.clone(),
+            }))
+            .await?;
+
+        let ch: Change<TableMeta> = resp.try_into().unwrap();
+        let (prev, result) = ch.unwrap();
+
+        assert_eq!(prev, result);
+    }
+
+    tracing::info!("--- update OK");
+    {
+        let resp = m
+            .apply_cmd(&Cmd::UpdateTableMeta(UpdateTableMetaReq {
+                table_id,
+                seq: MatchSeq::Exact(version),
+                new_table_meta: new_table_meta.clone(),
+            }))
+            .await?;
+
+        let ch: Change<TableMeta> = resp.try_into().unwrap();
+        let (prev, result) = ch.unwrap();
+
+        tracing::info!("--- check prev state is returned");
+        assert_eq!(version, prev.seq);
+        assert_eq!(TableMeta::default(), prev.data);
+
+        tracing::info!("--- check result state, the whole table meta is replaced");
+        assert!(result.seq > version);
+        assert_eq!(new_table_meta, result.data);
+
+        tracing::info!("--- check table is updated");
+        let got = m.get_table_meta_by_id(&table_id)?.unwrap();
+        assert_eq!(result.seq, got.seq);
+        assert_eq!(new_table_meta, got.data);
+    }
+
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 async fn test_state_machine_apply_non_dup_generic_kv_upsert_get() -> anyhow::Result<()> {
     let (_log_guards, ut_span) = init_raft_store_ut!();
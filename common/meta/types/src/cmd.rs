@@ -23,6 +23,7 @@ use crate::MatchSeq;
 use crate::Node;
 use crate::Operation;
 use crate::TableMeta;
+use crate::UpdateTableMetaReq;
 use crate::UpsertTableOptionReq;
 
 /// A Cmd describes what a user want to do to raft state machine
@@ -61,6 +62,15 @@ pub enum Cmd {
     /// Otherwise it returns the TableMeta before and after update.
     UpsertTableOptions(UpsertTableOptionReq),
 
+    /// Replace the TableMeta of a table, e.g., to evolve its schema.
+    ///
+    /// This Cmd requires a present table to operate on.
+    /// Otherwise an `UnknownTableId` is returned.
+    ///
+    /// With mismatched seq, it returns a unchanged state: (prev:TableMeta, prev:TableMeta)
+    /// Otherwise it returns the TableMeta before and after update.
+    UpdateTableMeta(UpdateTableMetaReq),
+
     /// Update or insert a general purpose kv store
     UpsertKV {
         key: String,
@@ -126,6 +136,13 @@ impl fmt::Display for Cmd {
                     req.table_id, req.seq, req.options
                 )
             }
+            Cmd::UpdateTableMeta(req) => {
+                write!(
+                    f,
+                    "update-table-meta: table-id:{}({:?}) = {:?}",
+                    req.table_id, req.seq, req.new_table_meta
+                )
+            }
         }
     }
 }
@@ -223,6 +223,28 @@ impl UpsertTableOptionReq {
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
 pub struct UpsertTableOptionReply {}
 
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct UpdateTableMetaReq {
+    pub table_id: u64,
+    pub seq: MatchSeq,
+
+    /// Replaces the whole TableMeta, e.g., the schema and the options of an altered table.
+    pub new_table_meta: TableMeta,
+}
+
+impl UpdateTableMetaReq {
+    pub fn new(table_ident: &TableIdent, new_table_meta: TableMeta) -> UpdateTableMetaReq {
+        UpdateTableMetaReq {
+            table_id: table_ident.table_id,
+            seq: MatchSeq::Exact(table_ident.version),
+            new_table_meta,
+        }
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct UpdateTableMetaReply {}
+
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
 pub struct GetTableReq {
     pub inner: TableNameIndent,
@@ -19,6 +19,7 @@ mod plan_builder;
 mod plan_copy;
 mod plan_database_create;
 mod plan_database_drop;
+mod plan_delete;
 mod plan_describe_table;
 mod plan_display;
 mod plan_display_indent;
@@ -35,11 +36,13 @@ mod plan_expression_rewriter;
 mod plan_expression_sort;
 mod plan_expression_validator;
 mod plan_expression_visitor;
+mod plan_expression_window;
 mod plan_extras;
 mod plan_filter;
 mod plan_grant_privilege;
 mod plan_having;
 mod plan_insert_into;
+mod plan_join;
 mod plan_kill;
 mod plan_limit;
 mod plan_limit_by;
@@ -58,14 +61,19 @@ mod plan_sort;
 mod plan_stage;
 mod plan_statistics;
 mod plan_subqueries_set;
+mod plan_table_alter;
 mod plan_table_create;
 mod plan_table_drop;
+mod plan_table_optimize;
 mod plan_truncate_table;
+mod plan_union_all;
+mod plan_update;
 mod plan_use_database;
 mod plan_user_alter;
 mod plan_user_create;
 mod plan_user_drop;
 mod plan_visitor;
+mod plan_window_func;
 
 pub use plan_aggregator_final::AggregatorFinalPlan;
 pub use plan_aggregator_partial::AggregatorPartialPlan;
@@ -75,6 +83,7 @@ pub use plan_copy::CopyPlan;
 pub use plan_database_create::CreateDatabasePlan;
 pub use plan_database_create::DatabaseOptions;
 pub use plan_database_drop::DropDatabasePlan;
+pub use plan_delete::DeletePlan;
 pub use plan_describe_table::DescribeTablePlan;
 pub use plan_empty::EmptyPlan;
 pub use plan_explain::ExplainPlan;
@@ -87,11 +96,14 @@ pub use plan_expression_chain::ExpressionChain;
 pub use plan_expression_column::col;
 pub use plan_expression_common::expand_aggregate_arg_exprs;
 pub use plan_expression_common::expand_wildcard;
+pub use plan_expression_common::expand_window_arg_exprs;
 pub use plan_expression_common::expr_as_column_expr;
 pub use plan_expression_common::extract_aliases;
 pub use plan_expression_common::find_aggregate_exprs;
 pub use plan_expression_common::find_aggregate_exprs_in_expr;
 pub use plan_expression_common::find_columns_not_satisfy_exprs;
+pub use plan_expression_common::find_window_exprs;
+pub use plan_expression_common::find_window_exprs_in_expr;
 pub use plan_expression_common::rebase_expr;
 pub use plan_expression_common::rebase_expr_from_input;
 pub use plan_expression_common::resolve_aliases_to_exprs;
@@ -109,11 +121,16 @@ pub use plan_expression_sort::sort;
 pub use plan_expression_validator::validate_expression;
 pub use plan_expression_visitor::ExpressionVisitor;
 pub use plan_expression_visitor::Recursion;
+pub use plan_expression_window::WindowFrame;
+pub use plan_expression_window::WindowFrameBound;
+pub use plan_expression_window::WindowFrameUnits;
 pub use plan_extras::Extras;
 pub use plan_filter::FilterPlan;
 pub use plan_grant_privilege::GrantPrivilegePlan;
 pub use plan_having::HavingPlan;
 pub use plan_insert_into::InsertIntoPlan;
+pub use plan_join::JoinPlan;
+pub use plan_join::JoinType;
 pub use plan_kill::KillPlan;
 pub use plan_limit::LimitPlan;
 pub use plan_limit_by::LimitByPlan;
@@ -137,12 +154,20 @@ pub use plan_stage::StageKind;
 pub use plan_stage::StagePlan;
 pub use plan_statistics::Statistics;
 pub use plan_subqueries_set::SubQueriesSetPlan;
+pub use plan_table_alter::AlterTableAction;
+pub use plan_table_alter::AlterTablePlan;
 pub use plan_table_create::CreateTablePlan;
 pub use plan_table_create::TableOptions;
 pub use plan_table_drop::DropTablePlan;
+pub use plan_table_optimize::OptimizeTableAction;
+pub use plan_table_optimize::OptimizeTablePlan;
+pub use plan_table_optimize::PurgeRetention;
 pub use plan_truncate_table::TruncateTablePlan;
+pub use plan_union_all::UnionAllPlan;
+pub use plan_update::UpdatePlan;
 pub use plan_use_database::UseDatabasePlan;
 pub use plan_user_alter::AlterUserPlan;
 pub use plan_user_create::CreateUserPlan;
 pub use plan_user_drop::DropUserPlan;
 pub use plan_visitor::PlanVisitor;
+pub use plan_window_func::WindowFuncPlan;
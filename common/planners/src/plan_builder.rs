@@ -15,14 +15,19 @@
 use std::sync::Arc;
 
 use common_datablocks::DataBlock;
+use common_datavalues::compare_coercion;
+use common_datavalues::merge_types;
 use common_datavalues::DataField;
 use common_datavalues::DataSchema;
 use common_datavalues::DataSchemaRef;
 use common_datavalues::DataSchemaRefExt;
 use common_datavalues::DataType;
+use common_exception::ErrorCode;
 use common_exception::Result;
 
 use crate::col;
+use crate::find_column_exprs;
+use crate::lit;
 use crate::plan_subqueries_set::SubQueriesSetPlan;
 use crate::validate_expression;
 use crate::AggregatorFinalPlan;
@@ -34,6 +39,8 @@ use crate::Expression;
 use crate::ExpressionPlan;
 use crate::FilterPlan;
 use crate::HavingPlan;
+use crate::JoinPlan;
+use crate::JoinType;
 use crate::LimitByPlan;
 use crate::LimitPlan;
 use crate::PlanNode;
@@ -41,6 +48,8 @@ use crate::ProjectionPlan;
 use crate::RewriteHelper;
 use crate::SelectPlan;
 use crate::SortPlan;
+use crate::UnionAllPlan;
+use crate::WindowFuncPlan;
 
 pub enum AggregateMode {
     Partial,
@@ -218,6 +227,142 @@ impl PlanBuilder {
         })))
     }
 
+    /// Join with the right plan, the current plan is the left(probe) side.
+    /// The equi-conditions of the `condition` conjunction become the hash keys.
+    pub fn join(
+        &self,
+        right: &PlanNode,
+        join_type: JoinType,
+        condition: Option<Expression>,
+    ) -> Result<Self> {
+        let left_schema = self.plan.schema();
+        let right_schema = right.schema();
+
+        let mut fields =
+            Vec::with_capacity(left_schema.fields().len() + right_schema.fields().len());
+        for field in left_schema.fields() {
+            let nullable = field.is_nullable() || join_type.keep_right_unmatched();
+            fields.push(DataField::new(
+                field.name(),
+                field.data_type().clone(),
+                nullable,
+            ));
+        }
+        for field in right_schema.fields() {
+            let nullable = field.is_nullable() || join_type.keep_left_unmatched();
+            fields.push(DataField::new(
+                field.name(),
+                field.data_type().clone(),
+                nullable,
+            ));
+        }
+        let schema = DataSchemaRefExt::create(fields);
+
+        let mut conjunctions = vec![];
+        if let Some(condition) = condition {
+            Self::split_conjunctions(condition, &mut conjunctions);
+        }
+
+        let mut left_keys = vec![];
+        let mut right_keys = vec![];
+        let mut predicate: Option<Expression> = None;
+        for conjunction in conjunctions {
+            match Self::split_equi_condition(&conjunction, &left_schema, &right_schema)? {
+                Some((left_key, right_key)) => {
+                    left_keys.push(left_key);
+                    right_keys.push(right_key);
+                }
+                None => {
+                    predicate = match predicate {
+                        None => Some(conjunction),
+                        Some(predicate) => Some(predicate.and(conjunction)),
+                    };
+                }
+            }
+        }
+
+        if let Some(predicate) = &predicate {
+            validate_expression(predicate)?;
+        }
+
+        Ok(Self::from(&PlanNode::Join(JoinPlan {
+            join_type,
+            left_keys,
+            right_keys,
+            predicate,
+            schema,
+            left: Arc::new(self.plan.clone()),
+            right: Arc::new(right.clone()),
+        })))
+    }
+
+    fn split_conjunctions(expr: Expression, conjunctions: &mut Vec<Expression>) {
+        match expr {
+            Expression::BinaryExpression { left, op, right } if op.eq_ignore_ascii_case("and") => {
+                Self::split_conjunctions(*left, conjunctions);
+                Self::split_conjunctions(*right, conjunctions);
+            }
+            _ => conjunctions.push(expr),
+        }
+    }
+
+    // Returns the (left key, right key) if the expression is `left_expr = right_expr`.
+    fn split_equi_condition(
+        expr: &Expression,
+        left_schema: &DataSchemaRef,
+        right_schema: &DataSchemaRef,
+    ) -> Result<Option<(Expression, Expression)>> {
+        let only_columns_of = |expr: &Expression, schema: &DataSchemaRef| {
+            let columns = find_column_exprs(&[expr.clone()]);
+            !columns.is_empty()
+                && columns.iter().all(|column| match column {
+                    Expression::Column(name) => schema.column_with_name(name).is_some(),
+                    _ => false,
+                })
+        };
+
+        if let Expression::BinaryExpression { left, op, right } = expr {
+            if op == "=" {
+                let (left_key, right_key) = match (left.as_ref(), right.as_ref()) {
+                    (l, r)
+                        if only_columns_of(l, left_schema) && only_columns_of(r, right_schema) =>
+                    {
+                        (l.clone(), r.clone())
+                    }
+                    (l, r)
+                        if only_columns_of(r, left_schema) && only_columns_of(l, right_schema) =>
+                    {
+                        (r.clone(), l.clone())
+                    }
+                    _ => return Ok(None),
+                };
+
+                // Keys are compared by the serialized value, so both sides must have the same type.
+                let left_type = left_key.to_data_type(left_schema)?;
+                let right_type = right_key.to_data_type(right_schema)?;
+                if left_type == right_type {
+                    return Ok(Some((left_key, right_key)));
+                }
+
+                let data_type = compare_coercion(&left_type, &right_type)?;
+                let cast = |expr: Expression, expr_type: &DataType| match expr_type == &data_type {
+                    true => expr,
+                    false => Expression::Cast {
+                        expr: Box::new(expr),
+                        data_type: data_type.clone(),
+                    },
+                };
+
+                return Ok(Some((
+                    cast(left_key, &left_type),
+                    cast(right_key, &right_type),
+                )));
+            }
+        }
+
+        Ok(None)
+    }
+
     pub fn sort(&self, exprs: &[Expression]) -> Result<Self> {
         Ok(Self::from(&PlanNode::Sort(SortPlan {
             order_by: exprs.to_vec(),
@@ -226,6 +371,159 @@ impl PlanBuilder {
         })))
     }
 
+    /// Apply a window function, the result column is appended to the input columns.
+    pub fn window_func(&self, expr: Expression) -> Result<Self> {
+        let input_schema = self.plan.schema();
+        let mut fields = input_schema.fields().clone();
+        fields.push(expr.to_data_field(&input_schema)?);
+
+        Ok(Self::from(&PlanNode::WindowFunc(WindowFuncPlan {
+            window_func: expr,
+            schema: DataSchemaRefExt::create(fields),
+            input: Arc::new(self.plan.clone()),
+        })))
+    }
+
+    /// Concatenate with the rows of the right plan, the duplicate rows are kept.
+    /// The output columns are named after the current plan, and the columns of
+    /// both sides are cast to their common types if necessary.
+    pub fn union_all(&self, right: &PlanNode) -> Result<Self> {
+        let left_schema = self.plan.schema();
+        let right_schema = right.schema();
+
+        if left_schema.fields().len() != right_schema.fields().len() {
+            return Err(ErrorCode::BadArguments(format!(
+                "Set operation requires both sides to have the same number of columns, but got {} and {}",
+                left_schema.fields().len(),
+                right_schema.fields().len()
+            )));
+        }
+
+        let mut fields = Vec::with_capacity(left_schema.fields().len());
+        for (left_field, right_field) in left_schema.fields().iter().zip(right_schema.fields()) {
+            let data_type = merge_types(left_field.data_type(), right_field.data_type())?;
+            let nullable = left_field.is_nullable() || right_field.is_nullable();
+            fields.push(DataField::new(left_field.name(), data_type, nullable));
+        }
+        let schema = DataSchemaRefExt::create(fields);
+
+        Ok(Self::from(&PlanNode::UnionAll(UnionAllPlan {
+            left: Arc::new(Self::project_to_schema(&self.plan, &schema)?),
+            right: Arc::new(Self::project_to_schema(right, &schema)?),
+            schema,
+        })))
+    }
+
+    /// Union with the rows of the right plan, the duplicate rows are removed.
+    pub fn union(&self, right: &PlanNode) -> Result<Self> {
+        self.union_all(right)?.distinct()
+    }
+
+    /// Keep the distinct rows which are also in the right plan.
+    pub fn intersect(&self, right: &PlanNode) -> Result<Self> {
+        // The rows are in both sides if they come from more than one side.
+        self.set_operation_by_sides(right, |min_side, max_side| min_side.not_eq(max_side))
+    }
+
+    /// Keep the distinct rows which are not in the right plan.
+    pub fn except(&self, right: &PlanNode) -> Result<Self> {
+        // The rows are only in the left side if they all come from the left side.
+        self.set_operation_by_sides(right, |_, max_side| max_side.eq(lit(0u8)))
+    }
+
+    /// Remove the duplicate rows by grouping by all the columns.
+    pub fn distinct(&self) -> Result<Self> {
+        let schema = self.plan.schema();
+        let group_by = schema
+            .fields()
+            .iter()
+            .map(|field| col(field.name()))
+            .collect::<Vec<_>>();
+
+        self.aggregate_partial(&[], &group_by)?
+            .aggregate_final(schema, &[], &group_by)
+    }
+
+    // The rows of the left and right side are tagged with the side(0 and 1), and then grouped
+    // by all the columns, the groups are filtered by the min and max side of their rows.
+    fn set_operation_by_sides(
+        &self,
+        right: &PlanNode,
+        predicate: impl Fn(Expression, Expression) -> Expression,
+    ) -> Result<Self> {
+        const SIDE_COLUMN: &str = "_set_operation_side";
+
+        let tag_side = |plan: &PlanNode, side: u8| -> Result<PlanNode> {
+            let mut exprs = plan
+                .schema()
+                .fields()
+                .iter()
+                .map(|field| col(field.name()))
+                .collect::<Vec<_>>();
+            exprs.push(lit(side).alias(SIDE_COLUMN));
+            Self::from(plan).project(&exprs)?.build()
+        };
+
+        let left = tag_side(&self.plan, 0)?;
+        let right = tag_side(right, 1)?;
+        let union_all = Self::from(&left).union_all(&right)?;
+
+        let schema_before_group_by = union_all.plan.schema();
+        let columns = self
+            .plan
+            .schema()
+            .fields()
+            .iter()
+            .map(|field| col(field.name()))
+            .collect::<Vec<_>>();
+        let side_aggregate = |op: &str| Expression::AggregateFunction {
+            op: op.to_string(),
+            distinct: false,
+            params: vec![],
+            args: vec![col(SIDE_COLUMN)],
+        };
+        let aggr_expr = vec![side_aggregate("min"), side_aggregate("max")];
+        let min_side = col(&aggr_expr[0].column_name());
+        let max_side = col(&aggr_expr[1].column_name());
+
+        union_all
+            .aggregate_partial(&aggr_expr, &columns)?
+            .aggregate_final(schema_before_group_by, &aggr_expr, &columns)?
+            .having(predicate(min_side, max_side))?
+            .project(&columns)
+    }
+
+    // Rename and cast the columns of the plan to the schema, the plan is unchanged if
+    // the names and types of the columns are the same.
+    fn project_to_schema(plan: &PlanNode, schema: &DataSchemaRef) -> Result<PlanNode> {
+        let plan_schema = plan.schema();
+        let fields = plan_schema.fields().iter().zip(schema.fields());
+
+        if fields.clone().all(|(field, target)| {
+            field.name() == target.name() && field.data_type() == target.data_type()
+        }) {
+            return Ok(plan.clone());
+        }
+
+        let exprs = fields
+            .map(|(field, target)| {
+                let expr = match field.data_type() == target.data_type() {
+                    true => col(field.name()),
+                    false => Expression::Cast {
+                        expr: Box::new(col(field.name())),
+                        data_type: target.data_type().clone(),
+                    },
+                };
+                match expr.column_name() == *target.name() {
+                    true => expr,
+                    false => expr.alias(target.name()),
+                }
+            })
+            .collect::<Vec<_>>();
+
+        Self::from(plan).project(&exprs)?.build()
+    }
+
     /// Apply a limit
     pub fn limit(&self, n: usize) -> Result<Self> {
         Ok(Self::from(&PlanNode::Limit(LimitPlan {
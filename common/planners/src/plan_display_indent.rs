@@ -24,6 +24,7 @@ use crate::DropDatabasePlan;
 use crate::DropTablePlan;
 use crate::Expression;
 use crate::ExpressionPlan;
+use crate::JoinPlan;
 use crate::LimitPlan;
 use crate::PlanNode;
 use crate::ProjectionPlan;
@@ -31,6 +32,7 @@ use crate::ReadDataSourcePlan;
 use crate::SortPlan;
 use crate::StagePlan;
 use crate::SubQueriesSetPlan;
+use crate::UnionAllPlan;
 
 pub struct PlanNodeIndentFormatDisplay<'a> {
     indent: usize,
@@ -64,6 +66,9 @@ impl<'a> fmt::Display for PlanNodeIndentFormatDisplay<'a> {
             PlanNode::Filter(plan) => write!(f, "Filter: {:?}", plan.predicate),
             PlanNode::Having(plan) => write!(f, "Having: {:?}", plan.predicate),
             PlanNode::Sort(plan) => Self::format_sort(f, plan),
+            PlanNode::WindowFunc(plan) => write!(f, "WindowFunc: {:?}", plan.window_func),
+            PlanNode::Join(plan) => Self::format_join(f, plan),
+            PlanNode::UnionAll(plan) => Self::format_union_all(f, plan),
             PlanNode::Limit(plan) => Self::format_limit(f, plan),
             PlanNode::SubQueryExpression(plan) => Self::format_subquery_expr(f, plan),
             PlanNode::ReadSource(plan) => Self::format_read_source(f, plan),
@@ -183,6 +188,29 @@ impl<'a> PlanNodeIndentFormatDisplay<'a> {
         fmt::Result::Ok(())
     }
 
+    fn format_join(f: &mut Formatter, plan: &JoinPlan) -> fmt::Result {
+        write!(
+            f,
+            "Join: {}, left_keys={:?}, right_keys={:?}",
+            plan.join_type, plan.left_keys, plan.right_keys
+        )?;
+
+        match &plan.predicate {
+            None => fmt::Result::Ok(()),
+            Some(predicate) => write!(f, ", predicate={:?}", predicate),
+        }
+    }
+
+    fn format_union_all(f: &mut Formatter, plan: &UnionAllPlan) -> fmt::Result {
+        let names = plan
+            .schema
+            .fields()
+            .iter()
+            .map(|field| format!("{}:{:?}", field.name(), field.data_type()))
+            .collect::<Vec<_>>();
+        write!(f, "UnionAll: [{}]", names.join(", "))
+    }
+
     fn format_limit(f: &mut Formatter, plan: &LimitPlan) -> fmt::Result {
         match (plan.n, plan.offset) {
             (Some(n), 0) => write!(f, "Limit: {}", n),
@@ -18,16 +18,21 @@ use std::sync::Arc;
 
 use common_datavalues::DataField;
 use common_datavalues::DataSchemaRef;
+use common_datavalues::DataSchemaRefExt;
 use common_datavalues::DataType;
 use common_datavalues::DataValue;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_functions::aggregates::AggregateFunctionFactory;
 use common_functions::aggregates::AggregateFunctionRef;
+use common_functions::scalars::ArrayLambdaFunction;
 use common_functions::scalars::FunctionFactory;
+use common_functions::window::WindowFunction;
+use common_functions::window::WindowFunctionFactory;
 use lazy_static::lazy_static;
 
 use crate::PlanNode;
+use crate::WindowFrame;
 
 lazy_static! {
     static ref OP_SET: HashSet<&'static str> = ["database", "version", "current_user"]
@@ -96,6 +101,29 @@ pub enum Expression {
         args: Vec<Expression>,
     },
 
+    /// A window function with a set of arguments, evaluated over the window of each row,
+    /// such as `rank() OVER (PARTITION BY a ORDER BY b)`.
+    WindowFunction {
+        op: String,
+        params: Vec<DataValue>,
+        args: Vec<Expression>,
+        partition_by: Vec<Expression>,
+        /// The sort expressions of the window
+        order_by: Vec<Expression>,
+        /// The frame of the window, the default frame is used if it is None
+        window_frame: Option<WindowFrame>,
+    },
+
+    /// A higher-order function whose first argument is a lambda, such as
+    /// `arrayMap(x -> x + 1, arr)`. The body of the lambda can only refer to its params,
+    /// which are bound to the items of the array arguments.
+    LambdaFunction {
+        op: String,
+        params: Vec<String>,
+        body: Box<Expression>,
+        args: Vec<Expression>,
+    },
+
     /// A sort expression, that can be used to sort values.
     Sort {
         /// The expression to sort on
@@ -211,6 +239,53 @@ impl Expression {
                     false => format!("{}({})", prefix, args_column_name.join(", ")),
                 }
             }
+            Expression::WindowFunction {
+                op,
+                params,
+                args,
+                partition_by,
+                order_by,
+                window_frame,
+            } => {
+                let args_column_name = args.iter().map(Expression::column_name).collect::<Vec<_>>();
+                let params_name = params
+                    .iter()
+                    .map(|v| DataValue::custom_display(v, true))
+                    .collect::<Vec<_>>();
+
+                let prefix = if params.is_empty() {
+                    op.to_string()
+                } else {
+                    format!("{}({})", op, params_name.join(", "))
+                };
+
+                format!(
+                    "{}({}) OVER ({})",
+                    prefix,
+                    args_column_name.join(", "),
+                    Self::window_spec_display(
+                        partition_by,
+                        order_by,
+                        window_frame,
+                        Expression::column_name
+                    )
+                )
+            }
+            Expression::LambdaFunction {
+                op,
+                params,
+                body,
+                args,
+            } => {
+                let args_column_name = args.iter().map(Expression::column_name).collect::<Vec<_>>();
+                format!(
+                    "{}({} -> {}, {})",
+                    op,
+                    Self::lambda_params_display(params),
+                    body.column_name(),
+                    args_column_name.join(", ")
+                )
+            }
             Expression::Sort { expr, .. } => expr.column_name(),
             Expression::Cast { expr, data_type } => {
                 format!("cast({} as {:?})", expr.column_name(), data_type)
@@ -304,6 +379,24 @@ impl Expression {
                 let func = self.to_aggregate_function(input_schema)?;
                 func.return_type()
             }
+            Expression::WindowFunction { .. } => {
+                let func = self.to_window_function(input_schema)?;
+                func.return_type()
+            }
+            Expression::LambdaFunction {
+                op,
+                params,
+                body,
+                args,
+            } => {
+                let mut arg_types = Vec::with_capacity(args.len());
+                for arg in args {
+                    arg_types.push(arg.to_data_type(input_schema)?);
+                }
+                let func = ArrayLambdaFunction::try_create(op)?;
+                let lambda_schema = Self::to_lambda_schema(op, params, &func, &arg_types)?;
+                func.return_type(&arg_types, &body.to_data_type(&lambda_schema)?)
+            }
             Expression::Wildcard => Result::Err(ErrorCode::IllegalDataType(
                 "Wildcard expressions are not valid to get return type",
             )),
@@ -337,6 +430,82 @@ impl Expression {
         }
     }
 
+    pub fn to_window_function(&self, schema: &DataSchemaRef) -> Result<WindowFunction> {
+        match self {
+            Expression::WindowFunction {
+                op, params, args, ..
+            } => {
+                let mut fields = Vec::with_capacity(args.len());
+                for arg in args.iter() {
+                    fields.push(arg.to_data_field(schema)?);
+                }
+                WindowFunctionFactory::instance().get(op, params.clone(), fields)
+            }
+            _ => Err(ErrorCode::LogicalError(
+                "Expression must be window function",
+            )),
+        }
+    }
+
+    /// The schema of the lambda params, each param is an item of the array argument at the same position.
+    pub fn to_lambda_schema(
+        op: &str,
+        params: &[String],
+        func: &ArrayLambdaFunction,
+        arg_types: &[DataType],
+    ) -> Result<DataSchemaRef> {
+        let param_types = func.param_types(arg_types)?;
+        if params.len() != param_types.len() {
+            return Err(ErrorCode::NumberArgumentsNotMatch(format!(
+                "The lambda of {} expects {} params, but got {} arrays",
+                op,
+                params.len(),
+                param_types.len()
+            )));
+        }
+
+        let fields = params
+            .iter()
+            .zip(param_types.into_iter())
+            .map(|(name, data_type)| DataField::new(name, data_type, true))
+            .collect::<Vec<_>>();
+        Ok(DataSchemaRefExt::create(fields))
+    }
+
+    fn lambda_params_display(params: &[String]) -> String {
+        match params.len() {
+            1 => params[0].clone(),
+            _ => format!("({})", params.join(", ")),
+        }
+    }
+
+    fn window_spec_display(
+        partition_by: &[Expression],
+        order_by: &[Expression],
+        window_frame: &Option<WindowFrame>,
+        display: impl Fn(&Expression) -> String,
+    ) -> String {
+        let mut spec = vec![];
+        if !partition_by.is_empty() {
+            let names = partition_by.iter().map(&display).collect::<Vec<_>>();
+            spec.push(format!("PARTITION BY {}", names.join(", ")));
+        }
+        if !order_by.is_empty() {
+            let names = order_by
+                .iter()
+                .map(|expr| match expr {
+                    Expression::Sort { asc: false, .. } => format!("{} DESC", display(expr)),
+                    _ => display(expr),
+                })
+                .collect::<Vec<_>>();
+            spec.push(format!("ORDER BY {}", names.join(", ")));
+        }
+        if let Some(window_frame) = window_frame {
+            spec.push(window_frame.to_string());
+        }
+        spec.join(" ")
+    }
+
     pub fn to_aggregate_function_names(&self) -> Result<Vec<String>> {
         match self {
             Expression::AggregateFunction { args, .. } => {
@@ -426,6 +595,56 @@ impl fmt::Debug for Expression {
                 Ok(())
             }
 
+            Expression::WindowFunction {
+                op,
+                params,
+                args,
+                partition_by,
+                order_by,
+                window_frame,
+            } => {
+                let args_name = args
+                    .iter()
+                    .map(|arg| format!("{:?}", arg))
+                    .collect::<Vec<_>>();
+                let params_name = params
+                    .iter()
+                    .map(|v| DataValue::custom_display(v, true))
+                    .collect::<Vec<_>>();
+
+                if params.is_empty() {
+                    write!(f, "{}", op)?;
+                } else {
+                    write!(f, "{}({})", op, params_name.join(", "))?;
+                };
+
+                let spec =
+                    Self::window_spec_display(partition_by, order_by, window_frame, |expr| {
+                        format!("{:?}", expr)
+                    });
+                write!(f, "({}) OVER ({})", args_name.join(", "), spec)
+            }
+
+            Expression::LambdaFunction {
+                op,
+                params,
+                body,
+                args,
+            } => {
+                let args_name = args
+                    .iter()
+                    .map(|arg| format!("{:?}", arg))
+                    .collect::<Vec<_>>();
+                write!(
+                    f,
+                    "{}({} -> {:?}, {})",
+                    op,
+                    Self::lambda_params_display(params),
+                    body,
+                    args_name.join(", ")
+                )
+            }
+
             Expression::Sort { expr, .. } => write!(f, "{:?}", expr),
             Expression::Wildcard => write!(f, "*"),
             Expression::Cast { expr, data_type } => {
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use common_datavalues::DataField;
+use common_datavalues::DataSchemaRef;
 use common_datavalues::DataType;
 use common_datavalues::DataValue;
 use common_exception::ErrorCode;
@@ -23,6 +24,8 @@ use common_functions::scalars::CastFunction;
 use common_functions::scalars::Function;
 use common_functions::scalars::FunctionFactory;
 
+use crate::Expression;
+
 #[derive(Debug, Clone)]
 pub enum ExpressionAction {
     /// Column which must be in input.
@@ -31,6 +34,10 @@ pub enum ExpressionAction {
     Constant(ActionConstant),
     Alias(ActionAlias),
     Function(ActionFunction),
+    /// Function unnesting the array into the rows, the other columns are replicated.
+    ArrayJoin(ActionFunction),
+    /// Higher-order function evaluating the lambda over the items of the arrays.
+    Lambda(ActionLambda),
 }
 
 #[derive(Debug, Clone)]
@@ -70,6 +77,18 @@ pub struct ActionFunction {
     pub arg_fields: Vec<DataField>,
 }
 
+#[derive(Debug, Clone)]
+pub struct ActionLambda {
+    pub name: String,
+    pub func_name: String,
+    pub return_type: DataType,
+    pub arg_names: Vec<String>,
+
+    // the lambda params are the columns of the schema
+    pub lambda_schema: DataSchemaRef,
+    pub body: Expression,
+}
+
 impl ExpressionAction {
     pub fn column_name(&self) -> &str {
         match self {
@@ -77,6 +96,8 @@ impl ExpressionAction {
             ExpressionAction::Constant(c) => &c.name,
             ExpressionAction::Alias(a) => &a.name,
             ExpressionAction::Function(f) => &f.name,
+            ExpressionAction::ArrayJoin(f) => &f.name,
+            ExpressionAction::Lambda(l) => &l.name,
         }
     }
 }
@@ -15,12 +15,14 @@
 use common_datavalues::DataSchemaRef;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_functions::scalars::ArrayLambdaFunction;
 use common_functions::scalars::FunctionFactory;
 
 use crate::ActionAlias;
 use crate::ActionConstant;
 use crate::ActionFunction;
 use crate::ActionInput;
+use crate::ActionLambda;
 use crate::Expression;
 use crate::ExpressionAction;
 
@@ -169,7 +171,39 @@ impl ExpressionChain {
                     return_type: func.return_type(&arg_types)?,
                 };
 
-                self.actions.push(ExpressionAction::Function(function));
+                match FunctionFactory::instance().get_features(op)?.is_array_join {
+                    true => self.actions.push(ExpressionAction::ArrayJoin(function)),
+                    false => self.actions.push(ExpressionAction::Function(function)),
+                }
+            }
+
+            Expression::LambdaFunction {
+                op,
+                params,
+                body,
+                args,
+            } => {
+                for expr in args.iter() {
+                    self.add_expr(expr)?;
+                }
+
+                let func = ArrayLambdaFunction::try_create(op)?;
+                let arg_types = args
+                    .iter()
+                    .map(|action| action.to_data_type(&self.schema))
+                    .collect::<Result<Vec<_>>>()?;
+                let lambda_schema = Expression::to_lambda_schema(op, params, &func, &arg_types)?;
+
+                let lambda = ActionLambda {
+                    name: expr.column_name(),
+                    func_name: op.clone(),
+                    return_type: expr.to_data_type(&self.schema)?,
+                    arg_names: args.iter().map(|action| action.column_name()).collect(),
+                    lambda_schema,
+                    body: body.as_ref().clone(),
+                };
+
+                self.actions.push(ExpressionAction::Lambda(lambda));
             }
 
             Expression::AggregateFunction {
@@ -195,6 +229,11 @@ impl ExpressionChain {
 
                 self.actions.push(ExpressionAction::Function(function));
             }
+            Expression::WindowFunction { .. } => {
+                return Err(ErrorCode::LogicalError(
+                    "Window function must be evaluated by the window plan, it's a bug.",
+                ));
+            }
             Expression::Sort { expr, .. } => {
                 self.add_expr(expr)?;
             }
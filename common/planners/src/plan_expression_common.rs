@@ -49,6 +49,43 @@ pub fn find_aggregate_exprs_in_expr(expr: &Expression) -> Vec<Expression> {
     })
 }
 
+/// Collect all deeply nested `Expression::WindowFunction`. They are returned in order of
+/// occurrence (depth first), with duplicates omitted.
+pub fn find_window_exprs(exprs: &[Expression]) -> Vec<Expression> {
+    find_exprs_in_exprs(exprs, &|nest_exprs| {
+        matches!(nest_exprs, Expression::WindowFunction { .. })
+    })
+}
+
+pub fn find_window_exprs_in_expr(expr: &Expression) -> Vec<Expression> {
+    find_exprs_in_expr(expr, &|nest_exprs| {
+        matches!(nest_exprs, Expression::WindowFunction { .. })
+    })
+}
+
+/// Collect all arguments, partition by and order by expressions of the window functions
+/// [rank() OVER (PARTITION BY a ORDER BY b + 1)] ---> [ColumnExpr(a), (b + 1)]
+pub fn expand_window_arg_exprs(exprs: &[Expression]) -> Vec<Expression> {
+    let mut res = vec![];
+    for expr in exprs {
+        if let Expression::WindowFunction {
+            args,
+            partition_by,
+            order_by,
+            ..
+        } = expr
+        {
+            let order_by = order_by.iter().map(sort_to_inner_expr).collect::<Vec<_>>();
+            for arg in args.iter().chain(partition_by).chain(&order_by) {
+                if !res.contains(arg) {
+                    res.push(arg.clone());
+                }
+            }
+        }
+    }
+    res
+}
+
 /// Collect all arguments from aggregation function and append to this exprs
 /// [ColumnExpr(b), Aggr(sum(a, b))] ---> [ColumnExpr(b), ColumnExpr(a)]
 
@@ -303,6 +340,45 @@ where F: Fn(&Expression) -> Result<Option<Expression>> {
                     .collect::<Result<Vec<Expression>>>()?,
             }),
 
+            Expression::WindowFunction {
+                op,
+                params,
+                args,
+                partition_by,
+                order_by,
+                window_frame,
+            } => {
+                let clone_all = |exprs: &[Expression]| {
+                    exprs
+                        .iter()
+                        .map(|e| clone_with_replacement(e, replacement_fn))
+                        .collect::<Result<Vec<Expression>>>()
+                };
+                Ok(Expression::WindowFunction {
+                    op: op.clone(),
+                    params: params.clone(),
+                    args: clone_all(args)?,
+                    partition_by: clone_all(partition_by)?,
+                    order_by: clone_all(order_by)?,
+                    window_frame: *window_frame,
+                })
+            }
+
+            Expression::LambdaFunction {
+                op,
+                params,
+                body,
+                args,
+            } => Ok(Expression::LambdaFunction {
+                op: op.clone(),
+                params: params.clone(),
+                body: body.clone(),
+                args: args
+                    .iter()
+                    .map(|e| clone_with_replacement(e, replacement_fn))
+                    .collect::<Result<Vec<Expression>>>()?,
+            }),
+
             Expression::Sort {
                 expr: nested_expr,
                 asc,
@@ -106,6 +106,46 @@ impl Expression {
                     args: new_args,
                 }
             }
+            Expression::WindowFunction {
+                op,
+                params,
+                args,
+                partition_by,
+                order_by,
+                window_frame,
+            } => {
+                let rewrite_all = |exprs: Vec<Expression>, rewriter: &mut R| {
+                    exprs
+                        .into_iter()
+                        .map(|expr| expr.rewrite(rewriter))
+                        .collect::<Result<Vec<_>>>()
+                };
+                Expression::WindowFunction {
+                    op,
+                    params,
+                    args: rewrite_all(args, rewriter)?,
+                    partition_by: rewrite_all(partition_by, rewriter)?,
+                    order_by: rewrite_all(order_by, rewriter)?,
+                    window_frame,
+                }
+            }
+            Expression::LambdaFunction {
+                op,
+                params,
+                body,
+                args,
+            } => {
+                let mut new_args = Vec::with_capacity(args.len());
+                for arg in args {
+                    new_args.push(arg.rewrite(rewriter)?);
+                }
+                Expression::LambdaFunction {
+                    op,
+                    params,
+                    body,
+                    args: new_args,
+                }
+            }
             Expression::Cast { expr, data_type } => {
                 let expr = expr.rewrite(rewriter)?;
                 Expression::Cast {
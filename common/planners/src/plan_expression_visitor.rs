@@ -62,6 +62,26 @@ pub trait ExpressionVisitor: Sized {
                 }
                 Ok(visitor)
             }
+            Expression::WindowFunction {
+                args,
+                partition_by,
+                order_by,
+                ..
+            } => {
+                let mut visitor = self;
+                for arg in args.iter().chain(partition_by).chain(order_by) {
+                    visitor = arg.accept(visitor)?;
+                }
+                Ok(visitor)
+            }
+            // The lambda body only refers to the lambda params, so only the arguments are visited.
+            Expression::LambdaFunction { args, .. } => {
+                let mut visitor = self;
+                for arg in args {
+                    visitor = arg.accept(visitor)?;
+                }
+                Ok(visitor)
+            }
             Expression::Cast { expr, .. } => expr.accept(self),
             Expression::Sort { expr, .. } => expr.accept(self),
             _ => Ok(self),
@@ -0,0 +1,91 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+use std::fmt::Formatter;
+use std::sync::Arc;
+
+use common_datavalues::DataSchemaRef;
+
+use crate::Expression;
+use crate::PlanNode;
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, PartialEq)]
+pub enum JoinType {
+    Inner,
+    Left,
+    Right,
+    Full,
+    Cross,
+}
+
+impl JoinType {
+    /// Whether the unmatched rows of the left input are kept.
+    pub fn keep_left_unmatched(&self) -> bool {
+        matches!(self, JoinType::Left | JoinType::Full)
+    }
+
+    /// Whether the unmatched rows of the right input are kept.
+    pub fn keep_right_unmatched(&self) -> bool {
+        matches!(self, JoinType::Right | JoinType::Full)
+    }
+}
+
+impl fmt::Display for JoinType {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            JoinType::Inner => write!(f, "INNER"),
+            JoinType::Left => write!(f, "LEFT OUTER"),
+            JoinType::Right => write!(f, "RIGHT OUTER"),
+            JoinType::Full => write!(f, "FULL OUTER"),
+            JoinType::Cross => write!(f, "CROSS"),
+        }
+    }
+}
+
+/// Joins the rows of two inputs.
+/// The equi-conditions are split into `left_keys` and `right_keys`, which are
+/// evaluated on the left and right input respectively, and the rest of the
+/// join condition is kept in `predicate`, evaluated on the joined rows.
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq)]
+pub struct JoinPlan {
+    /// The kind of the join
+    pub join_type: JoinType,
+    /// The key expressions of the left input
+    pub left_keys: Vec<Expression>,
+    /// The key expressions of the right input, same length and types as `left_keys`
+    pub right_keys: Vec<Expression>,
+    /// The non-equi part of the join condition
+    pub predicate: Option<Expression>,
+    /// The schema description of the output, the left fields followed by the right fields
+    pub schema: DataSchemaRef,
+    /// The probe side of the join
+    pub left: Arc<PlanNode>,
+    /// The build side of the join
+    pub right: Arc<PlanNode>,
+}
+
+impl JoinPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        self.schema.clone()
+    }
+
+    pub fn set_left(&mut self, node: &PlanNode) {
+        self.left = Arc::new(node.clone());
+    }
+
+    pub fn set_right(&mut self, node: &PlanNode) {
+        self.right = Arc::new(node.clone());
+    }
+}
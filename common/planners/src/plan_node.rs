@@ -20,11 +20,13 @@ use crate::plan_broadcast::BroadcastPlan;
 use crate::plan_subqueries_set::SubQueriesSetPlan;
 use crate::AggregatorFinalPlan;
 use crate::AggregatorPartialPlan;
+use crate::AlterTablePlan;
 use crate::AlterUserPlan;
 use crate::CopyPlan;
 use crate::CreateDatabasePlan;
 use crate::CreateTablePlan;
 use crate::CreateUserPlan;
+use crate::DeletePlan;
 use crate::DescribeTablePlan;
 use crate::DropDatabasePlan;
 use crate::DropTablePlan;
@@ -36,9 +38,11 @@ use crate::FilterPlan;
 use crate::GrantPrivilegePlan;
 use crate::HavingPlan;
 use crate::InsertIntoPlan;
+use crate::JoinPlan;
 use crate::KillPlan;
 use crate::LimitByPlan;
 use crate::LimitPlan;
+use crate::OptimizeTablePlan;
 use crate::ProjectionPlan;
 use crate::ReadDataSourcePlan;
 use crate::RemotePlan;
@@ -50,7 +54,10 @@ use crate::SinkPlan;
 use crate::SortPlan;
 use crate::StagePlan;
 use crate::TruncateTablePlan;
+use crate::UnionAllPlan;
+use crate::UpdatePlan;
 use crate::UseDatabasePlan;
+use crate::WindowFuncPlan;
 
 #[allow(clippy::large_enum_variant)]
 #[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq)]
@@ -66,6 +73,8 @@ pub enum PlanNode {
     Filter(FilterPlan),
     Having(HavingPlan),
     Sort(SortPlan),
+    Join(JoinPlan),
+    UnionAll(UnionAllPlan),
     Limit(LimitPlan),
     LimitBy(LimitByPlan),
     ReadSource(ReadDataSourcePlan),
@@ -78,6 +87,11 @@ pub enum PlanNode {
     DescribeTable(DescribeTablePlan),
     DropTable(DropTablePlan),
     TruncateTable(TruncateTablePlan),
+    WindowFunc(WindowFuncPlan),
+    OptimizeTable(OptimizeTablePlan),
+    AlterTable(AlterTablePlan),
+    Update(UpdatePlan),
+    Delete(DeletePlan),
     UseDatabase(UseDatabasePlan),
     SetVariable(SettingPlan),
     InsertInto(InsertIntoPlan),
@@ -106,6 +120,8 @@ impl PlanNode {
             PlanNode::AggregatorFinal(v) => v.schema(),
             PlanNode::Filter(v) => v.schema(),
             PlanNode::Having(v) => v.schema(),
+            PlanNode::Join(v) => v.schema(),
+            PlanNode::UnionAll(v) => v.schema(),
             PlanNode::Limit(v) => v.schema(),
             PlanNode::LimitBy(v) => v.schema(),
             PlanNode::ReadSource(v) => v.schema(),
@@ -117,6 +133,11 @@ impl PlanNode {
             PlanNode::DropTable(v) => v.schema(),
             PlanNode::DescribeTable(v) => v.schema(),
             PlanNode::TruncateTable(v) => v.schema(),
+            PlanNode::WindowFunc(v) => v.schema(),
+            PlanNode::OptimizeTable(v) => v.schema(),
+            PlanNode::AlterTable(v) => v.schema(),
+            PlanNode::Update(v) => v.schema(),
+            PlanNode::Delete(v) => v.schema(),
             PlanNode::SetVariable(v) => v.schema(),
             PlanNode::Sort(v) => v.schema(),
             PlanNode::UseDatabase(v) => v.schema(),
@@ -146,6 +167,8 @@ impl PlanNode {
             PlanNode::AggregatorFinal(_) => "AggregatorFinalPlan",
             PlanNode::Filter(_) => "FilterPlan",
             PlanNode::Having(_) => "HavingPlan",
+            PlanNode::Join(_) => "JoinPlan",
+            PlanNode::UnionAll(_) => "UnionAllPlan",
             PlanNode::Limit(_) => "LimitPlan",
             PlanNode::LimitBy(_) => "LimitByPlan",
             PlanNode::ReadSource(_) => "ReadSourcePlan",
@@ -157,6 +180,11 @@ impl PlanNode {
             PlanNode::DescribeTable(_) => "DescribeTablePlan",
             PlanNode::DropTable(_) => "DropTablePlan",
             PlanNode::TruncateTable(_) => "TruncateTablePlan",
+            PlanNode::WindowFunc(_) => "WindowFuncPlan",
+            PlanNode::OptimizeTable(_) => "OptimizeTablePlan",
+            PlanNode::AlterTable(_) => "AlterTablePlan",
+            PlanNode::Update(_) => "UpdatePlan",
+            PlanNode::Delete(_) => "DeletePlan",
             PlanNode::SetVariable(_) => "SetVariablePlan",
             PlanNode::Sort(_) => "SortPlan",
             PlanNode::UseDatabase(_) => "UseDatabasePlan",
@@ -184,10 +212,13 @@ impl PlanNode {
             PlanNode::AggregatorFinal(v) => vec![v.input.clone()],
             PlanNode::Filter(v) => vec![v.input.clone()],
             PlanNode::Having(v) => vec![v.input.clone()],
+            PlanNode::Join(v) => vec![v.left.clone(), v.right.clone()],
+            PlanNode::UnionAll(v) => vec![v.left.clone(), v.right.clone()],
             PlanNode::Limit(v) => vec![v.input.clone()],
             PlanNode::Explain(v) => vec![v.input.clone()],
             PlanNode::Select(v) => vec![v.input.clone()],
             PlanNode::Sort(v) => vec![v.input.clone()],
+            PlanNode::WindowFunc(v) => vec![v.input.clone()],
             PlanNode::SubQueryExpression(v) => v.get_inputs(),
             PlanNode::Sink(v) => vec![v.input.clone()],
 
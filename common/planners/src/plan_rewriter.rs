@@ -25,11 +25,13 @@ use crate::plan_broadcast::BroadcastPlan;
 use crate::plan_subqueries_set::SubQueriesSetPlan;
 use crate::AggregatorFinalPlan;
 use crate::AggregatorPartialPlan;
+use crate::AlterTablePlan;
 use crate::AlterUserPlan;
 use crate::CopyPlan;
 use crate::CreateDatabasePlan;
 use crate::CreateTablePlan;
 use crate::CreateUserPlan;
+use crate::DeletePlan;
 use crate::DescribeTablePlan;
 use crate::DropDatabasePlan;
 use crate::DropTablePlan;
@@ -43,9 +45,11 @@ use crate::FilterPlan;
 use crate::GrantPrivilegePlan;
 use crate::HavingPlan;
 use crate::InsertIntoPlan;
+use crate::JoinPlan;
 use crate::KillPlan;
 use crate::LimitByPlan;
 use crate::LimitPlan;
+use crate::OptimizeTablePlan;
 use crate::PlanBuilder;
 use crate::PlanNode;
 use crate::ProjectionPlan;
@@ -59,7 +63,10 @@ use crate::SinkPlan;
 use crate::SortPlan;
 use crate::StagePlan;
 use crate::TruncateTablePlan;
+use crate::UnionAllPlan;
+use crate::UpdatePlan;
 use crate::UseDatabasePlan;
+use crate::WindowFuncPlan;
 
 /// `PlanRewriter` is a visitor that can help to rewrite `PlanNode`
 /// By default, a `PlanRewriter` will traverse the plan tree in pre-order and return rewritten plan tree.
@@ -89,6 +96,8 @@ pub trait PlanRewriter {
             PlanNode::Projection(plan) => self.rewrite_projection(plan),
             PlanNode::Filter(plan) => self.rewrite_filter(plan),
             PlanNode::Sort(plan) => self.rewrite_sort(plan),
+            PlanNode::Join(plan) => self.rewrite_join(plan),
+            PlanNode::UnionAll(plan) => self.rewrite_union_all(plan),
             PlanNode::Limit(plan) => self.rewrite_limit(plan),
             PlanNode::LimitBy(plan) => self.rewrite_limit_by(plan),
             PlanNode::ReadSource(plan) => self.rewrite_read_data_source(plan),
@@ -111,6 +120,11 @@ pub trait PlanRewriter {
             PlanNode::ShowCreateTable(plan) => self.rewrite_show_create_table(plan),
             PlanNode::SubQueryExpression(plan) => self.rewrite_sub_queries_sets(plan),
             PlanNode::TruncateTable(plan) => self.rewrite_truncate_table(plan),
+            PlanNode::WindowFunc(plan) => self.rewrite_window_func(plan),
+            PlanNode::OptimizeTable(plan) => self.rewrite_optimize_table(plan),
+            PlanNode::AlterTable(plan) => self.rewrite_alter_table(plan),
+            PlanNode::Update(plan) => self.rewrite_update(plan),
+            PlanNode::Delete(plan) => self.rewrite_delete(plan),
             PlanNode::Kill(plan) => self.rewrite_kill(plan),
             PlanNode::CreateUser(plan) => self.create_user(plan),
             PlanNode::AlterUser(plan) => self.alter_user(plan),
@@ -156,6 +170,32 @@ pub trait PlanRewriter {
                 params: params.clone(),
                 args: self.rewrite_exprs(schema, args)?,
             }),
+            Expression::WindowFunction {
+                op,
+                params,
+                args,
+                partition_by,
+                order_by,
+                window_frame,
+            } => Ok(Expression::WindowFunction {
+                op: op.clone(),
+                params: params.clone(),
+                args: self.rewrite_exprs(schema, args)?,
+                partition_by: self.rewrite_exprs(schema, partition_by)?,
+                order_by: self.rewrite_exprs(schema, order_by)?,
+                window_frame: *window_frame,
+            }),
+            Expression::LambdaFunction {
+                op,
+                params,
+                body,
+                args,
+            } => Ok(Expression::LambdaFunction {
+                op: op.clone(),
+                params: params.clone(),
+                body: body.clone(),
+                args: self.rewrite_exprs(schema, args)?,
+            }),
             Expression::Sort {
                 expr,
                 asc,
@@ -277,6 +317,33 @@ pub trait PlanRewriter {
         PlanBuilder::from(&new_input).sort(&new_order_by)?.build()
     }
 
+    fn rewrite_join(&mut self, plan: &JoinPlan) -> Result<PlanNode> {
+        let new_left = self.rewrite_plan_node(plan.left.as_ref())?;
+        let new_right = self.rewrite_plan_node(plan.right.as_ref())?;
+        let new_left_keys = self.rewrite_exprs(&new_left.schema(), &plan.left_keys)?;
+        let new_right_keys = self.rewrite_exprs(&new_right.schema(), &plan.right_keys)?;
+        let new_predicate = match &plan.predicate {
+            None => None,
+            Some(predicate) => Some(self.rewrite_expr(&plan.schema, predicate)?),
+        };
+
+        Ok(PlanNode::Join(JoinPlan {
+            join_type: plan.join_type,
+            left_keys: new_left_keys,
+            right_keys: new_right_keys,
+            predicate: new_predicate,
+            schema: plan.schema.clone(),
+            left: Arc::new(new_left),
+            right: Arc::new(new_right),
+        }))
+    }
+
+    fn rewrite_union_all(&mut self, plan: &UnionAllPlan) -> Result<PlanNode> {
+        let new_left = self.rewrite_plan_node(plan.left.as_ref())?;
+        let new_right = self.rewrite_plan_node(plan.right.as_ref())?;
+        PlanBuilder::from(&new_left).union_all(&new_right)?.build()
+    }
+
     fn rewrite_limit(&mut self, plan: &LimitPlan) -> Result<PlanNode> {
         let new_input = self.rewrite_plan_node(plan.input.as_ref())?;
         PlanBuilder::from(&new_input)
@@ -352,6 +419,29 @@ pub trait PlanRewriter {
         Ok(PlanNode::TruncateTable(plan.clone()))
     }
 
+    fn rewrite_window_func(&mut self, plan: &WindowFuncPlan) -> Result<PlanNode> {
+        let new_input = self.rewrite_plan_node(plan.input.as_ref())?;
+        PlanBuilder::from(&new_input)
+            .window_func(plan.window_func.clone())?
+            .build()
+    }
+
+    fn rewrite_optimize_table(&mut self, plan: &OptimizeTablePlan) -> Result<PlanNode> {
+        Ok(PlanNode::OptimizeTable(plan.clone()))
+    }
+
+    fn rewrite_alter_table(&mut self, plan: &AlterTablePlan) -> Result<PlanNode> {
+        Ok(PlanNode::AlterTable(plan.clone()))
+    }
+
+    fn rewrite_update(&mut self, plan: &UpdatePlan) -> Result<PlanNode> {
+        Ok(PlanNode::Update(plan.clone()))
+    }
+
+    fn rewrite_delete(&mut self, plan: &DeletePlan) -> Result<PlanNode> {
+        Ok(PlanNode::Delete(plan.clone()))
+    }
+
     fn rewrite_kill(&mut self, plan: &KillPlan) -> Result<PlanNode> {
         Ok(PlanNode::Kill(plan.clone()))
     }
@@ -523,6 +613,50 @@ impl RewriteHelper {
                 }
             }
 
+            Expression::WindowFunction {
+                op,
+                params,
+                args,
+                partition_by,
+                order_by,
+                window_frame,
+            } => {
+                let mut rewrite_all = |exprs: &[Expression]| {
+                    exprs
+                        .iter()
+                        .map(|v| RewriteHelper::expr_rewrite_alias(v, data))
+                        .collect::<Result<Vec<_>>>()
+                };
+
+                Ok(Expression::WindowFunction {
+                    op: op.clone(),
+                    params: params.clone(),
+                    args: rewrite_all(args)?,
+                    partition_by: rewrite_all(partition_by)?,
+                    order_by: rewrite_all(order_by)?,
+                    window_frame: *window_frame,
+                })
+            }
+
+            Expression::LambdaFunction {
+                op,
+                params,
+                body,
+                args,
+            } => {
+                let new_args = args
+                    .iter()
+                    .map(|v| RewriteHelper::expr_rewrite_alias(v, data))
+                    .collect::<Result<Vec<_>>>()?;
+
+                Ok(Expression::LambdaFunction {
+                    op: op.clone(),
+                    params: params.clone(),
+                    body: body.clone(),
+                    args: new_args,
+                })
+            }
+
             Expression::Alias(alias, plan) => {
                 if data.inside_aliases.contains(alias) {
                     return Result::Err(ErrorCode::SyntaxException(format!(
@@ -614,6 +748,18 @@ impl RewriteHelper {
             }
             Expression::ScalarFunction { args, .. } => args.clone(),
             Expression::AggregateFunction { args, .. } => args.clone(),
+            Expression::WindowFunction {
+                args,
+                partition_by,
+                order_by,
+                ..
+            } => args
+                .iter()
+                .chain(partition_by)
+                .chain(order_by)
+                .cloned()
+                .collect(),
+            Expression::LambdaFunction { args, .. } => args.clone(),
             Expression::Wildcard => vec![],
             Expression::Sort { expr, .. } => vec![expr.as_ref().clone()],
             Expression::Cast { expr, .. } => vec![expr.as_ref().clone()],
@@ -652,6 +798,27 @@ impl RewriteHelper {
                 }
                 v
             }
+            Expression::WindowFunction {
+                args,
+                partition_by,
+                order_by,
+                ..
+            } => {
+                let mut v = vec![];
+                for arg in args.iter().chain(partition_by).chain(order_by) {
+                    let mut col = Self::expression_plan_columns(arg)?;
+                    v.append(&mut col);
+                }
+                v
+            }
+            Expression::LambdaFunction { args, .. } => {
+                let mut v = vec![];
+                for arg in args {
+                    let mut col = Self::expression_plan_columns(arg)?;
+                    v.append(&mut col);
+                }
+                v
+            }
             Expression::Wildcard => vec![],
             Expression::Sort { expr, .. } => Self::expression_plan_columns(expr)?,
             Expression::Cast { expr, .. } => Self::expression_plan_columns(expr)?,
@@ -724,6 +891,33 @@ impl RewriteHelper {
                 params: params.clone(),
                 args: expressions.to_vec(),
             },
+            Expression::WindowFunction {
+                op,
+                params,
+                args,
+                partition_by,
+                window_frame,
+                ..
+            } => {
+                let (args, others) = expressions.split_at(args.len());
+                let (partition_by, order_by) = others.split_at(partition_by.len());
+                Expression::WindowFunction {
+                    op: op.clone(),
+                    params: params.clone(),
+                    args: args.to_vec(),
+                    partition_by: partition_by.to_vec(),
+                    order_by: order_by.to_vec(),
+                    window_frame: *window_frame,
+                }
+            }
+            Expression::LambdaFunction {
+                op, params, body, ..
+            } => Expression::LambdaFunction {
+                op: op.clone(),
+                params: params.clone(),
+                body: body.clone(),
+                args: expressions.to_vec(),
+            },
             other => other.clone(),
         }
     }
@@ -0,0 +1,49 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_datavalues::DataSchema;
+use common_datavalues::DataSchemaRef;
+
+/// Which historical data of a table are kept while purging
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub enum PurgeRetention {
+    /// Keeps the latest N snapshots
+    Snapshots(u64),
+    /// Keeps the snapshots that are needed to travel back to any time point of the latest N seconds
+    Seconds(u64),
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub enum OptimizeTableAction {
+    /// Merges the small blocks and the segments of the table
+    Compact,
+    /// Removes the historical data which are out of the retention
+    Purge(PurgeRetention),
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct OptimizeTablePlan {
+    pub db: String,
+    /// The table name
+    pub table: String,
+    pub action: OptimizeTableAction,
+}
+
+impl OptimizeTablePlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
@@ -0,0 +1,44 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_datavalues::DataSchemaRef;
+
+use crate::PlanNode;
+
+/// Concatenates the rows of two inputs.
+/// Both inputs have the same column names and types as the output schema,
+/// the plan builder projects the right input onto the left one if they are different.
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq)]
+pub struct UnionAllPlan {
+    /// The schema description of the output, same as the left input
+    pub schema: DataSchemaRef,
+    pub left: Arc<PlanNode>,
+    pub right: Arc<PlanNode>,
+}
+
+impl UnionAllPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        self.schema.clone()
+    }
+
+    pub fn set_left(&mut self, node: &PlanNode) {
+        self.left = Arc::new(node.clone());
+    }
+
+    pub fn set_right(&mut self, node: &PlanNode) {
+        self.right = Arc::new(node.clone());
+    }
+}
@@ -18,11 +18,13 @@ use crate::plan_broadcast::BroadcastPlan;
 use crate::plan_subqueries_set::SubQueriesSetPlan;
 use crate::AggregatorFinalPlan;
 use crate::AggregatorPartialPlan;
+use crate::AlterTablePlan;
 use crate::AlterUserPlan;
 use crate::CopyPlan;
 use crate::CreateDatabasePlan;
 use crate::CreateTablePlan;
 use crate::CreateUserPlan;
+use crate::DeletePlan;
 use crate::DescribeTablePlan;
 use crate::DropDatabasePlan;
 use crate::DropTablePlan;
@@ -35,9 +37,11 @@ use crate::FilterPlan;
 use crate::GrantPrivilegePlan;
 use crate::HavingPlan;
 use crate::InsertIntoPlan;
+use crate::JoinPlan;
 use crate::KillPlan;
 use crate::LimitByPlan;
 use crate::LimitPlan;
+use crate::OptimizeTablePlan;
 use crate::PlanNode;
 use crate::ProjectionPlan;
 use crate::ReadDataSourcePlan;
@@ -50,7 +54,10 @@ use crate::SinkPlan;
 use crate::SortPlan;
 use crate::StagePlan;
 use crate::TruncateTablePlan;
+use crate::UnionAllPlan;
+use crate::UpdatePlan;
 use crate::UseDatabasePlan;
+use crate::WindowFuncPlan;
 
 /// `PlanVisitor` implements visitor pattern(reference [syn](https://docs.rs/syn/1.0.72/syn/visit/trait.Visit.html)) for `PlanNode`.
 ///
@@ -102,6 +109,8 @@ pub trait PlanVisitor {
             PlanNode::Projection(plan) => self.visit_projection(plan),
             PlanNode::Filter(plan) => self.visit_filter(plan),
             PlanNode::Sort(plan) => self.visit_sort(plan),
+            PlanNode::Join(plan) => self.visit_join(plan),
+            PlanNode::UnionAll(plan) => self.visit_union_all(plan),
             PlanNode::Limit(plan) => self.visit_limit(plan),
             PlanNode::LimitBy(plan) => self.visit_limit_by(plan),
             PlanNode::ReadSource(plan) => self.visit_read_data_source(plan),
@@ -113,6 +122,11 @@ pub trait PlanVisitor {
             PlanNode::DropTable(plan) => self.visit_drop_table(plan),
             PlanNode::DescribeTable(plan) => self.visit_describe_table(plan),
             PlanNode::TruncateTable(plan) => self.visit_truncate_table(plan),
+            PlanNode::WindowFunc(plan) => self.visit_window_func(plan),
+            PlanNode::OptimizeTable(plan) => self.visit_optimize_table(plan),
+            PlanNode::AlterTable(plan) => self.visit_alter_table(plan),
+            PlanNode::Update(plan) => self.visit_update(plan),
+            PlanNode::Delete(plan) => self.visit_delete(plan),
             PlanNode::UseDatabase(plan) => self.visit_use_database(plan),
             PlanNode::SetVariable(plan) => self.visit_set_variable(plan),
             PlanNode::Stage(plan) => self.visit_stage(plan),
@@ -218,6 +232,22 @@ pub trait PlanVisitor {
         self.visit_exprs(&plan.order_by)
     }
 
+    fn visit_join(&mut self, plan: &JoinPlan) -> Result<()> {
+        self.visit_plan_node(plan.left.as_ref())?;
+        self.visit_plan_node(plan.right.as_ref())?;
+        self.visit_exprs(&plan.left_keys)?;
+        self.visit_exprs(&plan.right_keys)?;
+        match &plan.predicate {
+            None => Ok(()),
+            Some(predicate) => self.visit_expr(predicate),
+        }
+    }
+
+    fn visit_union_all(&mut self, plan: &UnionAllPlan) -> Result<()> {
+        self.visit_plan_node(plan.left.as_ref())?;
+        self.visit_plan_node(plan.right.as_ref())
+    }
+
     fn visit_limit(&mut self, plan: &LimitPlan) -> Result<()> {
         self.visit_plan_node(plan.input.as_ref())
     }
@@ -302,6 +332,26 @@ pub trait PlanVisitor {
         Ok(())
     }
 
+    fn visit_window_func(&mut self, plan: &WindowFuncPlan) -> Result<()> {
+        self.visit_plan_node(plan.input.as_ref())
+    }
+
+    fn visit_optimize_table(&mut self, _: &OptimizeTablePlan) -> Result<()> {
+        Ok(())
+    }
+
+    fn visit_alter_table(&mut self, _: &AlterTablePlan) -> Result<()> {
+        Ok(())
+    }
+
+    fn visit_update(&mut self, _: &UpdatePlan) -> Result<()> {
+        Ok(())
+    }
+
+    fn visit_delete(&mut self, _: &DeletePlan) -> Result<()> {
+        Ok(())
+    }
+
     fn visit_kill_query(&mut self, _: &KillPlan) -> Result<()> {
         Ok(())
     }
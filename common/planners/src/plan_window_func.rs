@@ -0,0 +1,41 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_datavalues::DataSchemaRef;
+
+use crate::Expression;
+use crate::PlanNode;
+
+/// Evaluates one window function over the whole input,
+/// the result column is appended to the input columns.
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq)]
+pub struct WindowFuncPlan {
+    /// The window function expression, its arguments, partition by and order by
+    /// expressions are columns of the input
+    pub window_func: Expression,
+    pub schema: DataSchemaRef,
+    pub input: Arc<PlanNode>,
+}
+
+impl WindowFuncPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        self.schema.clone()
+    }
+
+    pub fn set_input(&mut self, node: &PlanNode) {
+        self.input = Arc::new(node.clone());
+    }
+}
@@ -0,0 +1,80 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_planners::*;
+
+fn join_inputs() -> (PlanNode, PlanNode) {
+    let left = DataSchemaRefExt::create(vec![
+        DataField::new("a", DataType::Int32, false),
+        DataField::new("b", DataType::String, false),
+    ]);
+    let right = DataSchemaRefExt::create(vec![
+        DataField::new("c", DataType::Int64, false),
+        DataField::new("d", DataType::String, false),
+    ]);
+
+    (
+        PlanNode::Empty(EmptyPlan::create_with_schema(left)),
+        PlanNode::Empty(EmptyPlan::create_with_schema(right)),
+    )
+}
+
+#[test]
+fn test_join_plan() -> Result<()> {
+    use pretty_assertions::assert_eq;
+
+    let (left, right) = join_inputs();
+    let condition = col("a").eq(col("c")).and(col("b").not_eq(col("d")));
+    let plan = PlanBuilder::from(&left)
+        .join(&right, JoinType::Inner, Some(condition))?
+        .build()?;
+
+    let expect = "Join: INNER, left_keys=[cast(a as Int64)], right_keys=[c], predicate=(b != d)";
+    let actual = format!("{:?}", plan);
+    assert_eq!(expect, actual);
+
+    let schema = plan.schema();
+    assert_eq!(4, schema.fields().len());
+    assert!(schema.fields().iter().all(|field| !field.is_nullable()));
+    Ok(())
+}
+
+#[test]
+fn test_outer_join_plan() -> Result<()> {
+    use pretty_assertions::assert_eq;
+
+    let (left, right) = join_inputs();
+    let plan = PlanBuilder::from(&left)
+        .join(&right, JoinType::Left, Some(col("d").eq(col("b"))))?
+        .build()?;
+
+    let expect = "Join: LEFT OUTER, left_keys=[b], right_keys=[d]";
+    let actual = format!("{:?}", plan);
+    assert_eq!(expect, actual);
+
+    // The right side is padded with NULLs for the unmatched left rows.
+    let schema = plan.schema();
+    assert!(!schema.field_with_name("a")?.is_nullable());
+    assert!(schema.field_with_name("c")?.is_nullable());
+
+    let plan = PlanBuilder::from(&left)
+        .join(&right, JoinType::Cross, None)?
+        .build()?;
+    let expect = "Join: CROSS, left_keys=[], right_keys=[]";
+    let actual = format!("{:?}", plan);
+    assert_eq!(expect, actual);
+    Ok(())
+}
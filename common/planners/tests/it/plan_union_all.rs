@@ -0,0 +1,76 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_planners::*;
+
+fn union_inputs() -> (PlanNode, PlanNode) {
+    let left = DataSchemaRefExt::create(vec![
+        DataField::new("a", DataType::Int32, false),
+        DataField::new("b", DataType::String, false),
+    ]);
+    let right = DataSchemaRefExt::create(vec![
+        DataField::new("c", DataType::Int64, false),
+        DataField::new("d", DataType::String, true),
+    ]);
+
+    (
+        PlanNode::Empty(EmptyPlan::create_with_schema(left)),
+        PlanNode::Empty(EmptyPlan::create_with_schema(right)),
+    )
+}
+
+#[test]
+fn test_union_all_plan() -> Result<()> {
+    use pretty_assertions::assert_eq;
+
+    let (left, right) = union_inputs();
+    let plan = PlanBuilder::from(&left).union_all(&right)?.build()?;
+
+    let expect = "\
+    UnionAll: [a:Int64, b:String]\
+    \n  Projection: cast(a as Int64) as a:Int64, b:String\
+    \n  Projection: c as a:Int64, d as b:String";
+    let actual = format!("{:?}", plan);
+    assert_eq!(expect, actual);
+
+    let schema = plan.schema();
+    assert!(!schema.field_with_name("a")?.is_nullable());
+    assert!(schema.field_with_name("b")?.is_nullable());
+    Ok(())
+}
+
+#[test]
+fn test_set_operation_plans() -> Result<()> {
+    use pretty_assertions::assert_eq;
+
+    let (left, right) = union_inputs();
+    for plan in [
+        PlanBuilder::from(&left).union(&right)?.build()?,
+        PlanBuilder::from(&left).intersect(&right)?.build()?,
+        PlanBuilder::from(&left).except(&right)?.build()?,
+    ] {
+        let schema = plan.schema();
+        let names = schema.fields().iter().map(|f| f.name().as_str());
+        assert_eq!(vec!["a", "b"], names.collect::<Vec<_>>());
+        assert_eq!(&DataType::Int64, schema.field(0).data_type());
+    }
+
+    let single_column = DataSchemaRefExt::create(vec![DataField::new("a", DataType::Int32, false)]);
+    let single_column = PlanNode::Empty(EmptyPlan::create_with_schema(single_column));
+    let result = PlanBuilder::from(&left).union_all(&single_column);
+    let expect = "Code: 6, displayText = Set operation requires both sides to have the same number of columns, but got 2 and 1.";
+    assert_eq!(expect, format!("{}", result.err().unwrap()));
+    Ok(())
+}
@@ -0,0 +1,93 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::DataType;
+use common_datavalues::DataValue;
+use common_exception::Result;
+use common_planners::*;
+
+use crate::test::Test;
+
+#[test]
+fn test_window_func_plan() -> Result<()> {
+    use pretty_assertions::assert_eq;
+
+    let source = Test::create().generate_source_plan_for_test(10000)?;
+    let window_func = Expression::WindowFunction {
+        op: "sum".to_string(),
+        params: vec![],
+        args: vec![col("number")],
+        partition_by: vec![modular(col("number"), lit(3i64))],
+        order_by: vec![Expression::Sort {
+            expr: Box::new(col("number")),
+            asc: false,
+            nulls_first: false,
+            origin_expr: Box::new(col("number")),
+        }],
+        window_frame: Some(WindowFrame {
+            units: WindowFrameUnits::Rows,
+            start_bound: WindowFrameBound::Preceding(Some(1)),
+            end_bound: WindowFrameBound::Following(None),
+        }),
+    };
+
+    let expect_name =
+        "sum(number) OVER (PARTITION BY (number % 3) ORDER BY number DESC ROWS BETWEEN 1 PRECEDING AND UNBOUNDED FOLLOWING)";
+    assert_eq!(expect_name, window_func.column_name());
+
+    let plan = PlanBuilder::from(&source)
+        .expression(&[modular(col("number"), lit(3i64))], "Before Window")?
+        .window_func(window_func)?
+        .build()?;
+
+    let schema = plan.schema();
+    assert_eq!(3, schema.fields().len());
+    assert_eq!(expect_name, schema.field(2).name());
+    assert_eq!(&DataType::UInt64, schema.field(2).data_type());
+
+    let expect = "\
+    WindowFunc: row_number() OVER (ORDER BY number DESC)\
+    \n  ReadDataSource: scan partitions: [8], scan schema: [number:UInt64], statistics: [read_rows: 10000, read_bytes: 80000]";
+    let row_number = Expression::WindowFunction {
+        op: "row_number".to_string(),
+        params: vec![],
+        args: vec![],
+        partition_by: vec![],
+        order_by: vec![Expression::Sort {
+            expr: Box::new(col("number")),
+            asc: false,
+            nulls_first: false,
+            origin_expr: Box::new(col("number")),
+        }],
+        window_frame: None,
+    };
+    let plan = PlanBuilder::from(&source)
+        .window_func(row_number)?
+        .build()?;
+    let actual = format!("{:?}", plan);
+    assert_eq!(expect, actual);
+
+    // The offset of lag must be non-negative.
+    let lag = Expression::WindowFunction {
+        op: "lag".to_string(),
+        params: vec![DataValue::Int64(Some(-1))],
+        args: vec![col("number")],
+        partition_by: vec![],
+        order_by: vec![],
+        window_frame: None,
+    };
+    let result = PlanBuilder::from(&source).window_func(lag);
+    assert!(result.is_err());
+    Ok(())
+}
@@ -21,6 +21,7 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 
 use crate::CsvSource;
+use crate::NdJsonSource;
 use crate::ParquetSource;
 use crate::Source;
 
@@ -55,6 +56,14 @@ impl SourceFactory {
                     params.max_block_size,
                 )?))
             }
+            "ndjson" | "jsoneachrow" => {
+                let reader = params.acc.get_input_stream(params.path, None)?;
+                Ok(Box::new(NdJsonSource::try_create(
+                    reader,
+                    params.schema,
+                    params.max_block_size,
+                )?))
+            }
             "parquet" => Ok(Box::new(ParquetSource::new(
                 params.acc,
                 params.path.to_owned(),
@@ -23,6 +23,7 @@ use common_datavalues::DataField;
 use common_datavalues::DataSchemaRefExt;
 use common_datavalues::DataType;
 use common_streams::CsvSource;
+use common_streams::NdJsonSource;
 use common_streams::Source;
 use common_streams::ValueSource;
 
@@ -93,3 +94,45 @@ async fn test_parse_csvs() {
     drop(file);
     dir.close().unwrap();
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_parse_ndjson() {
+    let dir = tempfile::tempdir().unwrap();
+    let name = "my-temporary-note.ndjson";
+    let file_path = dir.path().join(name);
+    let mut file = File::create(file_path).unwrap();
+    writeln!(
+        file,
+        "{{\"a\": 1, \"b\": \"x\", \"c\": {{\"k\": [1, 2]}}}}\n{{\"a\": 2, \"c\": \"str\"}}\n\n{{\"c\": null, \"b\": \"y\", \"a\": 3}}"
+    )
+    .unwrap();
+
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", DataType::Int8, false),
+        DataField::new("b", DataType::String, true),
+        DataField::new("c", DataType::Variant, true),
+    ]);
+
+    let local = Local::with_path(dir.path().to_path_buf());
+    let stream = local.get_input_stream(name, None).unwrap();
+    let mut ndjson_source = NdJsonSource::try_create(stream, schema, 10).unwrap();
+    let block = ndjson_source.read().await.unwrap().unwrap();
+    assert_blocks_eq(
+        vec![
+            "+---+------+-------------+",
+            "| a | b    | c           |",
+            "+---+------+-------------+",
+            "| 1 | x    | {\"k\":[1,2]} |",
+            "| 2 | NULL | \"str\"       |",
+            "| 3 | y    | NULL        |",
+            "+---+------+-------------+",
+        ],
+        &[block],
+    );
+
+    let block = ndjson_source.read().await.unwrap();
+    assert!(block.is_none());
+
+    drop(file);
+    dir.close().unwrap();
+}
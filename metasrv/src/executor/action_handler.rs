@@ -76,6 +76,7 @@ impl ActionHandler {
             MetaFlightAction::ListTables(a) => s.serialize(self.handle(a).await?),
             MetaFlightAction::GetTableExt(a) => s.serialize(self.handle(a).await?),
             MetaFlightAction::CommitTable(a) => s.serialize(self.handle(a).await?),
+            MetaFlightAction::UpdateTableMeta(a) => s.serialize(self.handle(a).await?),
         }
     }
 }
@@ -31,6 +31,7 @@ use databend_query::servers::MySQLHandler;
 use databend_query::servers::Server;
 use databend_query::servers::ShutdownHandle;
 use databend_query::sessions::SessionManager;
+use databend_query::storages::fuse::CompactionScheduler;
 use log::info;
 
 #[databend_main]
@@ -161,8 +162,21 @@ async fn main(_global_tracker: Arc<RuntimeTracker>) -> common_exception::Result<
         );
     }
 
+    // Background compaction of fuse tables.
+    let mut compaction_scheduler = CompactionScheduler::try_create(&conf, session_manager.clone());
+    if let Some(scheduler) = compaction_scheduler.as_mut() {
+        scheduler.start();
+        info!(
+            "Background compaction of fuse tables is scheduled every {} seconds",
+            conf.query.fuse_compact_interval_secs
+        );
+    }
+
     log::info!("Ready for connections.");
     shutdown_handle.wait_for_termination_request().await;
+    if let Some(scheduler) = compaction_scheduler.as_mut() {
+        scheduler.shutdown().await?;
+    }
     log::info!("Shutdown server.");
     Ok(())
 }
@@ -33,6 +33,8 @@ use common_meta_types::MetaId;
 use common_meta_types::TableIdent;
 use common_meta_types::TableInfo;
 use common_meta_types::TableMeta;
+use common_meta_types::UpdateTableMetaReply;
+use common_meta_types::UpdateTableMetaReq;
 use common_meta_types::UpsertTableOptionReply;
 use common_meta_types::UpsertTableOptionReq;
 use common_tracing::tracing;
@@ -226,4 +228,8 @@ impl Catalog for MutableCatalog {
     ) -> Result<UpsertTableOptionReply> {
         self.ctx.meta.upsert_table_option(req).await
     }
+
+    async fn update_table_meta(&self, req: UpdateTableMetaReq) -> Result<UpdateTableMetaReply> {
+        self.ctx.meta.update_table_meta(req).await
+    }
 }
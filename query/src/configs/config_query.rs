@@ -32,6 +32,9 @@ pub const QUERY_FLIGHT_API_ADDRESS: &str = "QUERY_FLIGHT_API_ADDRESS";
 pub const QUERY_HTTP_API_ADDRESS: &str = "QUERY_HTTP_API_ADDRESS";
 pub const QUERY_METRICS_API_ADDRESS: &str = "QUERY_METRIC_API_ADDRESS";
 pub const QUERY_WAIT_TIMEOUT_MILLS: &str = "QUERY_WAIT_TIMEOUT_MILLS";
+pub const QUERY_FUSE_COMPACT_INTERVAL_SECS: &str = "QUERY_FUSE_COMPACT_INTERVAL_SECS";
+pub const QUERY_FUSE_COMPACT_SEGMENT_THRESHOLD: &str = "QUERY_FUSE_COMPACT_SEGMENT_THRESHOLD";
+pub const QUERY_FUSE_COMPACT_BLOCK_THRESHOLD: &str = "QUERY_FUSE_COMPACT_BLOCK_THRESHOLD";
 const QUERY_API_TLS_SERVER_CERT: &str = "QUERY_API_TLS_SERVER_CERT";
 const QUERY_API_TLS_SERVER_KEY: &str = "QUERY_API_TLS_SERVER_KEY";
 const QUERY_API_TLS_SERVER_ROOT_CA_CERT: &str = "QUERY_API_TLS_SERVER_ROOT_CA_CERT";
@@ -206,6 +209,33 @@ pub struct QueryConfig {
     )]
     #[serde(default)]
     pub wait_timeout_mills: u64,
+
+    #[structopt(
+        long,
+        env = QUERY_FUSE_COMPACT_INTERVAL_SECS,
+        default_value = "0",
+        help = "Interval in seconds of checking whether fuse tables need compaction, 0 disables the background compaction"
+    )]
+    #[serde(default)]
+    pub fuse_compact_interval_secs: u64,
+
+    #[structopt(
+        long,
+        env = QUERY_FUSE_COMPACT_SEGMENT_THRESHOLD,
+        default_value = "100",
+        help = "A fuse table is compacted in background once it has this many segments"
+    )]
+    #[serde(default)]
+    pub fuse_compact_segment_threshold: u64,
+
+    #[structopt(
+        long,
+        env = QUERY_FUSE_COMPACT_BLOCK_THRESHOLD,
+        default_value = "100",
+        help = "A fuse table is compacted in background once it has this many undersized blocks"
+    )]
+    #[serde(default)]
+    pub fuse_compact_block_threshold: u64,
 }
 
 impl QueryConfig {
@@ -235,6 +265,9 @@ impl QueryConfig {
             table_engine_parquet_enabled: false,
             table_engine_memory_enabled: true,
             wait_timeout_mills: 5000,
+            fuse_compact_interval_secs: 0,
+            fuse_compact_segment_threshold: 100,
+            fuse_compact_block_threshold: 100,
         }
     }
 
@@ -355,5 +388,26 @@ impl QueryConfig {
             u64,
             QUERY_WAIT_TIMEOUT_MILLS
         );
+        env_helper!(
+            mut_config,
+            query,
+            fuse_compact_interval_secs,
+            u64,
+            QUERY_FUSE_COMPACT_INTERVAL_SECS
+        );
+        env_helper!(
+            mut_config,
+            query,
+            fuse_compact_segment_threshold,
+            u64,
+            QUERY_FUSE_COMPACT_SEGMENT_THRESHOLD
+        );
+        env_helper!(
+            mut_config,
+            query,
+            fuse_compact_block_threshold,
+            u64,
+            QUERY_FUSE_COMPACT_BLOCK_THRESHOLD
+        );
     }
 }
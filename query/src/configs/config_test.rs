@@ -61,6 +61,9 @@ table_engine_csv_enabled = false
 table_engine_parquet_enabled = false
 table_engine_memory_enabled = true
 wait_timeout_mills = 5000
+fuse_compact_interval_secs = 0
+fuse_compact_segment_threshold = 100
+fuse_compact_block_threshold = 100
 
 [log]
 log_level = \"INFO\"
@@ -39,6 +39,7 @@ mod interpreter_table_create_test;
 #[cfg(test)]
 mod interpreter_table_drop_test;
 #[cfg(test)]
+mod interpreter_table_optimize;
 mod interpreter_truncate_table_test;
 #[cfg(test)]
 mod interpreter_use_database_test;
@@ -54,6 +55,7 @@ mod interpreter_common;
 mod interpreter_copy;
 mod interpreter_database_create;
 mod interpreter_database_drop;
+mod interpreter_delete;
 mod interpreter_describe_table;
 mod interpreter_explain;
 mod interpreter_factory;
@@ -65,9 +67,11 @@ mod interpreter_revoke_privilege;
 mod interpreter_select;
 mod interpreter_setting;
 mod interpreter_show_create_table;
+mod interpreter_table_alter;
 mod interpreter_table_create;
 mod interpreter_table_drop;
 mod interpreter_truncate_table;
+mod interpreter_update;
 mod interpreter_use_database;
 mod interpreter_user_alter;
 mod interpreter_user_create;
@@ -79,6 +83,7 @@ pub use interpreter::InterpreterPtr;
 pub use interpreter_copy::CopyInterpreter;
 pub use interpreter_database_create::CreateDatabaseInterpreter;
 pub use interpreter_database_drop::DropDatabaseInterpreter;
+pub use interpreter_delete::DeleteInterpreter;
 pub use interpreter_describe_table::DescribeTableInterpreter;
 pub use interpreter_explain::ExplainInterpreter;
 pub use interpreter_factory::InterpreterFactory;
@@ -90,9 +95,12 @@ pub use interpreter_revoke_privilege::RevokePrivilegeInterpreter;
 pub use interpreter_select::SelectInterpreter;
 pub use interpreter_setting::SettingInterpreter;
 pub use interpreter_show_create_table::ShowCreateTableInterpreter;
+pub use interpreter_table_alter::AlterTableInterpreter;
 pub use interpreter_table_create::CreateTableInterpreter;
 pub use interpreter_table_drop::DropTableInterpreter;
+pub use interpreter_table_optimize::OptimizeTableInterpreter;
 pub use interpreter_truncate_table::TruncateTableInterpreter;
+pub use interpreter_update::UpdateInterpreter;
 pub use interpreter_use_database::UseDatabaseInterpreter;
 pub use interpreter_user_alter::AlterUserInterpreter;
 pub use interpreter_user_create::CreatUserInterpreter;
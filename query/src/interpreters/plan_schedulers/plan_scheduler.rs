@@ -29,6 +29,7 @@ use common_planners::ExpressionPlan;
 use common_planners::Expressions;
 use common_planners::FilterPlan;
 use common_planners::HavingPlan;
+use common_planners::JoinPlan;
 use common_planners::LimitByPlan;
 use common_planners::LimitPlan;
 use common_planners::Partitions;
@@ -42,6 +43,8 @@ use common_planners::SortPlan;
 use common_planners::StageKind;
 use common_planners::StagePlan;
 use common_planners::SubQueriesSetPlan;
+use common_planners::UnionAllPlan;
+use common_planners::WindowFuncPlan;
 use common_tracing::tracing;
 
 use crate::api::BroadcastAction;
@@ -300,8 +303,11 @@ impl PlanScheduler {
             PlanNode::Projection(plan) => self.visit_projection(plan, tasks),
             PlanNode::Filter(plan) => self.visit_filter(plan, tasks),
             PlanNode::Sort(plan) => self.visit_sort(plan, tasks),
+            PlanNode::Join(plan) => self.visit_join(plan, tasks),
+            PlanNode::UnionAll(plan) => self.visit_union_all(plan, tasks),
             PlanNode::Limit(plan) => self.visit_limit(plan, tasks),
             PlanNode::LimitBy(plan) => self.visit_limit_by(plan, tasks),
+            PlanNode::WindowFunc(plan) => self.visit_window_func(plan, tasks),
             PlanNode::ReadSource(plan) => self.visit_data_source(plan, tasks),
             PlanNode::Sink(plan) => self.visit_sink(plan, tasks),
             PlanNode::Select(plan) => self.visit_select(plan, tasks),
@@ -726,6 +732,48 @@ impl PlanScheduler {
         }
     }
 
+    fn visit_join(&mut self, plan: &JoinPlan, tasks: &mut Tasks) -> Result<()> {
+        self.visit_plan_node(plan.left.as_ref(), tasks)?;
+        let right_nodes_plan = self.visit_subquery(plan.right.as_ref(), tasks)?;
+        match self.running_mode {
+            RunningMode::Cluster => {
+                Err(ErrorCode::LogicalError("Join cannot work on cluster mode"))
+            }
+            RunningMode::Standalone => {
+                self.visit_local_join(plan, &right_nodes_plan[self.local_pos]);
+                Ok(())
+            }
+        }
+    }
+
+    fn visit_local_join(&mut self, plan: &JoinPlan, right: &PlanNode) {
+        let mut new_plan = plan.clone();
+        new_plan.set_left(&self.nodes_plan[self.local_pos]);
+        new_plan.set_right(right);
+        self.nodes_plan[self.local_pos] = PlanNode::Join(new_plan);
+    }
+
+    fn visit_union_all(&mut self, plan: &UnionAllPlan, tasks: &mut Tasks) -> Result<()> {
+        self.visit_plan_node(plan.left.as_ref(), tasks)?;
+        let right_nodes_plan = self.visit_subquery(plan.right.as_ref(), tasks)?;
+        match self.running_mode {
+            RunningMode::Cluster => Err(ErrorCode::LogicalError(
+                "Union all cannot work on cluster mode",
+            )),
+            RunningMode::Standalone => {
+                self.visit_local_union_all(plan, &right_nodes_plan[self.local_pos]);
+                Ok(())
+            }
+        }
+    }
+
+    fn visit_local_union_all(&mut self, plan: &UnionAllPlan, right: &PlanNode) {
+        let mut new_plan = plan.clone();
+        new_plan.set_left(&self.nodes_plan[self.local_pos]);
+        new_plan.set_right(right);
+        self.nodes_plan[self.local_pos] = PlanNode::UnionAll(new_plan);
+    }
+
     fn visit_limit(&mut self, plan: &LimitPlan, tasks: &mut Tasks) -> Result<()> {
         self.visit_plan_node(plan.input.as_ref(), tasks)?;
         match self.running_mode {
@@ -780,6 +828,33 @@ impl PlanScheduler {
         }
     }
 
+    fn visit_window_func(&mut self, plan: &WindowFuncPlan, tasks: &mut Tasks) -> Result<()> {
+        self.visit_plan_node(plan.input.as_ref(), tasks)?;
+        match self.running_mode {
+            RunningMode::Cluster => self.visit_cluster_window_func(plan),
+            RunningMode::Standalone => self.visit_local_window_func(plan),
+        };
+        Ok(())
+    }
+
+    fn visit_local_window_func(&mut self, plan: &WindowFuncPlan) {
+        self.nodes_plan[self.local_pos] = PlanNode::WindowFunc(WindowFuncPlan {
+            window_func: plan.window_func.clone(),
+            schema: plan.schema.clone(),
+            input: Arc::new(self.nodes_plan[self.local_pos].clone()),
+        });
+    }
+
+    fn visit_cluster_window_func(&mut self, plan: &WindowFuncPlan) {
+        for index in 0..self.nodes_plan.len() {
+            self.nodes_plan[index] = PlanNode::WindowFunc(WindowFuncPlan {
+                window_func: plan.window_func.clone(),
+                schema: plan.schema.clone(),
+                input: Arc::new(self.nodes_plan[index].clone()),
+            });
+        }
+    }
+
     fn visit_data_source(&mut self, plan: &ReadDataSourcePlan, _: &mut Tasks) -> Result<()> {
         let table = self.query_context.build_table_from_source_plan(plan)?;
 
@@ -22,6 +22,7 @@ use common_planners::AggregatorFinalPlan;
 use common_planners::AggregatorPartialPlan;
 use common_planners::BroadcastPlan;
 use common_planners::Expression;
+use common_planners::JoinPlan;
 use common_planners::LimitByPlan;
 use common_planners::LimitPlan;
 use common_planners::PlanBuilder;
@@ -31,6 +32,8 @@ use common_planners::ReadDataSourcePlan;
 use common_planners::SortPlan;
 use common_planners::StageKind;
 use common_planners::StagePlan;
+use common_planners::UnionAllPlan;
+use common_planners::WindowFuncPlan;
 
 use crate::optimizers::Optimizer;
 use crate::sessions::QueryContext;
@@ -174,6 +177,41 @@ impl ScattersOptimizerImpl {
         }
     }
 
+    fn cluster_window_func(&mut self, plan: &WindowFuncPlan) -> Result<PlanNode> {
+        // Window function needs all the rows of a partition, we convergent it in local node
+        self.running_mode = RunningMode::Standalone;
+
+        match self.input.take() {
+            None => Err(ErrorCode::LogicalError(
+                "Cluster window function input is None.",
+            )),
+            Some(input) => Self::convergent_shuffle_stage_builder(input)
+                .window_func(plan.window_func.clone())?
+                .build(),
+        }
+    }
+
+    fn standalone_window_func(&mut self, plan: &WindowFuncPlan) -> Result<PlanNode> {
+        match self.input.take() {
+            None => Err(ErrorCode::LogicalError(
+                "Standalone window function input is None.",
+            )),
+            Some(input) => PlanBuilder::from(input.as_ref())
+                .window_func(plan.window_func.clone())?
+                .build(),
+        }
+    }
+
+    fn converge_join_input(&mut self, input: PlanNode) -> Result<PlanNode> {
+        match self.running_mode {
+            RunningMode::Standalone => Ok(input),
+            RunningMode::Cluster => {
+                self.running_mode = RunningMode::Standalone;
+                Self::convergent_shuffle_stage(input)
+            }
+        }
+    }
+
     fn convergent_shuffle_stage_builder(input: Arc<PlanNode>) -> PlanBuilder {
         PlanBuilder::from(&PlanNode::Stage(StagePlan {
             kind: StageKind::Convergent,
@@ -278,6 +316,47 @@ impl PlanRewriter for ScattersOptimizerImpl {
         }
     }
 
+    fn rewrite_window_func(&mut self, plan: &WindowFuncPlan) -> Result<PlanNode> {
+        self.input = Some(Arc::new(self.rewrite_plan_node(plan.input.as_ref())?));
+
+        match self.running_mode {
+            RunningMode::Cluster => self.cluster_window_func(plan),
+            RunningMode::Standalone => self.standalone_window_func(plan),
+        }
+    }
+
+    fn rewrite_join(&mut self, plan: &JoinPlan) -> Result<PlanNode> {
+        // Join we convergent both inputs in local node
+        let new_left = self.rewrite_plan_node(plan.left.as_ref())?;
+        let new_left = self.converge_join_input(new_left)?;
+
+        let join_ctx = QueryContext::new(self.ctx.clone());
+        let mut right_optimizer = ScattersOptimizerImpl::create(join_ctx);
+        let new_right = right_optimizer.rewrite_plan_node(plan.right.as_ref())?;
+        let new_right = right_optimizer.converge_join_input(new_right)?;
+
+        let mut new_plan = plan.clone();
+        new_plan.set_left(&new_left);
+        new_plan.set_right(&new_right);
+        Ok(PlanNode::Join(new_plan))
+    }
+
+    fn rewrite_union_all(&mut self, plan: &UnionAllPlan) -> Result<PlanNode> {
+        // Union all we convergent both inputs in local node
+        let new_left = self.rewrite_plan_node(plan.left.as_ref())?;
+        let new_left = self.converge_join_input(new_left)?;
+
+        let union_ctx = QueryContext::new(self.ctx.clone());
+        let mut right_optimizer = ScattersOptimizerImpl::create(union_ctx);
+        let new_right = right_optimizer.rewrite_plan_node(plan.right.as_ref())?;
+        let new_right = right_optimizer.converge_join_input(new_right)?;
+
+        let mut new_plan = plan.clone();
+        new_plan.set_left(&new_left);
+        new_plan.set_right(&new_right);
+        Ok(PlanNode::UnionAll(new_plan))
+    }
+
     fn rewrite_read_data_source(&mut self, plan: &ReadDataSourcePlan) -> Result<PlanNode> {
         let t = self.ctx.build_table_from_source_plan(plan)?;
 
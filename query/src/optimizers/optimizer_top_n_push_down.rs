@@ -77,6 +77,16 @@ impl PlanRewriter for TopNPushDownImpl {
         }
     }
 
+    fn rewrite_window_func(&mut self, plan: &WindowFuncPlan) -> Result<PlanNode> {
+        // The window function requires all the rows, we clear the top n option.
+        self.limit = None;
+
+        let new_input = self.rewrite_plan_node(&plan.input)?;
+        PlanBuilder::from(&new_input)
+            .window_func(plan.window_func.clone())?
+            .build()
+    }
+
     fn rewrite_limit(&mut self, plan: &LimitPlan) -> Result<PlanNode> {
         let current_limit = self.limit;
         let current_order_by = self.order_by.clone();
@@ -133,6 +143,29 @@ impl PlanRewriter for TopNPushDownImpl {
         optimizer.optimize(subquery_plan)
     }
 
+    fn rewrite_join(&mut self, plan: &JoinPlan) -> Result<PlanNode> {
+        // The top n of the join cannot be pushed down to either input.
+        let new_left = TopNPushDownImpl::new().rewrite_plan_node(plan.left.as_ref())?;
+        let new_right = TopNPushDownImpl::new().rewrite_plan_node(plan.right.as_ref())?;
+
+        let mut new_plan = plan.clone();
+        new_plan.set_left(&new_left);
+        new_plan.set_right(&new_right);
+        Ok(PlanNode::Join(new_plan))
+    }
+
+    fn rewrite_union_all(&mut self, plan: &UnionAllPlan) -> Result<PlanNode> {
+        // The top n of the union all cannot be pushed down to either input,
+        // the sort columns are named after the left input.
+        let new_left = TopNPushDownImpl::new().rewrite_plan_node(plan.left.as_ref())?;
+        let new_right = TopNPushDownImpl::new().rewrite_plan_node(plan.right.as_ref())?;
+
+        let mut new_plan = plan.clone();
+        new_plan.set_left(&new_left);
+        new_plan.set_right(&new_right);
+        Ok(PlanNode::UnionAll(new_plan))
+    }
+
     fn rewrite_sort(&mut self, plan: &SortPlan) -> Result<PlanNode> {
         if self.limit.is_some() {
             self.order_by = plan.order_by.clone();
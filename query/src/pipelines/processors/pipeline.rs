@@ -115,6 +115,28 @@ impl Pipeline {
         Ok(())
     }
 
+    /// Merge the processors of this pipeline and the other pipeline into one-way.
+    ///
+    /// processor1 --
+    ///               \
+    /// processor2      --> processor
+    ///               /
+    /// other_processor1 --
+    ///
+    pub fn union_pipeline(&mut self, other: Pipeline) -> Result<()> {
+        let mut processors = self.last_pipe()?.processors();
+        processors.extend(other.last_pipe()?.processors());
+
+        let mut merge = MergeProcessor::create(self.ctx.clone());
+        for x in processors {
+            merge.connect_to(x)?;
+        }
+        let mut new_pipe = Pipe::create();
+        new_pipe.add(Arc::from(merge));
+        self.pipes.push(new_pipe);
+        Ok(())
+    }
+
     /// Mixed M processors into N processes.
     ///
     /// processor1 --          processor1
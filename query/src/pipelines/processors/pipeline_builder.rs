@@ -21,6 +21,7 @@ use common_planners::BroadcastPlan;
 use common_planners::ExpressionPlan;
 use common_planners::FilterPlan;
 use common_planners::HavingPlan;
+use common_planners::JoinPlan;
 use common_planners::LimitByPlan;
 use common_planners::LimitPlan;
 use common_planners::PlanNode;
@@ -32,6 +33,8 @@ use common_planners::SinkPlan;
 use common_planners::SortPlan;
 use common_planners::StagePlan;
 use common_planners::SubQueriesSetPlan;
+use common_planners::UnionAllPlan;
+use common_planners::WindowFuncPlan;
 use common_tracing::tracing;
 
 use crate::api::FlightTicket;
@@ -42,6 +45,8 @@ use crate::pipelines::transforms::CreateSetsTransform;
 use crate::pipelines::transforms::ExpressionTransform;
 use crate::pipelines::transforms::GroupByFinalTransform;
 use crate::pipelines::transforms::GroupByPartialTransform;
+use crate::pipelines::transforms::HashJoinBuildSide;
+use crate::pipelines::transforms::HashJoinTransform;
 use crate::pipelines::transforms::HavingTransform;
 use crate::pipelines::transforms::LimitByTransform;
 use crate::pipelines::transforms::LimitTransform;
@@ -53,6 +58,7 @@ use crate::pipelines::transforms::SortPartialTransform;
 use crate::pipelines::transforms::SourceTransform;
 use crate::pipelines::transforms::SubQueriesPuller;
 use crate::pipelines::transforms::WhereTransform;
+use crate::pipelines::transforms::WindowFuncTransform;
 use crate::sessions::QueryContext;
 
 pub struct PipelineBuilder {
@@ -92,8 +98,11 @@ impl PipelineBuilder {
             PlanNode::Filter(node) => self.visit_filter(node),
             PlanNode::Having(node) => self.visit_having(node),
             PlanNode::Sort(node) => self.visit_sort(node),
+            PlanNode::Join(node) => self.visit_join(node),
+            PlanNode::UnionAll(node) => self.visit_union_all(node),
             PlanNode::Limit(node) => self.visit_limit(node),
             PlanNode::LimitBy(node) => self.visit_limit_by(node),
+            PlanNode::WindowFunc(node) => self.visit_window_func(node),
             PlanNode::ReadSource(node) => self.visit_read_data_source(node),
             PlanNode::SubQueryExpression(node) => self.visit_create_sets(node),
             PlanNode::Sink(node) => self.visit_sink(node),
@@ -284,6 +293,33 @@ impl PipelineBuilder {
         Ok(pipeline)
     }
 
+    fn visit_join(&mut self, plan: &JoinPlan) -> Result<Pipeline> {
+        let mut pipeline = self.visit(&*plan.left)?;
+
+        // The unmatched rows of the build side are known after all the probe side rows are joined.
+        if plan.join_type.keep_right_unmatched() {
+            pipeline.merge_processor()?;
+        }
+
+        let build_side = HashJoinBuildSide::create(self.ctx.clone(), plan);
+        pipeline.add_simple_transform(|| {
+            Ok(Box::new(HashJoinTransform::try_create(
+                plan,
+                build_side.clone(),
+            )?))
+        })?;
+        Ok(pipeline)
+    }
+
+    fn visit_union_all(&mut self, plan: &UnionAllPlan) -> Result<Pipeline> {
+        // Both sides are built with their own builders, the limit of the outer query
+        // must not be pushed down to the sides.
+        let mut pipeline = PipelineBuilder::create(self.ctx.clone()).build(&plan.left)?;
+        let right_pipeline = PipelineBuilder::create(self.ctx.clone()).build(&plan.right)?;
+        pipeline.union_pipeline(right_pipeline)?;
+        Ok(pipeline)
+    }
+
     fn visit_limit(&mut self, node: &LimitPlan) -> Result<Pipeline> {
         self.limit = node.n;
         self.offset = node.offset;
@@ -308,6 +344,19 @@ impl PipelineBuilder {
         Ok(pipeline)
     }
 
+    fn visit_window_func(&mut self, node: &WindowFuncPlan) -> Result<Pipeline> {
+        let mut pipeline = self.visit(&*node.input)?;
+        pipeline.merge_processor()?;
+        pipeline.add_simple_transform(|| {
+            Ok(Box::new(WindowFuncTransform::try_create(
+                node.window_func.clone(),
+                node.schema(),
+                node.input.schema(),
+            )?))
+        })?;
+        Ok(pipeline)
+    }
+
     fn visit_read_data_source(&mut self, plan: &ReadDataSourcePlan) -> Result<Pipeline> {
         // Bind plan partitions to context.
         self.ctx.try_set_partitions(plan.parts.clone())?;
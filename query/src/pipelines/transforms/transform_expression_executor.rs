@@ -20,8 +20,11 @@ use common_datavalues::columns::DataColumn;
 use common_datavalues::prelude::DataColumnWithField;
 use common_datavalues::DataField;
 use common_datavalues::DataSchemaRef;
+use common_datavalues::DataSchemaRefExt;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_functions::scalars::ArrayJoinFunction;
+use common_functions::scalars::ArrayLambdaFunction;
 use common_planners::Expression;
 use common_planners::ExpressionAction;
 use common_planners::ExpressionChain;
@@ -87,7 +90,7 @@ impl ExpressionExecutor {
             column_map.insert(f.name(), column);
         }
 
-        let rows = block.num_rows();
+        let mut rows = block.num_rows();
         for action in self.chain.actions.iter() {
             if let ExpressionAction::Alias(alias) = action {
                 if let Some(v) = alias_action_map.get_mut(alias.arg_name.as_str()) {
@@ -147,6 +150,70 @@ impl ExpressionExecutor {
 
                     column_map.insert(constant.name.as_str(), column);
                 }
+                ExpressionAction::ArrayJoin(f) => {
+                    let column = column_map.get(f.arg_names[0].as_str()).ok_or_else(|| {
+                        ErrorCode::LogicalError("Arguments must be prepared before array join")
+                    })?;
+
+                    // Each item becomes a row, the columns computed so far are replicated.
+                    let (items, indices) = ArrayJoinFunction::unnest(column.column(), rows)?;
+                    for column in column_map.values_mut() {
+                        let replicated = match column.column() {
+                            DataColumn::Array(array) => {
+                                DataColumn::Array(array.take_iter(&mut indices.iter().copied())?)
+                            }
+                            DataColumn::Constant(_, _) => {
+                                column.column().resize_constant(indices.len())
+                            }
+                        };
+                        *column = DataColumnWithField::new(replicated, column.field().clone());
+                    }
+                    rows = indices.len();
+
+                    let column = DataColumnWithField::new(
+                        items.into(),
+                        DataField::new(&f.name, f.return_type.clone(), f.is_nullable),
+                    );
+                    column_map.insert(f.name.as_str(), column);
+                }
+                ExpressionAction::Lambda(l) => {
+                    let mut arrays = Vec::with_capacity(l.arg_names.len());
+                    for arg in l.arg_names.iter() {
+                        let column = column_map.get(arg.as_str()).ok_or_else(|| {
+                            ErrorCode::LogicalError(
+                                "Arguments must be prepared before lambda function",
+                            )
+                        })?;
+                        arrays.push(column.column().clone());
+                    }
+
+                    // The lambda body is evaluated over the items of the arrays.
+                    let func = ArrayLambdaFunction::try_create(&l.func_name)?;
+                    let params = func
+                        .lambda_arguments(&arrays, rows)?
+                        .into_iter()
+                        .map(DataColumn::Array)
+                        .collect::<Vec<_>>();
+                    let params_block = DataBlock::create(l.lambda_schema.clone(), params);
+
+                    let body_field = l.body.to_data_field(&l.lambda_schema)?;
+                    let body_executor = ExpressionExecutor::try_create(
+                        "lambda executor",
+                        l.lambda_schema.clone(),
+                        DataSchemaRefExt::create(vec![body_field.clone()]),
+                        vec![l.body.clone()],
+                        false,
+                    )?;
+                    let body_block = body_executor.execute(&params_block)?;
+                    let lambda_result =
+                        DataColumnWithField::new(body_block.column(0).clone(), body_field);
+
+                    let column = DataColumnWithField::new(
+                        func.eval(&arrays, &lambda_result, rows)?,
+                        DataField::new(&l.name, l.return_type.clone(), true),
+                    );
+                    column_map.insert(l.name.as_str(), column);
+                }
                 _ => {}
             }
         }
@@ -0,0 +1,479 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use async_stream::stream;
+use common_datablocks::DataBlock;
+use common_datablocks::HashMethod;
+use common_datablocks::HashMethodSerializer;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_infallible::Mutex;
+use common_planners::Expression;
+use common_planners::JoinPlan;
+use common_planners::JoinType;
+use common_planners::PlanNode;
+use common_streams::SendableDataBlockStream;
+use common_tracing::tracing;
+use futures::future::BoxFuture;
+use futures::future::Shared;
+use futures::FutureExt;
+use futures::StreamExt;
+
+use crate::common::HashMap;
+use crate::pipelines::processors::EmptyProcessor;
+use crate::pipelines::processors::PipelineBuilder;
+use crate::pipelines::processors::Processor;
+use crate::pipelines::transforms::group_by::KeysRef;
+use crate::pipelines::transforms::ExpressionExecutor;
+use crate::sessions::QueryContext;
+
+/// Hash join: the right(build) side is collected into a `JoinHashTable` once and shared by
+/// all the processors of the left(probe) side.
+pub struct HashJoinTransform {
+    joiner: Arc<HashJoiner>,
+    build_side: Arc<Mutex<HashJoinBuildSide>>,
+    input: Arc<dyn Processor>,
+}
+
+impl HashJoinTransform {
+    pub fn try_create(
+        plan: &JoinPlan,
+        build_side: Arc<Mutex<HashJoinBuildSide>>,
+    ) -> Result<HashJoinTransform> {
+        Ok(HashJoinTransform {
+            joiner: Arc::new(HashJoiner::try_create(plan)?),
+            build_side,
+            input: Arc::new(EmptyProcessor::create()),
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl Processor for HashJoinTransform {
+    fn name(&self) -> &str {
+        "HashJoinTransform"
+    }
+
+    fn connect_to(&mut self, input: Arc<dyn Processor>) -> Result<()> {
+        self.input = input;
+        Ok(())
+    }
+
+    fn inputs(&self) -> Vec<Arc<dyn Processor>> {
+        vec![self.input.clone()]
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    async fn execute(&self) -> Result<SendableDataBlockStream> {
+        tracing::debug!("execute...");
+
+        let hash_table_future = self.build_side.lock().take_hash_table()?;
+        let hash_table = hash_table_future.await?;
+
+        let joiner = self.joiner.clone();
+        let mut input_stream = self.input.execute().await?;
+
+        let stream = stream! {
+            let mut build_matched = match joiner.join_type.keep_right_unmatched() {
+                true => vec![false; hash_table.num_rows()],
+                false => vec![],
+            };
+
+            while let Some(block) = input_stream.next().await {
+                let joined = block.and_then(|block| {
+                    joiner.probe(&hash_table, &block, &mut build_matched)
+                });
+
+                match joined {
+                    Ok(None) => continue,
+                    Ok(Some(joined_block)) => yield Ok(joined_block),
+                    Err(cause) => yield Err(cause),
+                }
+            }
+
+            if joiner.join_type.keep_right_unmatched() {
+                match joiner.build_unmatched(&hash_table, &build_matched) {
+                    Ok(None) => {}
+                    Ok(Some(unmatched_block)) => yield Ok(unmatched_block),
+                    Err(cause) => yield Err(cause),
+                }
+            }
+        };
+
+        Ok(Box::pin(stream))
+    }
+}
+
+type HashTableFuture = Shared<BoxFuture<'static, Result<Arc<JoinHashTable>>>>;
+
+/// Executes the right side of the join in a sub pipeline and builds the hash table.
+pub struct HashJoinBuildSide {
+    ctx: Arc<QueryContext>,
+    plan: PlanNode,
+    keys: Vec<Expression>,
+    hash_table: Option<HashTableFuture>,
+}
+
+impl HashJoinBuildSide {
+    pub fn create(ctx: Arc<QueryContext>, plan: &JoinPlan) -> Arc<Mutex<HashJoinBuildSide>> {
+        Arc::new(Mutex::new(HashJoinBuildSide {
+            ctx,
+            plan: plan.right.as_ref().clone(),
+            keys: plan.right_keys.clone(),
+            hash_table: None,
+        }))
+    }
+
+    pub fn take_hash_table(&mut self) -> Result<HashTableFuture> {
+        match &self.hash_table {
+            Some(hash_table) => Ok(hash_table.clone()),
+            None => {
+                let hash_table = self.init()?;
+                self.hash_table = Some(hash_table.clone());
+                Ok(hash_table)
+            }
+        }
+    }
+
+    fn init(&self) -> Result<HashTableFuture> {
+        let schema = self.plan.schema();
+        let keys_executor = keys_executor("join build keys executor", &schema, &self.keys)?;
+
+        let build_ctx = QueryContext::new(self.ctx.clone());
+        let mut pipeline = PipelineBuilder::create(build_ctx).build(&self.plan)?;
+
+        let hash_table_future = async move {
+            let mut stream = pipeline.execute().await?;
+
+            let mut blocks = vec![];
+            while let Some(block) = stream.next().await {
+                let block = block?;
+                if !block.is_empty() {
+                    blocks.push(block);
+                }
+            }
+
+            let block = match blocks.is_empty() {
+                true => DataBlock::empty_with_schema(schema),
+                false => DataBlock::concat_blocks(&blocks)?,
+            };
+
+            Ok(Arc::new(JoinHashTable::try_create(block, keys_executor)?))
+        };
+
+        Ok(hash_table_future.boxed().shared())
+    }
+}
+
+pub struct JoinHashTable {
+    block: DataBlock,
+    // The serialized keys of the block rows, the keys of hash_map point into them.
+    _keys: Vec<Vec<u8>>,
+    // Key to the position of the rows with this key in rows_list.
+    hash_map: HashMap<KeysRef, usize>,
+    rows_list: Vec<Vec<u32>>,
+}
+
+// The hash table is read only after built, the KeysRef only reference the owned keys.
+unsafe impl Send for JoinHashTable {}
+
+unsafe impl Sync for JoinHashTable {}
+
+impl JoinHashTable {
+    fn try_create(block: DataBlock, keys_executor: Option<ExpressionExecutor>) -> Result<Self> {
+        let mut hash_map = HashMap::<KeysRef, usize>::create();
+        let mut rows_list: Vec<Vec<u32>> = vec![];
+
+        let keys = match keys_executor {
+            None => vec![],
+            Some(executor) => {
+                let (keys, valid) = build_join_keys(&executor, &block)?;
+
+                for (row, key) in keys.iter().enumerate() {
+                    // NULL never equals to any value.
+                    if !valid[row] {
+                        continue;
+                    }
+
+                    let mut inserted = false;
+                    let keys_ref = KeysRef::create(key.as_ptr() as usize, key.len());
+                    let entity = hash_map.insert_key(&keys_ref, &mut inserted);
+
+                    match inserted {
+                        true => {
+                            entity.set_value(rows_list.len());
+                            rows_list.push(vec![row as u32]);
+                        }
+                        false => rows_list[*entity.get_value()].push(row as u32),
+                    }
+                }
+
+                keys
+            }
+        };
+
+        Ok(JoinHashTable {
+            block,
+            _keys: keys,
+            hash_map,
+            rows_list,
+        })
+    }
+
+    pub fn num_rows(&self) -> usize {
+        self.block.num_rows()
+    }
+
+    fn find_rows(&self, key: &[u8]) -> Option<&[u32]> {
+        let keys_ref = KeysRef::create(key.as_ptr() as usize, key.len());
+        self.hash_map
+            .find_key(&keys_ref)
+            .map(|entity| self.rows_list[*entity.get_value()].as_slice())
+    }
+}
+
+struct HashJoiner {
+    join_type: JoinType,
+    schema: DataSchemaRef,
+    left_schema: DataSchemaRef,
+    right_schema: DataSchemaRef,
+    keys_executor: Option<ExpressionExecutor>,
+    predicate_executor: Option<ExpressionExecutor>,
+}
+
+impl HashJoiner {
+    fn try_create(plan: &JoinPlan) -> Result<HashJoiner> {
+        let left_schema = plan.left.schema();
+        let keys_executor =
+            keys_executor("join probe keys executor", &left_schema, &plan.left_keys)?;
+
+        let predicate_executor = match &plan.predicate {
+            None => None,
+            Some(predicate) => {
+                let predicate_field = predicate.to_data_field(&plan.schema)?;
+                Some(ExpressionExecutor::try_create(
+                    "join predicate executor",
+                    plan.schema.clone(),
+                    DataSchemaRefExt::create(vec![predicate_field]),
+                    vec![predicate.clone()],
+                    false,
+                )?)
+            }
+        };
+
+        Ok(HashJoiner {
+            join_type: plan.join_type,
+            schema: plan.schema.clone(),
+            left_schema,
+            right_schema: plan.right.schema(),
+            keys_executor,
+            predicate_executor,
+        })
+    }
+
+    fn probe(
+        &self,
+        hash_table: &JoinHashTable,
+        block: &DataBlock,
+        build_matched: &mut [bool],
+    ) -> Result<Option<DataBlock>> {
+        let rows = block.num_rows();
+        let mut probe_indices = vec![];
+        let mut build_indices = vec![];
+
+        match &self.keys_executor {
+            // Without equi-condition, every pair of rows is a candidate.
+            None => {
+                for row in 0..rows {
+                    for build_row in 0..hash_table.num_rows() {
+                        probe_indices.push(row as u32);
+                        build_indices.push(build_row as u32);
+                    }
+                }
+            }
+            Some(executor) => {
+                let (keys, valid) = build_join_keys(executor, block)?;
+                for (row, key) in keys.iter().enumerate() {
+                    if !valid[row] {
+                        continue;
+                    }
+
+                    if let Some(build_rows) = hash_table.find_rows(key) {
+                        for build_row in build_rows {
+                            probe_indices.push(row as u32);
+                            build_indices.push(*build_row);
+                        }
+                    }
+                }
+            }
+        }
+
+        if let Some(executor) = &self.predicate_executor {
+            if !probe_indices.is_empty() {
+                let joined_block =
+                    self.joined_block(block, &probe_indices, &hash_table.block, &build_indices)?;
+                let predicate = executor.execute(&joined_block)?.column(0).to_array()?;
+                let predicate = predicate.cast_with_type(&DataType::Boolean)?;
+                let predicate = predicate.bool()?.collect_values();
+
+                let mut index = 0;
+                probe_indices.retain(|_| {
+                    index += 1;
+                    predicate[index - 1] == Some(true)
+                });
+
+                let mut index = 0;
+                build_indices.retain(|_| {
+                    index += 1;
+                    predicate[index - 1] == Some(true)
+                });
+            }
+        }
+
+        for build_row in &build_indices {
+            if let Some(matched) = build_matched.get_mut(*build_row as usize) {
+                *matched = true;
+            }
+        }
+
+        let mut blocks = Vec::with_capacity(2);
+        if !probe_indices.is_empty() {
+            let joined_block =
+                self.joined_block(block, &probe_indices, &hash_table.block, &build_indices)?;
+            blocks.push(joined_block);
+        }
+
+        if self.join_type.keep_left_unmatched() {
+            let mut probe_matched = vec![false; rows];
+            for row in &probe_indices {
+                probe_matched[*row as usize] = true;
+            }
+
+            let unmatched = (0..rows as u32)
+                .filter(|row| !probe_matched[*row as usize])
+                .collect::<Vec<_>>();
+
+            if !unmatched.is_empty() {
+                let probe_block = DataBlock::block_take_by_indices(block, &[], &unmatched)?;
+                let mut columns = probe_block.columns().to_vec();
+                columns.extend(Self::null_columns(&self.right_schema, unmatched.len()));
+                blocks.push(DataBlock::create(self.schema.clone(), columns));
+            }
+        }
+
+        match blocks.len() {
+            0 => Ok(None),
+            1 => Ok(Some(blocks.remove(0))),
+            _ => Ok(Some(DataBlock::concat_blocks(&blocks)?)),
+        }
+    }
+
+    fn build_unmatched(
+        &self,
+        hash_table: &JoinHashTable,
+        build_matched: &[bool],
+    ) -> Result<Option<DataBlock>> {
+        let unmatched = (0..hash_table.num_rows() as u32)
+            .filter(|row| !build_matched[*row as usize])
+            .collect::<Vec<_>>();
+
+        if unmatched.is_empty() {
+            return Ok(None);
+        }
+
+        let build_block = DataBlock::block_take_by_indices(&hash_table.block, &[], &unmatched)?;
+        let mut columns = Self::null_columns(&self.left_schema, unmatched.len());
+        columns.extend_from_slice(build_block.columns());
+        Ok(Some(DataBlock::create(self.schema.clone(), columns)))
+    }
+
+    fn joined_block(
+        &self,
+        probe_block: &DataBlock,
+        probe_indices: &[u32],
+        build_block: &DataBlock,
+        build_indices: &[u32],
+    ) -> Result<DataBlock> {
+        let probe_block = DataBlock::block_take_by_indices(probe_block, &[], probe_indices)?;
+        let build_block = DataBlock::block_take_by_indices(build_block, &[], build_indices)?;
+
+        let mut columns = probe_block.columns().to_vec();
+        columns.extend_from_slice(build_block.columns());
+        Ok(DataBlock::create(self.schema.clone(), columns))
+    }
+
+    fn null_columns(schema: &DataSchemaRef, rows: usize) -> Vec<DataColumn> {
+        schema
+            .fields()
+            .iter()
+            .map(|field| DataColumn::Constant(DataValue::from(field.data_type()), rows))
+            .collect()
+    }
+}
+
+fn keys_executor(
+    description: &str,
+    schema: &DataSchemaRef,
+    keys: &[Expression],
+) -> Result<Option<ExpressionExecutor>> {
+    if keys.is_empty() {
+        return Ok(None);
+    }
+
+    let mut fields = Vec::with_capacity(keys.len());
+    for key in keys {
+        fields.push(key.to_data_field(schema)?);
+    }
+
+    Ok(Some(ExpressionExecutor::try_create(
+        description,
+        schema.clone(),
+        DataSchemaRefExt::create(fields),
+        keys.to_vec(),
+        false,
+    )?))
+}
+
+// Serialize the keys of each row, the row is invalid if any key is NULL.
+fn build_join_keys(
+    executor: &ExpressionExecutor,
+    block: &DataBlock,
+) -> Result<(Vec<Vec<u8>>, Vec<bool>)> {
+    let rows = block.num_rows();
+    let keys_block = executor.execute(block)?;
+
+    let mut valid = vec![true; rows];
+    let mut keys_columns = Vec::with_capacity(keys_block.num_columns());
+    for column in keys_block.columns() {
+        let series = column.to_array()?;
+        if series.null_count() > 0 {
+            for (row, row_valid) in valid.iter_mut().enumerate() {
+                if series.is_null(row) {
+                    *row_valid = false;
+                }
+            }
+        }
+
+        keys_columns.push(column);
+    }
+
+    let keys = HashMethodSerializer::default().build_keys(&keys_columns, rows)?;
+    Ok((keys, valid))
+}
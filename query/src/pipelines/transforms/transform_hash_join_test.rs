@@ -0,0 +1,122 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::tokio;
+use common_exception::Result;
+use common_planners::*;
+use futures::TryStreamExt;
+use pretty_assertions::assert_eq;
+
+use crate::pipelines::processors::*;
+use crate::pipelines::transforms::*;
+
+fn create_join_plan(
+    test_source: &crate::tests::NumberTestData,
+    join_type: JoinType,
+) -> Result<JoinPlan> {
+    let left = PlanNode::ReadSource(test_source.number_read_source_plan_for_test(8)?);
+    let right = PlanBuilder::from(&PlanNode::ReadSource(
+        test_source.number_read_source_plan_for_test(4)?,
+    ))
+    .project(&[col("number").alias("r")])?
+    .build()?;
+
+    match PlanBuilder::from(&left)
+        .join(&right, join_type, Some(col("number").eq(col("r"))))?
+        .build()?
+    {
+        PlanNode::Join(plan) => Ok(plan),
+        _ => unreachable!(),
+    }
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_transform_inner_hash_join() -> Result<()> {
+    let ctx = crate::tests::create_query_context()?;
+    let test_source = crate::tests::NumberTestData::create(ctx.clone());
+    let plan = create_join_plan(&test_source, JoinType::Inner)?;
+
+    let mut pipeline = Pipeline::create(ctx.clone());
+    let a = test_source.number_source_transform_for_test(8)?;
+    pipeline.add_source(Arc::new(a))?;
+
+    let build_side = HashJoinBuildSide::create(ctx.clone(), &plan);
+    pipeline.add_simple_transform(|| {
+        Ok(Box::new(HashJoinTransform::try_create(
+            &plan,
+            build_side.clone(),
+        )?))
+    })?;
+
+    let stream = pipeline.execute().await?;
+    let result = stream.try_collect::<Vec<_>>().await?;
+    let block = &result[0];
+    assert_eq!(block.num_columns(), 2);
+
+    let expected = vec![
+        "+--------+---+",
+        "| number | r |",
+        "+--------+---+",
+        "| 0      | 0 |",
+        "| 1      | 1 |",
+        "| 2      | 2 |",
+        "| 3      | 3 |",
+        "+--------+---+",
+    ];
+    common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_transform_left_hash_join() -> Result<()> {
+    let ctx = crate::tests::create_query_context()?;
+    let test_source = crate::tests::NumberTestData::create(ctx.clone());
+    let plan = create_join_plan(&test_source, JoinType::Left)?;
+
+    let mut pipeline = Pipeline::create(ctx.clone());
+    let a = test_source.number_source_transform_for_test(8)?;
+    pipeline.add_source(Arc::new(a))?;
+
+    let build_side = HashJoinBuildSide::create(ctx.clone(), &plan);
+    pipeline.add_simple_transform(|| {
+        Ok(Box::new(HashJoinTransform::try_create(
+            &plan,
+            build_side.clone(),
+        )?))
+    })?;
+
+    let stream = pipeline.execute().await?;
+    let result = stream.try_collect::<Vec<_>>().await?;
+
+    let expected = vec![
+        "+--------+------+",
+        "| number | r    |",
+        "+--------+------+",
+        "| 0      | 0    |",
+        "| 1      | 1    |",
+        "| 2      | 2    |",
+        "| 3      | 3    |",
+        "| 4      | NULL |",
+        "| 5      | NULL |",
+        "| 6      | NULL |",
+        "| 7      | NULL |",
+        "+--------+------+",
+    ];
+    common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+
+    Ok(())
+}
@@ -0,0 +1,423 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+use std::time::Instant;
+
+use bumpalo::Bump;
+use common_datablocks::DataBlock;
+use common_datablocks::SortColumnDescription;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_functions::aggregates::AggregateFunctionRef;
+use common_functions::aggregates::StateAddr;
+use common_functions::window::WindowFunction;
+use common_planners::Expression;
+use common_planners::WindowFrame;
+use common_planners::WindowFrameBound;
+use common_planners::WindowFrameUnits;
+use common_streams::DataBlockStream;
+use common_streams::SendableDataBlockStream;
+use common_tracing::tracing;
+use futures::TryStreamExt;
+
+use crate::pipelines::processors::EmptyProcessor;
+use crate::pipelines::processors::Processor;
+use crate::pipelines::transforms::get_sort_descriptions;
+
+/// Evaluates a window function:
+/// 1. collects all the input blocks into one block
+/// 2. sorts the block by the partition by and order by columns
+/// 3. evaluates the window function partition by partition, and appends the result column
+pub struct WindowFuncTransform {
+    window_func: Expression,
+    schema: DataSchemaRef,
+    input_schema: DataSchemaRef,
+    input: Arc<dyn Processor>,
+}
+
+impl WindowFuncTransform {
+    pub fn try_create(
+        window_func: Expression,
+        schema: DataSchemaRef,
+        input_schema: DataSchemaRef,
+    ) -> Result<Self> {
+        match &window_func {
+            Expression::WindowFunction { .. } => Ok(WindowFuncTransform {
+                window_func,
+                schema,
+                input_schema,
+                input: Arc::new(EmptyProcessor::create()),
+            }),
+            other => Err(ErrorCode::BadTransformType(format!(
+                "Window expression must be WindowFunction, but got: {:?}",
+                other
+            ))),
+        }
+    }
+
+    fn evaluate(&self, block: DataBlock) -> Result<DataBlock> {
+        let (args, partition_by, order_by, window_frame) = match &self.window_func {
+            Expression::WindowFunction {
+                args,
+                partition_by,
+                order_by,
+                window_frame,
+                ..
+            } => (args, partition_by, order_by, window_frame),
+            _ => unreachable!(),
+        };
+
+        let mut sort_descriptions = partition_by
+            .iter()
+            .map(|expr| SortColumnDescription {
+                column_name: expr.column_name(),
+                asc: true,
+                nulls_first: true,
+            })
+            .collect::<Vec<_>>();
+        sort_descriptions.extend(get_sort_descriptions(&self.input_schema, order_by)?);
+
+        let block = match sort_descriptions.is_empty() {
+            true => block,
+            false => DataBlock::sort_block(&block, &sort_descriptions, None)?,
+        };
+
+        let column_of = |expr: &Expression| block.try_array_by_name(&expr.column_name());
+        let arg_columns = args.iter().map(column_of).collect::<Result<Vec<_>>>()?;
+        let partition_columns = partition_by
+            .iter()
+            .map(column_of)
+            .collect::<Result<Vec<_>>>()?;
+        let mut peer_columns = partition_columns.clone();
+        for expr in order_by {
+            match expr {
+                Expression::Sort { expr, .. } => peer_columns.push(column_of(expr)?),
+                _ => peer_columns.push(column_of(expr)?),
+            }
+        }
+
+        let rows = block.num_rows();
+        let partitions = WindowPartitions::new(
+            Self::value_changes(&partition_columns, rows)?,
+            Self::value_changes(&peer_columns, rows)?,
+        );
+
+        let func = self.window_func.to_window_function(&self.input_schema)?;
+        let frame =
+            window_frame.unwrap_or_else(|| WindowFrame::default_frame(!order_by.is_empty()));
+        let values = match &func {
+            WindowFunction::Aggregate(func) => {
+                Self::evaluate_aggregate(func, &arg_columns, &partitions, &frame)?
+            }
+            _ => Self::evaluate_non_aggregate(&func, &arg_columns, &partitions, &frame)?,
+        };
+
+        let mut columns = block.columns().to_vec();
+        let return_type = func.return_type()?;
+        columns.push(DataColumn::Array(Self::values_to_series(
+            &values,
+            &return_type,
+        )?));
+        Ok(DataBlock::create(self.schema.clone(), columns))
+    }
+
+    fn evaluate_non_aggregate(
+        func: &WindowFunction,
+        arg_columns: &[Series],
+        partitions: &WindowPartitions,
+        frame: &WindowFrame,
+    ) -> Result<Vec<DataValue>> {
+        let mut values = Vec::with_capacity(partitions.rows());
+        for (start, end) in partitions.partitions() {
+            let mut dense_rank = 0;
+            for row in start..end {
+                if partitions.peer_starts[row] {
+                    dense_rank += 1;
+                }
+
+                let value = match func {
+                    WindowFunction::RowNumber => DataValue::UInt64(Some((row - start + 1) as u64)),
+                    WindowFunction::Rank => {
+                        let (peer_start, _) = partitions.peers_of(row);
+                        DataValue::UInt64(Some((peer_start - start + 1) as u64))
+                    }
+                    WindowFunction::DenseRank => DataValue::UInt64(Some(dense_rank)),
+                    WindowFunction::Lag {
+                        offset, default, ..
+                    } => match row >= start + offset {
+                        true => arg_columns[0].try_get(row - offset)?,
+                        false => default.clone(),
+                    },
+                    WindowFunction::Lead {
+                        offset, default, ..
+                    } => match row + offset < end {
+                        true => arg_columns[0].try_get(row + offset)?,
+                        false => default.clone(),
+                    },
+                    WindowFunction::FirstValue { data_type } => {
+                        let (frame_start, frame_end) =
+                            partitions.frame_of(row, start, end, frame)?;
+                        match frame_start < frame_end {
+                            true => arg_columns[0].try_get(frame_start)?,
+                            false => DataValue::from(data_type),
+                        }
+                    }
+                    WindowFunction::LastValue { data_type } => {
+                        let (frame_start, frame_end) =
+                            partitions.frame_of(row, start, end, frame)?;
+                        match frame_start < frame_end {
+                            true => arg_columns[0].try_get(frame_end - 1)?,
+                            false => DataValue::from(data_type),
+                        }
+                    }
+                    WindowFunction::Aggregate(_) => unreachable!(),
+                };
+                values.push(value);
+            }
+        }
+        Ok(values)
+    }
+
+    fn evaluate_aggregate(
+        func: &AggregateFunctionRef,
+        arg_columns: &[Series],
+        partitions: &WindowPartitions,
+        frame: &WindowFrame,
+    ) -> Result<Vec<DataValue>> {
+        let arena = Bump::new();
+        let new_state = || {
+            let place: StateAddr = arena.alloc_layout(func.state_layout()).into();
+            func.init_state(place);
+            place
+        };
+
+        let mut values = Vec::with_capacity(partitions.rows());
+        for (start, end) in partitions.partitions() {
+            // The state of the frame [state_start, state_end), it is reused if the frame
+            // of the next row starts at the same row and does not shrink, which is the case
+            // of the default frame, so that the rows are accumulated only once.
+            let mut place = new_state();
+            let mut state_start = start;
+            let mut state_end = start;
+
+            for row in start..end {
+                let (frame_start, frame_end) = partitions.frame_of(row, start, end, frame)?;
+                let frame_end = std::cmp::max(frame_start, frame_end);
+                if frame_start != state_start || frame_end < state_end {
+                    place = new_state();
+                    state_start = frame_start;
+                    state_end = frame_start;
+                }
+
+                if frame_end > state_end {
+                    let length = frame_end - state_end;
+                    let arrays = arg_columns
+                        .iter()
+                        .map(|column| column.slice(state_end, length))
+                        .collect::<Vec<_>>();
+                    func.accumulate(place, &arrays, length)?;
+                    state_end = frame_end;
+                }
+
+                values.push(func.merge_result(place)?);
+            }
+        }
+        Ok(values)
+    }
+
+    // Marks the rows whose values differ from the previous row.
+    fn value_changes(columns: &[Series], rows: usize) -> Result<Vec<bool>> {
+        let mut changes = vec![false; rows];
+        if rows > 0 {
+            changes[0] = true;
+        }
+
+        for column in columns {
+            let mut prev = match rows {
+                0 => continue,
+                _ => column.try_get(0)?,
+            };
+            for (row, change) in changes.iter_mut().enumerate().skip(1) {
+                let value = column.try_get(row)?;
+                if value != prev {
+                    *change = true;
+                }
+                prev = value;
+            }
+        }
+        Ok(changes)
+    }
+
+    fn values_to_series(values: &[DataValue], data_type: &DataType) -> Result<Series> {
+        let values = values
+            .iter()
+            .map(|value| match value {
+                v if v.is_null() => Ok(DataValue::from(data_type)),
+                v if &v.data_type() == data_type => Ok(v.clone()),
+                v => v.to_array()?.cast_with_type(data_type)?.try_get(0),
+            })
+            .collect::<Result<Vec<_>>>()?;
+        DataValue::try_into_data_array(&values, data_type)
+    }
+}
+
+// The partitions and the peers(rows with the same partition by and order by values)
+// of the sorted block.
+struct WindowPartitions {
+    partition_starts: Vec<bool>,
+    peer_starts: Vec<bool>,
+    // The [start, end) of the peers of each row.
+    peers: Vec<(usize, usize)>,
+}
+
+impl WindowPartitions {
+    // The peers never cross the partitions, as the partition by columns are compared as well
+    // when telling the peers apart.
+    fn new(partition_starts: Vec<bool>, peer_starts: Vec<bool>) -> Self {
+        let rows = peer_starts.len();
+        let mut peers = Vec::with_capacity(rows);
+        let mut start = 0;
+        for row in 1..=rows {
+            if row == rows || peer_starts[row] {
+                peers.extend(std::iter::repeat((start, row)).take(row - start));
+                start = row;
+            }
+        }
+        WindowPartitions {
+            partition_starts,
+            peer_starts,
+            peers,
+        }
+    }
+
+    fn rows(&self) -> usize {
+        self.partition_starts.len()
+    }
+
+    // The [start, end) of each partition.
+    fn partitions(&self) -> Vec<(usize, usize)> {
+        let mut partitions = vec![];
+        let mut start = 0;
+        for row in 1..self.rows() {
+            if self.partition_starts[row] {
+                partitions.push((start, row));
+                start = row;
+            }
+        }
+        if self.rows() > 0 {
+            partitions.push((start, self.rows()));
+        }
+        partitions
+    }
+
+    // The [start, end) of the peers of the row.
+    fn peers_of(&self, row: usize) -> (usize, usize) {
+        self.peers[row]
+    }
+
+    // The [start, end) of the window frame of the row, it may be empty(start >= end).
+    fn frame_of(
+        &self,
+        row: usize,
+        partition_start: usize,
+        partition_end: usize,
+        frame: &WindowFrame,
+    ) -> Result<(usize, usize)> {
+        let (start, end) = (partition_start as i64, partition_end as i64);
+        let bound_of = |bound: &WindowFrameBound, is_start: bool| -> Result<i64> {
+            let current = row as i64;
+            Ok(match (frame.units, bound) {
+                (_, WindowFrameBound::Preceding(None)) => start,
+                (_, WindowFrameBound::Following(None)) => end,
+                (WindowFrameUnits::Rows, WindowFrameBound::CurrentRow) => match is_start {
+                    true => current,
+                    false => current + 1,
+                },
+                (WindowFrameUnits::Rows, WindowFrameBound::Preceding(Some(n))) => match is_start {
+                    true => current - *n as i64,
+                    false => current - *n as i64 + 1,
+                },
+                (WindowFrameUnits::Rows, WindowFrameBound::Following(Some(n))) => match is_start {
+                    true => current + *n as i64,
+                    false => current + *n as i64 + 1,
+                },
+                (WindowFrameUnits::Range, WindowFrameBound::CurrentRow) => {
+                    let (peer_start, peer_end) = self.peers_of(row);
+                    match is_start {
+                        true => peer_start as i64,
+                        false => peer_end as i64,
+                    }
+                }
+                (WindowFrameUnits::Range, _) => {
+                    return Err(ErrorCode::UnImplement(format!(
+                        "Unsupported window frame: {}, RANGE frame with offset is not supported yet",
+                        frame
+                    )));
+                }
+            })
+        };
+
+        let frame_start = bound_of(&frame.start_bound, true)?.clamp(start, end);
+        let frame_end = bound_of(&frame.end_bound, false)?.clamp(start, end);
+        Ok((frame_start as usize, frame_end as usize))
+    }
+}
+
+#[async_trait::async_trait]
+impl Processor for WindowFuncTransform {
+    fn name(&self) -> &str {
+        "WindowFuncTransform"
+    }
+
+    fn connect_to(&mut self, input: Arc<dyn Processor>) -> Result<()> {
+        self.input = input;
+        Ok(())
+    }
+
+    fn inputs(&self) -> Vec<Arc<dyn Processor>> {
+        vec![self.input.clone()]
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    async fn execute(&self) -> Result<SendableDataBlockStream> {
+        tracing::debug!("execute...");
+        let start = Instant::now();
+
+        let stream = self.input.execute().await?;
+        let blocks = stream
+            .try_filter(|block| futures::future::ready(!block.is_empty()))
+            .try_collect::<Vec<_>>()
+            .await?;
+
+        let blocks = match blocks.is_empty() {
+            true => vec![],
+            false => vec![self.evaluate(DataBlock::concat_blocks(&blocks)?)?],
+        };
+
+        let delta = start.elapsed();
+        tracing::debug!("Window function cost: {:?}", delta);
+
+        Ok(Box::pin(DataBlockStream::create(
+            self.schema.clone(),
+            None,
+            blocks,
+        )))
+    }
+}
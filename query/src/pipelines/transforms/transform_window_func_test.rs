@@ -0,0 +1,139 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::tokio;
+use common_exception::Result;
+use common_planners::*;
+use futures::TryStreamExt;
+use pretty_assertions::assert_eq;
+
+use crate::pipelines::processors::*;
+use crate::pipelines::transforms::*;
+
+fn sort_desc(name: &str) -> Expression {
+    Expression::Sort {
+        expr: Box::new(col(name)),
+        asc: false,
+        nulls_first: false,
+        origin_expr: Box::new(col(name)),
+    }
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_transform_window_func() -> Result<()> {
+    let ctx = crate::tests::create_query_context()?;
+    let test_source = crate::tests::NumberTestData::create(ctx.clone());
+
+    let mut pipeline = Pipeline::create(ctx.clone());
+
+    let a = test_source.number_source_transform_for_test(6)?;
+    pipeline.add_source(Arc::new(a))?;
+
+    // row_number() OVER (PARTITION BY (number % 2) ORDER BY number DESC)
+    let row_number = Expression::WindowFunction {
+        op: "row_number".to_string(),
+        params: vec![],
+        args: vec![],
+        partition_by: vec![col("(number % 2)")],
+        order_by: vec![sort_desc("number")],
+        window_frame: None,
+    };
+
+    // sum(number) OVER (PARTITION BY (number % 2) ORDER BY number DESC ROWS BETWEEN 1 PRECEDING AND CURRENT ROW)
+    let sum = Expression::WindowFunction {
+        op: "sum".to_string(),
+        params: vec![],
+        args: vec![col("number")],
+        partition_by: vec![col("(number % 2)")],
+        order_by: vec![sort_desc("number")],
+        window_frame: Some(WindowFrame {
+            units: WindowFrameUnits::Rows,
+            start_bound: WindowFrameBound::Preceding(Some(1)),
+            end_bound: WindowFrameBound::CurrentRow,
+        }),
+    };
+
+    let exprs = vec![modular(col("number"), lit(2u64)), col("number")];
+    let expression_plan = PlanBuilder::create(test_source.number_schema_for_test()?)
+        .expression(&exprs, "")?
+        .build()?;
+    let row_number_plan = PlanBuilder::from(&expression_plan)
+        .window_func(row_number.clone())?
+        .build()?;
+    let sum_plan = PlanBuilder::from(&row_number_plan)
+        .window_func(sum.clone())?
+        .build()?;
+    let projection_exprs = vec![
+        col("number"),
+        col(&row_number.column_name()).alias("rn"),
+        col(&sum.column_name()).alias("s"),
+    ];
+    let projection_plan = PlanBuilder::from(&sum_plan)
+        .project(&projection_exprs)?
+        .build()?;
+
+    pipeline.add_simple_transform(|| {
+        Ok(Box::new(ExpressionTransform::try_create(
+            test_source.number_schema_for_test()?,
+            expression_plan.schema(),
+            exprs.clone(),
+        )?))
+    })?;
+
+    pipeline.merge_processor()?;
+    pipeline.add_simple_transform(|| {
+        Ok(Box::new(WindowFuncTransform::try_create(
+            row_number.clone(),
+            row_number_plan.schema(),
+            expression_plan.schema(),
+        )?))
+    })?;
+    pipeline.add_simple_transform(|| {
+        Ok(Box::new(WindowFuncTransform::try_create(
+            sum.clone(),
+            sum_plan.schema(),
+            row_number_plan.schema(),
+        )?))
+    })?;
+    pipeline.add_simple_transform(|| {
+        Ok(Box::new(ProjectionTransform::try_create(
+            sum_plan.schema(),
+            projection_plan.schema(),
+            projection_exprs.clone(),
+        )?))
+    })?;
+
+    let stream = pipeline.execute().await?;
+    let result = stream.try_collect::<Vec<_>>().await?;
+    let block = &result[0];
+    assert_eq!(block.num_columns(), 3);
+
+    let expected = vec![
+        "+--------+----+---+",
+        "| number | rn | s |",
+        "+--------+----+---+",
+        "| 0      | 3  | 2 |",
+        "| 1      | 3  | 4 |",
+        "| 2      | 2  | 6 |",
+        "| 3      | 2  | 8 |",
+        "| 4      | 1  | 4 |",
+        "| 5      | 1  | 5 |",
+        "+--------+----+---+",
+    ];
+    common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+
+    Ok(())
+}
@@ -22,12 +22,14 @@ use common_clickhouse_srv::connection::Connection;
 use common_clickhouse_srv::errors::Error as CHError;
 use common_clickhouse_srv::errors::Result as CHResult;
 use common_clickhouse_srv::errors::ServerError;
+use common_clickhouse_srv::types::column::datetime64::DateTime64ColumnData;
 use common_clickhouse_srv::types::column::ArcColumnData;
 use common_clickhouse_srv::types::column::ArcColumnWrapper;
 use common_clickhouse_srv::types::column::ColumnFrom;
 use common_clickhouse_srv::types::column::{self};
 use common_clickhouse_srv::types::Block;
 use common_clickhouse_srv::types::DateTimeType;
+use common_clickhouse_srv::types::Decimal;
 use common_clickhouse_srv::types::SqlType;
 use common_datablocks::DataBlock;
 use common_datavalues::prelude::*;
@@ -175,6 +177,14 @@ pub fn to_clickhouse_block(block: DataBlock) -> Result<Block> {
 }
 
 pub fn from_clickhouse_block(schema: DataSchemaRef, block: Block) -> Result<DataBlock> {
+    // The ticks are kept in the precision of the table column.
+    let datetime64_precision = |index: usize, precision: u32| -> u32 {
+        match schema.field(index).data_type() {
+            DataType::DateTime64(precision, _) => *precision,
+            _ => precision,
+        }
+    };
+
     let get_series = |block: &Block, index: usize| -> CHResult<Series> {
         let col = &block.columns()[index];
         match col.sql_type() {
@@ -212,6 +222,18 @@ pub fn from_clickhouse_block(schema: DataSchemaRef, block: Block) -> Result<Data
             SqlType::FixedString(_) => {
                 Ok(DFStringArray::new_from_iter(col.iter::<&[u8]>()?).into_series())
             }
+            SqlType::DateTime(DateTimeType::DateTime64(precision, _)) => {
+                let precision = datetime64_precision(index, precision);
+                Ok(DFInt64Array::new_from_iter(
+                    col.iter::<DateTime<Tz>>()?
+                        .map(|v| datetime64_from_date_time(&v, precision)),
+                )
+                .into_series())
+            }
+            SqlType::Decimal(_, _) => Ok(DFInt64Array::new_from_iter(
+                col.iter::<Decimal>()?.map(|v| v.internal::<i64>()),
+            )
+            .into_series()),
 
             SqlType::Nullable(SqlType::UInt8) => Ok(DFUInt8Array::new_from_opt_iter(
                 col.iter::<Option<u8>>()?.map(|c| c.copied()),
@@ -260,6 +282,19 @@ pub fn from_clickhouse_block(schema: DataSchemaRef, block: Block) -> Result<Data
             SqlType::Nullable(SqlType::FixedString(_)) => {
                 Ok(DFStringArray::new_from_opt_iter(col.iter::<Option<&[u8]>>()?).into_series())
             }
+            SqlType::Nullable(SqlType::DateTime(DateTimeType::DateTime64(precision, _))) => {
+                let precision = datetime64_precision(index, *precision);
+                Ok(DFInt64Array::new_from_opt_iter(
+                    col.iter::<Option<DateTime<Tz>>>()?
+                        .map(|c| c.map(|v| datetime64_from_date_time(&v, precision))),
+                )
+                .into_series())
+            }
+            SqlType::Nullable(SqlType::Decimal(_, _)) => Ok(DFInt64Array::new_from_opt_iter(
+                col.iter::<Option<Decimal>>()?
+                    .map(|c| c.map(|v| v.internal::<i64>())),
+            )
+            .into_series()),
 
             other => Err(CHError::Other(Cow::from(format!(
                 "Unsupported type: {:?}",
@@ -322,6 +357,16 @@ fn to_clickhouse_column(field: &DataField, column: &Series) -> Result<ArcColumnD
 
                 Vec::column_from::<ArcColumnWrapper>(c)
             }
+            DataType::DateTime64(precision, tz) => {
+                let tz = tz.clone();
+                let tz = tz.unwrap_or_else(|| "UTC".to_string());
+                let tz: Tz = tz.parse().unwrap();
+                DateTime64ColumnData::from_opt_ticks::<ArcColumnWrapper>(
+                    column.i64()?.collect_values(),
+                    *precision,
+                    tz,
+                )
+            }
             DataType::UInt64 => {
                 Vec::column_from::<ArcColumnWrapper>(column.u64()?.collect_values())
             }
@@ -331,7 +376,7 @@ fn to_clickhouse_column(field: &DataField, column: &Series) -> Result<ArcColumnD
             DataType::Float64 => {
                 Vec::column_from::<ArcColumnWrapper>(column.f64()?.collect_values())
             }
-            DataType::String => {
+            DataType::String | DataType::Variant => {
                 Vec::column_from::<ArcColumnWrapper>(column.string()?.collect_values())
             }
             DataType::Boolean => {
@@ -343,6 +388,14 @@ fn to_clickhouse_column(field: &DataField, column: &Series) -> Result<ArcColumnD
 
                 Vec::column_from::<ArcColumnWrapper>(v)
             }
+            DataType::Decimal(_, scale) => {
+                let c: Vec<Option<Decimal>> = column
+                    .i64()?
+                    .into_iter()
+                    .map(|x| x.map(|v| Decimal::new(*v, *scale)))
+                    .collect();
+                Vec::column_from::<ArcColumnWrapper>(c)
+            }
             DataType::Struct(fields) => Vec::column_from::<ArcColumnWrapper>(
                 fields
                     .iter()
@@ -413,6 +466,16 @@ fn to_clickhouse_column(field: &DataField, column: &Series) -> Result<ArcColumnD
 
                 Vec::column_from::<ArcColumnWrapper>(c)
             }
+            DataType::DateTime64(precision, tz) => {
+                let tz = tz.clone();
+                let tz = tz.unwrap_or_else(|| "UTC".to_string());
+                let tz: Tz = tz.parse().unwrap();
+                DateTime64ColumnData::from_ticks::<ArcColumnWrapper>(
+                    column.i64()?.inner().values().as_slice().to_vec(),
+                    *precision,
+                    tz,
+                )
+            }
 
             DataType::UInt64 => Vec::column_from::<ArcColumnWrapper>(
                 column.u64()?.inner().values().as_slice().to_vec(),
@@ -423,7 +486,7 @@ fn to_clickhouse_column(field: &DataField, column: &Series) -> Result<ArcColumnD
             DataType::Float64 => Vec::column_from::<ArcColumnWrapper>(
                 column.f64()?.inner().values().as_slice().to_vec(),
             ),
-            DataType::String => {
+            DataType::String | DataType::Variant => {
                 let vs: Vec<&[u8]> = column.string()?.into_no_null_iter().collect();
                 Vec::column_from::<ArcColumnWrapper>(vs)
             }
@@ -438,6 +501,14 @@ fn to_clickhouse_column(field: &DataField, column: &Series) -> Result<ArcColumnD
             DataType::Interval(_) => Vec::column_from::<ArcColumnWrapper>(
                 column.i64()?.inner().values().as_slice().to_vec(),
             ),
+            DataType::Decimal(_, scale) => {
+                let c: Vec<Decimal> = column
+                    .i64()?
+                    .into_no_null_iter()
+                    .map(|v| Decimal::new(*v, *scale))
+                    .collect();
+                Vec::column_from::<ArcColumnWrapper>(c)
+            }
             DataType::Struct(fields) => Vec::column_from::<ArcColumnWrapper>(
                 fields
                     .iter()
@@ -18,8 +18,14 @@ use common_datablocks::DataBlock;
 use common_datavalues::arrays::DFPrimitiveArray;
 use common_datavalues::chrono::TimeZone;
 use common_datavalues::chrono::Utc;
+use common_datavalues::datetime64_to_string;
+use common_datavalues::decimal_to_string;
+use common_datavalues::parse_variant;
+use common_datavalues::series::Series;
 use common_datavalues::DFPrimitiveType;
 use common_datavalues::DataType;
+use common_datavalues::DateConverter;
+use common_datavalues::Tz;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use serde::Serialize;
@@ -92,6 +98,31 @@ where
         .collect()
 }
 
+// Lists and maps are strings in the text format like `[1,2]` and `{'a':1}`,
+// since the keys of the maps may be non-string.
+fn nested_array_to_json(series: &Series, data_type: &DataType) -> Result<Vec<JsonValue>> {
+    let serializer = data_type.create_serializer();
+    (0..series.len())
+        .map(|row| {
+            let value = series.try_get(row)?;
+            match value.is_null() {
+                true => Ok(JsonValue::Null),
+                false => Ok(to_json_value(serializer.serialize_value(&value)?)),
+            }
+        })
+        .collect()
+}
+
+// The date times are shown in the timezone of their type, UTC if there is none.
+fn timezone(tz: &Option<String>) -> Result<Tz> {
+    match tz {
+        None => Ok(Tz::UTC),
+        Some(tz) => tz
+            .parse::<Tz>()
+            .map_err(|_| ErrorCode::BadArguments(format!("Unknown timezone: {}", tz))),
+    }
+}
+
 fn bad_type(data_type: &DataType) -> ErrorCode {
     ErrorCode::BadDataValueType(format!("Unsupported column type:{:?}", data_type))
 }
@@ -127,8 +158,40 @@ pub(crate) fn block_to_json(block: &DataBlock) -> Result<Vec<Vec<JsonValue>>> {
                 DataType::Boolean => series.bool()?.into_iter().map(to_json_value).collect(),
                 DataType::Date16 => date_array_to_string_array(series.u16()?, DATE_FMT),
                 DataType::Date32 => date_array_to_string_array(series.i32()?, DATE_FMT),
-                // TODO(youngsofun): add time zone?
-                DataType::DateTime32(_) => date_array_to_string_array(series.i32()?, TIME_FMT),
+                DataType::DateTime32(tz) => {
+                    let tz = timezone(tz)?;
+                    series
+                        .u32()?
+                        .into_iter()
+                        .map(|o| o.map(|x| x.to_date_time(&tz).format(TIME_FMT).to_string()))
+                        .map(to_json_value)
+                        .collect()
+                }
+                DataType::DateTime64(precision, tz) => {
+                    let tz = timezone(tz)?;
+                    series
+                        .i64()?
+                        .into_iter()
+                        .map(|o| o.map(|x| datetime64_to_string(*x, *precision, &tz)))
+                        .map(to_json_value)
+                        .collect()
+                }
+                // Decimals are strings to keep the precision in JSON.
+                DataType::Decimal(_, scale) => series
+                    .i64()?
+                    .into_iter()
+                    .map(|o| o.map(|x| decimal_to_string(*x, *scale)))
+                    .map(to_json_value)
+                    .collect(),
+                DataType::Variant => series
+                    .string()?
+                    .into_iter()
+                    .map(|o| o.map(parse_variant).transpose())
+                    .map(|o| o.map(|v| v.unwrap_or(JsonValue::Null)))
+                    .collect::<Result<Vec<_>>>()?,
+                DataType::List(_) | DataType::Map(_, _) => {
+                    nested_array_to_json(&series, data_type)?
+                }
                 // TODO(youngsofun): support other DataType
                 _ => return Err(bad_type(data_type)),
             },
@@ -156,8 +219,37 @@ pub(crate) fn block_to_json(block: &DataBlock) -> Result<Vec<Vec<JsonValue>>> {
                     .collect(),
                 DataType::Date16 => date_array_to_string_array_not_null(series.u16()?, DATE_FMT),
                 DataType::Date32 => date_array_to_string_array_not_null(series.i32()?, DATE_FMT),
-                DataType::DateTime32(_) => {
-                    date_array_to_string_array_not_null(series.i32()?, TIME_FMT)
+                DataType::DateTime32(tz) => {
+                    let tz = timezone(tz)?;
+                    series
+                        .u32()?
+                        .into_no_null_iter()
+                        .map(|x| x.to_date_time(&tz).format(TIME_FMT).to_string())
+                        .map(to_json_value)
+                        .collect()
+                }
+                DataType::DateTime64(precision, tz) => {
+                    let tz = timezone(tz)?;
+                    series
+                        .i64()?
+                        .into_no_null_iter()
+                        .map(|x| datetime64_to_string(*x, *precision, &tz))
+                        .map(to_json_value)
+                        .collect()
+                }
+                DataType::Decimal(_, scale) => series
+                    .i64()?
+                    .into_no_null_iter()
+                    .map(|x| decimal_to_string(*x, *scale))
+                    .map(to_json_value)
+                    .collect(),
+                DataType::Variant => series
+                    .string()?
+                    .into_no_null_iter()
+                    .map(parse_variant)
+                    .collect::<Result<Vec<_>>>()?,
+                DataType::List(_) | DataType::Map(_, _) => {
+                    nested_array_to_json(&series, data_type)?
                 }
                 _ => return Err(bad_type(data_type)),
             },
@@ -65,3 +65,25 @@ fn test_data_block_nullable() -> Result<()> {
 fn test_data_block_not_nullable() -> Result<()> {
     test_data_block(false)
 }
+
+#[test]
+fn test_data_block_datetime_timezone() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("c1", DataType::DateTime32(None), false),
+        DataField::new(
+            "c2",
+            DataType::DateTime32(Some("Asia/Shanghai".to_string())),
+            true,
+        ),
+    ]);
+
+    let block = DataBlock::create_by_array(schema, vec![
+        Series::new(vec![1638331230_u32]),
+        Series::new(vec![1638331230_u32]),
+    ]);
+    let json_block = block_to_json(&block)?;
+    let expect = vec![vec![val("2021-12-01 04:00:30"), val("2021-12-01 12:00:30")]];
+
+    assert_eq!(json_block, expect);
+    Ok(())
+}
@@ -19,6 +19,7 @@ use async_stream::stream;
 use common_base::ProgressValues;
 use common_planners::PlanNode;
 use common_streams::CsvSource;
+use common_streams::NdJsonSource;
 use common_streams::Source;
 use futures::StreamExt;
 use poem::error::BadRequest;
@@ -69,22 +70,31 @@ pub async fn streaming_load(
     let interpreter = InterpreterFactory::get(context.clone(), plan.clone())?;
 
     context.attach_query_str(insert_sql);
-    if let PlanNode::InsertInto(insert) = &plan {
-        if insert.format.is_none() {
-            return Err(BadRequest(
-                "Streaming load only support csv format, but got none",
-            ));
-        }
+    let format = match &plan {
+        PlanNode::InsertInto(insert) => match &insert.format {
+            Some(format) => format.to_lowercase(),
+            None => {
+                return Err(BadRequest(
+                    "Streaming load only support csv and ndjson format, but got none",
+                ))
+            }
+        },
+        _ => "csv".to_string(),
+    };
 
-        if insert.format.as_ref().unwrap().to_lowercase().as_str() != "csv" {
-            return Err(BadRequest("Streaming load only support csv format"));
-        }
+    if !matches!(format.as_str(), "csv" | "ndjson" | "jsoneachrow") {
+        return Err(BadRequest(
+            "Streaming load only support csv and ndjson format",
+        ));
     }
 
     let stream = stream! {
         while let Ok(Some(field)) = multipart.next_field().await {
             let reader = field.into_async_read();
-            let mut source = CsvSource::try_create(reader.compat(), plan.schema(), csv_header, max_block_size)?;
+            let mut source: Box<dyn Source> = match format.as_str() {
+                "csv" => Box::new(CsvSource::try_create(reader.compat(), plan.schema(), csv_header, max_block_size)?),
+                _ => Box::new(NdJsonSource::try_create(reader.compat(), plan.schema(), max_block_size)?),
+            };
 
             loop {
                 let block = source.read().await;
@@ -72,13 +72,18 @@ impl<'a, W: std::io::Write> DFQueryResultWriter<'a, W> {
                 DataType::UInt64 => Ok(ColumnType::MYSQL_TYPE_LONG),
                 DataType::Float32 => Ok(ColumnType::MYSQL_TYPE_FLOAT),
                 DataType::Float64 => Ok(ColumnType::MYSQL_TYPE_FLOAT),
-                DataType::String => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
+                DataType::String | DataType::Variant => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
                 DataType::Boolean => Ok(ColumnType::MYSQL_TYPE_SHORT),
                 DataType::Date16 | DataType::Date32 => Ok(ColumnType::MYSQL_TYPE_DATE),
-                DataType::DateTime32(_) => Ok(ColumnType::MYSQL_TYPE_DATETIME),
+                DataType::DateTime32(_) | DataType::DateTime64(_, _) => {
+                    Ok(ColumnType::MYSQL_TYPE_DATETIME)
+                }
                 DataType::Null => Ok(ColumnType::MYSQL_TYPE_NULL),
                 DataType::Interval(_) => Ok(ColumnType::MYSQL_TYPE_LONG),
-                DataType::Struct(_) => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
+                DataType::Struct(_) | DataType::List(_) | DataType::Map(_, _) => {
+                    Ok(ColumnType::MYSQL_TYPE_VARCHAR)
+                }
+                DataType::Decimal(_, _) => Ok(ColumnType::MYSQL_TYPE_NEWDECIMAL),
                 _ => Err(ErrorCode::UnImplement(format!(
                     "Unsupported column type:{:?}",
                     field.data_type()
@@ -163,10 +168,15 @@ impl<'a, W: std::io::Write> DFQueryResultWriter<'a, W> {
                                     let tz: Tz = tz.parse().unwrap();
                                     row_writer.write_col(v.to_date_time(&tz).naive_local())?
                                 }
-                                (DataType::String, DataValue::String(Some(v))) => {
+                                (DataType::String, DataValue::String(Some(v)))
+                                | (DataType::Variant, DataValue::String(Some(v))) => {
                                     row_writer.write_col(v)?
                                 }
-                                (DataType::Struct(_), DataValue::Struct(_)) => {
+                                (DataType::Decimal(_, _), DataValue::Int64(Some(_)))
+                                | (DataType::DateTime64(_, _), DataValue::Int64(Some(_)))
+                                | (DataType::Struct(_), DataValue::Struct(_))
+                                | (DataType::List(_), DataValue::List(Some(_), _))
+                                | (DataType::Map(_, _), DataValue::List(Some(_), _)) => {
                                     let serializer = data_type.create_serializer();
                                     row_writer.write_col(serializer.serialize_value(&val)?)?
                                 }
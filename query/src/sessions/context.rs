@@ -51,6 +51,7 @@ use crate::servers::http::v1::query::HttpQueryHandle;
 use crate::sessions::QueryContextShared;
 use crate::sessions::SessionManager;
 use crate::sessions::Settings;
+use crate::storages::MemoryTable;
 use crate::storages::Table;
 
 pub struct QueryContext {
@@ -89,6 +90,17 @@ impl QueryContext {
         &self,
         plan: &ReadDataSourcePlan,
     ) -> Result<Arc<dyn Table>> {
+        if plan.table_info.engine() == MemoryTable::MATERIALIZED_CTE_ENGINE {
+            return self
+                .get_materialized_cte(&plan.table_info.desc)
+                .ok_or_else(|| {
+                    ErrorCode::UnknownTable(format!(
+                        "Unknown materialized CTE: {}",
+                        plan.table_info.name
+                    ))
+                });
+        }
+
         let catalog = self.get_catalog();
 
         if plan.tbl_args.is_none() {
@@ -190,6 +202,17 @@ impl QueryContext {
         self.shared.get_table(database, table).await
     }
 
+    /// Get the table which a CTE of the query is materialized into.
+    pub fn get_materialized_cte(&self, key: &str) -> Option<Arc<dyn Table>> {
+        self.shared.get_materialized_cte(key)
+    }
+
+    /// Share the table which a CTE is materialized into with the whole query,
+    /// returns the table materialized first if the CTE is already materialized.
+    pub fn add_materialized_cte(&self, key: String, table: Arc<dyn Table>) -> Arc<dyn Table> {
+        self.shared.add_materialized_cte(key, table)
+    }
+
     pub fn get_id(&self) -> String {
         self.shared.init_query_id.as_ref().read().clone()
     }
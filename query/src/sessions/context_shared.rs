@@ -62,6 +62,7 @@ pub struct QueryContextShared {
     pub(in crate::sessions) http_query: Arc<RwLock<Option<HttpQueryHandle>>>,
     pub(in crate::sessions) running_plan: Arc<RwLock<Option<PlanNode>>>,
     pub(in crate::sessions) tables_refs: Arc<Mutex<HashMap<DatabaseAndTable, Arc<dyn Table>>>>,
+    pub(in crate::sessions) materialized_ctes: Arc<Mutex<HashMap<String, Arc<dyn Table>>>>,
     pub(in crate::sessions) dal_ctx: Arc<DalContext>,
 }
 
@@ -85,6 +86,7 @@ impl QueryContextShared {
             http_query: Arc::new(RwLock::new(None)),
             running_plan: Arc::new(RwLock::new(None)),
             tables_refs: Arc::new(Mutex::new(HashMap::new())),
+            materialized_ctes: Arc::new(Mutex::new(HashMap::new())),
             dal_ctx: Arc::new(Default::default()),
         })
     }
@@ -145,6 +147,16 @@ impl QueryContextShared {
         }
     }
 
+    pub fn get_materialized_cte(&self, key: &str) -> Option<Arc<dyn Table>> {
+        self.materialized_ctes.lock().get(key).cloned()
+    }
+
+    pub fn add_materialized_cte(&self, key: String, table: Arc<dyn Table>) -> Arc<dyn Table> {
+        // The CTE may be materialized concurrently by the subqueries, the first one wins.
+        let mut materialized_ctes = self.materialized_ctes.lock();
+        materialized_ctes.entry(key).or_insert(table).clone()
+    }
+
     async fn get_table_to_cache(&self, database: &str, table: &str) -> Result<Arc<dyn Table>> {
         let catalog = self.get_catalog();
         let cache_table = catalog.get_table(database, table).await?;
@@ -80,7 +80,7 @@ mod test {
                 ]),
                 SelectTarget::Indirections(vec![Indirection::Star]),
             ],
-            from: TableReference::Join(Join {
+            from: Some(TableReference::Join(Join {
                 op: JoinOperator::Inner,
                 condition: JoinCondition::Natural,
                 left: Box::new(TableReference::Table {
@@ -99,7 +99,7 @@ mod test {
                     },
                     alias: None,
                 }),
-            }),
+            })),
             selection: Some(Expr::BinaryOp {
                 op: BinaryOperator::Eq,
                 left: Box::new(Expr::ColumnRef {
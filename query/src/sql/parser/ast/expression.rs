@@ -145,6 +145,10 @@ pub enum BinaryOperator {
     Or,
     Like,
     NotLike,
+    Regexp,
+    NotRegexp,
+    RLike,
+    NotRLike,
     BitwiseOr,
     BitwiseAnd,
     BitwiseXor,
@@ -227,6 +231,18 @@ impl Display for BinaryOperator {
             BinaryOperator::NotLike => {
                 write!(f, "NOT LIKE")
             }
+            BinaryOperator::Regexp => {
+                write!(f, "REGEXP")
+            }
+            BinaryOperator::NotRegexp => {
+                write!(f, "NOT REGEXP")
+            }
+            BinaryOperator::RLike => {
+                write!(f, "RLIKE")
+            }
+            BinaryOperator::NotRLike => {
+                write!(f, "NOT RLIKE")
+            }
             BinaryOperator::BitwiseOr => {
                 write!(f, "|")
             }
@@ -474,7 +490,7 @@ impl Display for Expr {
                 write!(f, "END")?;
             }
             Expr::Exists(subquery) => {
-                write!(f, "EXITS ({})", subquery)?;
+                write!(f, "EXISTS ({})", subquery)?;
             }
             Expr::Subquery(subquery) => {
                 write!(f, "({})", subquery)?;
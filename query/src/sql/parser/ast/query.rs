@@ -18,6 +18,7 @@ use std::fmt::Formatter;
 use crate::sql::parser::ast::display_identifier_vec;
 use crate::sql::parser::ast::Expr;
 use crate::sql::parser::ast::Identifier;
+use crate::sql::parser::ast::Literal;
 
 // Root node of a query tree
 #[derive(Debug, Clone, PartialEq)]
@@ -28,8 +29,31 @@ pub struct Query {
     // The following clauses can only appear in top level of a subquery/query
     // `ORDER BY` clause
     pub order_by: Vec<OrderByExpr>,
+    // ClickHouse-style `LIMIT n BY expr, ...` clause
+    pub limit_by: Option<LimitBy>,
     // `LIMIT` clause
     pub limit: Option<Expr>,
+    // `OFFSET` clause
+    pub offset: Option<Expr>,
+    // ClickHouse-style `SETTINGS name = value, ...` clause
+    pub settings: Vec<Setting>,
+    // ClickHouse-style `FORMAT name` clause, the output format of the result set
+    pub format: Option<Identifier>,
+}
+
+// `LIMIT [offset,] n BY expr, ...`, keeps at most n rows for each distinct value of the expressions
+#[derive(Debug, Clone, PartialEq)]
+pub struct LimitBy {
+    pub limit: Expr,
+    pub offset: Option<Expr>,
+    pub exprs: Vec<Expr>,
+}
+
+// A setting of the `SETTINGS` clause
+#[derive(Debug, Clone, PartialEq)]
+pub struct Setting {
+    pub name: Identifier,
+    pub value: Literal,
 }
 
 // A relational set expression, like `SELECT ... FROM ... {UNION|EXCEPT|INTERSECT} SELECT ... FROM ...`
@@ -62,7 +86,8 @@ pub struct SelectStmt {
     // `FROM` clause, a list of table references.
     // The table references split by `,` will be joined with cross join,
     // and the result set is union of the joined tables by default.
-    pub from: TableReference,
+    // It's `None` if there is no `FROM` clause, like `SELECT 1`.
+    pub from: Option<TableReference>,
     // `WHERE` clause
     pub selection: Option<Expr>,
     // `GROUP BY` clause
@@ -179,6 +204,29 @@ impl Display for OrderByExpr {
     }
 }
 
+impl Display for LimitBy {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "LIMIT ")?;
+        if let Some(offset) = &self.offset {
+            write!(f, "{}, ", offset)?;
+        }
+        write!(f, "{} BY ", self.limit)?;
+        for i in 0..self.exprs.len() {
+            write!(f, "{}", self.exprs[i])?;
+            if i != self.exprs.len() - 1 {
+                write!(f, ", ")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Display for Setting {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} = {}", self.name, self.value)
+    }
+}
+
 impl Display for TableAlias {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "AS {}", &self.name)?;
@@ -317,7 +365,9 @@ impl Display for SelectStmt {
         }
 
         // FROM clause
-        write!(f, " FROM {}", self.from)?;
+        if let Some(from) = &self.from {
+            write!(f, " FROM {}", from)?;
+        }
 
         // WHERE clause
         if let Some(expr) = &self.selection {
@@ -399,11 +449,37 @@ impl Display for Query {
             }
         }
 
+        // LIMIT BY clause
+        if let Some(limit_by) = &self.limit_by {
+            write!(f, " {}", limit_by)?;
+        }
+
         // LIMIT clause
         if let Some(limit) = &self.limit {
             write!(f, " LIMIT {}", limit)?;
         }
 
+        // OFFSET clause
+        if let Some(offset) = &self.offset {
+            write!(f, " OFFSET {}", offset)?;
+        }
+
+        // SETTINGS clause
+        if !self.settings.is_empty() {
+            write!(f, " SETTINGS ")?;
+            for i in 0..self.settings.len() {
+                write!(f, "{}", self.settings[i])?;
+                if i != self.settings.len() - 1 {
+                    write!(f, ", ")?;
+                }
+            }
+        }
+
+        // FORMAT clause
+        if let Some(format) = &self.format {
+            write!(f, " FORMAT {}", format)?;
+        }
+
         Ok(())
     }
 }
@@ -13,11 +13,13 @@
 // limitations under the License.
 
 pub mod ast;
+pub mod native;
 pub mod transformer;
 
 use common_exception::Result;
 
 use crate::sql::parser::ast::Statement;
+use crate::sql::parser::native::NativeParser;
 use crate::sql::parser::transformer::AstTransformer;
 use crate::sql::parser::transformer::AstTransformerFactory;
 
@@ -26,8 +28,8 @@ pub struct Parser;
 impl Parser {
     // Parse a SQL string into `Statement`s.
     #[allow(unused)]
-    pub fn parse_sql(&self, _: &str) -> Result<Vec<Statement>> {
-        todo!()
+    pub fn parse_sql(&self, sql: &str) -> Result<Vec<Statement>> {
+        NativeParser::try_create(sql)?.parse_statements()
     }
 
     #[allow(unused)]
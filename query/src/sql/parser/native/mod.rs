@@ -0,0 +1,228 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod parse_expr;
+mod parse_query;
+mod parse_statement;
+mod tokenizer;
+
+#[cfg(test)]
+mod native_parser_test;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+pub use tokenizer::Position;
+pub use tokenizer::Token;
+pub use tokenizer::TokenKind;
+pub use tokenizer::Tokenizer;
+
+use crate::sql::parser::ast::Identifier;
+use crate::sql::parser::ast::Statement;
+
+// Keywords which can not be used as an implicit alias or a bare column name,
+// otherwise `SELECT a FROM t WHERE ...` would be ambiguous.
+const RESERVED_KEYWORDS: &[&str] = &[
+    "ALL",
+    "AND",
+    "AS",
+    "BETWEEN",
+    "BY",
+    "CASE",
+    "CROSS",
+    "DISTINCT",
+    "ELSE",
+    "END",
+    "EXCEPT",
+    "EXISTS",
+    "FORMAT",
+    "FROM",
+    "FULL",
+    "GROUP",
+    "HAVING",
+    "IN",
+    "INNER",
+    "INTERSECT",
+    "IS",
+    "JOIN",
+    "LEFT",
+    "LIKE",
+    "LIMIT",
+    "NATURAL",
+    "NOT",
+    "OFFSET",
+    "ON",
+    "OR",
+    "ORDER",
+    "OUTER",
+    "RIGHT",
+    "SELECT",
+    "SETTINGS",
+    "THEN",
+    "UNION",
+    "USING",
+    "WHEN",
+    "WHERE",
+];
+
+// A hand-written recursive descent parser, which produces `Statement`s directly.
+// Errors are reported with the line and the column of the unexpected token.
+pub struct NativeParser {
+    tokens: Vec<Token>,
+    index: usize,
+}
+
+impl NativeParser {
+    pub fn try_create(sql: &str) -> Result<Self> {
+        Ok(NativeParser {
+            tokens: Tokenizer::new(sql).tokenize()?,
+            index: 0,
+        })
+    }
+
+    // Parse the statements separated by `;`.
+    pub fn parse_statements(&mut self) -> Result<Vec<Statement>> {
+        let mut statements = vec![];
+        loop {
+            while self.consume(&TokenKind::SemiColon) {}
+
+            if self.peek().kind == TokenKind::EOF {
+                return Ok(statements);
+            }
+
+            statements.push(self.parse_statement()?);
+
+            if !self.consume(&TokenKind::SemiColon) && self.peek().kind != TokenKind::EOF {
+                return self.expected("end of statement");
+            }
+        }
+    }
+
+    fn peek(&self) -> &Token {
+        &self.tokens[self.index]
+    }
+
+    fn peek_nth(&self, n: usize) -> &Token {
+        &self.tokens[std::cmp::min(self.index + n, self.tokens.len() - 1)]
+    }
+
+    fn next(&mut self) -> Token {
+        let token = self.peek().clone();
+        // The last token is always EOF, never go beyond it.
+        if token.kind != TokenKind::EOF {
+            self.index += 1;
+        }
+        token
+    }
+
+    fn consume(&mut self, kind: &TokenKind) -> bool {
+        match &self.peek().kind == kind {
+            true => {
+                self.next();
+                true
+            }
+            false => false,
+        }
+    }
+
+    fn expect(&mut self, kind: &TokenKind) -> Result<()> {
+        match self.consume(kind) {
+            true => Ok(()),
+            false => self.expected(&format!("'{}'", kind)),
+        }
+    }
+
+    fn is_keyword(&self, keyword: &str) -> bool {
+        self.peek().is_keyword(keyword)
+    }
+
+    fn parse_keyword(&mut self, keyword: &str) -> bool {
+        match self.is_keyword(keyword) {
+            true => {
+                self.next();
+                true
+            }
+            false => false,
+        }
+    }
+
+    // Consume the keywords only if all of them are matched.
+    fn parse_keywords(&mut self, keywords: &[&str]) -> bool {
+        let matched = keywords
+            .iter()
+            .enumerate()
+            .all(|(n, keyword)| self.peek_nth(n).is_keyword(keyword));
+
+        if matched {
+            self.index += keywords.len();
+        }
+        matched
+    }
+
+    fn expect_keyword(&mut self, keyword: &str) -> Result<()> {
+        match self.parse_keyword(keyword) {
+            true => Ok(()),
+            false => self.expected(keyword),
+        }
+    }
+
+    fn is_reserved_keyword(token: &Token) -> bool {
+        RESERVED_KEYWORDS
+            .iter()
+            .any(|keyword| token.is_keyword(keyword))
+    }
+
+    fn expected<T>(&self, expected: &str) -> Result<T> {
+        let token = self.peek();
+        Err(ErrorCode::SyntaxException(format!(
+            "Expected {}, found {} at {}",
+            expected, token.kind, token.position
+        )))
+    }
+
+    fn parse_identifier(&mut self) -> Result<Identifier> {
+        match &self.peek().kind {
+            TokenKind::Word(word) => {
+                let name = word.clone();
+                self.next();
+                Ok(Identifier { name, quote: None })
+            }
+            TokenKind::QuotedIdent(ident, quote) => {
+                let (name, quote) = (ident.clone(), Some(*quote));
+                self.next();
+                Ok(Identifier { name, quote })
+            }
+            _ => self.expected("identifier"),
+        }
+    }
+
+    // `table` or `database.table`
+    fn parse_object_name(&mut self) -> Result<(Option<Identifier>, Identifier)> {
+        let name = self.parse_identifier()?;
+        match self.consume(&TokenKind::Period) {
+            true => Ok((Some(name), self.parse_identifier()?)),
+            false => Ok((None, name)),
+        }
+    }
+
+    fn parse_comma_separated<T>(
+        &mut self,
+        mut parse: impl FnMut(&mut Self) -> Result<T>,
+    ) -> Result<Vec<T>> {
+        let mut values = vec![parse(self)?];
+        while self.consume(&TokenKind::Comma) {
+            values.push(parse(self)?);
+        }
+        Ok(values)
+    }
+}
@@ -0,0 +1,237 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+
+use crate::sql::parser::ast::*;
+use crate::sql::parser::native::*;
+use crate::sql::parser::Parser;
+
+fn assert_parsed(sqls: Vec<&str>, expected: Vec<&str>) {
+    let parser = Parser {};
+    let stmts: Vec<Statement> = sqls
+        .into_iter()
+        .map(|sql| {
+            parser
+                .parse_sql(sql)
+                .map_err(|e| e.add_message(format!("SQL: {}", sql.to_owned())))
+                .unwrap()
+        })
+        .flatten()
+        .collect();
+
+    assert_eq!(stmts.len(), expected.len());
+    for (stmt, expect) in stmts.iter().zip(expected) {
+        assert_eq!(format!("{}", stmt), expect);
+    }
+}
+
+#[test]
+fn test_tokenizer() -> Result<()> {
+    let tokens = Tokenizer::new("SELECT a.`b c`, 'it''s' -- comment\n>= 1.5e3::int").tokenize()?;
+    let kinds = tokens.into_iter().map(|t| t.kind).collect::<Vec<_>>();
+    assert_eq!(kinds, vec![
+        TokenKind::Word("SELECT".to_string()),
+        TokenKind::Word("a".to_string()),
+        TokenKind::Period,
+        TokenKind::QuotedIdent("b c".to_string(), '`'),
+        TokenKind::Comma,
+        TokenKind::String("it's".to_string()),
+        TokenKind::GtEq,
+        TokenKind::Number("1.5e3".to_string()),
+        TokenKind::DoubleColon,
+        TokenKind::Word("int".to_string()),
+        TokenKind::EOF,
+    ]);
+
+    let tokens = Tokenizer::new("select\n  a").tokenize()?;
+    assert_eq!(tokens[1].position, Position { line: 2, column: 3 });
+
+    let err = Tokenizer::new("select 'abc").tokenize().unwrap_err();
+    assert_eq!(
+        err.message(),
+        "Unterminated quoted text starting at line 1, column 8"
+    );
+
+    let err = Tokenizer::new("select a ? b").tokenize().unwrap_err();
+    assert_eq!(
+        err.message(),
+        "Unexpected character '?' at line 1, column 10"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_parse_select() {
+    let sqls = vec![
+        "select distinct a, count(*) from t where a = 1 and b - 1 < a group by a having a = 1;",
+        "select * from a, b, c;",
+        "select * from a join b on a.a = b.a;",
+        "select * from a left outer join b on a.a = b.a;",
+        "select * from a right join b on a.a = b.a;",
+        "select * from a full outer join b on a.a = b.a;",
+        "select * from a inner join b using(a);",
+        "select 1",
+        "select t.*, db.t.a as x, b y from db.t as t2",
+        "select * from (select a from t) as s (x) where x > 1",
+        "select * from numbers(10) n",
+    ];
+    let expected = vec![
+        r#"SELECT DISTINCT a, count(*) FROM t WHERE a = 1 AND b - 1 < a GROUP BY a HAVING a = 1"#,
+        r#"SELECT * FROM a CROSS JOIN b CROSS JOIN c"#,
+        r#"SELECT * FROM a INNER JOIN b ON a.a = b.a"#,
+        r#"SELECT * FROM a LEFT OUTER JOIN b ON a.a = b.a"#,
+        r#"SELECT * FROM a RIGHT OUTER JOIN b ON a.a = b.a"#,
+        r#"SELECT * FROM a FULL OUTER JOIN b ON a.a = b.a"#,
+        r#"SELECT * FROM a INNER JOIN b USING(a)"#,
+        r#"SELECT 1"#,
+        r#"SELECT t.*, db.t.a AS x, b AS y FROM db.t AS t2"#,
+        r#"SELECT * FROM (SELECT a FROM t) AS s (x) WHERE x > 1"#,
+        r#"SELECT * FROM numbers(10) AS n"#,
+    ];
+    assert_parsed(sqls, expected);
+}
+
+#[test]
+fn test_parse_query_clauses() {
+    let sqls = vec![
+        "select a from t order by a desc, b limit 10 offset 5",
+        "select a from t limit 5, 10",
+        "select a, b from t limit 2 by a, b limit 10 settings max_threads = 1, enable_x = 'on' format JSON",
+        "select a from t limit 1, 2 by a format TSV settings max_threads = 8",
+        "select a from t1 union all select a from t2 intersect select a from t3 order by a",
+        "(select a from t1 except select a from t2) union select a from t3",
+    ];
+    let expected = vec![
+        r#"SELECT a FROM t ORDER BY a DESC, b LIMIT 10 OFFSET 5"#,
+        r#"SELECT a FROM t LIMIT 10 OFFSET 5"#,
+        r#"SELECT a, b FROM t LIMIT 2 BY a, b LIMIT 10 SETTINGS max_threads = 1, enable_x = "on" FORMAT JSON"#,
+        r#"SELECT a FROM t LIMIT 1, 2 BY a SETTINGS max_threads = 8 FORMAT TSV"#,
+        r#"SELECT a FROM t1 UNION ALL SELECT a FROM t2 INTERSECT SELECT a FROM t3 ORDER BY a"#,
+        r#"(SELECT a FROM t1 EXCEPT SELECT a FROM t2) UNION SELECT a FROM t3"#,
+    ];
+    assert_parsed(sqls, expected);
+}
+
+#[test]
+fn test_parse_expr() -> Result<()> {
+    let sqls = vec![
+        "select a + b * c, -a, not a = 1 or b is not null",
+        "select a between 1 and 10 and b not in (1, 2, 3)",
+        "select a in (select b from t), exists (select 1), (select max(b) from t)",
+        "select case a when 1 then 'one' else 'other' end, case when a > 1 then true end",
+        "select cast(a as bigint), a::varchar, b not like 'x%', quantile(0.5)(distinct a)",
+        "select a regexp '^x', a rlike 'y', b not regexp 'x', b not rlike 'y'",
+    ];
+    let expected = vec![
+        r#"SELECT a + b * c, - a, NOT a = 1 OR b IS NOT NULL"#,
+        r#"SELECT a BETWEEN 1 AND 10 AND b NOT IN(1, 2, 3)"#,
+        r#"SELECT a IN(SELECT b FROM t), EXISTS (SELECT 1), (SELECT max(b) FROM t)"#,
+        r#"SELECT CASE a WHEN 1 THEN "one" ELSE "other" END, CASE WHEN a > 1 THEN TRUE END"#,
+        r#"SELECT CAST(a AS BIGINT), CAST(a AS VARCHAR), b NOT LIKE "x%", quantile(0.5)(DISTINCT a)"#,
+        r#"SELECT a REGEXP "^x", a RLIKE "y", b NOT REGEXP "x", b NOT RLIKE "y""#,
+    ];
+    assert_parsed(sqls, expected);
+
+    // The precedence is not visible in the displayed SQL, check the tree instead.
+    let stmts = NativeParser::try_create("select (a + b) * c")?.parse_statements()?;
+    let query = match &stmts[0] {
+        Statement::Select(query) => query,
+        _ => unreachable!(),
+    };
+    match &query.body {
+        SetExpr::Select(select) => match &select.select_list[0] {
+            SelectTarget::Projection { expr, .. } => {
+                assert!(matches!(expr, Expr::BinaryOp {
+                    op: BinaryOperator::Multiply,
+                    ..
+                }))
+            }
+            _ => unreachable!(),
+        },
+        _ => unreachable!(),
+    }
+    Ok(())
+}
+
+#[test]
+fn test_parse_ddl() {
+    let sqls = vec![
+        "truncate table test",
+        "truncate table test_db.test",
+        "DROP table table1",
+        "DROP table IF EXISTS table1",
+        "CREATE TABLE t(c1 int null, c2 bigint null, c3 varchar(255) null)",
+        "CREATE TABLE t(c1 int not null, c2 bigint not null, c3 varchar(255) not null)",
+        "CREATE TABLE t(c1 int default 1)",
+    ];
+    let expected = vec![
+        r#"TRUNCATE TABLE test"#,
+        r#"TRUNCATE TABLE test_db.test"#,
+        r#"DROP TABLE table1"#,
+        r#"DROP TABLE IF EXISTS table1"#,
+        r#"CREATE TABLE t (c1 INTEGER NULL, c2 BIGINT NULL, c3 VARCHAR(255) NULL)"#,
+        r#"CREATE TABLE t (c1 INTEGER NOT NULL, c2 BIGINT NOT NULL, c3 VARCHAR(255) NOT NULL)"#,
+        r#"CREATE TABLE t (c1 INTEGER NULL DEFAULT 1)"#,
+    ];
+    assert_parsed(sqls, expected);
+}
+
+#[test]
+fn test_parse_multiple_statements() -> Result<()> {
+    let stmts = NativeParser::try_create("select 1; ; select 2;")?.parse_statements()?;
+    assert_eq!(stmts.len(), 2);
+    Ok(())
+}
+
+#[test]
+fn test_parse_error_position() {
+    let cases = vec![
+        (
+            "select a,\nfrom t",
+            "Expected expression, found from at line 2, column 1",
+        ),
+        (
+            "select a from t limit",
+            "Expected expression, found EOF at line 1, column 22",
+        ),
+        (
+            "select a from t where a in (1, 2",
+            "Expected ')', found EOF at line 1, column 33",
+        ),
+        (
+            "select a from t t1 t2",
+            "Expected end of statement, found t2 at line 1, column 20",
+        ),
+        (
+            "select * from a cross join b on a.a = b.a",
+            "CROSS JOIN can not have a join condition at line 1, column 17",
+        ),
+        (
+            "select a.b.c.d from t",
+            "Too many qualifiers in column reference at line 1, column 8",
+        ),
+        (
+            "select cast(a as unknown)",
+            "Unsupported data type unknown at line 1, column 18",
+        ),
+    ];
+
+    let parser = Parser {};
+    for (sql, expected) in cases {
+        let err = parser.parse_sql(sql).unwrap_err();
+        assert_eq!(err.code(), 5, "SQL: {}", sql);
+        assert_eq!(err.message(), expected, "SQL: {}", sql);
+    }
+}
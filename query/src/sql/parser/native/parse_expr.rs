@@ -0,0 +1,521 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use super::NativeParser;
+use super::Token;
+use super::TokenKind;
+use crate::sql::parser::ast::BinaryOperator;
+use crate::sql::parser::ast::Expr;
+use crate::sql::parser::ast::Literal;
+use crate::sql::parser::ast::TypeName;
+use crate::sql::parser::ast::UnaryOperator;
+
+const OR_PRECEDENCE: u8 = 5;
+const AND_PRECEDENCE: u8 = 10;
+const NOT_PRECEDENCE: u8 = 15;
+const IS_PRECEDENCE: u8 = 17;
+const COMPARISON_PRECEDENCE: u8 = 20;
+const BITWISE_OR_PRECEDENCE: u8 = 21;
+const BITWISE_XOR_PRECEDENCE: u8 = 22;
+const BITWISE_AND_PRECEDENCE: u8 = 23;
+const PLUS_MINUS_PRECEDENCE: u8 = 30;
+const MULTIPLY_DIVIDE_PRECEDENCE: u8 = 40;
+const UNARY_PRECEDENCE: u8 = 45;
+const CAST_PRECEDENCE: u8 = 50;
+
+impl NativeParser {
+    pub(super) fn parse_expr(&mut self) -> Result<Expr> {
+        self.parse_subexpr(0)
+    }
+
+    // Precedence climbing, only the operators binding tighter than `precedence` are consumed.
+    fn parse_subexpr(&mut self, precedence: u8) -> Result<Expr> {
+        let mut expr = self.parse_prefix()?;
+        loop {
+            let next_precedence = self.next_precedence();
+            if next_precedence <= precedence {
+                return Ok(expr);
+            }
+            expr = self.parse_infix(expr, next_precedence)?;
+        }
+    }
+
+    fn next_precedence(&self) -> u8 {
+        let token = self.peek();
+        match &token.kind {
+            TokenKind::Word(_) if token.is_keyword("OR") => OR_PRECEDENCE,
+            TokenKind::Word(_) if token.is_keyword("AND") => AND_PRECEDENCE,
+            TokenKind::Word(_) if token.is_keyword("IS") => IS_PRECEDENCE,
+            TokenKind::Word(_) if token.is_keyword("DIV") => MULTIPLY_DIVIDE_PRECEDENCE,
+            TokenKind::Word(_) if Self::is_comparison_keyword(token) => COMPARISON_PRECEDENCE,
+            TokenKind::Word(_)
+                if token.is_keyword("NOT") && Self::is_comparison_keyword(self.peek_nth(1)) =>
+            {
+                COMPARISON_PRECEDENCE
+            }
+            TokenKind::Eq
+            | TokenKind::NotEq
+            | TokenKind::Lt
+            | TokenKind::Gt
+            | TokenKind::LtEq
+            | TokenKind::GtEq => COMPARISON_PRECEDENCE,
+            TokenKind::BitwiseOr => BITWISE_OR_PRECEDENCE,
+            TokenKind::BitwiseXor => BITWISE_XOR_PRECEDENCE,
+            TokenKind::BitwiseAnd => BITWISE_AND_PRECEDENCE,
+            TokenKind::Plus | TokenKind::Minus | TokenKind::StringConcat => PLUS_MINUS_PRECEDENCE,
+            TokenKind::Multiply | TokenKind::Divide | TokenKind::Modulo => {
+                MULTIPLY_DIVIDE_PRECEDENCE
+            }
+            TokenKind::DoubleColon => CAST_PRECEDENCE,
+            _ => 0,
+        }
+    }
+
+    fn is_comparison_keyword(token: &Token) -> bool {
+        token.is_keyword("LIKE")
+            || token.is_keyword("REGEXP")
+            || token.is_keyword("RLIKE")
+            || token.is_keyword("IN")
+            || token.is_keyword("BETWEEN")
+    }
+
+    fn parse_infix(&mut self, expr: Expr, precedence: u8) -> Result<Expr> {
+        let token = self.next();
+        let op = match &token.kind {
+            TokenKind::Word(_) if token.is_keyword("OR") => BinaryOperator::Or,
+            TokenKind::Word(_) if token.is_keyword("AND") => BinaryOperator::And,
+            TokenKind::Word(_) if token.is_keyword("DIV") => BinaryOperator::Div,
+            TokenKind::Word(_) if token.is_keyword("LIKE") => BinaryOperator::Like,
+            TokenKind::Word(_) if token.is_keyword("REGEXP") => BinaryOperator::Regexp,
+            TokenKind::Word(_) if token.is_keyword("RLIKE") => BinaryOperator::RLike,
+            TokenKind::Word(_) if token.is_keyword("IS") => {
+                let not = self.parse_keyword("NOT");
+                self.expect_keyword("NULL")?;
+                return match not {
+                    true => Ok(Expr::IsNotNull(Box::new(expr))),
+                    false => Ok(Expr::IsNull(Box::new(expr))),
+                };
+            }
+            TokenKind::Word(_) if token.is_keyword("IN") => return self.parse_in(expr, false),
+            TokenKind::Word(_) if token.is_keyword("BETWEEN") => {
+                return self.parse_between(expr, false);
+            }
+            TokenKind::Word(_) if token.is_keyword("NOT") => {
+                if self.parse_keyword("IN") {
+                    return self.parse_in(expr, true);
+                }
+                if self.parse_keyword("BETWEEN") {
+                    return self.parse_between(expr, true);
+                }
+                if self.parse_keyword("REGEXP") {
+                    BinaryOperator::NotRegexp
+                } else if self.parse_keyword("RLIKE") {
+                    BinaryOperator::NotRLike
+                } else {
+                    self.expect_keyword("LIKE")?;
+                    BinaryOperator::NotLike
+                }
+            }
+            TokenKind::Eq => BinaryOperator::Eq,
+            TokenKind::NotEq => BinaryOperator::NotEq,
+            TokenKind::Lt => BinaryOperator::Lt,
+            TokenKind::Gt => BinaryOperator::Gt,
+            TokenKind::LtEq => BinaryOperator::Lte,
+            TokenKind::GtEq => BinaryOperator::Gte,
+            TokenKind::BitwiseOr => BinaryOperator::BitwiseOr,
+            TokenKind::BitwiseXor => BinaryOperator::BitwiseXor,
+            TokenKind::BitwiseAnd => BinaryOperator::BitwiseAnd,
+            TokenKind::Plus => BinaryOperator::Plus,
+            TokenKind::Minus => BinaryOperator::Minus,
+            TokenKind::StringConcat => BinaryOperator::StringConcat,
+            TokenKind::Multiply => BinaryOperator::Multiply,
+            TokenKind::Divide => BinaryOperator::Divide,
+            TokenKind::Modulo => BinaryOperator::Modulo,
+            TokenKind::DoubleColon => {
+                return Ok(Expr::Cast {
+                    expr: Box::new(expr),
+                    target_type: self.parse_type_name()?,
+                });
+            }
+            _ => {
+                return Err(ErrorCode::LogicalError(format!(
+                    "Unexpected infix operator {} at {}",
+                    token.kind, token.position
+                )));
+            }
+        };
+
+        Ok(Expr::BinaryOp {
+            op,
+            left: Box::new(expr),
+            right: Box::new(self.parse_subexpr(precedence)?),
+        })
+    }
+
+    // `[NOT] IN (expr, ...)` or `[NOT] IN (SELECT ...)`, the `[NOT] IN` is consumed.
+    fn parse_in(&mut self, expr: Expr, not: bool) -> Result<Expr> {
+        self.expect(&TokenKind::LParen)?;
+        if self.is_subquery_start() {
+            let subquery = Box::new(self.parse_query()?);
+            self.expect(&TokenKind::RParen)?;
+            return Ok(Expr::InSubquery {
+                expr: Box::new(expr),
+                subquery,
+                not,
+            });
+        }
+
+        let list = self.parse_comma_separated(Self::parse_expr)?;
+        self.expect(&TokenKind::RParen)?;
+        Ok(Expr::InList {
+            expr: Box::new(expr),
+            list,
+            not,
+        })
+    }
+
+    // `[NOT] BETWEEN low AND high`, the `[NOT] BETWEEN` is consumed.
+    fn parse_between(&mut self, expr: Expr, negated: bool) -> Result<Expr> {
+        // The bounds bind tighter than `AND`, so the `AND` in between is not consumed by `low`.
+        let low = self.parse_subexpr(COMPARISON_PRECEDENCE)?;
+        self.expect_keyword("AND")?;
+        let high = self.parse_subexpr(COMPARISON_PRECEDENCE)?;
+        Ok(Expr::Between {
+            expr: Box::new(expr),
+            negated,
+            low: Box::new(low),
+            high: Box::new(high),
+        })
+    }
+
+    fn is_subquery_start(&self) -> bool {
+        self.is_keyword("SELECT")
+            || (self.peek().kind == TokenKind::LParen && self.peek_nth(1).is_keyword("SELECT"))
+    }
+
+    fn parse_prefix(&mut self) -> Result<Expr> {
+        let token = self.peek().clone();
+        match &token.kind {
+            TokenKind::Word(_) if token.is_keyword("NOT") => {
+                self.next();
+                Ok(Expr::UnaryOp {
+                    op: UnaryOperator::Not,
+                    expr: Box::new(self.parse_subexpr(NOT_PRECEDENCE)?),
+                })
+            }
+            TokenKind::Word(_) if token.is_keyword("CASE") => {
+                self.next();
+                self.parse_case()
+            }
+            TokenKind::Word(_) if token.is_keyword("CAST") => {
+                self.next();
+                self.expect(&TokenKind::LParen)?;
+                let expr = self.parse_expr()?;
+                self.expect_keyword("AS")?;
+                let target_type = self.parse_type_name()?;
+                self.expect(&TokenKind::RParen)?;
+                Ok(Expr::Cast {
+                    expr: Box::new(expr),
+                    target_type,
+                })
+            }
+            TokenKind::Word(_) if token.is_keyword("EXISTS") => {
+                self.next();
+                self.expect(&TokenKind::LParen)?;
+                let subquery = self.parse_query()?;
+                self.expect(&TokenKind::RParen)?;
+                Ok(Expr::Exists(Box::new(subquery)))
+            }
+            TokenKind::Word(_)
+                if token.is_keyword("NULL")
+                    || token.is_keyword("TRUE")
+                    || token.is_keyword("FALSE") =>
+            {
+                Ok(Expr::Literal(self.parse_literal()?))
+            }
+            TokenKind::Number(_) | TokenKind::String(_) => Ok(Expr::Literal(self.parse_literal()?)),
+            TokenKind::Plus | TokenKind::Minus => {
+                self.next();
+                let op = match token.kind {
+                    TokenKind::Plus => UnaryOperator::Plus,
+                    _ => UnaryOperator::Minus,
+                };
+                Ok(Expr::UnaryOp {
+                    op,
+                    expr: Box::new(self.parse_subexpr(UNARY_PRECEDENCE)?),
+                })
+            }
+            TokenKind::Multiply => {
+                self.next();
+                Ok(Expr::Wildcard)
+            }
+            TokenKind::LParen => {
+                if self.is_subquery_start_after_paren() {
+                    self.next();
+                    let subquery = self.parse_query()?;
+                    self.expect(&TokenKind::RParen)?;
+                    return Ok(Expr::Subquery(Box::new(subquery)));
+                }
+
+                self.next();
+                let expr = self.parse_expr()?;
+                self.expect(&TokenKind::RParen)?;
+                Ok(expr)
+            }
+            TokenKind::Word(_) if Self::is_reserved_keyword(&token) => self.expected("expression"),
+            TokenKind::Word(_) | TokenKind::QuotedIdent(..) => self.parse_column_or_function(),
+            _ => self.expected("expression"),
+        }
+    }
+
+    // Whether the current `(` starts a subquery, like `(SELECT ...)` or `((SELECT ...) UNION ...)`.
+    fn is_subquery_start_after_paren(&self) -> bool {
+        let mut n = 1;
+        while self.peek_nth(n).kind == TokenKind::LParen {
+            n += 1;
+        }
+        self.peek_nth(n).is_keyword("SELECT")
+    }
+
+    // `column`, `table.column`, `database.table.column` or a function call.
+    fn parse_column_or_function(&mut self) -> Result<Expr> {
+        let position = self.peek().position;
+        let mut idents = vec![self.parse_identifier()?];
+        while self.consume(&TokenKind::Period) {
+            idents.push(self.parse_identifier()?);
+        }
+
+        if self.peek().kind == TokenKind::LParen {
+            let name = idents
+                .iter()
+                .map(|ident| ident.name.clone())
+                .collect::<Vec<_>>()
+                .join(".");
+            return self.parse_function_call(name);
+        }
+
+        let column = idents.pop().unwrap();
+        let table = idents.pop();
+        let database = idents.pop();
+        match idents.is_empty() {
+            true => Ok(Expr::ColumnRef {
+                database,
+                table,
+                column,
+            }),
+            false => Err(ErrorCode::SyntaxException(format!(
+                "Too many qualifiers in column reference at {}",
+                position
+            ))),
+        }
+    }
+
+    // `name([DISTINCT] args)` or the parametric function `name(params)([DISTINCT] args)`,
+    // the name is consumed.
+    pub(super) fn parse_function_call(&mut self, name: String) -> Result<Expr> {
+        let position = self.peek().position;
+        let (mut distinct, mut args) = self.parse_function_args()?;
+
+        let mut params = vec![];
+        if self.peek().kind == TokenKind::LParen {
+            if distinct {
+                return Err(ErrorCode::SyntaxException(format!(
+                    "DISTINCT is not allowed in the parameters of function {} at {}",
+                    name, position
+                )));
+            }
+
+            for arg in args {
+                match arg {
+                    Expr::Literal(literal) => params.push(literal),
+                    _ => {
+                        return Err(ErrorCode::SyntaxException(format!(
+                            "Parameters of function {} must be literals at {}",
+                            name, position
+                        )));
+                    }
+                }
+            }
+
+            let (second_distinct, second_args) = self.parse_function_args()?;
+            distinct = second_distinct;
+            args = second_args;
+        }
+
+        Ok(Expr::FunctionCall {
+            distinct,
+            name,
+            args,
+            params,
+        })
+    }
+
+    fn parse_function_args(&mut self) -> Result<(bool, Vec<Expr>)> {
+        self.expect(&TokenKind::LParen)?;
+        if self.consume(&TokenKind::RParen) {
+            return Ok((false, vec![]));
+        }
+
+        let distinct = self.parse_keyword("DISTINCT");
+        let args = self.parse_comma_separated(Self::parse_expr)?;
+        self.expect(&TokenKind::RParen)?;
+        Ok((distinct, args))
+    }
+
+    // `CASE [operand] WHEN condition THEN result ... [ELSE result] END`, the `CASE` is consumed.
+    fn parse_case(&mut self) -> Result<Expr> {
+        let mut operand = None;
+        if !self.is_keyword("WHEN") {
+            operand = Some(Box::new(self.parse_expr()?));
+        }
+
+        let mut conditions = vec![];
+        let mut results = vec![];
+        self.expect_keyword("WHEN")?;
+        loop {
+            conditions.push(self.parse_expr()?);
+            self.expect_keyword("THEN")?;
+            results.push(self.parse_expr()?);
+            if !self.parse_keyword("WHEN") {
+                break;
+            }
+        }
+
+        let mut else_result = None;
+        if self.parse_keyword("ELSE") {
+            else_result = Some(Box::new(self.parse_expr()?));
+        }
+        self.expect_keyword("END")?;
+
+        Ok(Expr::Case {
+            operand,
+            conditions,
+            results,
+            else_result,
+        })
+    }
+
+    // A literal value, numbers can be signed.
+    pub(super) fn parse_literal(&mut self) -> Result<Literal> {
+        let token = self.peek().clone();
+        let literal = match &token.kind {
+            TokenKind::Number(number) => Literal::Number(number.clone()),
+            TokenKind::String(string) => Literal::String(string.clone()),
+            TokenKind::Minus | TokenKind::Plus => match &self.peek_nth(1).kind {
+                TokenKind::Number(number) if token.kind == TokenKind::Minus => {
+                    let number = format!("-{}", number);
+                    self.next();
+                    Literal::Number(number)
+                }
+                TokenKind::Number(number) => {
+                    let number = number.clone();
+                    self.next();
+                    Literal::Number(number)
+                }
+                _ => return self.expected("literal"),
+            },
+            TokenKind::Word(_) if token.is_keyword("NULL") => Literal::Null,
+            TokenKind::Word(_) if token.is_keyword("TRUE") => Literal::Boolean(true),
+            TokenKind::Word(_) if token.is_keyword("FALSE") => Literal::Boolean(false),
+            _ => return self.expected("literal"),
+        };
+
+        self.next();
+        Ok(literal)
+    }
+
+    pub(super) fn parse_type_name(&mut self) -> Result<TypeName> {
+        let token = self.peek().clone();
+        let name = match &token.kind {
+            TokenKind::Word(word) => word.to_uppercase(),
+            _ => return self.expected("data type"),
+        };
+        self.next();
+
+        let type_name = match name.as_str() {
+            "CHAR" | "CHARACTER" => TypeName::Char(self.parse_optional_precision()?),
+            "VARCHAR" => TypeName::Varchar(self.parse_optional_precision()?),
+            "DECIMAL" | "NUMERIC" => {
+                let (precision, scale) = self.parse_optional_precision_scale()?;
+                TypeName::Decimal(precision, scale)
+            }
+            "FLOAT" => TypeName::Float(self.parse_optional_precision()?),
+            "INT" | "INTEGER" => TypeName::Int(self.parse_optional_precision()?),
+            "TINYINT" => TypeName::TinyInt(self.parse_optional_precision()?),
+            "SMALLINT" => TypeName::SmallInt(self.parse_optional_precision()?),
+            "BIGINT" => TypeName::BigInt(self.parse_optional_precision()?),
+            "REAL" => TypeName::Real,
+            "DOUBLE" => {
+                self.parse_keyword("PRECISION");
+                TypeName::Double
+            }
+            "BOOLEAN" | "BOOL" => TypeName::Boolean,
+            "DATE" => TypeName::Date,
+            "TIME" => TypeName::Time,
+            "TIMESTAMP" => TypeName::Timestamp,
+            "TEXT" | "STRING" => TypeName::Text,
+            _ => {
+                return Err(ErrorCode::SyntaxException(format!(
+                    "Unsupported data type {} at {}",
+                    token.kind, token.position
+                )));
+            }
+        };
+        Ok(type_name)
+    }
+
+    // Optional `(n)` following a type name.
+    fn parse_optional_precision(&mut self) -> Result<Option<u64>> {
+        if !self.consume(&TokenKind::LParen) {
+            return Ok(None);
+        }
+
+        let precision = self.parse_unsigned_integer()?;
+        self.expect(&TokenKind::RParen)?;
+        Ok(Some(precision))
+    }
+
+    // Optional `(p)` or `(p, s)` following a type name.
+    fn parse_optional_precision_scale(&mut self) -> Result<(Option<u64>, Option<u64>)> {
+        if !self.consume(&TokenKind::LParen) {
+            return Ok((None, None));
+        }
+
+        let precision = self.parse_unsigned_integer()?;
+        let mut scale = None;
+        if self.consume(&TokenKind::Comma) {
+            scale = Some(self.parse_unsigned_integer()?);
+        }
+        self.expect(&TokenKind::RParen)?;
+        Ok((Some(precision), scale))
+    }
+
+    fn parse_unsigned_integer(&mut self) -> Result<u64> {
+        let value = match &self.peek().kind {
+            TokenKind::Number(number) => number.parse::<u64>().ok(),
+            _ => None,
+        };
+
+        match value {
+            None => self.expected("unsigned integer"),
+            Some(value) => {
+                self.next();
+                Ok(value)
+            }
+        }
+    }
+}
@@ -0,0 +1,423 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use super::NativeParser;
+use super::TokenKind;
+use crate::sql::parser::ast::Expr;
+use crate::sql::parser::ast::Identifier;
+use crate::sql::parser::ast::Indirection;
+use crate::sql::parser::ast::Join;
+use crate::sql::parser::ast::JoinCondition;
+use crate::sql::parser::ast::JoinOperator;
+use crate::sql::parser::ast::LimitBy;
+use crate::sql::parser::ast::OrderByExpr;
+use crate::sql::parser::ast::Query;
+use crate::sql::parser::ast::SelectStmt;
+use crate::sql::parser::ast::SelectTarget;
+use crate::sql::parser::ast::SetExpr;
+use crate::sql::parser::ast::SetOperator;
+use crate::sql::parser::ast::Setting;
+use crate::sql::parser::ast::TableAlias;
+use crate::sql::parser::ast::TableReference;
+
+impl NativeParser {
+    pub(super) fn parse_query(&mut self) -> Result<Query> {
+        let body = self.parse_set_expr(0)?;
+
+        let mut order_by = vec![];
+        if self.parse_keywords(&["ORDER", "BY"]) {
+            order_by = self.parse_comma_separated(Self::parse_order_by_expr)?;
+        }
+
+        let mut limit_by = None;
+        let (mut limit, mut offset) = (None, None);
+        if self.parse_keyword("LIMIT") {
+            let (first_limit, first_offset) = self.parse_limit()?;
+            match self.parse_keyword("BY") {
+                true => {
+                    limit_by = Some(LimitBy {
+                        limit: first_limit,
+                        offset: first_offset,
+                        exprs: self.parse_comma_separated(Self::parse_expr)?,
+                    });
+
+                    if self.parse_keyword("LIMIT") {
+                        let (second_limit, second_offset) = self.parse_limit()?;
+                        limit = Some(second_limit);
+                        offset = second_offset;
+                    }
+                }
+                false => {
+                    limit = Some(first_limit);
+                    offset = first_offset;
+                }
+            }
+        }
+
+        if offset.is_none() && self.parse_keyword("OFFSET") {
+            offset = Some(self.parse_expr()?);
+            if !self.parse_keyword("ROWS") {
+                self.parse_keyword("ROW");
+            }
+        }
+
+        // ClickHouse accepts `SETTINGS` and `FORMAT` in either order.
+        let mut settings = vec![];
+        let mut format = None;
+        loop {
+            if settings.is_empty() && self.parse_keyword("SETTINGS") {
+                settings = self.parse_comma_separated(Self::parse_setting)?;
+            } else if format.is_none() && self.parse_keyword("FORMAT") {
+                format = Some(self.parse_identifier()?);
+            } else {
+                break;
+            }
+        }
+
+        Ok(Query {
+            body,
+            order_by,
+            limit_by,
+            limit,
+            offset,
+            settings,
+            format,
+        })
+    }
+
+    // `n`, `m, n` or `n OFFSET m`, returns (limit, offset).
+    fn parse_limit(&mut self) -> Result<(Expr, Option<Expr>)> {
+        let first = self.parse_expr()?;
+        if self.consume(&TokenKind::Comma) {
+            return Ok((self.parse_expr()?, Some(first)));
+        }
+
+        // `LIMIT n OFFSET m BY ...`, the `OFFSET` must be followed by `BY` here,
+        // otherwise it's the `OFFSET` clause of the query.
+        if self.is_keyword("OFFSET") {
+            let index = self.index;
+            self.next();
+            let offset = self.parse_expr()?;
+            if self.is_keyword("BY") {
+                return Ok((first, Some(offset)));
+            }
+            self.index = index;
+        }
+
+        Ok((first, None))
+    }
+
+    fn parse_setting(&mut self) -> Result<Setting> {
+        let name = self.parse_identifier()?;
+        self.expect(&TokenKind::Eq)?;
+        let value = self.parse_literal()?;
+        Ok(Setting { name, value })
+    }
+
+    fn parse_order_by_expr(&mut self) -> Result<OrderByExpr> {
+        let expr = self.parse_expr()?;
+
+        let mut asc = None;
+        if self.parse_keyword("ASC") {
+            asc = Some(true);
+        } else if self.parse_keyword("DESC") {
+            asc = Some(false);
+        }
+
+        let mut nulls_first = None;
+        if self.parse_keywords(&["NULLS", "FIRST"]) {
+            nulls_first = Some(true);
+        } else if self.parse_keywords(&["NULLS", "LAST"]) {
+            nulls_first = Some(false);
+        }
+
+        Ok(OrderByExpr {
+            expr,
+            asc,
+            nulls_first,
+        })
+    }
+
+    // INTERSECT binds tighter than UNION and EXCEPT, operators of the same precedence are
+    // left associative.
+    fn parse_set_expr(&mut self, precedence: u8) -> Result<SetExpr> {
+        let mut expr = match self.consume(&TokenKind::LParen) {
+            true => {
+                let query = self.parse_query()?;
+                self.expect(&TokenKind::RParen)?;
+                SetExpr::Query(Box::new(query))
+            }
+            false => SetExpr::Select(Box::new(self.parse_select()?)),
+        };
+
+        loop {
+            let (op, op_precedence) = if self.is_keyword("UNION") {
+                (SetOperator::Union, 1)
+            } else if self.is_keyword("EXCEPT") {
+                (SetOperator::Except, 1)
+            } else if self.is_keyword("INTERSECT") {
+                (SetOperator::Intersect, 2)
+            } else {
+                break;
+            };
+
+            if op_precedence <= precedence {
+                break;
+            }
+
+            self.next();
+            let all = self.parse_keyword("ALL");
+            if !all {
+                self.parse_keyword("DISTINCT");
+            }
+
+            let right = self.parse_set_expr(op_precedence)?;
+            expr = SetExpr::SetOperation {
+                op,
+                all,
+                left: Box::new(expr),
+                right: Box::new(right),
+            };
+        }
+
+        Ok(expr)
+    }
+
+    fn parse_select(&mut self) -> Result<SelectStmt> {
+        self.expect_keyword("SELECT")?;
+        let distinct = self.parse_keyword("DISTINCT");
+        if !distinct {
+            self.parse_keyword("ALL");
+        }
+
+        let select_list = self.parse_comma_separated(Self::parse_select_target)?;
+
+        let mut from = None;
+        if self.parse_keyword("FROM") {
+            from = Some(self.parse_from()?);
+        }
+
+        let mut selection = None;
+        if self.parse_keyword("WHERE") {
+            selection = Some(self.parse_expr()?);
+        }
+
+        let mut group_by = vec![];
+        if self.parse_keywords(&["GROUP", "BY"]) {
+            group_by = self.parse_comma_separated(Self::parse_expr)?;
+        }
+
+        let mut having = None;
+        if self.parse_keyword("HAVING") {
+            having = Some(self.parse_expr()?);
+        }
+
+        Ok(SelectStmt {
+            distinct,
+            select_list,
+            from,
+            selection,
+            group_by,
+            having,
+        })
+    }
+
+    fn parse_select_target(&mut self) -> Result<SelectTarget> {
+        if let Some(indirections) = self.parse_wildcard_indirections()? {
+            return Ok(SelectTarget::Indirections(indirections));
+        }
+
+        let expr = self.parse_expr()?;
+        let alias = self.parse_alias()?;
+        Ok(SelectTarget::Projection { expr, alias })
+    }
+
+    // `*`, `table.*` or `database.table.*`, returns `None` if it's not a wildcard.
+    fn parse_wildcard_indirections(&mut self) -> Result<Option<Vec<Indirection>>> {
+        let index = self.index;
+        let mut indirections = vec![];
+        loop {
+            match &self.peek().kind {
+                TokenKind::Multiply => {
+                    self.next();
+                    indirections.push(Indirection::Star);
+                    return Ok(Some(indirections));
+                }
+                TokenKind::Word(_) | TokenKind::QuotedIdent(..)
+                    if self.peek_nth(1).kind == TokenKind::Period =>
+                {
+                    let ident = self.parse_identifier()?;
+                    self.next();
+                    indirections.push(Indirection::Identifier(ident));
+                }
+                _ => {
+                    self.index = index;
+                    return Ok(None);
+                }
+            }
+        }
+    }
+
+    // `[AS] alias`, an alias without `AS` can not be a reserved keyword.
+    fn parse_alias(&mut self) -> Result<Option<Identifier>> {
+        if self.parse_keyword("AS") {
+            return Ok(Some(self.parse_identifier()?));
+        }
+
+        match &self.peek().kind {
+            TokenKind::QuotedIdent(..) => Ok(Some(self.parse_identifier()?)),
+            TokenKind::Word(_) if !Self::is_reserved_keyword(self.peek()) => {
+                Ok(Some(self.parse_identifier()?))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    // `[AS] alias [(column, ...)]`
+    fn parse_table_alias(&mut self) -> Result<Option<TableAlias>> {
+        let name = match self.parse_alias()? {
+            None => return Ok(None),
+            Some(name) => name,
+        };
+
+        let mut columns = vec![];
+        if self.consume(&TokenKind::LParen) {
+            columns = self.parse_comma_separated(Self::parse_identifier)?;
+            self.expect(&TokenKind::RParen)?;
+        }
+
+        Ok(Some(TableAlias { name, columns }))
+    }
+
+    // The table references split by `,` are joined with cross join.
+    fn parse_from(&mut self) -> Result<TableReference> {
+        let mut from = self.parse_table_reference()?;
+        while self.consume(&TokenKind::Comma) {
+            from = TableReference::Join(Join {
+                op: JoinOperator::CrossJoin,
+                condition: JoinCondition::None,
+                left: Box::new(from),
+                right: Box::new(self.parse_table_reference()?),
+            });
+        }
+        Ok(from)
+    }
+
+    // A table reference with joins, which is built into a left-deep tree.
+    fn parse_table_reference(&mut self) -> Result<TableReference> {
+        let mut left = self.parse_table_primary()?;
+        loop {
+            let position = self.peek().position;
+            let natural = self.parse_keyword("NATURAL");
+
+            let op = if self.parse_keywords(&["CROSS", "JOIN"]) {
+                JoinOperator::CrossJoin
+            } else if self.parse_keyword("JOIN") || self.parse_keywords(&["INNER", "JOIN"]) {
+                JoinOperator::Inner
+            } else if self.parse_outer_join("LEFT") {
+                JoinOperator::LeftOuter
+            } else if self.parse_outer_join("RIGHT") {
+                JoinOperator::RightOuter
+            } else if self.parse_outer_join("FULL") {
+                JoinOperator::FullOuter
+            } else if natural {
+                return self.expected("JOIN");
+            } else {
+                return Ok(left);
+            };
+
+            let right = self.parse_table_primary()?;
+            let condition = if natural {
+                JoinCondition::Natural
+            } else if self.parse_keyword("ON") {
+                JoinCondition::On(self.parse_expr()?)
+            } else if self.parse_keyword("USING") {
+                self.expect(&TokenKind::LParen)?;
+                let columns = self.parse_comma_separated(Self::parse_identifier)?;
+                self.expect(&TokenKind::RParen)?;
+                JoinCondition::Using(columns)
+            } else {
+                JoinCondition::None
+            };
+
+            if op == JoinOperator::CrossJoin && condition != JoinCondition::None {
+                return Err(ErrorCode::SyntaxException(format!(
+                    "CROSS JOIN can not have a join condition at {}",
+                    position
+                )));
+            }
+
+            let outer = matches!(
+                op,
+                JoinOperator::LeftOuter | JoinOperator::RightOuter | JoinOperator::FullOuter
+            );
+            if outer && condition == JoinCondition::None {
+                return Err(ErrorCode::SyntaxException(format!(
+                    "OUTER JOIN must have a join condition at {}",
+                    position
+                )));
+            }
+
+            left = TableReference::Join(Join {
+                op,
+                condition,
+                left: Box::new(left),
+                right: Box::new(right),
+            });
+        }
+    }
+
+    // `LEFT [OUTER] JOIN`, `RIGHT [OUTER] JOIN` or `FULL [OUTER] JOIN`
+    fn parse_outer_join(&mut self, side: &str) -> bool {
+        self.parse_keywords(&[side, "JOIN"]) || self.parse_keywords(&[side, "OUTER", "JOIN"])
+    }
+
+    fn parse_table_primary(&mut self) -> Result<TableReference> {
+        if self.consume(&TokenKind::LParen) {
+            // `(SELECT ...)` or `((SELECT ...) UNION ...)` is a subquery, otherwise it's
+            // a parenthesized table reference, like `(a JOIN b ON ...)`.
+            if self.is_keyword("SELECT") || self.peek().kind == TokenKind::LParen {
+                let subquery = Box::new(self.parse_query()?);
+                self.expect(&TokenKind::RParen)?;
+                let alias = self.parse_table_alias()?;
+                return Ok(TableReference::Subquery { subquery, alias });
+            }
+
+            let table_reference = self.parse_table_reference()?;
+            self.expect(&TokenKind::RParen)?;
+            return Ok(table_reference);
+        }
+
+        let (database, table) = self.parse_object_name()?;
+        if self.peek().kind == TokenKind::LParen {
+            let name = match database {
+                None => table.name,
+                Some(database) => format!("{}.{}", database.name, table.name),
+            };
+            let expr = self.parse_function_call(name)?;
+            let alias = self.parse_table_alias()?;
+            return Ok(TableReference::TableFunction { expr, alias });
+        }
+
+        let alias = self.parse_table_alias()?;
+        Ok(TableReference::Table {
+            database,
+            table,
+            alias,
+        })
+    }
+}
@@ -0,0 +1,200 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+
+use super::NativeParser;
+use super::TokenKind;
+use crate::sql::parser::ast::ColumnDefinition;
+use crate::sql::parser::ast::Identifier;
+use crate::sql::parser::ast::Literal;
+use crate::sql::parser::ast::SQLProperty;
+use crate::sql::parser::ast::Statement;
+
+impl NativeParser {
+    pub(super) fn parse_statement(&mut self) -> Result<Statement> {
+        if self.peek().kind == TokenKind::LParen || self.is_keyword("SELECT") {
+            return Ok(Statement::Select(Box::new(self.parse_query()?)));
+        }
+
+        if self.parse_keyword("EXPLAIN") {
+            let analyze = self.parse_keyword("ANALYZE");
+            let query = Box::new(self.parse_statement()?);
+            return Ok(Statement::Explain { analyze, query });
+        }
+
+        if self.parse_keyword("SHOW") {
+            return self.parse_show();
+        }
+
+        if self.parse_keyword("DESC") || self.parse_keyword("DESCRIBE") {
+            self.parse_keyword("TABLE");
+            let (database, table) = self.parse_object_name()?;
+            return Ok(Statement::Describe { database, table });
+        }
+
+        if self.parse_keywords(&["CREATE", "TABLE"]) {
+            return self.parse_create_table();
+        }
+
+        if self.parse_keywords(&["CREATE", "DATABASE"]) {
+            let if_not_exists = self.parse_keywords(&["IF", "NOT", "EXISTS"]);
+            let name = self.parse_identifier()?;
+            let engine = self.parse_engine()?;
+            let options = self.parse_options()?;
+            return Ok(Statement::CreateDatabase {
+                if_not_exists,
+                name,
+                engine,
+                options,
+            });
+        }
+
+        if self.parse_keywords(&["DROP", "TABLE"]) {
+            let if_exists = self.parse_keywords(&["IF", "EXISTS"]);
+            let (database, table) = self.parse_object_name()?;
+            return Ok(Statement::DropTable {
+                if_exists,
+                database,
+                table,
+            });
+        }
+
+        if self.parse_keyword("TRUNCATE") {
+            self.parse_keyword("TABLE");
+            let (database, table) = self.parse_object_name()?;
+            return Ok(Statement::TruncateTable { database, table });
+        }
+
+        if self.parse_keyword("USE") {
+            let name = self.parse_identifier()?;
+            return Ok(Statement::UseDatabase { name });
+        }
+
+        if self.parse_keyword("KILL") {
+            if !self.parse_keyword("QUERY") {
+                self.parse_keyword("CONNECTION");
+            }
+
+            let object_id = match &self.peek().kind {
+                TokenKind::Number(id) => {
+                    let name = id.clone();
+                    self.next();
+                    Identifier { name, quote: None }
+                }
+                _ => self.parse_identifier()?,
+            };
+            return Ok(Statement::KillStmt { object_id });
+        }
+
+        self.expected("statement")
+    }
+
+    fn parse_show(&mut self) -> Result<Statement> {
+        if self.parse_keyword("TABLES") {
+            return Ok(Statement::ShowTables);
+        }
+
+        if self.parse_keyword("DATABASES") {
+            return Ok(Statement::ShowDatabases);
+        }
+
+        if self.parse_keyword("SETTINGS") {
+            return Ok(Statement::ShowSettings);
+        }
+
+        if self.parse_keyword("PROCESSLIST") {
+            return Ok(Statement::ShowProcessList);
+        }
+
+        if self.parse_keywords(&["CREATE", "TABLE"]) {
+            let (database, table) = self.parse_object_name()?;
+            return Ok(Statement::ShowCreateTable { database, table });
+        }
+
+        self.expected("TABLES, DATABASES, SETTINGS, PROCESSLIST or CREATE TABLE")
+    }
+
+    fn parse_create_table(&mut self) -> Result<Statement> {
+        let if_not_exists = self.parse_keywords(&["IF", "NOT", "EXISTS"]);
+        let (database, table) = self.parse_object_name()?;
+
+        self.expect(&TokenKind::LParen)?;
+        let columns = self.parse_comma_separated(Self::parse_column_definition)?;
+        self.expect(&TokenKind::RParen)?;
+
+        let engine = self.parse_engine()?;
+        let options = self.parse_options()?;
+        Ok(Statement::CreateTable {
+            if_not_exists,
+            database,
+            table,
+            columns,
+            engine,
+            options,
+        })
+    }
+
+    // `name type [NULL | NOT NULL] [DEFAULT literal]`, the column is nullable by default.
+    fn parse_column_definition(&mut self) -> Result<ColumnDefinition> {
+        let name = self.parse_identifier()?;
+        let data_type = self.parse_type_name()?;
+
+        let mut nullable = true;
+        let mut default_value = None;
+        loop {
+            if self.parse_keyword("NULL") {
+                nullable = true;
+            } else if self.parse_keywords(&["NOT", "NULL"]) {
+                nullable = false;
+            } else if self.parse_keyword("DEFAULT") {
+                default_value = Some(self.parse_literal()?);
+            } else {
+                break;
+            }
+        }
+
+        Ok(ColumnDefinition {
+            name,
+            data_type,
+            nullable,
+            default_value,
+        })
+    }
+
+    // `ENGINE [=] name`
+    fn parse_engine(&mut self) -> Result<String> {
+        if !self.parse_keyword("ENGINE") {
+            return Ok(String::new());
+        }
+
+        self.consume(&TokenKind::Eq);
+        Ok(self.parse_identifier()?.name)
+    }
+
+    // Options like `LOCATION = 'path'` following the engine.
+    fn parse_options(&mut self) -> Result<Vec<SQLProperty>> {
+        let mut options = vec![];
+        while matches!(self.peek().kind, TokenKind::Word(_)) {
+            let name = self.parse_identifier()?.name;
+            self.expect(&TokenKind::Eq)?;
+            let value = match self.parse_literal()? {
+                Literal::Number(value) | Literal::String(value) => value,
+                literal => literal.to_string(),
+            };
+            options.push(SQLProperty { name, value });
+        }
+        Ok(options)
+    }
+}
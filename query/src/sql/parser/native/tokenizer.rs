@@ -0,0 +1,353 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::iter::Peekable;
+use std::str::CharIndices;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+// Location of a token in the SQL text, both line and column are 1-based.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Position {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl Display for Position {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {}, column {}", self.line, self.column)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TokenKind {
+    // Unquoted word, it can be a keyword or an identifier
+    Word(String),
+    // Identifier quoted with `"` or `` ` ``
+    QuotedIdent(String, char),
+    Number(String),
+    // Single quoted string literal
+    String(String),
+    Comma,
+    Period,
+    LParen,
+    RParen,
+    SemiColon,
+    Plus,
+    Minus,
+    Multiply,
+    Divide,
+    Modulo,
+    Eq,
+    NotEq,
+    Lt,
+    Gt,
+    LtEq,
+    GtEq,
+    StringConcat,
+    BitwiseOr,
+    BitwiseAnd,
+    BitwiseXor,
+    DoubleColon,
+    EOF,
+}
+
+impl Display for TokenKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TokenKind::Word(word) => write!(f, "{}", word),
+            TokenKind::QuotedIdent(ident, quote) => write!(f, "{}{}{}", quote, ident, quote),
+            TokenKind::Number(number) => write!(f, "{}", number),
+            TokenKind::String(string) => write!(f, "'{}'", string),
+            TokenKind::Comma => write!(f, ","),
+            TokenKind::Period => write!(f, "."),
+            TokenKind::LParen => write!(f, "("),
+            TokenKind::RParen => write!(f, ")"),
+            TokenKind::SemiColon => write!(f, ";"),
+            TokenKind::Plus => write!(f, "+"),
+            TokenKind::Minus => write!(f, "-"),
+            TokenKind::Multiply => write!(f, "*"),
+            TokenKind::Divide => write!(f, "/"),
+            TokenKind::Modulo => write!(f, "%"),
+            TokenKind::Eq => write!(f, "="),
+            TokenKind::NotEq => write!(f, "<>"),
+            TokenKind::Lt => write!(f, "<"),
+            TokenKind::Gt => write!(f, ">"),
+            TokenKind::LtEq => write!(f, "<="),
+            TokenKind::GtEq => write!(f, ">="),
+            TokenKind::StringConcat => write!(f, "||"),
+            TokenKind::BitwiseOr => write!(f, "|"),
+            TokenKind::BitwiseAnd => write!(f, "&"),
+            TokenKind::BitwiseXor => write!(f, "^"),
+            TokenKind::DoubleColon => write!(f, "::"),
+            TokenKind::EOF => write!(f, "EOF"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Token {
+    pub kind: TokenKind,
+    pub position: Position,
+}
+
+impl Token {
+    // Whether the token is the given keyword, keywords are case insensitive.
+    pub fn is_keyword(&self, keyword: &str) -> bool {
+        matches!(&self.kind, TokenKind::Word(word) if word.eq_ignore_ascii_case(keyword))
+    }
+}
+
+// Split the SQL text into tokens, the whitespaces and the comments are skipped.
+// The last token is always `TokenKind::EOF`.
+pub struct Tokenizer<'a> {
+    chars: Peekable<CharIndices<'a>>,
+    line: usize,
+    column: usize,
+}
+
+impl<'a> Tokenizer<'a> {
+    pub fn new(sql: &'a str) -> Self {
+        Tokenizer {
+            chars: sql.char_indices().peekable(),
+            line: 1,
+            column: 1,
+        }
+    }
+
+    pub fn tokenize(mut self) -> Result<Vec<Token>> {
+        let mut tokens = vec![];
+        loop {
+            self.skip_whitespaces_and_comments()?;
+            let position = self.position();
+            let kind = match self.peek() {
+                None => {
+                    tokens.push(Token {
+                        kind: TokenKind::EOF,
+                        position,
+                    });
+                    return Ok(tokens);
+                }
+                Some(c) => self.next_token_kind(c, position)?,
+            };
+            tokens.push(Token { kind, position });
+        }
+    }
+
+    fn next_token_kind(&mut self, c: char, position: Position) -> Result<TokenKind> {
+        match c {
+            c if c.is_ascii_alphabetic() || c == '_' => {
+                Ok(TokenKind::Word(self.take_while(|c| {
+                    c.is_ascii_alphanumeric() || c == '_' || c == '$'
+                })))
+            }
+            c if c.is_ascii_digit() => Ok(TokenKind::Number(self.number())),
+            '.' => {
+                self.next();
+                match self.peek() {
+                    Some(c) if c.is_ascii_digit() => {
+                        let fraction = self.number();
+                        Ok(TokenKind::Number(format!(".{}", fraction)))
+                    }
+                    _ => Ok(TokenKind::Period),
+                }
+            }
+            '\'' => Ok(TokenKind::String(self.quoted('\'', position)?)),
+            '"' | '`' => Ok(TokenKind::QuotedIdent(self.quoted(c, position)?, c)),
+            _ => {
+                self.next();
+                let kind = match c {
+                    ',' => TokenKind::Comma,
+                    '(' => TokenKind::LParen,
+                    ')' => TokenKind::RParen,
+                    ';' => TokenKind::SemiColon,
+                    '+' => TokenKind::Plus,
+                    '-' => TokenKind::Minus,
+                    '*' => TokenKind::Multiply,
+                    '/' => TokenKind::Divide,
+                    '%' => TokenKind::Modulo,
+                    '=' if self.next_if('=') => TokenKind::Eq,
+                    '=' => TokenKind::Eq,
+                    '!' if self.next_if('=') => TokenKind::NotEq,
+                    '<' if self.next_if('=') => TokenKind::LtEq,
+                    '<' if self.next_if('>') => TokenKind::NotEq,
+                    '<' => TokenKind::Lt,
+                    '>' if self.next_if('=') => TokenKind::GtEq,
+                    '>' => TokenKind::Gt,
+                    '|' if self.next_if('|') => TokenKind::StringConcat,
+                    '|' => TokenKind::BitwiseOr,
+                    '&' => TokenKind::BitwiseAnd,
+                    '^' => TokenKind::BitwiseXor,
+                    ':' if self.next_if(':') => TokenKind::DoubleColon,
+                    other => {
+                        return Err(ErrorCode::SyntaxException(format!(
+                            "Unexpected character '{}' at {}",
+                            other, position
+                        )));
+                    }
+                };
+                Ok(kind)
+            }
+        }
+    }
+
+    // Digits with an optional fraction and an optional exponent, like `1`, `1.5` or `1.5e-3`.
+    fn number(&mut self) -> String {
+        let mut number = self.take_while(|c| c.is_ascii_digit());
+        if self.peek() == Some('.') {
+            self.next();
+            number.push('.');
+            number.push_str(&self.take_while(|c| c.is_ascii_digit()));
+        }
+
+        if matches!(self.peek(), Some('e') | Some('E')) {
+            let mut lookahead = self.chars.clone();
+            lookahead.next();
+            let signed = matches!(lookahead.peek(), Some((_, '+')) | Some((_, '-')));
+            if signed {
+                lookahead.next();
+            }
+
+            if matches!(lookahead.peek(), Some((_, c)) if c.is_ascii_digit()) {
+                number.push(self.next().unwrap());
+                if signed {
+                    number.push(self.next().unwrap());
+                }
+                number.push_str(&self.take_while(|c| c.is_ascii_digit()));
+            }
+        }
+
+        number
+    }
+
+    // The content of a quoted string or identifier, a doubled quote stands for the quote itself.
+    // Backslash escapes are only recognized in string literals.
+    fn quoted(&mut self, quote: char, position: Position) -> Result<String> {
+        self.next();
+        let mut value = String::new();
+        loop {
+            match self.next() {
+                None => {
+                    return Err(ErrorCode::SyntaxException(format!(
+                        "Unterminated quoted text starting at {}",
+                        position
+                    )));
+                }
+                Some(c) if c == quote => {
+                    if self.next_if(quote) {
+                        value.push(quote);
+                    } else {
+                        return Ok(value);
+                    }
+                }
+                Some('\\') if quote == '\'' => match self.next() {
+                    None => continue,
+                    Some('n') => value.push('\n'),
+                    Some('t') => value.push('\t'),
+                    Some('r') => value.push('\r'),
+                    Some('0') => value.push('\0'),
+                    Some(c) => value.push(c),
+                },
+                Some(c) => value.push(c),
+            }
+        }
+    }
+
+    fn skip_whitespaces_and_comments(&mut self) -> Result<()> {
+        loop {
+            match self.peek() {
+                Some(c) if c.is_whitespace() => {
+                    self.next();
+                }
+                Some('-') if self.peek_second() == Some('-') => {
+                    self.take_while(|c| c != '\n');
+                }
+                Some('#') => {
+                    self.take_while(|c| c != '\n');
+                }
+                Some('/') if self.peek_second() == Some('*') => {
+                    let position = self.position();
+                    self.next();
+                    self.next();
+                    loop {
+                        match self.next() {
+                            None => {
+                                return Err(ErrorCode::SyntaxException(format!(
+                                    "Unterminated comment starting at {}",
+                                    position
+                                )));
+                            }
+                            Some('*') if self.next_if('/') => break,
+                            Some(_) => {}
+                        }
+                    }
+                }
+                _ => return Ok(()),
+            }
+        }
+    }
+
+    fn take_while(&mut self, predicate: impl Fn(char) -> bool) -> String {
+        let mut value = String::new();
+        while let Some(c) = self.peek() {
+            if !predicate(c) {
+                break;
+            }
+            value.push(c);
+            self.next();
+        }
+        value
+    }
+
+    fn position(&self) -> Position {
+        Position {
+            line: self.line,
+            column: self.column,
+        }
+    }
+
+    fn peek(&mut self) -> Option<char> {
+        self.chars.peek().map(|(_, c)| *c)
+    }
+
+    fn peek_second(&self) -> Option<char> {
+        let mut chars = self.chars.clone();
+        chars.next();
+        chars.next().map(|(_, c)| c)
+    }
+
+    fn next_if(&mut self, expected: char) -> bool {
+        match self.peek() {
+            Some(c) if c == expected => {
+                self.next();
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let (_, c) = self.chars.next()?;
+        if c == '\n' {
+            self.line += 1;
+            self.column = 1;
+        } else {
+            self.column += 1;
+        }
+        Some(c)
+    }
+}
@@ -271,10 +271,19 @@ impl TransformerSqlparser {
             .as_ref()
             .map(|expr| self.transform_expr(expr))
             .transpose()?;
+        let offset = orig_ast
+            .offset
+            .as_ref()
+            .map(|offset| self.transform_expr(&offset.value))
+            .transpose()?;
         Ok(Query {
             body,
             order_by,
+            limit_by: None,
             limit,
+            offset,
+            settings: vec![],
+            format: None,
         })
     }
 
@@ -366,26 +375,24 @@ impl TransformerSqlparser {
         })
     }
 
-    fn transform_from(&self, orig_ast: &[TableWithJoins]) -> Result<TableReference> {
+    fn transform_from(&self, orig_ast: &[TableWithJoins]) -> Result<Option<TableReference>> {
         let mut table_refs: Vec<TableReference> = orig_ast
             .iter()
             .map(|v| self.transform_table_with_joins(v))
             .collect::<Result<_>>()?;
         if !table_refs.is_empty() {
             let head = table_refs.drain(0..1).next().unwrap();
-            table_refs.into_iter().fold(Ok(head), |acc, r| {
-                Ok(TableReference::Join(Join {
+            let from = table_refs.into_iter().fold(head, |acc, r| {
+                TableReference::Join(Join {
                     op: JoinOperator::CrossJoin,
                     condition: JoinCondition::None,
-                    left: Box::new(acc?),
+                    left: Box::new(acc),
                     right: Box::new(r),
-                }))
-            })
+                })
+            });
+            Ok(Some(from))
         } else {
-            Err(ErrorCode::SyntaxException(format!(
-                "Invalid SQL statement: {}",
-                self.orig_stmt
-            )))
+            Ok(None)
         }
     }
 
@@ -26,7 +26,11 @@ use crate::sessions::QueryContext;
 use crate::sql::statements::AnalyzableStatement;
 use crate::sql::statements::AnalyzedResult;
 use crate::sql::statements::QueryAnalyzeState;
+use crate::sql::statements::QueryJoinInput;
+use crate::sql::statements::QueryJoinRelation;
 use crate::sql::statements::QueryRelation;
+use crate::sql::statements::QuerySetOperationRelation;
+use crate::sql::statements::SetOperationType;
 use crate::sql::DfHint;
 use crate::sql::DfParser;
 use crate::sql::DfStatement;
@@ -74,7 +78,8 @@ impl PlanParser {
         let from = Self::build_from_plan(data)?;
         let filter = Self::build_filter_plan(from, data)?;
         let group_by = Self::build_group_by_plan(filter, data)?;
-        let before_order = Self::build_before_order(group_by, data)?;
+        let window = Self::build_window_plan(group_by, data)?;
+        let before_order = Self::build_before_order(window, data)?;
         let having = Self::build_having_plan(before_order, data)?;
         let order_by = Self::build_order_by_plan(having, data)?;
         let projection = Self::build_projection_plan(order_by, data)?;
@@ -86,10 +91,46 @@ impl PlanParser {
     }
 
     fn build_from_plan(data: &QueryAnalyzeState) -> Result<PlanNode> {
-        match &data.relation {
+        Self::build_relation_plan(&data.relation)
+    }
+
+    fn build_relation_plan(relation: &QueryRelation) -> Result<PlanNode> {
+        match relation {
             QueryRelation::None => Err(ErrorCode::LogicalError("Not from in select query")),
             QueryRelation::Nested(data) => Self::build_query_plan(data),
             QueryRelation::FromTable(plan) => Ok(PlanNode::ReadSource(plan.as_ref().clone())),
+            QueryRelation::Join(join) => Self::build_join_plan(join),
+            QueryRelation::SetOperation(set_operation) => {
+                Self::build_set_operation_plan(set_operation)
+            }
+        }
+    }
+
+    pub fn build_set_operation_plan(set_operation: &QuerySetOperationRelation) -> Result<PlanNode> {
+        let left = Self::build_query_plan(&set_operation.left)?;
+        let right = Self::build_query_plan(&set_operation.right)?;
+        let builder = PlanBuilder::from(&left);
+        match set_operation.op {
+            SetOperationType::UnionAll => builder.union_all(&right)?.build(),
+            SetOperationType::Union => builder.union(&right)?.build(),
+            SetOperationType::Intersect => builder.intersect(&right)?.build(),
+            SetOperationType::Except => builder.except(&right)?.build(),
+        }
+    }
+
+    fn build_join_plan(join: &QueryJoinRelation) -> Result<PlanNode> {
+        let left = Self::build_join_input_plan(&join.left)?;
+        let right = Self::build_join_input_plan(&join.right)?;
+        PlanBuilder::from(&left)
+            .join(&right, join.join_type, join.condition.clone())?
+            .build()
+    }
+
+    fn build_join_input_plan(input: &QueryJoinInput) -> Result<PlanNode> {
+        let plan = Self::build_relation_plan(&input.relation)?;
+        match input.projection.is_empty() {
+            true => Ok(plan),
+            false => PlanBuilder::from(&plan).project(&input.projection)?.build(),
         }
     }
 
@@ -149,6 +190,31 @@ impl PlanParser {
         }
     }
 
+    fn build_window_plan(plan: PlanNode, data: &QueryAnalyzeState) -> Result<PlanNode> {
+        fn is_all_column(exprs: &[Expression]) -> bool {
+            exprs
+                .iter()
+                .all(|expr| matches!(expr, Expression::Column(_)))
+        }
+
+        if data.window_expressions.is_empty() {
+            return Ok(plan);
+        }
+
+        let mut builder = match is_all_column(&data.before_window_expressions) {
+            true => PlanBuilder::from(&plan),
+            false => PlanBuilder::from(&plan)
+                .expression(&data.before_window_expressions, "Before Window")?,
+        };
+
+        // Each window function has its own partitions and ordering, evaluate them one by one.
+        for window_expression in &data.window_expressions {
+            builder = builder.window_func(window_expression.clone())?;
+        }
+
+        builder.build()
+    }
+
     fn build_before_order(plan: PlanNode, data: &QueryAnalyzeState) -> Result<PlanNode> {
         fn is_all_column(exprs: &[Expression]) -> bool {
             exprs
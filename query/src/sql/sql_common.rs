@@ -17,6 +17,8 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use sqlparser::ast::DataType as SQLDataType;
 
+use crate::sql::DfParser;
+
 pub struct SQLCommon;
 
 impl SQLCommon {
@@ -31,7 +33,14 @@ impl SQLCommon {
             SQLDataType::Varchar(_) => Ok(DataType::String),
             SQLDataType::String => Ok(DataType::String),
             SQLDataType::Text => Ok(DataType::String),
-            SQLDataType::Decimal(_, _) => Ok(DataType::Float64),
+            SQLDataType::Decimal(precision, scale) => {
+                let precision = precision.unwrap_or(DEFAULT_DECIMAL_PRECISION as u64);
+                let scale = scale.unwrap_or(0);
+                create_decimal_type(
+                    u8::try_from(precision).unwrap_or(u8::MAX),
+                    u8::try_from(scale).unwrap_or(u8::MAX),
+                )
+            }
             SQLDataType::Float(_) => Ok(DataType::Float32),
             SQLDataType::Real | SQLDataType::Double => Ok(DataType::Float64),
             SQLDataType::Boolean => Ok(DataType::Boolean),
@@ -54,10 +63,31 @@ impl SQLCommon {
                     "FLOAT32" => Ok(DataType::Float32),
                     "FLOAT64" => Ok(DataType::Float64),
                     "STRING" => Ok(DataType::String),
+                    "VARIANT" | "JSON" => Ok(DataType::Variant),
                     "DATE16" => Ok(DataType::Date16),
                     "DATE32" => Ok(DataType::Date32),
                     "DATETIME" => Ok(DataType::DateTime32(None)),
                     "DATETIME32" => Ok(DataType::DateTime32(None)),
+                    // DateTime64[(precision[, 'timezone'])], see DfParser::parse_column_data_type
+                    "DATETIME64" => {
+                        let precision = match obj.0.get(1) {
+                            Some(ident) => ident.value.parse::<u32>().map_err(|_| {
+                                ErrorCode::IllegalDataType(format!(
+                                    "Invalid DateTime64 precision: {}",
+                                    ident.value
+                                ))
+                            })?,
+                            None => DEFAULT_DATETIME64_PRECISION,
+                        };
+                        let tz = obj.0.get(2).map(|ident| ident.value.clone());
+                        create_datetime64_type(precision, tz)
+                    }
+                    // Map(K, V), see DfParser::parse_column_data_type
+                    "MAP" if obj.0.len() == 3 => {
+                        let key_type = Self::make_arg_data_type(&obj.0[1].value)?;
+                        let value_type = Self::make_arg_data_type(&obj.0[2].value)?;
+                        create_map_type(key_type, value_type)
+                    }
                     "SIGNED" => Ok(DataType::Int64),
                     "UNSIGNED" => Ok(DataType::UInt64),
 
@@ -73,4 +103,11 @@ impl SQLCommon {
             ))),
         }
     }
+
+    fn make_arg_data_type(text: &str) -> Result<DataType> {
+        let sql_type = DfParser::parse_data_type(text).map_err(|e| {
+            ErrorCode::IllegalDataType(format!("Invalid type argument {}: {}", text, e))
+        })?;
+        Self::make_data_type(&sql_type)
+    }
 }
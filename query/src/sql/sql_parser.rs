@@ -23,12 +23,15 @@ use common_meta_types::AuthType;
 use common_meta_types::UserPrivilege;
 use common_meta_types::UserPrivilegeType;
 use common_planners::ExplainType;
+use common_planners::PurgeRetention;
 use metrics::histogram;
 use sqlparser::ast::BinaryOperator;
 use sqlparser::ast::ColumnDef;
 use sqlparser::ast::ColumnOptionDef;
+use sqlparser::ast::DataType;
 use sqlparser::ast::Expr;
 use sqlparser::ast::Ident;
+use sqlparser::ast::ObjectName;
 use sqlparser::ast::SqlOption;
 use sqlparser::ast::Statement;
 use sqlparser::ast::TableConstraint;
@@ -44,11 +47,14 @@ use sqlparser::tokenizer::Tokenizer;
 use sqlparser::tokenizer::Whitespace;
 
 use super::statements::DfCopy;
+use crate::sql::statements::DfAlterTable;
+use crate::sql::statements::DfAlterTableAction;
 use crate::sql::statements::DfAlterUser;
 use crate::sql::statements::DfCompactTable;
 use crate::sql::statements::DfCreateDatabase;
 use crate::sql::statements::DfCreateTable;
 use crate::sql::statements::DfCreateUser;
+use crate::sql::statements::DfDeleteStatement;
 use crate::sql::statements::DfDescribeTable;
 use crate::sql::statements::DfDropDatabase;
 use crate::sql::statements::DfDropTable;
@@ -58,6 +64,8 @@ use crate::sql::statements::DfGrantObject;
 use crate::sql::statements::DfGrantStatement;
 use crate::sql::statements::DfInsertStatement;
 use crate::sql::statements::DfKillStatement;
+use crate::sql::statements::DfOptimizeTable;
+use crate::sql::statements::DfOptimizeTableAction;
 use crate::sql::statements::DfQueryStatement;
 use crate::sql::statements::DfRevokeStatement;
 use crate::sql::statements::DfSetVariable;
@@ -69,6 +77,7 @@ use crate::sql::statements::DfShowSettings;
 use crate::sql::statements::DfShowTables;
 use crate::sql::statements::DfShowUsers;
 use crate::sql::statements::DfTruncateTable;
+use crate::sql::statements::DfUpdateStatement;
 use crate::sql::statements::DfUseDatabase;
 use crate::sql::DfHint;
 use crate::sql::DfStatement;
@@ -95,13 +104,180 @@ impl<'a> DfParser<'a> {
     /// Parse the specified tokens with dialect
     pub fn new_with_dialect(sql: &str, dialect: &'a dyn Dialect) -> Result<Self, ParserError> {
         let mut tokenizer = Tokenizer::new(dialect, sql);
-        let tokens = tokenizer.tokenize()?;
+        let tokens = Self::strip_time_travel_keyword(tokenizer.tokenize()?);
+        let tokens = Self::rewrite_lambda(tokens);
 
         Ok(DfParser {
             parser: Parser::new(tokens, dialect),
         })
     }
 
+    /// Time travel of a table is expressed as `t AT (SNAPSHOT => 'id')` or
+    /// `t AT (TIMESTAMP => 'ts')` right after the table name of a table factor, which is not
+    /// recognized by sqlparser. The `AT` of such a table factor is removed so that the travel
+    /// point is parsed as a named argument of the table, any other `AT` is left as it is.
+    fn strip_time_travel_keyword(tokens: Vec<Token>) -> Vec<Token> {
+        let positions = (0..tokens.len())
+            .filter(|idx| !matches!(tokens[*idx], Token::Whitespace(_)))
+            .collect::<Vec<_>>();
+        let token = |i: usize| positions.get(i).map(|idx| &tokens[*idx]);
+        let is_word = |i: usize, words: &[&str]| match token(i) {
+            Some(Token::Word(w)) if w.quote_style.is_none() => {
+                words.iter().any(|word| w.value.eq_ignore_ascii_case(word))
+            }
+            _ => false,
+        };
+        let is_time_travel_at = |i: usize| {
+            is_word(i, &["AT"])
+                && token(i + 1) == Some(&Token::LParen)
+                && is_word(i + 2, &["SNAPSHOT", "TIMESTAMP"])
+                && token(i + 3) == Some(&Token::RArrow)
+        };
+
+        // Whether each level of parentheses is inside a FROM clause, and whether the next
+        // token starts a table factor.
+        let mut in_from = vec![false];
+        let mut expect_table = false;
+        let mut stripped = vec![];
+        let mut i = 0;
+        while let Some(current) = token(i) {
+            i += 1;
+            match current {
+                Token::LParen => in_from.push(expect_table),
+                Token::RParen => {
+                    in_from.pop();
+                    expect_table = false;
+                }
+                Token::Comma => expect_table = in_from.last() == Some(&true),
+                Token::Word(_) if is_word(i - 1, &["FROM", "JOIN"]) => {
+                    in_from.pop();
+                    in_from.push(true);
+                    expect_table = true;
+                }
+                Token::Word(_) if is_word(i - 1, Self::CLAUSES_AFTER_FROM) => {
+                    in_from.pop();
+                    in_from.push(false);
+                    expect_table = false;
+                }
+                Token::Word(_) if expect_table => {
+                    // the table name, `table` or `db.table`
+                    while token(i) == Some(&Token::Period)
+                        && matches!(token(i + 1), Some(Token::Word(_)))
+                    {
+                        i += 2;
+                    }
+                    if is_time_travel_at(i) {
+                        stripped.push(positions[i]);
+                    }
+                    expect_table = false;
+                }
+                _ => expect_table = false,
+            }
+        }
+
+        tokens
+            .into_iter()
+            .enumerate()
+            .filter(|(idx, _)| !stripped.contains(idx))
+            .map(|(_, token)| token)
+            .collect()
+    }
+
+    /// The keywords that end the FROM clause of a select.
+    const CLAUSES_AFTER_FROM: &'static [&'static str] = &[
+        "SELECT",
+        "WHERE",
+        "GROUP",
+        "HAVING",
+        "ORDER",
+        "LIMIT",
+        "OFFSET",
+        "UNION",
+        "EXCEPT",
+        "INTERSECT",
+    ];
+
+    /// The lambda of the higher-order functions is expressed as `x -> x + 1` or
+    /// `(x, y) -> x + y`, which is not recognized by sqlparser. It's rewritten to
+    /// `lambda(x, x + 1)` or `lambda((x, y), x + y)`, which is resolved by the analyzer.
+    fn rewrite_lambda(mut tokens: Vec<Token>) -> Vec<Token> {
+        while let Some((start, arrow, body)) = Self::find_lambda(&tokens) {
+            let end = match Self::find_lambda_end(&tokens, body) {
+                Some(end) => end,
+                None => break,
+            };
+
+            let mut rewritten = Vec::with_capacity(tokens.len() + 3);
+            rewritten.extend_from_slice(&tokens[..start]);
+            rewritten.push(Token::make_word("lambda", None));
+            rewritten.push(Token::LParen);
+            rewritten.extend_from_slice(&tokens[start..arrow]);
+            rewritten.push(Token::Comma);
+            rewritten.extend_from_slice(&tokens[body..end]);
+            rewritten.push(Token::RParen);
+            rewritten.extend_from_slice(&tokens[end..]);
+            tokens = rewritten;
+        }
+        tokens
+    }
+
+    /// Returns the start of the params, the arrow and the start of the body of the first lambda,
+    /// which must be an argument of a function.
+    fn find_lambda(tokens: &[Token]) -> Option<(usize, usize, usize)> {
+        let prev_token = |idx: usize| {
+            (0..idx)
+                .rev()
+                .find(|idx| !matches!(tokens[*idx], Token::Whitespace(_)))
+        };
+
+        for arrow in 0..tokens.len() {
+            // The arrow is tokenized as a single token or as `-` followed by `>`.
+            let body = match &tokens[arrow] {
+                token if token.to_string() == "->" => arrow + 1,
+                Token::Minus if tokens.get(arrow + 1) == Some(&Token::Gt) => arrow + 2,
+                _ => continue,
+            };
+
+            let start = match prev_token(arrow).map(|idx| (idx, &tokens[idx])) {
+                Some((idx, Token::Word(_))) => Some(idx),
+                Some((idx, Token::RParen)) => {
+                    let mut depth = 0;
+                    (0..=idx).rev().find(|idx| {
+                        match tokens[*idx] {
+                            Token::RParen => depth += 1,
+                            Token::LParen => depth -= 1,
+                            _ => {}
+                        }
+                        depth == 0
+                    })
+                }
+                _ => None,
+            };
+
+            if let Some(start) = start {
+                let prev = prev_token(start).map(|idx| &tokens[idx]);
+                if matches!(prev, Some(Token::LParen) | Some(Token::Comma)) {
+                    return Some((start, arrow, body));
+                }
+            }
+        }
+        None
+    }
+
+    /// The body of the lambda ends before the comma or the right parenthesis of the function.
+    fn find_lambda_end(tokens: &[Token], body: usize) -> Option<usize> {
+        let mut depth = 0;
+        for (idx, token) in tokens.iter().enumerate().skip(body) {
+            match token {
+                Token::LParen | Token::LBracket => depth += 1,
+                Token::RParen | Token::RBracket if depth > 0 => depth -= 1,
+                Token::RParen | Token::Comma if depth == 0 => return Some(idx),
+                _ => {}
+            }
+        }
+        None
+    }
+
     /// Parse a SQL statement and produce a set of statements with dialect
     pub fn parse_sql(sql: &str) -> Result<(Vec<DfStatement>, Vec<DfHint>), ErrorCode> {
         let dialect = &GenericDialect {};
@@ -229,6 +405,8 @@ impl<'a> DfParser<'a> {
                     Keyword::TRUNCATE => self.parse_truncate(),
                     Keyword::SET => self.parse_set(),
                     Keyword::INSERT => self.parse_insert(),
+                    Keyword::UPDATE => self.parse_update(),
+                    Keyword::DELETE => self.parse_delete(),
                     Keyword::SELECT | Keyword::WITH | Keyword::VALUES => self.parse_query(),
                     Keyword::GRANT => {
                         self.parser.next_token();
@@ -247,6 +425,7 @@ impl<'a> DfParser<'a> {
                         "USE" => self.parse_use_database(),
                         "KILL" => self.parse_kill_query(),
                         "COMPACT" => self.parse_compact(),
+                        "OPTIMIZE" => self.parse_optimize(),
                         _ => self.expected("Keyword", self.parser.peek_token()),
                     },
                     _ => self.expected("an SQL statement", Token::Word(w)),
@@ -311,6 +490,47 @@ impl<'a> DfParser<'a> {
         }
     }
 
+    fn parse_delete(&mut self) -> Result<DfStatement, ParserError> {
+        self.parser.next_token();
+        self.parser.expect_keyword(Keyword::FROM)?;
+        let name = self.parser.parse_object_name()?;
+        let selection = match self.parser.parse_keyword(Keyword::WHERE) {
+            true => Some(self.parser.parse_expr()?),
+            false => None,
+        };
+
+        Ok(DfStatement::Delete(DfDeleteStatement { name, selection }))
+    }
+
+    fn parse_update(&mut self) -> Result<DfStatement, ParserError> {
+        self.parser.next_token();
+        let name = self.parser.parse_object_name()?;
+        self.parser.expect_keyword(Keyword::SET)?;
+
+        let mut assignments = vec![];
+        loop {
+            let column = self.parser.parse_identifier()?;
+            self.parser.expect_token(&Token::Eq)?;
+            let value = self.parser.parse_expr()?;
+            assignments.push((column, value));
+
+            if !self.parser.consume_token(&Token::Comma) {
+                break;
+            }
+        }
+
+        let selection = match self.parser.parse_keyword(Keyword::WHERE) {
+            true => Some(self.parser.parse_expr()?),
+            false => None,
+        };
+
+        Ok(DfStatement::Update(DfUpdateStatement {
+            name,
+            assignments,
+            selection,
+        }))
+    }
+
     /// Parse an SQL EXPLAIN statement.
     pub fn parse_explain(&mut self) -> Result<DfStatement, ParserError> {
         // Parser is at the token immediately after EXPLAIN
@@ -432,7 +652,7 @@ impl<'a> DfParser<'a> {
 
     fn parse_column_def(&mut self) -> Result<ColumnDef, ParserError> {
         let name = self.parser.parse_identifier()?;
-        let data_type = self.parser.parse_data_type()?;
+        let data_type = self.parse_column_data_type()?;
         let collation = if self.parser.parse_keyword(Keyword::COLLATE) {
             Some(self.parser.parse_object_name()?)
         } else {
@@ -464,6 +684,75 @@ impl<'a> DfParser<'a> {
         })
     }
 
+    // The sqlparser can't parse the arguments of the custom types, so `DateTime64(3, 'UTC')`
+    // is parsed here, and the arguments are kept as the parts of the custom type name.
+    fn parse_column_data_type(&mut self) -> Result<DataType, ParserError> {
+        let data_type = self.parser.parse_data_type()?;
+        match data_type {
+            DataType::Custom(ObjectName(mut idents))
+                if idents.len() == 1 && idents[0].value.eq_ignore_ascii_case("DateTime64") =>
+            {
+                if self.parser.consume_token(&Token::LParen) {
+                    let precision = self.parser.parse_literal_uint()?;
+                    idents.push(Ident::new(precision.to_string()));
+                    if self.parser.consume_token(&Token::Comma) {
+                        let tz = self.parser.parse_literal_string()?;
+                        idents.push(Ident::new(tz));
+                    }
+                    self.parser.expect_token(&Token::RParen)?;
+                }
+                Ok(DataType::Custom(ObjectName(idents)))
+            }
+            // `Map(K, V)` keeps the text of the key type and the value type, they are parsed
+            // again by `DfParser::parse_data_type` when making the data type.
+            DataType::Custom(ObjectName(mut idents))
+                if idents.len() == 1 && idents[0].value.eq_ignore_ascii_case("Map") =>
+            {
+                self.parser.expect_token(&Token::LParen)?;
+                let key_type = self.parse_type_text()?;
+                self.parser.expect_token(&Token::Comma)?;
+                let value_type = self.parse_type_text()?;
+                self.parser.expect_token(&Token::RParen)?;
+                idents.push(Ident::new(key_type));
+                idents.push(Ident::new(value_type));
+                Ok(DataType::Custom(ObjectName(idents)))
+            }
+            data_type => Ok(data_type),
+        }
+    }
+
+    /// Collect the text of a type argument, until the comma or the right parenthesis
+    /// at the same level.
+    fn parse_type_text(&mut self) -> Result<String, ParserError> {
+        let mut text = String::new();
+        let mut depth = 0;
+        loop {
+            match self.parser.peek_token() {
+                Token::Comma | Token::RParen if depth == 0 => break,
+                Token::EOF => return self.expected("type argument", Token::EOF),
+                Token::LParen => depth += 1,
+                Token::RParen => depth -= 1,
+                _ => {}
+            }
+            text.push_str(&self.parser.next_token().to_string());
+        }
+
+        match text.is_empty() {
+            true => self.expected("type argument", self.parser.peek_token()),
+            false => Ok(text),
+        }
+    }
+
+    /// Parse the text of a column data type, such as the arguments of `Map(K, V)`.
+    pub fn parse_data_type(sql: &str) -> Result<DataType, ParserError> {
+        let mut parser = DfParser::new(sql)?;
+        let data_type = parser.parse_column_data_type()?;
+        match parser.parser.peek_token() {
+            Token::EOF => Ok(data_type),
+            unexpected => parser.expected("end of data type", unexpected),
+        }
+    }
+
     fn parse_create(&mut self) -> Result<DfStatement, ParserError> {
         match self.parser.next_token() {
             Token::Word(w) => match w.keyword {
@@ -479,6 +768,7 @@ impl<'a> DfParser<'a> {
     fn parse_alter(&mut self) -> Result<DfStatement, ParserError> {
         match self.parser.next_token() {
             Token::Word(w) => match w.keyword {
+                Keyword::TABLE => self.parse_alter_table(),
                 Keyword::USER => self.parse_alter_user(),
                 _ => self.expected("alter statement", Token::Word(w)),
             },
@@ -600,6 +890,39 @@ impl<'a> DfParser<'a> {
         Ok(DfStatement::CreateUser(create))
     }
 
+    // ALTER TABLE [db.]table ADD [COLUMN] column_def
+    // ALTER TABLE [db.]table DROP [COLUMN] column
+    // ALTER TABLE [db.]table RENAME COLUMN column TO new_column
+    fn parse_alter_table(&mut self) -> Result<DfStatement, ParserError> {
+        let table_name = self.parser.parse_object_name()?;
+        let action = match self.parser.next_token() {
+            Token::Word(w) => match w.keyword {
+                Keyword::ADD => {
+                    let _ = self.parser.parse_keyword(Keyword::COLUMN);
+                    DfAlterTableAction::AddColumn(self.parse_column_def()?)
+                }
+                Keyword::DROP => {
+                    let _ = self.parser.parse_keyword(Keyword::COLUMN);
+                    DfAlterTableAction::DropColumn(self.parser.parse_identifier()?)
+                }
+                Keyword::RENAME => {
+                    self.parser.expect_keyword(Keyword::COLUMN)?;
+                    let old_name = self.parser.parse_identifier()?;
+                    self.parser.expect_keyword(Keyword::TO)?;
+                    let new_name = self.parser.parse_identifier()?;
+                    DfAlterTableAction::RenameColumn { old_name, new_name }
+                }
+                _ => return self.expected("ADD, DROP or RENAME", Token::Word(w)),
+            },
+            unexpected => return self.expected("alter table action", unexpected),
+        };
+
+        Ok(DfStatement::AlterTable(DfAlterTable {
+            name: table_name,
+            action,
+        }))
+    }
+
     fn parse_alter_user(&mut self) -> Result<DfStatement, ParserError> {
         let if_current_user = self.consume_token("USER")
             && self.parser.expect_token(&Token::LParen).is_ok()
@@ -934,6 +1257,57 @@ impl<'a> DfParser<'a> {
         }
     }
 
+    fn parse_optimize(&mut self) -> Result<DfStatement, ParserError> {
+        self.parser.next_token();
+        self.parser.expect_keyword(Keyword::TABLE)?;
+        let table_name = self.parser.parse_object_name()?;
+        let action = match self.parser.next_token() {
+            Token::Word(w) => match w.value.to_uppercase().as_str() {
+                "COMPACT" => DfOptimizeTableAction::Compact,
+                "PURGE" => DfOptimizeTableAction::Purge(self.parse_purge_retention()?),
+                _ => return self.expected("COMPACT or PURGE", Token::Word(w)),
+            },
+            unexpected => return self.expected("COMPACT or PURGE", unexpected),
+        };
+
+        Ok(DfStatement::OptimizeTable(DfOptimizeTable {
+            name: table_name,
+            action,
+        }))
+    }
+
+    // [RETAIN n SNAPSHOTS | RETAIN n {DAYS | HOURS | MINUTES | SECONDS}]
+    fn parse_purge_retention(&mut self) -> Result<PurgeRetention, ParserError> {
+        if !self.consume_token("RETAIN") {
+            // only the latest snapshot is kept by default
+            return Ok(PurgeRetention::Snapshots(1));
+        }
+
+        let n = self.parser.parse_literal_uint()?;
+        match self.parser.next_token() {
+            Token::Word(w) => match w.value.to_uppercase().as_str() {
+                "SNAPSHOT" | "SNAPSHOTS" => Ok(PurgeRetention::Snapshots(n)),
+                "DAY" | "DAYS" => Self::purge_retention_seconds(n, 24 * 60 * 60, &w.value),
+                "HOUR" | "HOURS" => Self::purge_retention_seconds(n, 60 * 60, &w.value),
+                "MINUTE" | "MINUTES" => Self::purge_retention_seconds(n, 60, &w.value),
+                "SECOND" | "SECONDS" => Self::purge_retention_seconds(n, 1, &w.value),
+                _ => self.expected("SNAPSHOTS, DAYS, HOURS, MINUTES or SECONDS", Token::Word(w)),
+            },
+            unexpected => self.expected("retention unit", unexpected),
+        }
+    }
+
+    fn purge_retention_seconds(
+        n: u64,
+        unit_seconds: u64,
+        unit: &str,
+    ) -> Result<PurgeRetention, ParserError> {
+        match n.checked_mul(unit_seconds) {
+            Some(seconds) => Ok(PurgeRetention::Seconds(seconds)),
+            None => parser_err!(format!("Retention {} {} is out of range", n, unit)),
+        }
+    }
+
     fn consume_token(&mut self, expected: &str) -> bool {
         if self.parser.peek_token().to_string().to_uppercase() == *expected.to_uppercase() {
             self.parser.next_token();
@@ -16,23 +16,30 @@ use common_exception::Result;
 use common_meta_types::AuthType;
 use common_meta_types::UserPrivilege;
 use common_meta_types::UserPrivilegeType;
+use common_planners::PurgeRetention;
 use sqlparser::ast::*;
 
+use crate::sql::statements::DfAlterTable;
+use crate::sql::statements::DfAlterTableAction;
 use crate::sql::statements::DfAlterUser;
 use crate::sql::statements::DfCopy;
 use crate::sql::statements::DfCreateDatabase;
 use crate::sql::statements::DfCreateTable;
 use crate::sql::statements::DfCreateUser;
+use crate::sql::statements::DfDeleteStatement;
 use crate::sql::statements::DfDescribeTable;
 use crate::sql::statements::DfDropDatabase;
 use crate::sql::statements::DfDropTable;
 use crate::sql::statements::DfDropUser;
 use crate::sql::statements::DfGrantObject;
 use crate::sql::statements::DfGrantStatement;
+use crate::sql::statements::DfOptimizeTable;
+use crate::sql::statements::DfOptimizeTableAction;
 use crate::sql::statements::DfRevokeStatement;
 use crate::sql::statements::DfShowDatabases;
 use crate::sql::statements::DfShowTables;
 use crate::sql::statements::DfTruncateTable;
+use crate::sql::statements::DfUpdateStatement;
 use crate::sql::statements::DfUseDatabase;
 use crate::sql::*;
 
@@ -310,6 +317,240 @@ fn truncate_table() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn delete_test() -> Result<()> {
+    {
+        let sql = "DELETE FROM t1";
+        let expected = DfStatement::Delete(DfDeleteStatement {
+            name: ObjectName(vec![Ident::new("t1")]),
+            selection: None,
+        });
+        expect_parse_ok(sql, expected)?;
+    }
+
+    {
+        let sql = "DELETE FROM db1.t1 WHERE a = 1";
+        let expected = DfStatement::Delete(DfDeleteStatement {
+            name: ObjectName(vec![Ident::new("db1"), Ident::new("t1")]),
+            selection: Some(Expr::BinaryOp {
+                left: Box::new(Expr::Identifier(Ident::new("a"))),
+                op: BinaryOperator::Eq,
+                right: Box::new(Expr::Value(Value::Number("1".to_string(), false))),
+            }),
+        });
+        expect_parse_ok(sql, expected)?;
+    }
+
+    Ok(())
+}
+
+#[test]
+fn update_test() -> Result<()> {
+    {
+        let sql = "UPDATE t1 SET a = 1, b = 'x' WHERE a = 2";
+        let expected = DfStatement::Update(DfUpdateStatement {
+            name: ObjectName(vec![Ident::new("t1")]),
+            assignments: vec![
+                (
+                    Ident::new("a"),
+                    Expr::Value(Value::Number("1".to_string(), false)),
+                ),
+                (
+                    Ident::new("b"),
+                    Expr::Value(Value::SingleQuotedString("x".to_string())),
+                ),
+            ],
+            selection: Some(Expr::BinaryOp {
+                left: Box::new(Expr::Identifier(Ident::new("a"))),
+                op: BinaryOperator::Eq,
+                right: Box::new(Expr::Value(Value::Number("2".to_string(), false))),
+            }),
+        });
+        expect_parse_ok(sql, expected)?;
+    }
+
+    {
+        let sql = "UPDATE db1.t1 SET a = a + 1";
+        let expected = DfStatement::Update(DfUpdateStatement {
+            name: ObjectName(vec![Ident::new("db1"), Ident::new("t1")]),
+            assignments: vec![(Ident::new("a"), Expr::BinaryOp {
+                left: Box::new(Expr::Identifier(Ident::new("a"))),
+                op: BinaryOperator::Plus,
+                right: Box::new(Expr::Value(Value::Number("1".to_string(), false))),
+            })],
+            selection: None,
+        });
+        expect_parse_ok(sql, expected)?;
+    }
+
+    {
+        let sql = "UPDATE t1 SET WHERE a = 1";
+        expect_parse_err(
+            sql,
+            String::from("sql parser error: Expected identifier, found: WHERE"),
+        )?;
+    }
+
+    Ok(())
+}
+
+#[test]
+fn alter_table_test() -> Result<()> {
+    {
+        let sql = "ALTER TABLE t1 ADD COLUMN c Int";
+        let expected = DfStatement::AlterTable(DfAlterTable {
+            name: ObjectName(vec![Ident::new("t1")]),
+            action: DfAlterTableAction::AddColumn(make_column_def("c", DataType::Int(None))),
+        });
+        expect_parse_ok(sql, expected)?;
+    }
+
+    {
+        let sql = "ALTER TABLE db1.t1 ADD c Int";
+        let expected = DfStatement::AlterTable(DfAlterTable {
+            name: ObjectName(vec![Ident::new("db1"), Ident::new("t1")]),
+            action: DfAlterTableAction::AddColumn(make_column_def("c", DataType::Int(None))),
+        });
+        expect_parse_ok(sql, expected)?;
+    }
+
+    {
+        let sql = "ALTER TABLE t1 DROP COLUMN c";
+        let expected = DfStatement::AlterTable(DfAlterTable {
+            name: ObjectName(vec![Ident::new("t1")]),
+            action: DfAlterTableAction::DropColumn(Ident::new("c")),
+        });
+        expect_parse_ok(sql, expected)?;
+    }
+
+    {
+        let sql = "ALTER TABLE t1 RENAME COLUMN a TO b";
+        let expected = DfStatement::AlterTable(DfAlterTable {
+            name: ObjectName(vec![Ident::new("t1")]),
+            action: DfAlterTableAction::RenameColumn {
+                old_name: Ident::new("a"),
+                new_name: Ident::new("b"),
+            },
+        });
+        expect_parse_ok(sql, expected)?;
+    }
+
+    {
+        let sql = "ALTER TABLE t1 MODIFY COLUMN a Int";
+        expect_parse_err(
+            sql,
+            String::from("sql parser error: Expected ADD, DROP or RENAME, found: MODIFY"),
+        )?;
+    }
+
+    Ok(())
+}
+
+#[test]
+fn optimize_table_test() -> Result<()> {
+    let purge = |name: Vec<&str>, retention: PurgeRetention| {
+        DfStatement::OptimizeTable(DfOptimizeTable {
+            name: ObjectName(name.into_iter().map(Ident::new).collect()),
+            action: DfOptimizeTableAction::Purge(retention),
+        })
+    };
+
+    expect_parse_ok(
+        "OPTIMIZE TABLE db1.t1 COMPACT",
+        DfStatement::OptimizeTable(DfOptimizeTable {
+            name: ObjectName(vec![Ident::new("db1"), Ident::new("t1")]),
+            action: DfOptimizeTableAction::Compact,
+        }),
+    )?;
+    expect_parse_ok(
+        "OPTIMIZE TABLE t1 PURGE",
+        purge(vec!["t1"], PurgeRetention::Snapshots(1)),
+    )?;
+    expect_parse_ok(
+        "OPTIMIZE TABLE db1.t1 PURGE RETAIN 3 SNAPSHOTS",
+        purge(vec!["db1", "t1"], PurgeRetention::Snapshots(3)),
+    )?;
+    expect_parse_ok(
+        "optimize table t1 purge retain 2 days",
+        purge(vec!["t1"], PurgeRetention::Seconds(2 * 24 * 60 * 60)),
+    )?;
+    expect_parse_ok(
+        "OPTIMIZE TABLE t1 PURGE RETAIN 1 HOUR",
+        purge(vec!["t1"], PurgeRetention::Seconds(60 * 60)),
+    )?;
+    expect_parse_err(
+        "OPTIMIZE TABLE t1 PURGE RETAIN 1 WEEK",
+        String::from(
+            "sql parser error: Expected SNAPSHOTS, DAYS, HOURS, MINUTES or SECONDS, found: WEEK",
+        ),
+    )?;
+    expect_parse_err(
+        "OPTIMIZE TABLE t1 PURGE RETAIN 18446744073709551615 DAYS",
+        String::from("sql parser error: Retention 18446744073709551615 DAYS is out of range"),
+    )?;
+    expect_parse_err(
+        "OPTIMIZE TABLE t1 VACUUM",
+        String::from("sql parser error: Expected COMPACT or PURGE, found: VACUUM"),
+    )?;
+
+    Ok(())
+}
+
+#[test]
+fn time_travel_test() -> Result<()> {
+    let relation_of = |sql: &str| -> Result<TableFactor> {
+        let (statements, _) = DfParser::parse_sql(sql)?;
+        match &statements[0] {
+            DfStatement::Query(query) => Ok(query.from[0].relation.clone()),
+            _ => unreachable!(),
+        }
+    };
+
+    {
+        let sql = "SELECT * FROM t AT (SNAPSHOT => 'id') AS x";
+        let expected = TableFactor::Table {
+            name: ObjectName(vec![Ident::new("t")]),
+            alias: Some(TableAlias {
+                name: Ident::new("x"),
+                columns: vec![],
+            }),
+            args: vec![FunctionArg::Named {
+                name: Ident::new("SNAPSHOT"),
+                arg: Expr::Value(Value::SingleQuotedString("id".to_string())),
+            }],
+            with_hints: vec![],
+        };
+        assert_eq!(relation_of(sql)?, expected);
+    }
+
+    {
+        let sql = "SELECT * FROM t1 JOIN db.t2 AT (TIMESTAMP => '2021-01-01') ON t1.a = t2.a";
+        let (statements, _) = DfParser::parse_sql(sql)?;
+        let relation = match &statements[0] {
+            DfStatement::Query(query) => query.from[0].joins[0].relation.clone(),
+            _ => unreachable!(),
+        };
+        let expected = TableFactor::Table {
+            name: ObjectName(vec![Ident::new("db"), Ident::new("t2")]),
+            alias: None,
+            args: vec![FunctionArg::Named {
+                name: Ident::new("TIMESTAMP"),
+                arg: Expr::Value(Value::SingleQuotedString("2021-01-01".to_string())),
+            }],
+            with_hints: vec![],
+        };
+        assert_eq!(relation, expected);
+    }
+
+    // `AT` is only a time travel of a table factor
+    {
+        let sql = "SELECT * FROM t WHERE f AT (SNAPSHOT => 'id')";
+        assert!(DfParser::parse_sql(sql).is_err());
+    }
+
+    Ok(())
+}
+
 #[test]
 fn hint_test() -> Result<()> {
     {
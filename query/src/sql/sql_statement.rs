@@ -20,11 +20,13 @@ use nom::character::complete::multispace1;
 use nom::IResult;
 
 use super::statements::DfCopy;
+use crate::sql::statements::DfAlterTable;
 use crate::sql::statements::DfAlterUser;
 use crate::sql::statements::DfCompactTable;
 use crate::sql::statements::DfCreateDatabase;
 use crate::sql::statements::DfCreateTable;
 use crate::sql::statements::DfCreateUser;
+use crate::sql::statements::DfDeleteStatement;
 use crate::sql::statements::DfDescribeTable;
 use crate::sql::statements::DfDropDatabase;
 use crate::sql::statements::DfDropTable;
@@ -33,6 +35,7 @@ use crate::sql::statements::DfExplain;
 use crate::sql::statements::DfGrantStatement;
 use crate::sql::statements::DfInsertStatement;
 use crate::sql::statements::DfKillStatement;
+use crate::sql::statements::DfOptimizeTable;
 use crate::sql::statements::DfQueryStatement;
 use crate::sql::statements::DfRevokeStatement;
 use crate::sql::statements::DfSetVariable;
@@ -44,6 +47,7 @@ use crate::sql::statements::DfShowSettings;
 use crate::sql::statements::DfShowTables;
 use crate::sql::statements::DfShowUsers;
 use crate::sql::statements::DfTruncateTable;
+use crate::sql::statements::DfUpdateStatement;
 use crate::sql::statements::DfUseDatabase;
 
 /// Tokens parsed by `DFParser` are converted into these values.
@@ -67,6 +71,8 @@ pub enum DfStatement {
     DropTable(DfDropTable),
     TruncateTable(DfTruncateTable),
     CompactTable(DfCompactTable),
+    AlterTable(DfAlterTable),
+    OptimizeTable(DfOptimizeTable),
 
     // Settings.
     ShowSettings(DfShowSettings),
@@ -86,6 +92,12 @@ pub enum DfStatement {
     // Insert
     InsertQuery(DfInsertStatement),
 
+    // Update
+    Update(DfUpdateStatement),
+
+    // Delete
+    Delete(DfDeleteStatement),
+
     // User
     CreateUser(DfCreateUser),
     AlterUser(DfAlterUser),
@@ -15,12 +15,21 @@
 use std::convert::TryFrom;
 use std::sync::Arc;
 
+use common_datavalues::DataValue;
+use common_datavalues::Tz;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_functions::aggregates::AggregateFunctionFactory;
+use common_functions::scalars::ArrayLambdaFunction;
+use common_functions::window::WindowFunctionFactory;
+use common_planners::find_columns_not_satisfy_exprs;
 use common_planners::Expression;
+use common_planners::WindowFrame;
+use common_planners::WindowFrameBound;
+use common_planners::WindowFrameUnits;
 use sqlparser::ast::BinaryOperator;
 use sqlparser::ast::DataType;
+use sqlparser::ast::DateTimeField;
 use sqlparser::ast::Expr;
 use sqlparser::ast::Function;
 use sqlparser::ast::FunctionArg;
@@ -28,23 +37,38 @@ use sqlparser::ast::Ident;
 use sqlparser::ast::Query;
 use sqlparser::ast::UnaryOperator;
 use sqlparser::ast::Value;
+use sqlparser::ast::WindowFrame as SQLWindowFrame;
+use sqlparser::ast::WindowFrameBound as SQLWindowFrameBound;
+use sqlparser::ast::WindowFrameUnits as SQLWindowFrameUnits;
+use sqlparser::ast::WindowSpec;
 
 use crate::functions::ContextFunction;
 use crate::sessions::QueryContext;
 use crate::sql::statements::analyzer_value_expr::ValueExprAnalyzer;
 use crate::sql::statements::AnalyzableStatement;
 use crate::sql::statements::AnalyzedResult;
+use crate::sql::statements::DfCommonTableExpr;
 use crate::sql::statements::DfQueryStatement;
 use crate::sql::PlanParser;
 use crate::sql::SQLCommon;
 
 pub struct ExpressionAnalyzer {
     context: Arc<QueryContext>,
+    // The CTEs visible to the subqueries in the expressions.
+    ctes: Vec<DfCommonTableExpr>,
 }
 
 impl ExpressionAnalyzer {
     pub fn create(context: Arc<QueryContext>) -> ExpressionAnalyzer {
-        ExpressionAnalyzer { context }
+        ExpressionAnalyzer {
+            context,
+            ctes: vec![],
+        }
+    }
+
+    pub fn with_ctes(mut self, ctes: &[DfCommonTableExpr]) -> ExpressionAnalyzer {
+        self.ctes = ctes.to_vec();
+        self
     }
 
     pub async fn analyze(&self, expr: &Expr) -> Result<Expression> {
@@ -60,6 +84,9 @@ impl ExpressionAnalyzer {
                 ExprRPNItem::Wildcard => self.analyze_wildcard(&mut stack)?,
                 ExprRPNItem::Exists(v) => self.analyze_exists(v, &mut stack).await?,
                 ExprRPNItem::Subquery(v) => self.analyze_scalar_subquery(v, &mut stack).await?,
+                ExprRPNItem::InSubquery(v, negated) => {
+                    self.analyze_in_subquery(v, *negated, &mut stack).await?
+                }
                 ExprRPNItem::Cast(v) => self.analyze_cast(v, &mut stack)?,
                 ExprRPNItem::Between(negated) => self.analyze_between(*negated, &mut stack)?,
             }
@@ -79,6 +106,18 @@ impl ExpressionAnalyzer {
     }
 
     fn analyze_function(&self, info: &FunctionExprInfo, args: &mut Vec<Expression>) -> Result<()> {
+        // The partition by and order by expressions of the window are pushed after the arguments.
+        let window_exprs = match &info.window {
+            None => vec![],
+            Some(window) => {
+                let window_exprs_count = window.partition_by_count + window.order_by.len();
+                if args.len() < window_exprs_count {
+                    return Err(ErrorCode::LogicalError("It's a bug."));
+                }
+                args.split_off(args.len() - window_exprs_count)
+            }
+        };
+
         let mut arguments = Vec::with_capacity(info.args_count);
         for _index in 0..info.args_count {
             match args.pop() {
@@ -91,6 +130,18 @@ impl ExpressionAnalyzer {
             }
         }
 
+        if let Some(window) = &info.window {
+            args.push(self.window_function(info, window, arguments, window_exprs)?);
+            return Ok(());
+        }
+
+        if WindowFunctionFactory::instance().check_non_aggregate(&info.name) {
+            return Err(ErrorCode::SyntaxException(format!(
+                "Window function {} requires an OVER clause",
+                info.name
+            )));
+        }
+
         args.push(
             match AggregateFunctionFactory::instance().check(&info.name) {
                 true => self.aggr_function(info, &arguments),
@@ -131,6 +182,14 @@ impl ExpressionAnalyzer {
     }
 
     fn function(&self, info: &FunctionExprInfo, args: &[Expression]) -> Result<Expression> {
+        if ArrayLambdaFunction::check(&info.name) {
+            return Self::lambda_function(info, args);
+        }
+
+        if info.name.eq_ignore_ascii_case("toTimeZone") {
+            return Self::to_timezone_function(info, args);
+        }
+
         let query_context = self.context.clone();
         let context_args = ContextFunction::build_args_from_ctx(&info.name, query_context)?;
 
@@ -153,7 +212,79 @@ impl ExpressionAnalyzer {
         }
     }
 
-    fn aggr_function(&self, info: &FunctionExprInfo, args: &[Expression]) -> Result<Expression> {
+    /// `toTimeZone(time, 'timezone')` doesn't change the time but the timezone it's shown in,
+    /// so it's the cast to `DateTime32('timezone')`.
+    fn to_timezone_function(info: &FunctionExprInfo, args: &[Expression]) -> Result<Expression> {
+        match args {
+            [expr, Expression::Literal {
+                value: DataValue::String(Some(tz)),
+                ..
+            }] => {
+                let tz = String::from_utf8_lossy(tz).to_string();
+                if tz.parse::<Tz>().is_err() {
+                    return Err(ErrorCode::BadArguments(format!("Unknown timezone: {}", tz)));
+                }
+
+                Ok(Expression::Cast {
+                    expr: Box::new(expr.clone()),
+                    data_type: common_datavalues::DataType::DateTime32(Some(tz)),
+                })
+            }
+            _ => Err(ErrorCode::SyntaxException(format!(
+                "{} must have two arguments, the time and the constant timezone string",
+                info.name
+            ))),
+        }
+    }
+
+    /// The lambda `x -> x + 1` is rewritten to `lambda(x, x + 1)` by the parser.
+    fn lambda_function(info: &FunctionExprInfo, args: &[Expression]) -> Result<Expression> {
+        let (params, body) = match args.first() {
+            Some(Expression::ScalarFunction { op, args }) if op == "lambda" && args.len() == 2 => {
+                (&args[0], &args[1])
+            }
+            _ => {
+                return Err(ErrorCode::SyntaxException(format!(
+                    "The first argument of {} must be a lambda, such as `x -> x + 1`",
+                    info.name
+                )));
+            }
+        };
+
+        let params = match params {
+            Expression::Column(_) => vec![params.clone()],
+            Expression::ScalarFunction { op, args } if op == "tuple" => args.clone(),
+            _ => vec![params.clone()],
+        };
+        let mut names = Vec::with_capacity(params.len());
+        for param in &params {
+            match param {
+                Expression::Column(name) if !names.contains(name) => names.push(name.clone()),
+                _ => {
+                    return Err(ErrorCode::SyntaxException(format!(
+                        "The params of the lambda in {} must be distinct identifiers",
+                        info.name
+                    )));
+                }
+            }
+        }
+
+        if let Some(column) = find_columns_not_satisfy_exprs(&params, &[body.clone()])? {
+            return Err(ErrorCode::SyntaxException(format!(
+                "The lambda in {} can only refer to its params, but got {:?}",
+                info.name, column
+            )));
+        }
+
+        Ok(Expression::LambdaFunction {
+            op: info.name.clone(),
+            params: names,
+            body: Box::new(body.clone()),
+            args: args[1..].to_vec(),
+        })
+    }
+
+    fn function_parameters(info: &FunctionExprInfo) -> Result<Vec<DataValue>> {
         let mut parameters = Vec::with_capacity(info.parameters.len());
 
         for parameter in &info.parameters {
@@ -170,6 +301,12 @@ impl ExpressionAnalyzer {
             };
         }
 
+        Ok(parameters)
+    }
+
+    fn aggr_function(&self, info: &FunctionExprInfo, args: &[Expression]) -> Result<Expression> {
+        let parameters = Self::function_parameters(info)?;
+
         if info.name.eq_ignore_ascii_case("count")
             && !args.is_empty()
             && matches!(args[0], Expression::Wildcard)
@@ -190,6 +327,72 @@ impl ExpressionAnalyzer {
         }
     }
 
+    fn window_function(
+        &self,
+        info: &FunctionExprInfo,
+        window: &WindowSpecInfo,
+        mut args: Vec<Expression>,
+        mut window_exprs: Vec<Expression>,
+    ) -> Result<Expression> {
+        if !WindowFunctionFactory::instance().check(&info.name) {
+            return Err(ErrorCode::UnknownAggregateFunction(format!(
+                "Unsupported window function: {}",
+                info.name
+            )));
+        }
+
+        if info.distinct {
+            return Err(ErrorCode::SyntaxException(format!(
+                "DISTINCT is not supported in window function {}",
+                info.name
+            )));
+        }
+
+        let mut parameters = Self::function_parameters(info)?;
+
+        let lowercase_name = info.name.to_lowercase();
+        if lowercase_name == "lag" || lowercase_name == "lead" {
+            // lag(expr, offset, default): the offset and the default value are parameters.
+            for arg in args.split_off(std::cmp::min(args.len(), 1)) {
+                match arg {
+                    Expression::Literal { value, .. } => parameters.push(value),
+                    expr => {
+                        return Err(ErrorCode::SyntaxException(format!(
+                            "The offset and the default value of {} must be constants, but got {:?}",
+                            info.name, expr
+                        )));
+                    }
+                }
+            }
+        }
+
+        if lowercase_name == "count" && !args.is_empty() && matches!(args[0], Expression::Wildcard)
+        {
+            args = vec![common_planners::lit(0i64)];
+        }
+
+        let order_by_exprs = window_exprs.split_off(window.partition_by_count);
+        let order_by = order_by_exprs
+            .into_iter()
+            .zip(window.order_by.iter())
+            .map(|(expr, (asc, nulls_first))| Expression::Sort {
+                expr: Box::new(expr.clone()),
+                asc: *asc,
+                nulls_first: *nulls_first,
+                origin_expr: Box::new(expr),
+            })
+            .collect();
+
+        Ok(Expression::WindowFunction {
+            op: info.name.clone(),
+            params: parameters,
+            args,
+            partition_by: window_exprs,
+            order_by,
+            window_frame: window.window_frame,
+        })
+    }
+
     fn analyze_identifier(&self, ident: &Ident, arguments: &mut Vec<Expression>) -> Result<()> {
         let column_name = ident.clone().value;
         arguments.push(Expression::Column(column_name));
@@ -216,8 +419,28 @@ impl ExpressionAnalyzer {
         Ok(())
     }
 
+    async fn analyze_in_subquery(
+        &self,
+        subquery: &Query,
+        negated: bool,
+        args: &mut Vec<Expression>,
+    ) -> Result<()> {
+        let expr = match args.pop() {
+            None => return Err(ErrorCode::LogicalError("It's a bug.")),
+            Some(expr) => expr,
+        };
+
+        let subquery = self.analyze_subquery(subquery).await?;
+        args.push(Expression::ScalarFunction {
+            op: String::from(if negated { "not_in" } else { "in" }),
+            args: vec![expr, subquery],
+        });
+        Ok(())
+    }
+
     async fn analyze_subquery(&self, subquery: &Query) -> Result<Expression> {
-        let statement = DfQueryStatement::try_from(subquery.clone())?;
+        let mut statement = DfQueryStatement::try_from(subquery.clone())?;
+        statement.inherit_ctes(&self.ctes);
 
         let query_context = self.context.clone();
         let subquery_context = QueryContext::new(query_context.clone());
@@ -242,7 +465,8 @@ impl ExpressionAnalyzer {
         subquery: &Query,
         args: &mut Vec<Expression>,
     ) -> Result<()> {
-        let statement = DfQueryStatement::try_from(subquery.clone())?;
+        let mut statement = DfQueryStatement::try_from(subquery.clone())?;
+        statement.inherit_ctes(&self.ctes);
 
         let query_context = self.context.clone();
         let subquery_context = QueryContext::new(query_context.clone());
@@ -323,6 +547,14 @@ struct FunctionExprInfo {
     unary_operator: bool,
     binary_operator: bool,
     parameters: Vec<Value>,
+    window: Option<WindowSpecInfo>,
+}
+
+struct WindowSpecInfo {
+    partition_by_count: usize,
+    // (asc, nulls_first) of each order by expression
+    order_by: Vec<(bool, bool)>,
+    window_frame: Option<WindowFrame>,
 }
 
 enum ExprRPNItem {
@@ -333,6 +565,7 @@ enum ExprRPNItem {
     Wildcard,
     Exists(Box<Query>),
     Subquery(Box<Query>),
+    InSubquery(Box<Query>, bool),
     Cast(common_datavalues::DataType),
     Between(bool),
 }
@@ -346,6 +579,7 @@ impl ExprRPNItem {
             unary_operator: false,
             binary_operator: false,
             parameters: Vec::new(),
+            window: None,
         })
     }
 
@@ -357,6 +591,7 @@ impl ExprRPNItem {
             unary_operator: false,
             binary_operator: true,
             parameters: Vec::new(),
+            window: None,
         })
     }
 
@@ -368,6 +603,7 @@ impl ExprRPNItem {
             unary_operator: true,
             binary_operator: false,
             parameters: Vec::new(),
+            window: None,
         })
     }
 }
@@ -415,6 +651,24 @@ impl ExprRPNBuilder {
                 high,
             } => self.visit_between(expr, negated, low, high),
             Expr::Tuple(exprs) => self.visit_tuple(exprs),
+            Expr::InList {
+                expr,
+                list,
+                negated,
+            } => self.visit_in_list(expr, list, negated),
+            Expr::InSubquery {
+                expr,
+                subquery,
+                negated,
+            } => self.visit_in_subquery(expr, subquery, negated),
+            Expr::Case {
+                operand,
+                conditions,
+                results,
+                else_result,
+            } => self.visit_case(operand, conditions, results, else_result),
+            Expr::MapAccess { column, keys } => self.visit_map_access(column, keys),
+            Expr::Extract { field, expr } => self.visit_extract(field, expr),
             other => Result::Err(ErrorCode::SyntaxException(format!(
                 "Unsupported expression: {}, type: {:?}",
                 expr, other
@@ -440,6 +694,72 @@ impl ExprRPNBuilder {
         }
     }
 
+    fn visit_in_list(&mut self, expr: &Expr, list: &[Expr], negated: &bool) -> Result<()> {
+        if list.is_empty() {
+            return Err(ErrorCode::SyntaxException(
+                "IN list must have at least one element.",
+            ));
+        }
+
+        self.visit(expr)?;
+        for value in list {
+            self.visit(value)?;
+        }
+
+        let name = if *negated { "not_in" } else { "in" };
+        self.rpn
+            .push(ExprRPNItem::function(String::from(name), list.len() + 1));
+        Ok(())
+    }
+
+    fn visit_in_subquery(&mut self, expr: &Expr, subquery: &Query, negated: &bool) -> Result<()> {
+        self.visit(expr)?;
+        self.rpn.push(ExprRPNItem::InSubquery(
+            Box::new(subquery.clone()),
+            *negated,
+        ));
+        Ok(())
+    }
+
+    /// `CASE [operand] WHEN c1 THEN r1 ... WHEN cn THEN rn [ELSE e] END` is lowered to
+    /// `if(c1, r1, ... if(cn, rn, e))`, the conditions become `operand = ci` if there is an operand.
+    fn visit_case(
+        &mut self,
+        operand: &Option<Box<Expr>>,
+        conditions: &[Expr],
+        results: &[Expr],
+        else_result: &Option<Box<Expr>>,
+    ) -> Result<()> {
+        if conditions.is_empty() || conditions.len() != results.len() {
+            return Err(ErrorCode::SyntaxException(
+                "CASE must have at least one WHEN ... THEN ... clause.",
+            ));
+        }
+
+        for (condition, result) in conditions.iter().zip(results.iter()) {
+            match operand {
+                None => self.visit(condition)?,
+                Some(operand) => {
+                    self.visit(operand)?;
+                    self.visit(condition)?;
+                    self.rpn
+                        .push(ExprRPNItem::binary_operator(String::from("=")));
+                }
+            }
+            self.visit(result)?;
+        }
+
+        match else_result {
+            None => self.visit_value(&Value::Null)?,
+            Some(else_result) => self.visit(else_result)?,
+        }
+
+        for _ in 0..conditions.len() {
+            self.rpn.push(ExprRPNItem::function(String::from("if"), 3));
+        }
+        Ok(())
+    }
+
     fn visit_wildcard(&mut self) -> Result<()> {
         self.rpn.push(ExprRPNItem::Wildcard);
         Ok(())
@@ -482,6 +802,11 @@ impl ExprRPNBuilder {
             };
         }
 
+        let window = match &function.over {
+            None => None,
+            Some(window_spec) => Some(self.visit_window_spec(window_spec)?),
+        };
+
         self.rpn.push(ExprRPNItem::Function(FunctionExprInfo {
             name: function.name.to_string(),
             distinct: function.distinct,
@@ -489,10 +814,89 @@ impl ExprRPNBuilder {
             unary_operator: false,
             binary_operator: false,
             parameters: function.params.to_owned(),
+            window,
         }));
         Ok(())
     }
 
+    fn visit_window_spec(&mut self, window_spec: &WindowSpec) -> Result<WindowSpecInfo> {
+        for expr in &window_spec.partition_by {
+            self.visit(expr)?;
+        }
+
+        let mut order_by = Vec::with_capacity(window_spec.order_by.len());
+        for order_by_expr in &window_spec.order_by {
+            self.visit(&order_by_expr.expr)?;
+            let asc = order_by_expr.asc.unwrap_or(true);
+            order_by.push((asc, order_by_expr.nulls_first.unwrap_or(asc)));
+        }
+
+        let window_frame = match &window_spec.window_frame {
+            None => None,
+            Some(window_frame) => Some(Self::window_frame(window_frame)?),
+        };
+
+        Ok(WindowSpecInfo {
+            partition_by_count: window_spec.partition_by.len(),
+            order_by,
+            window_frame,
+        })
+    }
+
+    fn window_frame(window_frame: &SQLWindowFrame) -> Result<WindowFrame> {
+        let units = match window_frame.units {
+            SQLWindowFrameUnits::Rows => WindowFrameUnits::Rows,
+            SQLWindowFrameUnits::Range => WindowFrameUnits::Range,
+            SQLWindowFrameUnits::Groups => {
+                return Err(ErrorCode::UnImplement(
+                    "GROUPS window frame is not supported yet",
+                ));
+            }
+        };
+
+        let bound = |bound: &SQLWindowFrameBound| match bound {
+            SQLWindowFrameBound::CurrentRow => WindowFrameBound::CurrentRow,
+            SQLWindowFrameBound::Preceding(n) => WindowFrameBound::Preceding(*n),
+            SQLWindowFrameBound::Following(n) => WindowFrameBound::Following(*n),
+        };
+
+        // `ROWS <start>` is the shorthand of `ROWS BETWEEN <start> AND CURRENT ROW`
+        let frame = WindowFrame {
+            units,
+            start_bound: bound(&window_frame.start_bound),
+            end_bound: match &window_frame.end_bound {
+                None => WindowFrameBound::CurrentRow,
+                Some(end_bound) => bound(end_bound),
+            },
+        };
+
+        if matches!(frame.start_bound, WindowFrameBound::Following(None))
+            || matches!(frame.end_bound, WindowFrameBound::Preceding(None))
+        {
+            return Err(ErrorCode::SyntaxException(format!(
+                "Invalid window frame: {}",
+                frame
+            )));
+        }
+
+        let is_offset = |bound: &WindowFrameBound| {
+            matches!(
+                bound,
+                WindowFrameBound::Preceding(Some(_)) | WindowFrameBound::Following(Some(_))
+            )
+        };
+        if units == WindowFrameUnits::Range
+            && (is_offset(&frame.start_bound) || is_offset(&frame.end_bound))
+        {
+            return Err(ErrorCode::UnImplement(format!(
+                "Unsupported window frame: {}, RANGE frame with offset is not supported yet",
+                frame
+            )));
+        }
+
+        Ok(frame)
+    }
+
     fn visit_cast(&mut self, expr: &Expr, data_type: &DataType) -> Result<()> {
         self.visit(expr)?;
         self.rpn
@@ -509,12 +913,44 @@ impl ExprRPNBuilder {
         Ok(())
     }
 
+    /// `v['a'][0]` is the same as `get(get(v, 'a'), 0)`.
+    fn visit_map_access(&mut self, column: &Expr, keys: &[Value]) -> Result<()> {
+        self.visit(column)?;
+        for key in keys {
+            self.visit_value(key)?;
+            self.rpn.push(ExprRPNItem::function(String::from("get"), 2));
+        }
+        Ok(())
+    }
+
     fn visit_simple_function(&mut self, expr: &Expr, name: impl ToString) -> Result<()> {
         self.visit(expr)?;
         self.rpn.push(ExprRPNItem::function(name.to_string(), 1));
         Ok(())
     }
 
+    /// `EXTRACT(part FROM x)` is rewritten to the date function of the part, such as `toYear(x)`.
+    fn visit_extract(&mut self, field: &DateTimeField, expr: &Expr) -> Result<()> {
+        let name = match field.to_string().to_uppercase().as_str() {
+            "YEAR" => "toYear",
+            "QUARTER" => "toQuarter",
+            "MONTH" => "toMonth",
+            "DAY" => "toDayOfMonth",
+            "HOUR" => "toHour",
+            "MINUTE" => "toMinute",
+            "SECOND" => "toSecond",
+            "DOW" => "toDayOfWeek",
+            "DOY" => "toDayOfYear",
+            other => {
+                return Err(ErrorCode::SyntaxException(format!(
+                    "Unsupported EXTRACT part: {}",
+                    other
+                )))
+            }
+        };
+        self.visit_simple_function(expr, name)
+    }
+
     fn visit_unary_expr(&mut self, op: &UnaryOperator, expr: &Expr) -> Result<()> {
         self.visit(expr)?;
         self.rpn.push(ExprRPNItem::unary_operator(op.to_string()));
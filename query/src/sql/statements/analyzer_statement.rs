@@ -21,6 +21,7 @@ use common_datavalues::DataSchemaRef;
 use common_exception::Result;
 use common_planners::ExplainType;
 use common_planners::Expression;
+use common_planners::JoinType;
 use common_planners::PlanNode;
 use common_planners::ReadDataSourcePlan;
 
@@ -39,6 +40,38 @@ pub enum QueryRelation {
     None,
     FromTable(Box<ReadDataSourcePlan>),
     Nested(Box<QueryAnalyzeState>),
+    Join(Box<QueryJoinRelation>),
+    SetOperation(Box<QuerySetOperationRelation>),
+}
+
+#[derive(Clone)]
+pub struct QueryJoinRelation {
+    pub join_type: JoinType,
+    pub condition: Option<Expression>,
+    pub left: QueryJoinInput,
+    pub right: QueryJoinInput,
+}
+
+#[derive(Clone)]
+pub struct QueryJoinInput {
+    pub relation: QueryRelation,
+    // Rename the columns to the joined column names, empty if the names are unchanged.
+    pub projection: Vec<Expression>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SetOperationType {
+    UnionAll,
+    Union,
+    Intersect,
+    Except,
+}
+
+#[derive(Clone)]
+pub struct QuerySetOperationRelation {
+    pub op: SetOperationType,
+    pub left: Box<QueryAnalyzeState>,
+    pub right: Box<QueryAnalyzeState>,
 }
 
 #[derive(Clone)]
@@ -54,6 +87,9 @@ pub struct QueryAnalyzeState {
     pub aggregate_expressions: Vec<Expression>,
     pub before_group_by_expressions: Vec<Expression>,
 
+    pub window_expressions: Vec<Expression>,
+    pub before_window_expressions: Vec<Expression>,
+
     pub limit: Option<usize>,
     pub offset: Option<usize>,
 
@@ -73,6 +109,12 @@ impl QueryAnalyzeState {
             self.before_group_by_expressions.push(expr.clone());
         }
     }
+
+    pub fn add_before_window_expression(&mut self, expr: &Expression) {
+        if !self.before_window_expressions.contains(expr) {
+            self.before_window_expressions.push(expr.clone());
+        }
+    }
 }
 
 impl Default for QueryAnalyzeState {
@@ -86,6 +128,8 @@ impl Default for QueryAnalyzeState {
             group_by_expressions: vec![],
             aggregate_expressions: vec![],
             before_group_by_expressions: vec![],
+            window_expressions: vec![],
+            before_window_expressions: vec![],
             limit: None,
             offset: None,
             relation: QueryRelation::None,
@@ -114,6 +158,14 @@ impl Debug for QueryAnalyzeState {
             debug_struct.field("aggregate", &self.aggregate_expressions);
         }
 
+        if !self.before_window_expressions.is_empty() {
+            debug_struct.field("before_window", &self.before_window_expressions);
+        }
+
+        if !self.window_expressions.is_empty() {
+            debug_struct.field("window", &self.window_expressions);
+        }
+
         if !self.expressions.is_empty() {
             match self.order_by_expressions.is_empty() {
                 true => debug_struct.field("before_projection", &self.expressions),
@@ -156,6 +208,8 @@ impl AnalyzableStatement for DfStatement {
             DfStatement::DropTable(v) => v.analyze(ctx).await,
             DfStatement::TruncateTable(v) => v.analyze(ctx).await,
             DfStatement::CompactTable(v) => v.analyze(ctx).await,
+            DfStatement::AlterTable(v) => v.analyze(ctx).await,
+            DfStatement::OptimizeTable(v) => v.analyze(ctx).await,
             DfStatement::UseDatabase(v) => v.analyze(ctx).await,
             DfStatement::ShowCreateTable(v) => v.analyze(ctx).await,
             DfStatement::ShowTables(v) => v.analyze(ctx).await,
@@ -164,6 +218,8 @@ impl AnalyzableStatement for DfStatement {
             DfStatement::ShowMetrics(v) => v.analyze(ctx).await,
             DfStatement::KillStatement(v) => v.analyze(ctx).await,
             DfStatement::InsertQuery(v) => v.analyze(ctx).await,
+            DfStatement::Update(v) => v.analyze(ctx).await,
+            DfStatement::Delete(v) => v.analyze(ctx).await,
             DfStatement::SetVariable(v) => v.analyze(ctx).await,
             DfStatement::CreateUser(v) => v.analyze(ctx).await,
             DfStatement::AlterUser(v) => v.analyze(ctx).await,
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 #[cfg(test)]
+mod statement_optimize_table;
 mod statement_select_test;
 
 mod query;
@@ -20,12 +21,14 @@ mod query;
 mod analyzer_expr;
 mod analyzer_statement;
 mod analyzer_value_expr;
+mod statement_alter_table;
 mod statement_alter_user;
 mod statement_compact_table;
 mod statement_copy;
 mod statement_create_database;
 mod statement_create_table;
 mod statement_create_user;
+mod statement_delete;
 mod statement_describe_table;
 mod statement_drop_database;
 mod statement_drop_table;
@@ -46,19 +49,27 @@ mod statement_show_settings;
 mod statement_show_tables;
 mod statement_show_users;
 mod statement_truncate_table;
+mod statement_update;
 mod statement_use_database;
 
 pub use analyzer_statement::AnalyzableStatement;
 pub use analyzer_statement::AnalyzedResult;
 pub use analyzer_statement::QueryAnalyzeState;
+pub use analyzer_statement::QueryJoinInput;
+pub use analyzer_statement::QueryJoinRelation;
 pub use analyzer_statement::QueryRelation;
+pub use analyzer_statement::QuerySetOperationRelation;
+pub use analyzer_statement::SetOperationType;
 pub use query::QueryASTIR;
+pub use statement_alter_table::DfAlterTable;
+pub use statement_alter_table::DfAlterTableAction;
 pub use statement_alter_user::DfAlterUser;
 pub use statement_compact_table::DfCompactTable;
 pub use statement_copy::DfCopy;
 pub use statement_create_database::DfCreateDatabase;
 pub use statement_create_table::DfCreateTable;
 pub use statement_create_user::DfCreateUser;
+pub use statement_delete::DfDeleteStatement;
 pub use statement_describe_table::DfDescribeTable;
 pub use statement_drop_database::DfDropDatabase;
 pub use statement_drop_table::DfDropTable;
@@ -68,7 +79,10 @@ pub use statement_grant::DfGrantObject;
 pub use statement_grant::DfGrantStatement;
 pub use statement_insert::DfInsertStatement;
 pub use statement_kill::DfKillStatement;
+pub use statement_optimize_table::DfOptimizeTable;
+pub use statement_optimize_table::DfOptimizeTableAction;
 pub use statement_revoke::DfRevokeStatement;
+pub use statement_select::DfCommonTableExpr;
 pub use statement_select::DfQueryStatement;
 pub use statement_set_variable::DfSetVariable;
 pub use statement_show_create_table::DfShowCreateTable;
@@ -79,4 +93,5 @@ pub use statement_show_settings::DfShowSettings;
 pub use statement_show_tables::DfShowTables;
 pub use statement_show_users::DfShowUsers;
 pub use statement_truncate_table::DfTruncateTable;
+pub use statement_update::DfUpdateStatement;
 pub use statement_use_database::DfUseDatabase;
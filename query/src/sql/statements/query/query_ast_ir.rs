@@ -73,6 +73,26 @@ pub trait QueryASTIRVisitor<Data> {
 
                 Ok(())
             }
+            Expression::WindowFunction {
+                args,
+                partition_by,
+                order_by,
+                ..
+            } => {
+                for arg in args.iter_mut().chain(partition_by).chain(order_by) {
+                    Self::visit_recursive_expr(arg, data)?;
+                }
+
+                Ok(())
+            }
+            // The lambda body only refers to the lambda params, so only the arguments are visited.
+            Expression::LambdaFunction { args, .. } => {
+                for arg in args {
+                    Self::visit_recursive_expr(arg, data)?;
+                }
+
+                Ok(())
+            }
             Expression::Sort {
                 expr, origin_expr, ..
             } => {
@@ -48,6 +48,11 @@ impl QueryCollectPushDowns {
             require_columns: HashSet::new(),
         };
         QueryCollectPushDowns::visit(ir, &mut push_downs_data)?;
+
+        for mut condition in schema.get_relation().get_conditions() {
+            QueryCollectPushDowns::visit_recursive_expr(&mut condition, &mut push_downs_data)?;
+        }
+
         push_downs_data.collect_push_downs(schema)
     }
 
@@ -69,9 +74,10 @@ impl QueryCollectPushDowns {
     }
 
     fn collect_table_require_columns(&mut self, table_desc: &JoinedTableDesc) -> Vec<usize> {
-        match self.require_columns.is_empty() {
-            true => Self::collect_table_smallest_column(table_desc),
-            false => self.collect_table_projection_columns(table_desc),
+        match self.collect_table_projection_columns(table_desc) {
+            // The rows count of the table is still required, e.g. SELECT t1.a FROM t1, t2.
+            projection if projection.is_empty() => Self::collect_table_smallest_column(table_desc),
+            projection => projection,
         }
     }
 
@@ -96,14 +102,7 @@ impl QueryCollectPushDowns {
         let mut table_require_columns = Vec::new();
         let columns_desc = table_desc.get_columns_desc();
         for (column_index, column_desc) in columns_desc.iter().enumerate() {
-            let column_name = match column_desc.is_ambiguity {
-                true => format!(
-                    "{}.{}",
-                    table_desc.get_name_parts().join("."),
-                    column_desc.short_name
-                ),
-                false => column_desc.short_name.clone(),
-            };
+            let column_name = table_desc.get_column_name(column_desc);
 
             if self.require_columns.remove(&column_name) {
                 // Require this column.
@@ -28,6 +28,7 @@ use sqlparser::ast::SelectItem;
 use crate::sessions::QueryContext;
 use crate::sql::statements::analyzer_expr::ExpressionAnalyzer;
 use crate::sql::statements::query::QueryASTIR;
+use crate::sql::statements::DfCommonTableExpr;
 use crate::sql::statements::DfQueryStatement;
 
 pub struct QueryNormalizer {
@@ -38,9 +39,9 @@ pub struct QueryNormalizer {
 
 /// Replace alias in query and collect aggregate functions
 impl QueryNormalizer {
-    fn create(ctx: Arc<QueryContext>) -> QueryNormalizer {
+    fn create(ctx: Arc<QueryContext>, ctes: &[DfCommonTableExpr]) -> QueryNormalizer {
         QueryNormalizer {
-            expression_analyzer: ExpressionAnalyzer::create(ctx),
+            expression_analyzer: ExpressionAnalyzer::create(ctx).with_ctes(ctes),
             aliases_map: HashMap::new(),
             query_ast_ir: QueryASTIR {
                 filter_predicate: None,
@@ -56,7 +57,7 @@ impl QueryNormalizer {
     }
 
     pub async fn normalize(ctx: Arc<QueryContext>, v: &DfQueryStatement) -> Result<QueryASTIR> {
-        let query_normalizer = QueryNormalizer::create(ctx);
+        let query_normalizer = QueryNormalizer::create(ctx, &v.ctes);
         query_normalizer.transform(v).await
     }
 
@@ -16,6 +16,7 @@ use std::collections::hash_map::Entry;
 use std::collections::HashMap;
 use std::fmt::Debug;
 use std::fmt::Formatter;
+use std::ops::Range;
 use std::sync::Arc;
 
 use common_datavalues::DataField;
@@ -24,7 +25,9 @@ use common_datavalues::DataSchemaRef;
 use common_datavalues::DataType;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_planners::Expression;
 use common_planners::Extras;
+use common_planners::JoinType;
 
 use crate::sql::statements::QueryAnalyzeState;
 use crate::storages::Table;
@@ -35,6 +38,8 @@ pub struct JoinedSchema {
     short_name_columns: HashMap<String, JoinedColumnDesc>,
     // Reference by full name, short name may be ambiguous.
     tables_long_name_columns: Vec<JoinedTableDesc>,
+    // How the tables are joined, the leaves are indexes of tables_long_name_columns.
+    relation: JoinedRelation,
 }
 
 impl JoinedSchema {
@@ -42,6 +47,7 @@ impl JoinedSchema {
         JoinedSchema {
             short_name_columns: HashMap::new(),
             tables_long_name_columns: Vec::new(),
+            relation: JoinedRelation::Table(0),
         }
     }
 
@@ -78,6 +84,7 @@ impl JoinedSchema {
         Ok(JoinedSchema {
             short_name_columns,
             tables_long_name_columns: vec![table_desc],
+            relation: JoinedRelation::Table(0),
         })
     }
 
@@ -89,6 +96,16 @@ impl JoinedSchema {
         &self.tables_long_name_columns
     }
 
+    pub fn get_relation(&self) -> &JoinedRelation {
+        &self.relation
+    }
+
+    pub fn set_join_condition(&mut self, join_condition: Option<Expression>) {
+        if let JoinedRelation::Join { condition, .. } = &mut self.relation {
+            *condition = join_condition;
+        }
+    }
+
     pub fn set_table_push_downs(&mut self, table_pos: usize, extras: Extras) {
         let table_desc = &mut self.tables_long_name_columns[table_pos];
         if let JoinedTableDesc::Table { push_downs, .. } = table_desc {
@@ -129,8 +146,97 @@ impl JoinedSchema {
         Arc::new(DataSchema::new(fields))
     }
 
-    pub fn join(&self, _joined_schema: &JoinedSchema) -> Result<Arc<JoinedSchema>> {
-        unimplemented!("")
+    pub fn join(&self, right: &JoinedSchema, join_type: JoinType) -> Result<JoinedSchema> {
+        let left_tables = &self.tables_long_name_columns;
+        let right_tables = &right.tables_long_name_columns;
+        let mut tables_desc = Vec::with_capacity(left_tables.len() + right_tables.len());
+
+        for table_desc in left_tables {
+            let mut table_desc = table_desc.clone();
+            if join_type.keep_right_unmatched() {
+                table_desc.set_nullable();
+            }
+            tables_desc.push(table_desc);
+        }
+
+        for table_desc in right_tables {
+            if tables_desc
+                .iter()
+                .any(|v| v.get_name_parts() == table_desc.get_name_parts())
+            {
+                return Err(ErrorCode::SyntaxException(format!(
+                    "Not unique table/alias: {}",
+                    table_desc.get_name_parts().join(".")
+                )));
+            }
+
+            let mut table_desc = table_desc.clone();
+            if join_type.keep_left_unmatched() {
+                table_desc.set_nullable();
+            }
+            tables_desc.push(table_desc);
+        }
+
+        // The columns with the same short name in different tables become ambiguous.
+        let mut short_names_count = HashMap::new();
+        for table_desc in &tables_desc {
+            for column_desc in table_desc.get_columns_desc() {
+                *short_names_count
+                    .entry(column_desc.short_name.clone())
+                    .or_insert(0_usize) += 1;
+            }
+        }
+
+        let mut short_name_columns = HashMap::new();
+        for table_desc in &mut tables_desc {
+            for column_desc in table_desc.get_columns_desc_mut() {
+                column_desc.is_ambiguity = short_names_count[&column_desc.short_name] > 1;
+
+                if !column_desc.is_ambiguity {
+                    short_name_columns.insert(column_desc.short_name.clone(), column_desc.clone());
+                }
+            }
+        }
+
+        Ok(JoinedSchema {
+            short_name_columns,
+            tables_long_name_columns: tables_desc,
+            relation: JoinedRelation::Join {
+                join_type,
+                condition: None,
+                left: Box::new(self.relation.clone()),
+                right: Box::new(right.relation.shift(left_tables.len())),
+            },
+        })
+    }
+
+    /// Resolve the column name in the tables of the range, the name must be unique in these tables.
+    pub fn resolve_column(&self, tables: Range<usize>, name: &str) -> Result<Expression> {
+        let mut resolved = None;
+        for table_desc in &self.tables_long_name_columns[tables] {
+            for column_desc in table_desc.get_columns_desc() {
+                if column_desc.short_name != name {
+                    continue;
+                }
+
+                if resolved.is_some() {
+                    return Err(ErrorCode::SyntaxException(format!(
+                        "Column '{}' is ambiguous",
+                        name
+                    )));
+                }
+
+                resolved = Some(match column_desc.is_ambiguity {
+                    true => {
+                        let prefix = table_desc.get_name_parts().join(".");
+                        Expression::Column(format!("{}.{}", prefix, name))
+                    }
+                    false => Expression::Column(name.to_string()),
+                });
+            }
+        }
+
+        resolved.ok_or_else(|| ErrorCode::UnknownColumn(format!("Unknown column {}", name)))
     }
 }
 
@@ -226,6 +332,80 @@ impl JoinedTableDesc {
             JoinedTableDesc::Subquery { columns_desc, .. } => columns_desc,
         }
     }
+
+    fn get_columns_desc_mut(&mut self) -> &mut [JoinedColumnDesc] {
+        match self {
+            JoinedTableDesc::Table { columns_desc, .. } => columns_desc,
+            JoinedTableDesc::Subquery { columns_desc, .. } => columns_desc,
+        }
+    }
+
+    // The columns of the outer side of a join may be NULL.
+    fn set_nullable(&mut self) {
+        for column_desc in self.get_columns_desc_mut() {
+            column_desc.nullable = true;
+        }
+    }
+
+    /// The column name after the join, ambiguous columns are referenced by full name.
+    pub fn get_column_name(&self, column_desc: &JoinedColumnDesc) -> String {
+        match column_desc.is_ambiguity {
+            true => format!(
+                "{}.{}",
+                self.get_name_parts().join("."),
+                column_desc.short_name
+            ),
+            false => column_desc.short_name.clone(),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub enum JoinedRelation {
+    // The index of table in the joined tables.
+    Table(usize),
+    Join {
+        join_type: JoinType,
+        condition: Option<Expression>,
+        left: Box<JoinedRelation>,
+        right: Box<JoinedRelation>,
+    },
+}
+
+impl JoinedRelation {
+    fn shift(&self, offset: usize) -> JoinedRelation {
+        match self {
+            JoinedRelation::Table(index) => JoinedRelation::Table(index + offset),
+            JoinedRelation::Join {
+                join_type,
+                condition,
+                left,
+                right,
+            } => JoinedRelation::Join {
+                join_type: *join_type,
+                condition: condition.clone(),
+                left: Box::new(left.shift(offset)),
+                right: Box::new(right.shift(offset)),
+            },
+        }
+    }
+
+    pub fn get_conditions(&self) -> Vec<Expression> {
+        match self {
+            JoinedRelation::Table(_) => vec![],
+            JoinedRelation::Join {
+                condition,
+                left,
+                right,
+                ..
+            } => {
+                let mut conditions = left.get_conditions();
+                conditions.extend(right.get_conditions());
+                conditions.extend(condition.clone());
+                conditions
+            }
+        }
+    }
 }
 
 #[derive(Clone)]
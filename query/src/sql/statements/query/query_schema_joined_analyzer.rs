@@ -14,24 +14,50 @@
 
 use std::sync::Arc;
 
+use chrono::TimeZone;
+use chrono::Utc;
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_meta_types::TableIdent;
+use common_meta_types::TableInfo;
+use common_meta_types::TableMeta;
+use common_planners::Expression;
+use common_planners::JoinType;
+use futures::TryStreamExt;
+use sqlparser::ast::Expr;
 use sqlparser::ast::FunctionArg;
 use sqlparser::ast::Ident;
+use sqlparser::ast::JoinConstraint;
 use sqlparser::ast::JoinOperator;
 use sqlparser::ast::ObjectName;
 use sqlparser::ast::Query;
+use sqlparser::ast::SetExpr;
+use sqlparser::ast::SetOperator;
 use sqlparser::ast::TableAlias;
 use sqlparser::ast::TableFactor;
 use sqlparser::ast::TableWithJoins;
 
 use crate::catalogs::Catalog;
+use crate::interpreters::InterpreterFactory;
+use crate::pipelines::transforms::ExpressionExecutor;
 use crate::sessions::QueryContext;
 use crate::sql::statements::analyzer_expr::ExpressionAnalyzer;
 use crate::sql::statements::query::query_schema_joined::JoinedSchema;
+use crate::sql::statements::query::QualifiedRewriter;
 use crate::sql::statements::AnalyzableStatement;
 use crate::sql::statements::AnalyzedResult;
+use crate::sql::statements::DfCommonTableExpr;
 use crate::sql::statements::DfQueryStatement;
+use crate::sql::statements::QueryAnalyzeState;
+use crate::sql::statements::QueryRelation;
+use crate::sql::statements::QuerySetOperationRelation;
+use crate::sql::statements::SetOperationType;
+use crate::sql::PlanParser;
+use crate::storages::MemoryTable;
+use crate::storages::NavigationPoint;
+use crate::storages::Table;
 
 pub struct JoinedSchemaAnalyzer {
     ctx: Arc<QueryContext>,
@@ -49,11 +75,20 @@ impl JoinedSchemaAnalyzer {
         let rpn = RelationRPNBuilder::build(&query.from)?;
         for rpn_item in &rpn {
             match rpn_item {
-                RelationRPNItem::Join(_) => {
-                    return Err(ErrorCode::UnImplement("Unimplemented SELECT JOIN yet."));
+                RelationRPNItem::Join(join_operator) => {
+                    if analyzed_tables.len() < 2 {
+                        return Err(ErrorCode::LogicalError(
+                            "Logical error: this is relation rpn bug.",
+                        ));
+                    }
+
+                    let right = analyzed_tables.remove(analyzed_tables.len() - 1);
+                    let left = analyzed_tables.remove(analyzed_tables.len() - 1);
+                    let schema = self.join(left, right, join_operator);
+                    analyzed_tables.push(schema.await?);
                 }
                 RelationRPNItem::Table(v) => {
-                    let schema = self.table(v);
+                    let schema = self.table(v, &query.ctes);
                     analyzed_tables.push(schema.await?);
                 }
                 RelationRPNItem::TableFunction(v) => {
@@ -61,7 +96,7 @@ impl JoinedSchemaAnalyzer {
                     analyzed_tables.push(schema.await?);
                 }
                 RelationRPNItem::Derived(v) => {
-                    let schema = self.subquery(v);
+                    let schema = self.subquery(v, &query.ctes);
                     analyzed_tables.push(schema.await?);
                 }
             }
@@ -76,27 +111,193 @@ impl JoinedSchemaAnalyzer {
         Ok(analyzed_tables.remove(0))
     }
 
-    async fn subquery(&self, v: &DerivedRPNItem) -> Result<JoinedSchema> {
-        let subquery = &(*v.subquery);
-        let subquery = DfQueryStatement::try_from(subquery.clone())?;
-        match subquery.analyze(self.ctx.clone()).await? {
-            AnalyzedResult::SelectQuery(state) => match &v.alias {
-                None => JoinedSchema::from_subquery(state, Vec::new()),
-                Some(alias) => {
-                    let name_prefix = vec![alias.name.value.clone()];
-                    JoinedSchema::from_subquery(state, name_prefix)
+    async fn join(
+        &self,
+        left: JoinedSchema,
+        right: JoinedSchema,
+        join_operator: &JoinOperator,
+    ) -> Result<JoinedSchema> {
+        let (join_type, constraint) = match join_operator {
+            JoinOperator::Inner(constraint) => (JoinType::Inner, constraint),
+            JoinOperator::LeftOuter(constraint) => (JoinType::Left, constraint),
+            JoinOperator::RightOuter(constraint) => (JoinType::Right, constraint),
+            JoinOperator::FullOuter(constraint) => (JoinType::Full, constraint),
+            JoinOperator::CrossJoin => (JoinType::Cross, &JoinConstraint::None),
+            _ => {
+                return Err(ErrorCode::UnImplement(format!(
+                    "Unsupported join operator: {:?}",
+                    join_operator
+                )))
+            }
+        };
+
+        let left_tables = left.get_tables_desc().len();
+        let mut joined_schema = left.join(&right, join_type)?;
+        let tables = joined_schema.get_tables_desc().len();
+
+        let condition = match constraint {
+            JoinConstraint::None => None,
+            JoinConstraint::On(expr) => {
+                let analyzer = ExpressionAnalyzer::create(self.ctx.clone());
+                let mut condition = analyzer.analyze(expr).await?;
+                QualifiedRewriter::rewrite_expr(&joined_schema, self.ctx.clone(), &mut condition)?;
+                Some(condition)
+            }
+            JoinConstraint::Using(idents) => {
+                let mut condition: Option<Expression> = None;
+                for ident in idents {
+                    let name = &ident.value;
+                    let left_column = joined_schema.resolve_column(0..left_tables, name)?;
+                    let right_column = joined_schema.resolve_column(left_tables..tables, name)?;
+                    let equal = left_column.eq(right_column);
+                    condition = match condition {
+                        None => Some(equal),
+                        Some(condition) => Some(condition.and(equal)),
+                    };
                 }
-            },
+                condition
+            }
+            JoinConstraint::Natural => {
+                return Err(ErrorCode::UnImplement("Unsupported NATURAL JOIN yet."));
+            }
+        };
+
+        joined_schema.set_join_condition(condition);
+        Ok(joined_schema)
+    }
+
+    async fn subquery(
+        &self,
+        v: &DerivedRPNItem,
+        ctes: &[DfCommonTableExpr],
+    ) -> Result<JoinedSchema> {
+        let subquery = &(*v.subquery);
+        let state = match &subquery.body {
+            SetExpr::SetOperation {
+                op,
+                all,
+                left,
+                right,
+            } if Self::is_plain_body(subquery) => {
+                self.set_operation(op, *all, left, right, ctes).await?
+            }
+            _ => self.select_query(subquery.clone(), ctes).await?,
+        };
+
+        match &v.alias {
+            None => JoinedSchema::from_subquery(state, Vec::new()),
+            Some(alias) => {
+                let name_prefix = vec![alias.name.value.clone()];
+                JoinedSchema::from_subquery(state, name_prefix)
+            }
+        }
+    }
+
+    async fn select_query(
+        &self,
+        query: Query,
+        ctes: &[DfCommonTableExpr],
+    ) -> Result<Box<QueryAnalyzeState>> {
+        let mut query = DfQueryStatement::try_from(query)?;
+        query.inherit_ctes(ctes);
+        match query.analyze(self.ctx.clone()).await? {
+            AnalyzedResult::SelectQuery(state) => Ok(state),
             _ => Err(ErrorCode::LogicalError(
                 "Logical error, subquery analyzed data must be SelectQuery, it's a bug.",
             )),
         }
     }
 
-    async fn table(&self, item: &TableRPNItem) -> Result<JoinedSchema> {
+    async fn set_operation(
+        &self,
+        op: &SetOperator,
+        all: bool,
+        left: &SetExpr,
+        right: &SetExpr,
+        ctes: &[DfCommonTableExpr],
+    ) -> Result<Box<QueryAnalyzeState>> {
+        let op = match (op, all) {
+            (SetOperator::Union, true) => SetOperationType::UnionAll,
+            (SetOperator::Union, false) => SetOperationType::Union,
+            (SetOperator::Intersect, false) => SetOperationType::Intersect,
+            (SetOperator::Except, false) => SetOperationType::Except,
+            (op, true) => {
+                return Err(ErrorCode::UnImplement(format!(
+                    "{} ALL is not yet implemented",
+                    op
+                )));
+            }
+        };
+
+        let relation = QuerySetOperationRelation {
+            op,
+            left: self
+                .select_query(Self::set_operation_input(left), ctes)
+                .await?,
+            right: self
+                .select_query(Self::set_operation_input(right), ctes)
+                .await?,
+        };
+
+        // The output columns take the names of the left side and the common super types of both sides.
+        let finalize_schema = PlanParser::build_set_operation_plan(&relation)?.schema();
+        let projection_expressions = finalize_schema
+            .fields()
+            .iter()
+            .map(|field| Expression::Column(field.name().clone()))
+            .collect::<Vec<_>>();
+
+        Ok(Box::new(QueryAnalyzeState {
+            projection_expressions,
+            relation: QueryRelation::SetOperation(Box::new(relation)),
+            finalize_schema,
+            ..Default::default()
+        }))
+    }
+
+    fn set_operation_input(input: &SetExpr) -> Query {
+        match input {
+            SetExpr::Query(query) => query.as_ref().clone(),
+            body => Query {
+                with: None,
+                body: body.clone(),
+                order_by: vec![],
+                limit: None,
+                offset: None,
+                fetch: None,
+            },
+        }
+    }
+
+    fn is_plain_body(query: &Query) -> bool {
+        query.with.is_none()
+            && query.order_by.is_empty()
+            && query.limit.is_none()
+            && query.offset.is_none()
+            && query.fetch.is_none()
+    }
+
+    async fn table(&self, item: &TableRPNItem, ctes: &[DfCommonTableExpr]) -> Result<JoinedSchema> {
+        if let [name] = item.name.0.as_slice() {
+            // The later CTEs shadow the earlier ones with the same name.
+            let position = ctes
+                .iter()
+                .rposition(|cte| cte.alias.name.value == name.value);
+            if let Some(index) = position {
+                return self.common_table_expr(item, ctes, index).await;
+            }
+        }
+
         // TODO(Winter): await query_context.get_table
         let (database, table) = self.resolve_table(&item.name)?;
-        let read_table = self.ctx.get_table(&database, &table).await?;
+        let mut read_table = self.ctx.get_table(&database, &table).await?;
+
+        if let Some((kind, point)) = &item.navigation {
+            let navigation_point = self.navigation_point(kind, point).await?;
+            read_table = read_table
+                .navigate_to(self.ctx.clone(), &navigation_point)
+                .await?;
+        }
 
         match &item.alias {
             None => {
@@ -110,6 +311,108 @@ impl JoinedSchemaAnalyzer {
         }
     }
 
+    async fn common_table_expr(
+        &self,
+        item: &TableRPNItem,
+        ctes: &[DfCommonTableExpr],
+        index: usize,
+    ) -> Result<JoinedSchema> {
+        let cte = &ctes[index];
+        if item.navigation.is_some() {
+            return Err(ErrorCode::SyntaxException(format!(
+                "Cannot travel the CTE {} to a point",
+                cte.alias.name
+            )));
+        }
+
+        let name_prefix = match &item.alias {
+            None => vec![cte.alias.name.value.clone()],
+            Some(table_alias) => vec![table_alias.name.value.clone()],
+        };
+
+        let materialize = self.ctx.get_settings().get_enable_materialized_cte()? != 0;
+        let materialized_key = format!("WITH {} AS ({}) #{}", cte.alias, cte.query, cte.id);
+        if materialize && cte.references > 1 {
+            if let Some(table) = self.ctx.get_materialized_cte(&materialized_key) {
+                return JoinedSchema::from_table(table, name_prefix);
+            }
+        }
+
+        // The CTE can only reference the CTEs defined before it.
+        let mut state = self.select_query(cte.query.clone(), &ctes[..index]).await?;
+        if !cte.alias.columns.is_empty() {
+            state = Self::rename_columns(state, &cte.alias.columns)?;
+        }
+
+        match materialize && cte.references > 1 {
+            false => JoinedSchema::from_subquery(state, name_prefix),
+            true => {
+                let table = self.materialize(&cte.alias.name, materialized_key, &state);
+                JoinedSchema::from_table(table.await?, name_prefix)
+            }
+        }
+    }
+
+    // Run the CTE query once, the result blocks are read by all the references of the CTE.
+    async fn materialize(
+        &self,
+        name: &Ident,
+        key: String,
+        state: &QueryAnalyzeState,
+    ) -> Result<Arc<dyn Table>> {
+        let plan = PlanParser::build_query_plan(state)?;
+        let interpreter = InterpreterFactory::get(self.ctx.clone(), plan)?;
+        let stream = interpreter.execute(None).await?;
+        let blocks = stream.try_collect::<Vec<_>>().await?;
+
+        let table_info = TableInfo {
+            desc: key.clone(),
+            name: name.value.clone(),
+            ident: TableIdent::new(0, 0),
+            meta: TableMeta {
+                schema: state.finalize_schema.clone(),
+                engine: MemoryTable::MATERIALIZED_CTE_ENGINE.to_string(),
+                ..Default::default()
+            },
+        };
+        let table = MemoryTable::create_with_blocks(table_info, blocks);
+        Ok(self.ctx.add_materialized_cte(key, Arc::new(table)))
+    }
+
+    // Rename the output columns of `WITH name(column, ...) AS (query)`.
+    fn rename_columns(
+        state: Box<QueryAnalyzeState>,
+        columns: &[Ident],
+    ) -> Result<Box<QueryAnalyzeState>> {
+        let fields = state.finalize_schema.fields();
+        if fields.len() != columns.len() {
+            return Err(ErrorCode::SyntaxException(format!(
+                "CTE query has {} columns, but {} column names are specified",
+                fields.len(),
+                columns.len()
+            )));
+        }
+
+        let mut projection_expressions = Vec::with_capacity(fields.len());
+        let mut renamed_fields = Vec::with_capacity(fields.len());
+        for (field, column) in fields.iter().zip(columns) {
+            let column_expr = Expression::Column(field.name().clone());
+            projection_expressions.push(column_expr.alias(&column.value));
+            renamed_fields.push(DataField::new(
+                &column.value,
+                field.data_type().clone(),
+                field.is_nullable(),
+            ));
+        }
+
+        Ok(Box::new(QueryAnalyzeState {
+            projection_expressions,
+            finalize_schema: DataSchemaRefExt::create(renamed_fields),
+            relation: QueryRelation::Nested(state),
+            ..Default::default()
+        }))
+    }
+
     async fn table_function(&self, item: &TableFunctionRPNItem) -> Result<JoinedSchema> {
         if item.name.0.len() >= 2 {
             return Result::Err(ErrorCode::BadArguments(
@@ -139,6 +442,48 @@ impl JoinedSchemaAnalyzer {
         }
     }
 
+    async fn navigation_point(&self, kind: &Ident, point: &Expr) -> Result<NavigationPoint> {
+        let analyzer = ExpressionAnalyzer::create(self.ctx.clone());
+        let point = analyzer.analyze(point).await?;
+        match kind.value.to_uppercase().as_str() {
+            "SNAPSHOT" => match point {
+                Expression::Literal {
+                    value: DataValue::String(Some(snapshot_id)),
+                    ..
+                } => Ok(NavigationPoint::SnapshotID(String::from_utf8(snapshot_id)?)),
+                _ => Err(ErrorCode::BadArguments(format!(
+                    "Snapshot id must be a string literal, but got {:?}",
+                    point
+                ))),
+            },
+            _ => {
+                // evaluates the time point, e.g. `now() - INTERVAL 1 DAY`, as a DateTime64 of
+                // nanoseconds, the precision of the snapshot timestamps
+                let time_point = Expression::Cast {
+                    expr: Box::new(point),
+                    data_type: DataType::DateTime64(MAX_DATETIME64_PRECISION, None),
+                };
+                let dummy_schema =
+                    DataSchemaRefExt::create(vec![DataField::new("dummy", DataType::UInt8, false)]);
+                let executor = ExpressionExecutor::try_create(
+                    "time travel point executor",
+                    dummy_schema.clone(),
+                    DataSchemaRefExt::create(vec![time_point.to_data_field(&dummy_schema)?]),
+                    vec![time_point],
+                    false,
+                )?;
+                let dummy_block =
+                    DataBlock::create_by_array(dummy_schema, vec![Series::new(vec![1u8])]);
+                let nanos = executor
+                    .execute(&dummy_block)?
+                    .column(0)
+                    .try_get(0)?
+                    .as_i64()?;
+                Ok(NavigationPoint::TimePoint(Utc.timestamp_nanos(nanos)))
+            }
+        }
+    }
+
     fn resolve_table(&self, name: &ObjectName) -> Result<(String, String)> {
         match name.0.len() {
             0 => Err(ErrorCode::SyntaxException("Table name is empty")),
@@ -154,6 +499,8 @@ impl JoinedSchemaAnalyzer {
 struct TableRPNItem {
     name: ObjectName,
     alias: Option<TableAlias>,
+    /// The time travel point of the table, e.g. `t AT (SNAPSHOT => 'id')`
+    navigation: Option<(Ident, Expr)>,
 }
 
 struct DerivedRPNItem {
@@ -193,6 +540,7 @@ impl RelationRPNBuilder {
         self.rpn.push(RelationRPNItem::Table(TableRPNItem {
             name: ObjectName(vec![Ident::new("system"), Ident::new("one")]),
             alias: None,
+            navigation: None,
         }));
     }
 
@@ -239,9 +587,15 @@ impl RelationRPNBuilder {
                     ));
                 }
 
-                match args.is_empty() {
-                    true => self.visit_table(name, alias),
-                    false => self.visit_table_function(name, args, alias),
+                match args.as_slice() {
+                    [] => self.visit_table(name, alias, None),
+                    [FunctionArg::Named {
+                        name: arg_name,
+                        arg,
+                    }] if Self::is_navigation(arg_name) => {
+                        self.visit_table(name, alias, Some((arg_name.clone(), arg.clone())))
+                    }
+                    _ => self.visit_table_function(name, args, alias),
                 }
             }
             TableFactor::Derived {
@@ -266,14 +620,26 @@ impl RelationRPNBuilder {
         }
     }
 
-    fn visit_table(&mut self, name: &ObjectName, alias: &Option<TableAlias>) -> Result<()> {
+    fn visit_table(
+        &mut self,
+        name: &ObjectName,
+        alias: &Option<TableAlias>,
+        navigation: Option<(Ident, Expr)>,
+    ) -> Result<()> {
         self.rpn.push(RelationRPNItem::Table(TableRPNItem {
             name: name.clone(),
             alias: alias.clone(),
+            navigation,
         }));
         Ok(())
     }
 
+    // `AT (SNAPSHOT => ..)` and `AT (TIMESTAMP => ..)` are parsed as the only named argument
+    fn is_navigation(arg_name: &Ident) -> bool {
+        let arg_name = arg_name.value.to_uppercase();
+        arg_name == "SNAPSHOT" || arg_name == "TIMESTAMP"
+    }
+
     fn visit_table_function(
         &mut self,
         name: &ObjectName,
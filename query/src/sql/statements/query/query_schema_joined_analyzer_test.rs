@@ -49,6 +49,37 @@ async fn test_joined_schema_analyzer() -> Result<()> {
             query: "SELECT * FROM (SELECT * FROM system.databases)",
             expect: "QuerySchema { short_names: [\"name\"] }",
         },
+        TestCase {
+            name: "Cross join query",
+            query: "SELECT * FROM system.databases, system.one",
+            expect: "QuerySchema { short_names: [\"name\", \"dummy\"] }",
+        },
+        TestCase {
+            name: "Join query with ambiguous columns",
+            query:
+                "SELECT * FROM system.databases AS a JOIN system.databases AS b ON a.name = b.name",
+            expect: "QuerySchema { ambiguity_names: [[\"a\", \"name\"], [\"b\", \"name\"]] }",
+        },
+        TestCase {
+            name: "CTE query",
+            query: "WITH t AS (SELECT name FROM system.databases) SELECT * FROM t",
+            expect: "QuerySchema { short_names: [\"name\"] }",
+        },
+        TestCase {
+            name: "CTE query with column names",
+            query: "WITH t(a) AS (SELECT name FROM system.databases) SELECT * FROM t",
+            expect: "QuerySchema { short_names: [\"a\"] }",
+        },
+        TestCase {
+            name: "CTE query referenced by the following CTE",
+            query: "WITH t1 AS (SELECT 1 AS a), t2 AS (SELECT a AS b FROM t1) SELECT * FROM t2",
+            expect: "QuerySchema { short_names: [\"b\"] }",
+        },
+        TestCase {
+            name: "CTE query referenced by subquery",
+            query: "WITH t AS (SELECT 1 AS a) SELECT * FROM (SELECT a FROM t) AS x, t AS y",
+            expect: "QuerySchema { ambiguity_names: [[\"x\", \"a\"], [\"y\", \"a\"]] }",
+        },
     ];
 
     for test_case in &tests {
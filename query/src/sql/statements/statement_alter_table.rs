@@ -0,0 +1,102 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_datavalues::DataField;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_planners::AlterTableAction;
+use common_planners::AlterTablePlan;
+use common_planners::PlanNode;
+use common_tracing::tracing;
+use sqlparser::ast::ColumnDef;
+use sqlparser::ast::ColumnOption;
+use sqlparser::ast::Ident;
+use sqlparser::ast::ObjectName;
+
+use crate::sessions::QueryContext;
+use crate::sql::statements::AnalyzableStatement;
+use crate::sql::statements::AnalyzedResult;
+use crate::sql::SQLCommon;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum DfAlterTableAction {
+    AddColumn(ColumnDef),
+    DropColumn(Ident),
+    RenameColumn { old_name: Ident, new_name: Ident },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DfAlterTable {
+    /// Table name
+    pub name: ObjectName,
+    pub action: DfAlterTableAction,
+}
+
+#[async_trait::async_trait]
+impl AnalyzableStatement for DfAlterTable {
+    #[tracing::instrument(level = "info", skip(self, ctx), fields(ctx.id = ctx.get_id().as_str()))]
+    async fn analyze(&self, ctx: Arc<QueryContext>) -> Result<AnalyzedResult> {
+        let (db, table) = self.resolve_table(ctx)?;
+        let action = match &self.action {
+            DfAlterTableAction::AddColumn(column) => {
+                // The rows written before the column is added are read as NULLs, the options
+                // that conflict with it (e.g. DEFAULT, NOT NULL) are not supported yet.
+                if let Some(option) = column
+                    .options
+                    .iter()
+                    .find(|def| def.option != ColumnOption::Null)
+                {
+                    return Err(ErrorCode::UnImplement(format!(
+                        "Unsupported column option {} of the added column {}",
+                        option.option, column.name
+                    )));
+                }
+                let data_type = SQLCommon::make_data_type(&column.data_type)?;
+                AlterTableAction::AddColumn(DataField::new(&column.name.value, data_type, true))
+            }
+            DfAlterTableAction::DropColumn(column) => {
+                AlterTableAction::DropColumn(column.value.clone())
+            }
+            DfAlterTableAction::RenameColumn { old_name, new_name } => {
+                AlterTableAction::RenameColumn {
+                    old_name: old_name.value.clone(),
+                    new_name: new_name.value.clone(),
+                }
+            }
+        };
+
+        Ok(AnalyzedResult::SimpleQuery(Box::new(PlanNode::AlterTable(
+            AlterTablePlan { db, table, action },
+        ))))
+    }
+}
+
+impl DfAlterTable {
+    fn resolve_table(&self, ctx: Arc<QueryContext>) -> Result<(String, String)> {
+        let DfAlterTable {
+            name: ObjectName(idents),
+            ..
+        } = self;
+        match idents.len() {
+            0 => Err(ErrorCode::SyntaxException("Alter table name is empty")),
+            1 => Ok((ctx.get_current_database(), idents[0].value.clone())),
+            2 => Ok((idents[0].value.clone(), idents[1].value.clone())),
+            _ => Err(ErrorCode::SyntaxException(
+                "Alter table name must be [`db`].`table`",
+            )),
+        }
+    }
+}
@@ -16,13 +16,15 @@ use std::sync::Arc;
 
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_planners::OptimizeTableAction;
+use common_planners::OptimizeTablePlan;
+use common_planners::PlanNode;
 use common_tracing::tracing;
 use sqlparser::ast::ObjectName;
 
 use crate::sessions::QueryContext;
 use crate::sql::statements::AnalyzableStatement;
 use crate::sql::statements::AnalyzedResult;
-use crate::sql::PlanParser;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct DfCompactTable {
@@ -33,11 +35,14 @@ pub struct DfCompactTable {
 impl AnalyzableStatement for DfCompactTable {
     #[tracing::instrument(level = "info", skip(self, ctx), fields(ctx.id = ctx.get_id().as_str()))]
     async fn analyze(&self, ctx: Arc<QueryContext>) -> Result<AnalyzedResult> {
-        let (db, table) = self.resolve_table(ctx.clone())?;
-        let table = format!("{}.{}", db, table);
-        let rewritten_query = format!("INSERT OVERWRITE {} SELECT * FROM {}", table, table);
-        let rewritten_plan = PlanParser::parse(rewritten_query.as_str(), ctx).await?;
-        Ok(AnalyzedResult::SimpleQuery(Box::new(rewritten_plan)))
+        let (db, table) = self.resolve_table(ctx)?;
+        Ok(AnalyzedResult::SimpleQuery(Box::new(
+            PlanNode::OptimizeTable(OptimizeTablePlan {
+                db,
+                table,
+                action: OptimizeTableAction::Compact,
+            }),
+        )))
     }
 }
 
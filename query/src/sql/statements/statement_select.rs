@@ -19,19 +19,25 @@ use common_datavalues::DataSchemaRefExt;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_planners::expand_aggregate_arg_exprs;
+use common_planners::expand_window_arg_exprs;
 use common_planners::find_aggregate_exprs;
 use common_planners::find_aggregate_exprs_in_expr;
+use common_planners::find_window_exprs;
+use common_planners::find_window_exprs_in_expr;
 use common_planners::rebase_expr;
 use common_planners::Expression;
 use common_tracing::tracing;
 use sqlparser::ast::Expr;
 use sqlparser::ast::Offset;
 use sqlparser::ast::OrderByExpr;
+use sqlparser::ast::Query;
 use sqlparser::ast::SelectItem;
+use sqlparser::ast::TableAlias;
 use sqlparser::ast::TableWithJoins;
 
 use crate::sessions::QueryContext;
 use crate::sql::statements::analyzer_statement::QueryAnalyzeState;
+use crate::sql::statements::query::JoinedRelation;
 use crate::sql::statements::query::JoinedSchema;
 use crate::sql::statements::query::JoinedSchemaAnalyzer;
 use crate::sql::statements::query::JoinedTableDesc;
@@ -41,11 +47,27 @@ use crate::sql::statements::query::QueryCollectPushDowns;
 use crate::sql::statements::query::QueryNormalizer;
 use crate::sql::statements::AnalyzableStatement;
 use crate::sql::statements::AnalyzedResult;
+use crate::sql::statements::QueryJoinInput;
+use crate::sql::statements::QueryJoinRelation;
 use crate::sql::statements::QueryRelation;
 use crate::storages::ToReadDataSourcePlan;
 
+#[derive(Debug, Clone, PartialEq)]
+pub struct DfCommonTableExpr {
+    // Tells the CTE apart from the ones of the same definition in the other scopes, which may
+    // refer to the different CTEs.
+    pub id: u64,
+    pub alias: TableAlias,
+    pub query: Query,
+    // The number of references to the CTE in the query which defines it, including the ones in
+    // the subqueries of the expressions, it is materialized if referenced more than once.
+    pub references: usize,
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct DfQueryStatement {
+    // The CTEs visible to the query, the later ones shadow the earlier ones with the same name.
+    pub ctes: Vec<DfCommonTableExpr>,
     pub from: Vec<TableWithJoins>,
     pub projection: Vec<SelectItem>,
     pub selection: Option<Expr>,
@@ -76,6 +98,13 @@ impl AnalyzableStatement for DfQueryStatement {
 }
 
 impl DfQueryStatement {
+    /// Make the CTEs of the outer query visible to this query, its own CTEs take precedence.
+    pub fn inherit_ctes(&mut self, outer: &[DfCommonTableExpr]) {
+        if !outer.is_empty() {
+            self.ctes = outer.iter().chain(&self.ctes).cloned().collect();
+        }
+    }
+
     async fn analyze_query(&self, ir: QueryASTIR) -> Result<QueryAnalyzeState> {
         let limit = ir.limit;
         let offset = ir.offset;
@@ -87,6 +116,7 @@ impl DfQueryStatement {
 
         if let Some(predicate) = &ir.filter_predicate {
             Self::verify_no_aggregate(predicate, "filter")?;
+            Self::verify_no_window(predicate, "filter")?;
             analyze_state.filter = Some(predicate.clone());
         }
 
@@ -94,6 +124,7 @@ impl DfQueryStatement {
 
         // Allow `SELECT name FROM system.databases HAVING name = 'xxx'`
         if let Some(predicate) = &ir.having_predicate {
+            Self::verify_no_window(predicate, "having")?;
             analyze_state.having = Some(rebase_expr(predicate, &analyze_state.expressions)?);
         }
 
@@ -145,9 +176,40 @@ impl DfQueryStatement {
             Self::analyze_aggregate(&ir.aggregate_expressions, &mut analyze_state)?;
         }
 
+        Self::analyze_window(&mut analyze_state)?;
         Ok(analyze_state)
     }
 
+    fn analyze_window(state: &mut QueryAnalyzeState) -> Result<()> {
+        let window_functions = find_window_exprs(&state.expressions);
+
+        if window_functions.is_empty() {
+            return Ok(());
+        }
+
+        let window_functions_args = expand_window_arg_exprs(&window_functions);
+        for window_function_arg in &window_functions_args {
+            Self::verify_no_window(window_function_arg, "window function")?;
+            state.add_before_window_expression(window_function_arg);
+        }
+
+        for window_function in &window_functions {
+            let base_exprs = &state.before_window_expressions;
+            state
+                .window_expressions
+                .push(rebase_expr(window_function, base_exprs)?);
+        }
+
+        // Rebase expressions using window expressions, they are evaluated by the window plans.
+        let mut expressions = Vec::with_capacity(state.expressions.len());
+        for expression in &state.expressions {
+            expressions.push(rebase_expr(expression, &window_functions)?);
+        }
+
+        state.expressions = expressions;
+        Ok(())
+    }
+
     fn analyze_aggregate(exprs: &[Expression], state: &mut QueryAnalyzeState) -> Result<()> {
         let aggregate_functions = find_aggregate_exprs(exprs);
         let aggregate_functions_args = expand_aggregate_arg_exprs(&aggregate_functions);
@@ -180,6 +242,16 @@ impl DfQueryStatement {
         Ok(())
     }
 
+    fn verify_no_window(expr: &Expression, info: &str) -> Result<()> {
+        match find_window_exprs_in_expr(expr).is_empty() {
+            true => Ok(()),
+            false => Err(ErrorCode::SyntaxException(format!(
+                "{} cannot contain window functions",
+                info
+            ))),
+        }
+    }
+
     fn verify_no_aggregate(expr: &Expression, info: &str) -> Result<()> {
         match find_aggregate_exprs_in_expr(expr).is_empty() {
             true => Ok(()),
@@ -201,29 +273,100 @@ impl DfQueryStatement {
         let dry_run_res = Self::verify_with_dry_run(&schema, &state)?;
         state.finalize_schema = dry_run_res.schema().clone();
 
-        let mut tables_desc = schema.take_tables_desc();
+        let relation = schema.get_relation().clone();
+        let tables_desc = schema.take_tables_desc();
 
-        if tables_desc.len() != 1 {
-            return Err(ErrorCode::UnImplement("Select join unimplemented yet."));
+        let mut tables_relation = Vec::with_capacity(tables_desc.len());
+        for table_desc in tables_desc {
+            tables_relation.push(Self::table_relation(table_desc, ctx.clone()).await?);
         }
 
-        match tables_desc.remove(0) {
+        state.relation = match relation {
+            JoinedRelation::Table(_) if tables_relation.len() == 1 => {
+                tables_relation.remove(0).relation
+            }
+            _ => Self::join_relation(&relation, &tables_relation)?,
+        };
+
+        Ok(AnalyzedResult::SelectQuery(Box::new(state)))
+    }
+
+    async fn table_relation(
+        table_desc: JoinedTableDesc,
+        ctx: Arc<QueryContext>,
+    ) -> Result<QueryJoinInput> {
+        let (relation, output_names) = match &table_desc {
             JoinedTableDesc::Table {
                 table, push_downs, ..
             } => {
-                let source_plan = table.read_plan(ctx.clone(), push_downs).await?;
-                state.relation = QueryRelation::FromTable(Box::new(source_plan));
+                let source_plan = table.read_plan(ctx.clone(), push_downs.clone()).await?;
+                let schema = source_plan.schema();
+                let names = schema.fields().iter().map(|f| f.name().clone());
+                let names = names.collect::<Vec<_>>();
+                (QueryRelation::FromTable(Box::new(source_plan)), names)
             }
             JoinedTableDesc::Subquery {
                 state: subquery_state,
                 ..
             } => {
                 // TODO: maybe need reanalyze subquery.
-                state.relation = QueryRelation::Nested(subquery_state);
+                let schema = &subquery_state.finalize_schema;
+                let names = schema.fields().iter().map(|f| f.name().clone());
+                let names = names.collect::<Vec<_>>();
+                (QueryRelation::Nested(subquery_state.clone()), names)
+            }
+        };
+
+        let mut projection = Vec::with_capacity(output_names.len());
+        for column_desc in table_desc.get_columns_desc() {
+            if output_names.contains(&column_desc.short_name) {
+                let column = Expression::Column(column_desc.short_name.clone());
+                projection.push(match column_desc.is_ambiguity {
+                    true => column.alias(&table_desc.get_column_name(column_desc)),
+                    false => column,
+                });
             }
         }
 
-        Ok(AnalyzedResult::SelectQuery(Box::new(state)))
+        Ok(QueryJoinInput {
+            relation,
+            projection,
+        })
+    }
+
+    fn join_relation(
+        relation: &JoinedRelation,
+        tables_relation: &[QueryJoinInput],
+    ) -> Result<QueryRelation> {
+        match relation {
+            JoinedRelation::Table(_) => Err(ErrorCode::LogicalError(
+                "Logical error: join relation must be join, it's a bug.",
+            )),
+            JoinedRelation::Join {
+                join_type,
+                condition,
+                left,
+                right,
+            } => Ok(QueryRelation::Join(Box::new(QueryJoinRelation {
+                join_type: *join_type,
+                condition: condition.clone(),
+                left: Self::join_input(left, tables_relation)?,
+                right: Self::join_input(right, tables_relation)?,
+            }))),
+        }
+    }
+
+    fn join_input(
+        relation: &JoinedRelation,
+        tables_relation: &[QueryJoinInput],
+    ) -> Result<QueryJoinInput> {
+        match relation {
+            JoinedRelation::Table(index) => Ok(tables_relation[*index].clone()),
+            JoinedRelation::Join { .. } => Ok(QueryJoinInput {
+                relation: Self::join_relation(relation, tables_relation)?,
+                projection: vec![],
+            }),
+        }
     }
 
     fn verify_with_dry_run(schema: &JoinedSchema, state: &QueryAnalyzeState) -> Result<DataBlock> {
@@ -268,6 +411,29 @@ impl DfQueryStatement {
             }
         }
 
+        if !state.before_window_expressions.is_empty() {
+            match Self::dry_run_merged_exprs(&state.before_window_expressions, &data_block) {
+                Ok(res) => {
+                    data_block = res;
+                }
+                Err(cause) => {
+                    return Err(cause.add_message_back(" (while in select before window)"));
+                }
+            }
+        }
+
+        for window_expression in &state.window_expressions {
+            let window_expression = std::slice::from_ref(window_expression);
+            match Self::dry_run_merged_exprs(window_expression, &data_block) {
+                Ok(res) => {
+                    data_block = res;
+                }
+                Err(cause) => {
+                    return Err(cause.add_message_back(" (while in select window)"));
+                }
+            }
+        }
+
         if !state.expressions.is_empty() {
             match Self::dry_run_exprs(&state.expressions, &data_block) {
                 Ok(res) => {
@@ -316,6 +482,23 @@ impl DfQueryStatement {
         )))
     }
 
+    // The before window expression plan and the window plans keep the input columns.
+    fn dry_run_merged_exprs(exprs: &[Expression], data: &DataBlock) -> Result<DataBlock> {
+        let schema = data.schema();
+        let mut data_fields = schema.fields().clone();
+
+        for expr in exprs {
+            let data_field = expr.to_data_field(schema)?;
+            if !data_fields.iter().any(|f| f.name() == data_field.name()) {
+                data_fields.push(data_field);
+            }
+        }
+
+        Ok(DataBlock::empty_with_schema(DataSchemaRefExt::create(
+            data_fields,
+        )))
+    }
+
     fn dry_run_exprs(exprs: &[Expression], data: &DataBlock) -> Result<DataBlock> {
         let schema = data.schema();
         let mut new_data_fields = Vec::with_capacity(exprs.len());
@@ -13,26 +13,37 @@
 // limitations under the License.
 
 use std::convert::TryFrom;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
 
+use sqlparser::ast::Expr;
+use sqlparser::ast::FunctionArg;
+use sqlparser::ast::JoinConstraint;
+use sqlparser::ast::JoinOperator;
+use sqlparser::ast::OrderByExpr;
 use sqlparser::ast::Query;
 use sqlparser::ast::Select;
+use sqlparser::ast::SelectItem;
 use sqlparser::ast::SetExpr;
+use sqlparser::ast::TableFactor;
+use sqlparser::ast::TableWithJoins;
 use sqlparser::parser::ParserError;
 
+use crate::sql::statements::DfCommonTableExpr;
 use crate::sql::statements::DfQueryStatement;
 
+static NEXT_CTE_ID: AtomicU64 = AtomicU64::new(0);
+
 impl TryFrom<Query> for DfQueryStatement {
     type Error = ParserError;
 
     fn try_from(query: Query) -> Result<Self, Self::Error> {
-        let query_body = Self::get_body(&query)?;
-
-        if query.with.is_some() {
-            return Err(ParserError::ParserError(String::from(
-                "CTE is not yet implement",
-            )));
+        if let SetExpr::SetOperation { .. } | SetExpr::Query(_) = &query.body {
+            return Self::from_derived_body(query);
         }
 
+        let query_body = Self::get_body(&query)?;
+
         if query.fetch.is_some() {
             return Err(ParserError::ParserError(String::from(
                 "FETCH is not yet implement",
@@ -64,6 +75,7 @@ impl TryFrom<Query> for DfQueryStatement {
         }
 
         Ok(DfQueryStatement {
+            ctes: Self::common_table_exprs(&query)?,
             from: query_body.from.clone(),
             projection: query_body.projection.clone(),
             selection: query_body.selection.clone(),
@@ -86,4 +98,230 @@ impl DfQueryStatement {
             ))),
         }
     }
+
+    // Rewrite `<set operation> ORDER BY ... LIMIT ...` and `(<query>) ORDER BY ... LIMIT ...`
+    // into `SELECT * FROM (<body>) ORDER BY ... LIMIT ...`, the body is analyzed as a derived table.
+    fn from_derived_body(query: Query) -> Result<Self, ParserError> {
+        if query.fetch.is_some() {
+            return Err(ParserError::ParserError(String::from(
+                "FETCH is not yet implement",
+            )));
+        }
+
+        let ctes = Self::common_table_exprs(&query)?;
+        let subquery = match query.body {
+            SetExpr::Query(subquery) => subquery,
+            body => Box::new(Query {
+                with: None,
+                body,
+                order_by: vec![],
+                limit: None,
+                offset: None,
+                fetch: None,
+            }),
+        };
+
+        Ok(DfQueryStatement {
+            ctes,
+            from: vec![TableWithJoins {
+                relation: TableFactor::Derived {
+                    lateral: false,
+                    subquery,
+                    alias: None,
+                },
+                joins: vec![],
+            }],
+            projection: vec![SelectItem::Wildcard],
+            selection: None,
+            group_by: vec![],
+            having: None,
+            order_by: query.order_by,
+            limit: query.limit,
+            offset: query.offset,
+        })
+    }
+
+    fn common_table_exprs(query: &Query) -> Result<Vec<DfCommonTableExpr>, ParserError> {
+        let with = match &query.with {
+            None => return Ok(vec![]),
+            Some(with) => with,
+        };
+
+        if with.recursive {
+            return Err(ParserError::ParserError(String::from(
+                "Recursive CTE is not yet implement",
+            )));
+        }
+
+        let mut ctes = Vec::with_capacity(with.cte_tables.len());
+        for (index, cte) in with.cte_tables.iter().enumerate() {
+            let name = &cte.alias.name.value;
+            if with.cte_tables[..index]
+                .iter()
+                .any(|defined| &defined.alias.name.value == name)
+            {
+                return Err(ParserError::ParserError(format!(
+                    "Duplicate CTE name: {}",
+                    name
+                )));
+            }
+
+            // The CTE is visible to the query body and the CTEs defined after it.
+            let mut references = CteReferences::set_expr(&query.body, name)
+                + CteReferences::order_by(&query.order_by, name);
+            for following in &with.cte_tables[index + 1..] {
+                references += CteReferences::query(&following.query, name);
+            }
+
+            ctes.push(DfCommonTableExpr {
+                id: NEXT_CTE_ID.fetch_add(1, Ordering::Relaxed),
+                alias: cte.alias.clone(),
+                query: cte.query.clone(),
+                references,
+            });
+        }
+
+        Ok(ctes)
+    }
+}
+
+/// Count the table references to a CTE, in the FROM clauses and the subqueries of the
+/// expressions (e.g. IN, EXISTS, scalar subqueries).
+struct CteReferences;
+
+impl CteReferences {
+    fn query(query: &Query, name: &str) -> usize {
+        let mut references = 0;
+        if let Some(with) = &query.with {
+            for cte in &with.cte_tables {
+                references += Self::query(&cte.query, name);
+
+                // The CTE is shadowed by the CTE with the same name from here on.
+                if cte.alias.name.value == name {
+                    return references;
+                }
+            }
+        }
+
+        references + Self::set_expr(&query.body, name) + Self::order_by(&query.order_by, name)
+    }
+
+    fn set_expr(body: &SetExpr, name: &str) -> usize {
+        match body {
+            SetExpr::Select(select) => Self::select(select, name),
+            SetExpr::Query(query) => Self::query(query, name),
+            SetExpr::SetOperation { left, right, .. } => {
+                Self::set_expr(left, name) + Self::set_expr(right, name)
+            }
+            _ => 0,
+        }
+    }
+
+    fn select(select: &Select, name: &str) -> usize {
+        let from = select.from.iter();
+        let from = from.map(|table| Self::table_with_joins(table, name));
+        let projection = select.projection.iter().map(|item| match item {
+            SelectItem::UnnamedExpr(expr) | SelectItem::ExprWithAlias { expr, .. } => {
+                Self::expr(expr, name)
+            }
+            _ => 0,
+        });
+        let exprs = select.selection.iter().chain(&select.group_by);
+        let exprs = exprs.chain(&select.having);
+        from.sum::<usize>() + projection.sum::<usize>() + Self::exprs(exprs, name)
+    }
+
+    fn table_with_joins(table: &TableWithJoins, name: &str) -> usize {
+        let joins = table.joins.iter();
+        let joins = joins.map(|join| {
+            let constraint = match &join.join_operator {
+                JoinOperator::Inner(JoinConstraint::On(expr))
+                | JoinOperator::LeftOuter(JoinConstraint::On(expr))
+                | JoinOperator::RightOuter(JoinConstraint::On(expr))
+                | JoinOperator::FullOuter(JoinConstraint::On(expr)) => Self::expr(expr, name),
+                _ => 0,
+            };
+            Self::table_factor(&join.relation, name) + constraint
+        });
+        Self::table_factor(&table.relation, name) + joins.sum::<usize>()
+    }
+
+    fn table_factor(factor: &TableFactor, name: &str) -> usize {
+        match factor {
+            TableFactor::Table {
+                name: table_name, ..
+            } => match table_name.0.as_slice() {
+                [ident] if ident.value == name => 1,
+                _ => 0,
+            },
+            TableFactor::Derived { subquery, .. } => Self::query(subquery, name),
+            TableFactor::NestedJoin(table) => Self::table_with_joins(table, name),
+            TableFactor::TableFunction { expr, .. } => Self::expr(expr, name),
+        }
+    }
+
+    fn order_by(order_by: &[OrderByExpr], name: &str) -> usize {
+        Self::exprs(order_by.iter().map(|order_by| &order_by.expr), name)
+    }
+
+    fn exprs<'a>(exprs: impl Iterator<Item = &'a Expr>, name: &str) -> usize {
+        exprs.map(|expr| Self::expr(expr, name)).sum()
+    }
+
+    fn expr(expr: &Expr, name: &str) -> usize {
+        match expr {
+            Expr::Exists(subquery) | Expr::Subquery(subquery) => Self::query(subquery, name),
+            Expr::InSubquery { expr, subquery, .. } => {
+                Self::expr(expr, name) + Self::query(subquery, name)
+            }
+            Expr::Nested(expr)
+            | Expr::IsNull(expr)
+            | Expr::IsNotNull(expr)
+            | Expr::UnaryOp { expr, .. }
+            | Expr::Cast { expr, .. }
+            | Expr::Extract { expr, .. }
+            | Expr::MapAccess { column: expr, .. } => Self::expr(expr, name),
+            Expr::BinaryOp { left, right, .. } => Self::expr(left, name) + Self::expr(right, name),
+            Expr::Between {
+                expr, low, high, ..
+            } => Self::expr(expr, name) + Self::expr(low, name) + Self::expr(high, name),
+            Expr::InList { expr, list, .. } => {
+                Self::expr(expr, name) + Self::exprs(list.iter(), name)
+            }
+            Expr::Tuple(exprs) => Self::exprs(exprs.iter(), name),
+            Expr::Position {
+                substr_expr,
+                str_expr,
+            } => Self::expr(substr_expr, name) + Self::expr(str_expr, name),
+            Expr::Substring {
+                expr,
+                substring_from,
+                substring_for,
+            } => {
+                let exprs = substring_from.iter().chain(substring_for);
+                Self::expr(expr, name) + Self::exprs(exprs.map(|expr| &**expr), name)
+            }
+            Expr::Case {
+                operand,
+                conditions,
+                results,
+                else_result,
+            } => {
+                let exprs = operand.iter().chain(else_result).map(|expr| &**expr);
+                Self::exprs(exprs.chain(conditions).chain(results), name)
+            }
+            Expr::Function(function) => {
+                let args = function.args.iter().map(|arg| match arg {
+                    FunctionArg::Named { arg, .. } => arg,
+                    FunctionArg::Unnamed(expr) => expr,
+                });
+                let window = function.over.iter().map(|window_spec| {
+                    Self::exprs(window_spec.partition_by.iter(), name)
+                        + Self::order_by(&window_spec.order_by, name)
+                });
+                Self::exprs(args, name) + window.sum::<usize>()
+            }
+            _ => 0,
+        }
+    }
 }
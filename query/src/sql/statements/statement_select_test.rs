@@ -116,6 +116,41 @@ async fn test_statement_select_analyze() -> Result<()> {
             query: "SELECT avg(number), max(number + 1) + 1 FROM numbers_mt(10000) GROUP BY 1;",
             expect: "QueryAnalyzeState { before_group_by: [1, number, (number + 1)], group_by: [1], aggregate: [avg(number), max((number + 1))], before_projection: [avg(number), (max((number + 1)) + 1)], projection: [avg(number), (max((number + 1)) + 1)] }",
         },
+        TestCase {
+            name: "Window function query",
+            query: "SELECT number, row_number() OVER (ORDER BY number) FROM numbers(10)",
+            expect: "QueryAnalyzeState { before_window: [number], window: [row_number() OVER (ORDER BY number)], before_projection: [number, row_number() OVER (ORDER BY number)], projection: [number, row_number() OVER (ORDER BY number)] }",
+        },
+        TestCase {
+            name: "Window function query with partition by",
+            query: "SELECT sum(number) OVER (PARTITION BY number % 3) + 1 AS s FROM numbers(10)",
+            expect: "QueryAnalyzeState { before_window: [number, (number % 3)], window: [sum(number) OVER (PARTITION BY (number % 3))], before_projection: [(sum(number) OVER (PARTITION BY (number % 3)) + 1)], projection: [(sum(number) OVER (PARTITION BY (number % 3)) + 1) as s] }",
+        },
+        TestCase {
+            name: "Filter query with in list",
+            query: "SELECT number FROM numbers(10) WHERE number IN (1, 2) AND number NOT IN (3)",
+            expect: "QueryAnalyzeState { filter: (in(number, 1, 2) and not_in(number, 3)), before_projection: [number], projection: [number] }",
+        },
+        TestCase {
+            name: "Case when query",
+            query: "SELECT CASE WHEN number > 5 THEN 1 ELSE 0 END AS c FROM numbers(10)",
+            expect: "QueryAnalyzeState { before_projection: [if((number > 5), 1, 0)], projection: [if((number > 5), 1, 0) as c] }",
+        },
+        TestCase {
+            name: "Case when query with operand",
+            query: "SELECT CASE number WHEN 1 THEN 'a' WHEN 2 THEN 'b' END AS c FROM numbers(10)",
+            expect: "QueryAnalyzeState { before_projection: [if((number = 1), a, if((number = 2), b, NULL))], projection: [if((number = 1), a, if((number = 2), b, NULL)) as c] }",
+        },
+        TestCase {
+            name: "Extract query",
+            query: "SELECT EXTRACT(MONTH FROM today()) AS m",
+            expect: "QueryAnalyzeState { before_projection: [toMonth(today())], projection: [toMonth(today()) as m] }",
+        },
+        TestCase {
+            name: "To timezone query",
+            query: "SELECT toTimeZone(now(), 'Asia/Shanghai') AS t",
+            expect: "QueryAnalyzeState { before_projection: [cast(now() as DateTime32(Some(\"Asia/Shanghai\")))], projection: [cast(now() as DateTime32(Some(\"Asia/Shanghai\"))) as t] }",
+        },
     ];
 
     for test_case in &tests {
@@ -0,0 +1,148 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+//
+
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::time::Duration;
+
+use common_base::tokio;
+use common_base::tokio::sync::Notify;
+use common_base::tokio::task::JoinHandle;
+use common_base::tokio::time::sleep as tokio_async_sleep;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use futures::future::select;
+use futures::future::Either;
+use futures::Future;
+
+use crate::catalogs::Catalog;
+use crate::configs::Config;
+use crate::sessions::SessionManager;
+use crate::storages::fuse::operations::CompactionThresholds;
+use crate::storages::fuse::FuseTable;
+
+/// Compacts the fuse tables in background, once a table has too many segments or
+/// undersized blocks.
+pub struct CompactionScheduler {
+    interval: Duration,
+    thresholds: CompactionThresholds,
+    sessions: Arc<SessionManager>,
+    shutdown: Arc<AtomicBool>,
+    shutdown_notify: Arc<Notify>,
+    shutdown_handler: Option<JoinHandle<()>>,
+}
+
+impl CompactionScheduler {
+    /// Returns None if the background compaction is disabled by the config.
+    pub fn try_create(conf: &Config, sessions: Arc<SessionManager>) -> Option<CompactionScheduler> {
+        if conf.query.fuse_compact_interval_secs == 0 {
+            return None;
+        }
+
+        Some(CompactionScheduler {
+            interval: Duration::from_secs(conf.query.fuse_compact_interval_secs),
+            thresholds: CompactionThresholds {
+                segment_count: conf.query.fuse_compact_segment_threshold as usize,
+                small_block_count: conf.query.fuse_compact_block_threshold as usize,
+            },
+            sessions,
+            shutdown: Arc::new(AtomicBool::new(false)),
+            shutdown_notify: Arc::new(Notify::new()),
+            shutdown_handler: None,
+        })
+    }
+
+    fn schedule_loop(&self) -> impl Future<Output = ()> + 'static {
+        let shutdown = self.shutdown.clone();
+        let shutdown_notify = self.shutdown_notify.clone();
+        let sessions = self.sessions.clone();
+        let interval = self.interval;
+        let thresholds = self.thresholds;
+
+        async move {
+            let mut shutdown_notified = Box::pin(shutdown_notify.notified());
+
+            while !shutdown.load(Ordering::Relaxed) {
+                let sleep = tokio_async_sleep(interval);
+
+                match select(shutdown_notified, Box::pin(sleep)).await {
+                    Either::Left((_, _)) => {
+                        break;
+                    }
+                    Either::Right((_, new_shutdown_notified)) => {
+                        shutdown_notified = new_shutdown_notified;
+                        if let Err(failure) = Self::compact_tables(&sessions, thresholds).await {
+                            log::error!("Background compaction failure: {:?}", failure);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    async fn compact_tables(
+        sessions: &Arc<SessionManager>,
+        thresholds: CompactionThresholds,
+    ) -> Result<()> {
+        let session = sessions.create_session("BackgroundCompaction")?;
+        let ctx = session.create_context().await?;
+        let catalog = ctx.get_catalog();
+
+        for database in catalog.list_databases().await? {
+            for table in catalog.list_tables(database.name()).await? {
+                let fuse_table = match table.as_any().downcast_ref::<FuseTable>() {
+                    Some(fuse_table) => fuse_table,
+                    None => continue,
+                };
+
+                // a failure of one table should not stop the others from being compacted
+                match fuse_table.compact_if_needed(ctx.clone(), thresholds).await {
+                    Ok(true) => log::info!(
+                        "Table {}.{} is compacted in background",
+                        database.name(),
+                        table.name()
+                    ),
+                    Ok(false) => {}
+                    Err(failure) => log::warn!(
+                        "Cannot compact table {}.{} in background, cause {:?}",
+                        database.name(),
+                        table.name(),
+                        failure
+                    ),
+                }
+            }
+        }
+        Ok(())
+    }
+
+    pub fn start(&mut self) {
+        self.shutdown_handler = Some(tokio::spawn(self.schedule_loop()));
+    }
+
+    pub async fn shutdown(&mut self) -> Result<()> {
+        if let Some(shutdown_handler) = self.shutdown_handler.take() {
+            self.shutdown.store(true, Ordering::Relaxed);
+            self.shutdown_notify.notify_waiters();
+            if let Err(shutdown_failure) = shutdown_handler.await {
+                return Err(ErrorCode::TokioError(format!(
+                    "Cannot shutdown background compaction, cause {:?}",
+                    shutdown_failure
+                )));
+            }
+        }
+        Ok(())
+    }
+}
@@ -16,6 +16,9 @@
 pub const TBL_OPT_KEY_SNAPSHOT_LOC: &str = "SNAPSHOT_LOC";
 pub const TBL_OPT_KEY_CHUNK_BLOCK_NUM: &str = "CHUNK_BLOCK_NUM";
 pub const TBL_OPT_KEY_BLOCK_IN_MEM_SIZE_THRESHOLD: &str = "BLOCK_SIZE_THRESHOLD";
+pub const TBL_OPT_KEY_COLUMN_IDS: &str = "COLUMN_IDS";
+pub const TBL_OPT_KEY_NEXT_COLUMN_ID: &str = "NEXT_COLUMN_ID";
+pub const TBL_OPT_KEY_OLDEST_SNAPSHOT_ID: &str = "OLDEST_SNAPSHOT_ID";
 pub const FUSE_TBL_BLOCK_PREFIX: &str = "_b";
 pub const FUSE_TBL_SEGMENT_PREFIX: &str = "_sg";
 pub const FUSE_TBL_SNAPSHOT_PREFIX: &str = "_ss";
@@ -22,15 +22,15 @@ use common_planners::Extras;
 use futures::StreamExt;
 use futures::TryStreamExt;
 
-use crate::storages::fuse::io::snapshot_location;
 use crate::storages::fuse::meta::BlockMeta;
+use crate::storages::fuse::meta::ColumnId;
 use crate::storages::fuse::meta::SegmentInfo;
 use crate::storages::fuse::meta::TableSnapshot;
 use crate::storages::index::BlockStatistics;
 use crate::storages::index::RangeFilter;
 
 pub struct MinMaxIndex {
-    table_snapshot_loc: String,
+    segment_locs: Vec<String>,
     da: Arc<dyn DataAccessor>,
 }
 
@@ -38,9 +38,7 @@ type Pred = Box<dyn Fn(&BlockStatistics) -> Result<bool> + Send + Sync + Unpin>;
 impl MinMaxIndex {
     pub fn new(table_snapshot: &TableSnapshot, da: Arc<dyn DataAccessor>) -> Self {
         Self {
-            table_snapshot_loc: snapshot_location(
-                table_snapshot.snapshot_id.to_simple().to_string(),
-            ),
+            segment_locs: table_snapshot.segments.clone(),
             da,
         }
     }
@@ -49,28 +47,34 @@ impl MinMaxIndex {
     pub async fn apply(
         &self,
         schema: DataSchemaRef,
+        column_ids: &[ColumnId],
         push_down: Option<Extras>,
     ) -> Result<Vec<BlockMeta>> {
         let block_pred: Pred = match push_down {
             Some(exprs) if !exprs.filters.is_empty() => {
                 // for the time being, we only handle the first expr
                 let verifiable_expression = RangeFilter::try_create(&exprs.filters[0], schema)?;
-                Box::new(move |v: &BlockStatistics| verifiable_expression.eval(v))
+                let column_ids = column_ids.to_vec();
+                Box::new(move |v: &BlockStatistics| {
+                    // statistics are keyed by column id, while the range filter addresses
+                    // columns by their positions in the schema
+                    let stats = column_ids
+                        .iter()
+                        .enumerate()
+                        .filter_map(|(pos, id)| v.get(id).map(|s| (pos as ColumnId, s.clone())))
+                        .collect::<BlockStatistics>();
+                    verifiable_expression.eval(&stats)
+                })
             }
             _ => Box::new(|_: &BlockStatistics| Ok(true)),
         };
 
-        let snapshot =
-            common_dal::read_obj::<TableSnapshot>(self.da.clone(), self.table_snapshot_loc.clone())
-                .await?;
-        let segment_num = snapshot.segments.len();
-        let segment_locs = snapshot.segments;
-
-        if segment_locs.is_empty() {
+        let segment_num = self.segment_locs.len();
+        if segment_num == 0 {
             return Ok(vec![]);
         };
 
-        let res = futures::stream::iter(segment_locs)
+        let res = futures::stream::iter(self.segment_locs.clone())
             .map(|seg_loc| async {
                 let segment_info =
                     common_dal::read_obj::<SegmentInfo>(self.da.clone(), seg_loc).await?;
@@ -108,10 +112,11 @@ impl MinMaxIndex {
 pub async fn apply_range_filter(
     table_snapshot: &TableSnapshot,
     schema: DataSchemaRef,
+    column_ids: &[ColumnId],
     push_down: Option<Extras>,
     data_accessor: Arc<dyn DataAccessor>,
 ) -> Result<Vec<BlockMeta>> {
     MinMaxIndex::new(table_snapshot, data_accessor)
-        .apply(schema, push_down)
+        .apply(schema, column_ids, push_down)
         .await
 }
@@ -103,6 +103,7 @@ async fn test_min_max_index() -> Result<()> {
     let blocks = apply_range_filter(
         &snapshot,
         table.get_table_info().schema(),
+        &[0, 1],
         push_downs,
         da.clone(),
     )
@@ -119,6 +120,7 @@ async fn test_min_max_index() -> Result<()> {
     let blocks = apply_range_filter(
         &snapshot,
         table.get_table_info().schema(),
+        &[0, 1],
         Some(extra),
         da.clone(),
     )
@@ -130,8 +132,14 @@ async fn test_min_max_index() -> Result<()> {
     let pred = col("a").gt(lit(3)).and(col("b").gt(lit(3)));
     extra.filters = vec![pred];
 
-    let blocks =
-        apply_range_filter(&snapshot, table.get_table_info().schema(), Some(extra), da).await?;
+    let blocks = apply_range_filter(
+        &snapshot,
+        table.get_table_info().schema(),
+        &[0, 1],
+        Some(extra),
+        da,
+    )
+    .await?;
     assert_eq!(num - 1, blocks.len() as u64);
 
     Ok(())
@@ -31,6 +31,8 @@ use futures::TryStreamExt;
 
 use crate::storages::fuse::io::col_encoding::col_encoding;
 use crate::storages::fuse::io::location_gen::gen_block_location;
+use crate::storages::fuse::meta::BlockMeta;
+use crate::storages::fuse::meta::ColumnId;
 use crate::storages::fuse::meta::SegmentInfo;
 use crate::storages::fuse::meta::Stats;
 use crate::storages::fuse::statistics;
@@ -79,8 +81,10 @@ impl BlockAppender {
     // TODO should return a stream of SegmentInfo (batch blocks into segments)
     pub async fn append_blocks(
         data_accessor: Arc<dyn DataAccessor>,
+        location_prefix: &str,
         stream: SendableDataBlockStream,
         data_schema: &DataSchema,
+        column_ids: &[ColumnId],
         chunk_block_num: usize,
         block_size_threshold: usize,
     ) -> Result<Vec<SegmentInfo>> {
@@ -100,9 +104,9 @@ impl BlockAppender {
             let mut block_meta_acc = BlockMetaAccumulator::new();
 
             for block in blocks.into_iter() {
-                stats_acc.acc(&block)?;
+                stats_acc.acc(&block, column_ids)?;
                 let schema = block.schema().to_arrow();
-                let location = gen_block_location();
+                let location = gen_block_location(location_prefix);
                 let file_size = Self::save_block(&schema, block, &data_accessor, &location).await?;
                 block_meta_acc.acc(file_size, location, &mut stats_acc);
             }
@@ -110,7 +114,8 @@ impl BlockAppender {
             // summary and give back a segment_info
             // we need to send back a stream of segment latter
             let block_metas = block_meta_acc.blocks_metas;
-            let summary = statistics::reduce_block_stats(&stats_acc.blocks_stats, data_schema)?;
+            let summary =
+                statistics::reduce_block_stats(&stats_acc.blocks_stats, data_schema, column_ids)?;
             let seg = SegmentInfo {
                 blocks: block_metas,
                 summary: Stats {
@@ -126,6 +131,25 @@ impl BlockAppender {
         Ok(segments)
     }
 
+    // Saves a single block, gives back the meta of it
+    pub async fn append_block(
+        data_accessor: Arc<dyn DataAccessor>,
+        location_prefix: &str,
+        block: DataBlock,
+        column_ids: &[ColumnId],
+    ) -> Result<BlockMeta> {
+        let mut stats_acc = StatisticsAccumulator::new();
+        let mut block_meta_acc = BlockMetaAccumulator::new();
+
+        stats_acc.acc(&block, column_ids)?;
+        let schema = block.schema().to_arrow();
+        let location = gen_block_location(location_prefix);
+        let file_size = Self::save_block(&schema, block, &data_accessor, &location).await?;
+        block_meta_acc.acc(file_size, location, &mut stats_acc);
+
+        Ok(block_meta_acc.blocks_metas.remove(0))
+    }
+
     pub(super) async fn save_block(
         arrow_schema: &ArrowSchema,
         block: DataBlock,
@@ -24,7 +24,9 @@ use common_datavalues::DataSchemaRefExt;
 use common_datavalues::DataType;
 use tempfile::TempDir;
 
+use crate::storages::fuse::io::block_file_size;
 use crate::storages::fuse::io::BlockAppender;
+use crate::storages::fuse::meta::BlockMeta;
 use crate::storages::fuse::DEFAULT_CHUNK_BLOCK_NUM;
 
 #[tokio::test]
@@ -39,8 +41,10 @@ async fn test_fuse_table_block_appender() {
     let block_stream = futures::stream::iter(vec![Ok(block)]);
     let r = BlockAppender::append_blocks(
         local_fs.clone(),
+        "",
         Box::pin(block_stream),
-        schema.as_ref(),
+        &schema,
+        &[0],
         DEFAULT_CHUNK_BLOCK_NUM,
         0,
     )
@@ -57,8 +61,10 @@ async fn test_fuse_table_block_appender() {
     let block_stream = futures::stream::iter(blocks);
     let r = BlockAppender::append_blocks(
         local_fs.clone(),
+        "",
         Box::pin(block_stream),
-        schema.as_ref(),
+        &schema,
+        &[0],
         chunk_size,
         0,
     )
@@ -71,8 +77,10 @@ async fn test_fuse_table_block_appender() {
     let block_stream = futures::stream::iter(vec![]);
     let r = BlockAppender::append_blocks(
         local_fs,
+        "",
         Box::pin(block_stream),
-        schema.as_ref(),
+        &schema,
+        &[0],
         DEFAULT_CHUNK_BLOCK_NUM,
         0,
     )
@@ -81,6 +89,26 @@ async fn test_fuse_table_block_appender() {
     assert!(r.unwrap().is_empty())
 }
 
+#[tokio::test]
+async fn test_fuse_table_block_file_size_of_legacy_block() -> common_exception::Result<()> {
+    let tmp_dir = TempDir::new().unwrap();
+    let local_fs = Arc::new(common_dal::Local::with_path(tmp_dir.path().to_owned()));
+    let schema = DataSchemaRefExt::create(vec![DataField::new("a", DataType::Int32, false)]);
+    let block = DataBlock::create_by_array(schema, vec![Series::new(vec![1, 2, 3])]);
+    let block_meta = BlockAppender::append_block(local_fs.clone(), "", block, &[0]).await?;
+    assert!(block_meta.file_size > 0);
+
+    // the block meta written before `file_size` was introduced
+    let mut legacy = serde_json::to_value(&block_meta)?;
+    legacy.as_object_mut().unwrap().remove("file_size");
+    let legacy_block_meta: BlockMeta = serde_json::from_value(legacy)?;
+    assert_eq!(legacy_block_meta.file_size, 0);
+
+    let file_size = block_file_size(local_fs.as_ref(), &legacy_block_meta).await?;
+    assert_eq!(file_size, block_meta.file_size);
+    Ok(())
+}
+
 #[test]
 fn test_fuse_table_block_appender_reshape() -> common_exception::Result<()> {
     let schema = DataSchemaRefExt::create(vec![DataField::new("a", DataType::Int32, false)]);
@@ -0,0 +1,185 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+use std::sync::Arc;
+
+use common_dal::DataAccessor;
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_planners::Part;
+use common_streams::ParquetSource;
+use common_streams::Source;
+
+use crate::storages::fuse::meta::BlockMeta;
+use crate::storages::fuse::meta::ColumnId;
+
+/// What a partition of a fuse table is about, which is encoded into the name of the `Part`
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct BlockPartInfo {
+    pub location: String,
+    pub row_count: u64,
+    /// The ids of the columns stored in the block
+    pub col_ids: Vec<ColumnId>,
+}
+
+impl BlockPartInfo {
+    pub fn from_block_meta(block_meta: &BlockMeta) -> Self {
+        BlockPartInfo {
+            location: block_meta.location.location.clone(),
+            row_count: block_meta.row_count,
+            col_ids: block_meta.col_ids(),
+        }
+    }
+
+    pub fn encode(&self) -> Result<Part> {
+        Ok(Part {
+            name: serde_json::to_string(self)?,
+            version: 0,
+        })
+    }
+
+    pub fn decode(part: &Part) -> Result<Self> {
+        serde_json::from_str(&part.name).map_err(|e| {
+            ErrorCode::LogicalError(format!("invalid fuse partition {}, {}", part.name, e))
+        })
+    }
+}
+
+/// The size of the data file of the block. The blocks written before `BlockMeta::file_size` was
+/// introduced have it as 0, their data files are looked up instead.
+pub async fn block_file_size(da: &dyn DataAccessor, block_meta: &BlockMeta) -> Result<u64> {
+    match block_meta.file_size {
+        0 => da.object_size(&block_meta.location.location).await,
+        file_size => Ok(file_size),
+    }
+}
+
+/// Reads the projected columns of the table schema out of the blocks.
+///
+/// Columns are located in the blocks by their column ids, the columns which are not stored in a
+/// block (i.e. added to the table after the block is written) are filled with NULLs.
+pub struct BlockReader {
+    data_accessor: Arc<dyn DataAccessor>,
+    table_schema: DataSchemaRef,
+    column_ids: Vec<ColumnId>,
+    projection: Vec<usize>,
+    projected_schema: DataSchemaRef,
+}
+
+impl BlockReader {
+    pub fn new(
+        data_accessor: Arc<dyn DataAccessor>,
+        table_schema: DataSchemaRef,
+        column_ids: Vec<ColumnId>,
+        projection: Vec<usize>,
+    ) -> Self {
+        let projected_schema = Arc::new(table_schema.project(projection.clone()));
+        BlockReader {
+            data_accessor,
+            table_schema,
+            column_ids,
+            projection,
+            projected_schema,
+        }
+    }
+
+    pub fn schema(&self) -> DataSchemaRef {
+        self.projected_schema.clone()
+    }
+
+    pub async fn read(&self, part_info: &BlockPartInfo) -> Result<Vec<DataBlock>> {
+        // position of each projected column in the block, if the block has it
+        let block_projection = self
+            .projection
+            .iter()
+            .map(|idx| {
+                let column_id = self.column_ids[*idx];
+                part_info.col_ids.iter().position(|id| *id == column_id)
+            })
+            .collect::<Vec<_>>();
+        let read_projection = block_projection
+            .iter()
+            .flatten()
+            .cloned()
+            .collect::<Vec<_>>();
+
+        if read_projection.is_empty() {
+            let rows = part_info.row_count as usize;
+            let columns = self.null_columns(rows);
+            return Ok(vec![DataBlock::create(
+                self.projected_schema.clone(),
+                columns,
+            )]);
+        }
+
+        let mut source = ParquetSource::new(
+            self.data_accessor.clone(),
+            part_info.location.clone(),
+            self.block_schema(&part_info.col_ids),
+            read_projection,
+        );
+
+        let mut blocks = vec![];
+        while let Some(block) = source.read().await? {
+            let rows = block.num_rows();
+            let mut read_columns = block.columns().iter();
+            let columns = block_projection
+                .iter()
+                .zip(self.projected_schema.fields())
+                .map(|(position, field)| match position {
+                    Some(_) => read_columns.next().cloned().ok_or_else(|| {
+                        ErrorCode::LogicalError(format!(
+                            "column {} is not read from block {}",
+                            field.name(),
+                            part_info.location
+                        ))
+                    }),
+                    None => Ok(DataColumn::Constant(
+                        DataValue::from(field.data_type()),
+                        rows,
+                    )),
+                })
+                .collect::<Result<Vec<_>>>()?;
+            blocks.push(DataBlock::create(self.projected_schema.clone(), columns));
+        }
+        Ok(blocks)
+    }
+
+    // The schema of the data file of a block, with the columns of the table schema
+    fn block_schema(&self, block_col_ids: &[ColumnId]) -> DataSchemaRef {
+        let fields = block_col_ids
+            .iter()
+            .map(|block_col_id| {
+                match self.column_ids.iter().position(|id| id == block_col_id) {
+                    Some(idx) => self.table_schema.field(idx).clone(),
+                    // the column has been dropped, and will never be read
+                    None => {
+                        DataField::new(&format!("_dropped_{}", block_col_id), DataType::Null, true)
+                    }
+                }
+            })
+            .collect::<Vec<_>>();
+        DataSchemaRefExt::create(fields)
+    }
+
+    fn null_columns(&self, rows: usize) -> Vec<DataColumn> {
+        self.projected_schema
+            .fields()
+            .iter()
+            .map(|field| DataColumn::Constant(DataValue::from(field.data_type()), rows))
+            .collect()
+    }
+}
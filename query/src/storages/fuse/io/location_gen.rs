@@ -19,16 +19,18 @@ use crate::storages::fuse::constants::FUSE_TBL_BLOCK_PREFIX;
 use crate::storages::fuse::constants::FUSE_TBL_SEGMENT_PREFIX;
 use crate::storages::fuse::constants::FUSE_TBL_SNAPSHOT_PREFIX;
 
-pub fn gen_block_location() -> String {
+// The `prefix` is the location prefix of the table, see `FuseTable::location_prefix`
+
+pub fn gen_block_location(prefix: &str) -> String {
     let part_uuid = Uuid::new_v4().to_simple().to_string() + ".parquet";
-    format!("{}/{}", FUSE_TBL_BLOCK_PREFIX, part_uuid)
+    format!("{}{}/{}", prefix, FUSE_TBL_BLOCK_PREFIX, part_uuid)
 }
 
-pub fn gen_segment_info_location() -> String {
+pub fn gen_segment_info_location(prefix: &str) -> String {
     let segment_uuid = Uuid::new_v4().to_simple().to_string();
-    format!("{}/{}", FUSE_TBL_SEGMENT_PREFIX, segment_uuid)
+    format!("{}{}/{}", prefix, FUSE_TBL_SEGMENT_PREFIX, segment_uuid)
 }
 
-pub fn snapshot_location(name: impl AsRef<str>) -> String {
-    format!("{}/{}", FUSE_TBL_SNAPSHOT_PREFIX, name.as_ref())
+pub fn snapshot_location(prefix: &str, name: impl AsRef<str>) -> String {
+    format!("{}{}/{}", prefix, FUSE_TBL_SNAPSHOT_PREFIX, name.as_ref())
 }
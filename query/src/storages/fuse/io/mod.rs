@@ -17,9 +17,13 @@
 mod block_appender_test;
 
 mod block_appender;
+mod block_reader;
 mod col_encoding;
 mod location_gen;
 
 pub use block_appender::BlockAppender;
+pub use block_reader::block_file_size;
+pub use block_reader::BlockPartInfo;
+pub use block_reader::BlockReader;
 pub use location_gen::gen_segment_info_location;
 pub use location_gen::snapshot_location;
@@ -25,10 +25,22 @@ pub struct BlockMeta {
     /// Pointer of the data Block
     pub row_count: u64,
     pub block_size: u64,
+    /// Size of the data file
+    #[serde(default)]
+    pub file_size: u64,
     pub col_stats: HashMap<ColumnId, ColumnStatistics>,
     pub location: BlockLocation,
 }
 
+impl BlockMeta {
+    /// The ids of the columns stored in the block, in the order of the columns of the data file
+    pub fn col_ids(&self) -> Vec<ColumnId> {
+        let mut col_ids = self.col_stats.keys().cloned().collect::<Vec<_>>();
+        col_ids.sort_unstable();
+        col_ids
+    }
+}
+
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
 pub struct BlockLocation {
     pub location: Location,
@@ -17,7 +17,7 @@ use crate::storages::fuse::meta::BlockMeta;
 use crate::storages::fuse::meta::Stats;
 
 /// A segment comprised of one or more blocks
-#[derive(serde::Serialize, serde::Deserialize, Debug)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
 pub struct SegmentInfo {
     // TODO format_version
     // pub format_version: u32,
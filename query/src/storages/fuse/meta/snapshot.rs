@@ -14,6 +14,8 @@
 
 use std::collections::HashMap;
 
+use chrono::DateTime;
+use chrono::Utc;
 use common_base::uuid;
 use common_datavalues::DataSchema;
 use serde::Deserialize;
@@ -35,6 +37,12 @@ pub struct TableSnapshot {
 
     pub prev_snapshot_id: Option<SnapshotId>,
 
+    /// The time when the snapshot is taken, used to locate the snapshot in time travel
+    ///
+    /// It is None for the snapshots that taken before the timestamp is recorded.
+    #[serde(default)]
+    pub timestamp: Option<DateTime<Utc>>,
+
     /// For each snapshot, we keep a schema for it (in case of schema evolution)
     pub schema: DataSchema,
 
@@ -18,6 +18,7 @@ mod table_test;
 #[cfg(test)]
 mod table_test_fixture;
 
+mod compaction_scheduler;
 mod constants;
 mod index;
 mod io;
@@ -26,5 +27,6 @@ mod operations;
 mod statistics;
 mod table;
 
+pub use compaction_scheduler::CompactionScheduler;
 pub use constants::*;
 pub use table::FuseTable;
@@ -0,0 +1,169 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+//
+
+use std::sync::Arc;
+
+use chrono::Utc;
+use common_datavalues::DataField;
+use common_datavalues::DataSchemaRefExt;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_types::TableMeta;
+use common_meta_types::UpdateTableMetaReq;
+use common_planners::AlterTableAction;
+use common_planners::AlterTablePlan;
+use uuid::Uuid;
+
+use crate::catalogs::Catalog;
+use crate::sessions::QueryContext;
+use crate::storages::fuse::io;
+use crate::storages::fuse::meta::ColumnId;
+use crate::storages::fuse::operations::commit::remove_uncommitted_snapshot;
+use crate::storages::fuse::FuseTable;
+use crate::storages::fuse::TBL_OPT_KEY_COLUMN_IDS;
+use crate::storages::fuse::TBL_OPT_KEY_NEXT_COLUMN_ID;
+use crate::storages::fuse::TBL_OPT_KEY_SNAPSHOT_LOC;
+
+impl FuseTable {
+    /// Alters the schema of the table without touching the blocks.
+    ///
+    /// Columns are addressed by ids which never change: a newly added column gets a new id,
+    /// and the blocks written before it are read with NULLs; the id of a dropped column is never
+    /// reused, the data of it left in the blocks are just ignored.
+    #[inline]
+    pub async fn do_alter_table(
+        &self,
+        ctx: Arc<QueryContext>,
+        alter_plan: AlterTablePlan,
+    ) -> Result<()> {
+        self.commit_with_retry(ctx.as_ref(), |table| {
+            let ctx = ctx.clone();
+            let alter_plan = alter_plan.clone();
+            async move { table.try_alter_table(ctx, alter_plan).await }
+        })
+        .await
+    }
+
+    async fn try_alter_table(
+        &self,
+        ctx: Arc<QueryContext>,
+        alter_plan: AlterTablePlan,
+    ) -> Result<()> {
+        let mut fields = self.table_info.schema().fields().clone();
+        let mut column_ids = self.column_ids()?;
+        let mut next_column_id = self.next_column_id()?;
+
+        match alter_plan.action {
+            AlterTableAction::AddColumn(field) => {
+                self.check_column_not_exists(&fields, field.name())?;
+                fields.push(field);
+                column_ids.push(next_column_id);
+                next_column_id += 1;
+            }
+            AlterTableAction::DropColumn(name) => {
+                let idx = self.column_index(&fields, &name)?;
+                if fields.len() == 1 {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "Can not drop the only column {} of table {}",
+                        name, self.table_info.desc
+                    )));
+                }
+                fields.remove(idx);
+                column_ids.remove(idx);
+            }
+            AlterTableAction::RenameColumn { old_name, new_name } => {
+                let idx = self.column_index(&fields, &old_name)?;
+                self.check_column_not_exists(&fields, &new_name)?;
+                let field = &fields[idx];
+                fields[idx] =
+                    DataField::new(&new_name, field.data_type().clone(), field.is_nullable());
+            }
+        }
+
+        let schema = DataSchemaRefExt::create(fields);
+        let mut options = self.table_info.options().clone();
+        options.insert(
+            TBL_OPT_KEY_COLUMN_IDS.to_owned(),
+            Self::join_column_ids(&column_ids),
+        );
+        options.insert(
+            TBL_OPT_KEY_NEXT_COLUMN_ID.to_owned(),
+            next_column_id.to_string(),
+        );
+
+        // the snapshot keeps the schema of the table, a new snapshot (of the same data) is taken
+        // to record the altered one
+        let da = ctx.get_data_accessor()?;
+        let mut new_snapshot_loc = None;
+        if let Some(prev_snapshot) = self.table_snapshot(ctx.clone()).await? {
+            let prev_id = prev_snapshot.snapshot_id;
+            let mut new_snapshot = prev_snapshot;
+            new_snapshot.prev_snapshot_id = Some(prev_id);
+            new_snapshot.snapshot_id = Uuid::new_v4();
+            new_snapshot.timestamp = Some(Utc::now());
+            new_snapshot.schema = schema.as_ref().clone();
+            let location = io::snapshot_location(
+                &self.location_prefix(),
+                new_snapshot.snapshot_id.to_simple().to_string().as_str(),
+            );
+            let bytes = serde_json::to_vec(&new_snapshot)?;
+            da.put(&location, bytes).await?;
+            options.insert(TBL_OPT_KEY_SNAPSHOT_LOC.to_owned(), location.clone());
+            new_snapshot_loc = Some(location);
+        }
+
+        let table_meta = TableMeta {
+            schema,
+            engine: self.table_info.engine().to_owned(),
+            options,
+        };
+        let catalog = ctx.get_catalog();
+        let r = catalog
+            .update_table_meta(UpdateTableMetaReq::new(&self.table_info.ident, table_meta))
+            .await;
+        if let (Err(e), Some(location)) = (&r, &new_snapshot_loc) {
+            remove_uncommitted_snapshot(da.as_ref(), location, e).await;
+        }
+        r?;
+        Ok(())
+    }
+
+    fn column_index(&self, fields: &[DataField], name: &str) -> Result<usize> {
+        fields.iter().position(|f| f.name() == name).ok_or_else(|| {
+            ErrorCode::UnknownColumn(format!(
+                "Unknown column {} in table {}",
+                name, self.table_info.desc
+            ))
+        })
+    }
+
+    fn check_column_not_exists(&self, fields: &[DataField], name: &str) -> Result<()> {
+        if fields.iter().any(|f| f.name() == name) {
+            return Err(ErrorCode::BadArguments(format!(
+                "Column {} already exists in table {}",
+                name, self.table_info.desc
+            )));
+        }
+        Ok(())
+    }
+
+    fn join_column_ids(column_ids: &[ColumnId]) -> String {
+        column_ids
+            .iter()
+            .map(|id| id.to_string())
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+}
@@ -43,10 +43,13 @@ impl FuseTable {
         );
 
         let da = ctx.get_data_accessor()?;
+        let location_prefix = self.location_prefix();
         let segments = BlockAppender::append_blocks(
             da.clone(),
+            &location_prefix,
             stream,
             self.table_info.schema().as_ref(),
+            &self.column_ids()?,
             chunk_block_num,
             block_size_threshold,
         )
@@ -54,7 +57,7 @@ impl FuseTable {
 
         let mut result = Vec::with_capacity(segments.len());
         for seg in segments {
-            let seg_loc = io::gen_segment_info_location();
+            let seg_loc = io::gen_segment_info_location(&location_prefix);
             let bytes = serde_json::to_vec(&seg)?;
             da.put(&seg_loc, bytes).await?;
             result.push(AppendOperationLogEntry::new(seg_loc, seg))
@@ -62,7 +65,7 @@ impl FuseTable {
         Ok(result)
     }
 
-    fn get_option<T: FromStr>(&self, opt_key: &str, default: T) -> T {
+    pub(crate) fn get_option<T: FromStr>(&self, opt_key: &str, default: T) -> T {
         self.table_info
             .options()
             .get(opt_key)
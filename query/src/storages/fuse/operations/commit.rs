@@ -13,11 +13,18 @@
 //  limitations under the License.
 //
 
+use std::future::Future;
 use std::sync::Arc;
+use std::time::Duration;
 
+use chrono::Utc;
+use common_base::tokio;
+use common_dal::DataAccessor;
 use common_datavalues::DataSchema;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_meta_types::TableIdent;
+use common_meta_types::TableInfo;
 use common_meta_types::UpsertTableOptionReply;
 use common_meta_types::UpsertTableOptionReq;
 use uuid::Uuid;
@@ -25,12 +32,17 @@ use uuid::Uuid;
 use crate::catalogs::Catalog;
 use crate::sessions::QueryContext;
 use crate::storages::fuse::io;
+use crate::storages::fuse::meta::ColumnId;
 use crate::storages::fuse::meta::TableSnapshot;
 use crate::storages::fuse::operations::TableOperationLog;
 use crate::storages::fuse::statistics;
 use crate::storages::fuse::FuseTable;
 use crate::storages::fuse::TBL_OPT_KEY_SNAPSHOT_LOC;
 
+const OCC_DEFAULT_BACKOFF_INIT_DELAY: Duration = Duration::from_millis(5);
+const OCC_DEFAULT_BACKOFF_MAX_DELAY: Duration = Duration::from_millis(800);
+const OCC_DEFAULT_MAX_RETRIES: u32 = 10;
+
 impl FuseTable {
     #[inline]
     pub async fn do_commit(
@@ -39,44 +51,145 @@ impl FuseTable {
         operation_log: TableOperationLog,
         overwrite: bool,
     ) -> Result<()> {
-        // TODO OCC retry & resolves conflicts if applicable
+        // the operation log (appends only) is re-merged with the latest snapshot on retries
+        self.commit_with_retry(ctx.as_ref(), |table| {
+            let ctx = ctx.clone();
+            let operation_log = operation_log.clone();
+            async move { table.try_commit(ctx, operation_log, overwrite).await }
+        })
+        .await
+    }
+
+    /// Optimistic concurrency control: a commit is guarded by the version of the table meta
+    /// the operation has been run with. If some other commit sneaks in, the latest table meta
+    /// is reloaded, and the operation is run again against it.
+    pub(crate) async fn commit_with_retry<F, Fut, T>(
+        &self,
+        ctx: &QueryContext,
+        operation: F,
+    ) -> Result<T>
+    where
+        F: Fn(FuseTable) -> Fut + Send + Sync,
+        Fut: Future<Output = Result<T>> + Send,
+        T: Send,
+    {
+        let mut latest: Option<FuseTable> = None;
+        let mut retry_times = 0;
+        let mut backoff = OCC_DEFAULT_BACKOFF_INIT_DELAY;
+        loop {
+            let table = latest.as_ref().unwrap_or(self);
+            let r = operation(FuseTable {
+                table_info: table.table_info.clone(),
+            })
+            .await;
+            match r {
+                Err(e) if is_version_miss_match(&e) => {
+                    if retry_times >= OCC_DEFAULT_MAX_RETRIES {
+                        return Err(ErrorCode::TableCommitConflict(format!(
+                            "commit to table {} aborted after {} retries: {}",
+                            self.table_info.desc,
+                            retry_times,
+                            e.message()
+                        )));
+                    }
+                    tokio::time::sleep(backoff).await;
+                    backoff = std::cmp::min(backoff * 2, OCC_DEFAULT_BACKOFF_MAX_DELAY);
+                    retry_times += 1;
+
+                    let refreshed = table.refresh(ctx).await?;
+                    // the operation is planned with the schema of this table, it can not be
+                    // run again if the schema has been changed meanwhile.
+                    if refreshed.table_info.meta.schema != self.table_info.meta.schema {
+                        return Err(ErrorCode::TableCommitConflict(format!(
+                            "commit to table {} aborted, schema changed by a concurrent transaction",
+                            self.table_info.desc
+                        )));
+                    }
+                    latest = Some(refreshed);
+                }
+                r => return r,
+            }
+        }
+    }
 
+    async fn try_commit(
+        &self,
+        ctx: Arc<QueryContext>,
+        operation_log: TableOperationLog,
+        overwrite: bool,
+    ) -> Result<()> {
         let prev = self.table_snapshot(ctx.clone()).await?;
         let new_snapshot = if overwrite {
             let schema = self.table_info.meta.schema.as_ref().clone();
-            let (segments, summary) = statistics::merge_append_operations(&schema, operation_log)?;
+            let (segments, summary) =
+                statistics::merge_append_operations(&schema, &self.column_ids()?, operation_log)?;
             TableSnapshot {
                 snapshot_id: Uuid::new_v4(),
                 prev_snapshot_id: prev.as_ref().map(|v| v.snapshot_id),
+                timestamp: Some(Utc::now()),
                 schema,
                 summary,
                 segments,
             }
         } else {
-            Self::merge_table_operations(self.table_info.meta.schema.as_ref(), prev, operation_log)?
+            Self::merge_table_operations(
+                self.table_info.meta.schema.as_ref(),
+                &self.column_ids()?,
+                prev,
+                operation_log,
+            )?
+        };
+
+        self.commit_snapshot(ctx, new_snapshot).await
+    }
+
+    // Reloads the table meta from the meta server.
+    async fn refresh(&self, ctx: &QueryContext) -> Result<FuseTable> {
+        let catalog = ctx.get_catalog();
+        let (ident, meta) = catalog
+            .get_table_meta_by_id(self.table_info.ident.table_id)
+            .await?;
+        let table_info = TableInfo {
+            ident,
+            meta: meta.as_ref().clone(),
+            ..self.table_info.clone()
         };
+        Ok(FuseTable { table_info })
+    }
 
+    pub(crate) async fn commit_snapshot(
+        &self,
+        ctx: Arc<QueryContext>,
+        new_snapshot: TableSnapshot,
+    ) -> Result<()> {
         let uuid = new_snapshot.snapshot_id;
-        let snapshot_loc = io::snapshot_location(uuid.to_simple().to_string().as_str());
+        let snapshot_loc = io::snapshot_location(
+            &self.location_prefix(),
+            uuid.to_simple().to_string().as_str(),
+        );
         let bytes = serde_json::to_vec(&new_snapshot)?;
         let da = ctx.get_data_accessor()?;
         da.put(&snapshot_loc, bytes).await?;
 
-        self.commit_to_meta_server(ctx, snapshot_loc).await?;
+        if let Err(e) = self.commit_to_meta_server(ctx, snapshot_loc.clone()).await {
+            remove_uncommitted_snapshot(da.as_ref(), &snapshot_loc, &e).await;
+            return Err(e);
+        }
         Ok(())
     }
 
     fn merge_table_operations(
         schema: &DataSchema,
+        column_ids: &[ColumnId],
         prev: Option<TableSnapshot>,
         ops: TableOperationLog,
     ) -> Result<TableSnapshot> {
         // 1. merge operations(appends, currently) of this ops
-        let (mut segs, stats) = statistics::merge_append_operations(schema, ops)?;
+        let (mut segs, stats) = statistics::merge_append_operations(schema, column_ids, ops)?;
 
         // 2. merge stats with previous snapshot, if any
         let stats = if let Some(TableSnapshot { summary, .. }) = &prev {
-            statistics::merge_stats(schema, &stats, summary)?
+            statistics::merge_stats(schema, column_ids, &stats, summary)?
         } else {
             stats
         };
@@ -90,6 +203,7 @@ impl FuseTable {
         let new_snapshot = TableSnapshot {
             snapshot_id: Uuid::new_v4(),
             prev_snapshot_id,
+            timestamp: Some(Utc::now()),
             schema: schema.clone(),
             summary: stats,
             segments: segs,
@@ -117,3 +231,20 @@ impl FuseTable {
             .await
     }
 }
+
+pub(crate) fn is_version_miss_match(e: &ErrorCode) -> bool {
+    e.code() == ErrorCode::TableVersionMissMatch("").code()
+}
+
+/// Cleans up the snapshot of a failed commit, best effort. Only a version mismatch guarantees
+/// the meta server has rejected the commit; after any other error (e.g. a timeout) the snapshot
+/// may have been committed already, it is left alone then.
+pub(crate) async fn remove_uncommitted_snapshot(
+    da: &dyn DataAccessor,
+    snapshot_loc: &str,
+    e: &ErrorCode,
+) {
+    if is_version_miss_match(e) {
+        let _ = da.remove(snapshot_loc).await;
+    }
+}
@@ -0,0 +1,193 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+//
+
+use std::sync::Arc;
+
+use chrono::Utc;
+use common_dal::read_obj;
+use common_datablocks::DataBlock;
+use common_exception::Result;
+use uuid::Uuid;
+
+use crate::sessions::QueryContext;
+use crate::storages::fuse::io;
+use crate::storages::fuse::io::BlockAppender;
+use crate::storages::fuse::meta::BlockMeta;
+use crate::storages::fuse::meta::SegmentInfo;
+use crate::storages::fuse::meta::Stats;
+use crate::storages::fuse::meta::TableSnapshot;
+use crate::storages::fuse::statistics;
+use crate::storages::fuse::FuseTable;
+use crate::storages::fuse::DEFAULT_BLOCK_SIZE_IN_MEM_SIZE_THRESHOLD;
+use crate::storages::fuse::DEFAULT_CHUNK_BLOCK_NUM;
+use crate::storages::fuse::TBL_OPT_KEY_BLOCK_IN_MEM_SIZE_THRESHOLD;
+use crate::storages::fuse::TBL_OPT_KEY_CHUNK_BLOCK_NUM;
+
+/// The numbers of segments and small blocks a table reaches before it is compacted in background
+#[derive(Clone, Copy, Debug)]
+pub struct CompactionThresholds {
+    pub segment_count: usize,
+    pub small_block_count: usize,
+}
+
+impl FuseTable {
+    #[inline]
+    pub async fn do_compact(&self, ctx: Arc<QueryContext>) -> Result<()> {
+        self.compact(ctx, None).await?;
+        Ok(())
+    }
+
+    /// Compacts the table only if it reaches either of the thresholds, returns if it is compacted.
+    pub(crate) async fn compact_if_needed(
+        &self,
+        ctx: Arc<QueryContext>,
+        thresholds: CompactionThresholds,
+    ) -> Result<bool> {
+        self.compact(ctx, Some(thresholds)).await
+    }
+
+    // Merges the blocks that are smaller than the block size threshold into larger ones, while
+    // the others are kept untouched, and collapses the segments so that each of them holds
+    // `chunk_block_num` blocks (except the last one).
+    //
+    // The replaced segments and blocks are kept, as the previous snapshots still refer to them,
+    // until they are purged.
+    async fn compact(
+        &self,
+        ctx: Arc<QueryContext>,
+        thresholds: Option<CompactionThresholds>,
+    ) -> Result<bool> {
+        self.commit_with_retry(ctx.as_ref(), |table| {
+            let ctx = ctx.clone();
+            async move { table.try_compact(ctx, thresholds).await }
+        })
+        .await
+    }
+
+    async fn try_compact(
+        &self,
+        ctx: Arc<QueryContext>,
+        thresholds: Option<CompactionThresholds>,
+    ) -> Result<bool> {
+        let snapshot = match self.table_snapshot(ctx.clone()).await? {
+            Some(snapshot) => snapshot,
+            None => return Ok(false),
+        };
+
+        let chunk_block_num = self
+            .get_option(TBL_OPT_KEY_CHUNK_BLOCK_NUM, DEFAULT_CHUNK_BLOCK_NUM)
+            .max(1);
+        let block_size_threshold = self.get_option(
+            TBL_OPT_KEY_BLOCK_IN_MEM_SIZE_THRESHOLD,
+            DEFAULT_BLOCK_SIZE_IN_MEM_SIZE_THRESHOLD,
+        );
+
+        let da = ctx.get_data_accessor()?;
+        let mut block_metas = vec![];
+        let mut small_block_metas = vec![];
+        for segment_location in &snapshot.segments {
+            let segment: SegmentInfo = read_obj(da.clone(), segment_location.clone()).await?;
+            for block_meta in segment.blocks {
+                if (block_meta.block_size as usize) < block_size_threshold {
+                    small_block_metas.push(block_meta);
+                } else {
+                    block_metas.push(block_meta);
+                }
+            }
+        }
+
+        if let Some(thresholds) = thresholds {
+            if snapshot.segments.len() < thresholds.segment_count
+                && small_block_metas.len() < thresholds.small_block_count
+            {
+                return Ok(false);
+            }
+        }
+
+        let block_count = block_metas.len() + small_block_metas.len().min(1);
+        let segment_count = (block_count + chunk_block_num - 1) / chunk_block_num;
+        if small_block_metas.len() <= 1 && snapshot.segments.len() <= segment_count.max(1) {
+            // nothing to merge
+            return Ok(false);
+        }
+
+        // the kept blocks are summarized again in the collapsed segments
+        for block_meta in block_metas.iter_mut() {
+            block_meta.file_size = io::block_file_size(da.as_ref(), block_meta).await?;
+        }
+
+        // 1. merge the small blocks, at most 2 * block_size_threshold each
+        let reader = self.full_block_reader(da.clone())?;
+        let schema = self.table_info.schema();
+        let column_ids = self.column_ids()?;
+        let location_prefix = self.location_prefix();
+        let mut blocks = vec![];
+        let mut blocks_size = 0;
+        for block_meta in &small_block_metas {
+            let block = Self::read_block(&reader, block_meta).await?;
+            blocks_size += block.memory_size();
+            blocks.push(block);
+            if blocks_size >= block_size_threshold {
+                let merged = DataBlock::concat_blocks(&blocks)?;
+                block_metas.push(
+                    BlockAppender::append_block(da.clone(), &location_prefix, merged, &column_ids)
+                        .await?,
+                );
+                blocks.clear();
+                blocks_size = 0;
+            }
+        }
+        if !blocks.is_empty() {
+            let merged = DataBlock::concat_blocks(&blocks)?;
+            block_metas.push(
+                BlockAppender::append_block(da.clone(), &location_prefix, merged, &column_ids)
+                    .await?,
+            );
+        }
+
+        // 2. collapse the segments
+        let mut segments = vec![];
+        let mut summaries = vec![];
+        for chunk in block_metas.chunks(chunk_block_num) {
+            let chunk: Vec<BlockMeta> = chunk.to_vec();
+            let segment = SegmentInfo {
+                summary: statistics::reduce_block_metas(&chunk, &schema, &column_ids)?,
+                blocks: chunk,
+            };
+            let segment_location = io::gen_segment_info_location(&location_prefix);
+            da.put(&segment_location, serde_json::to_vec(&segment)?)
+                .await?;
+            segments.push(segment_location);
+            summaries.push(segment.summary);
+        }
+
+        // 3. commit the new snapshot
+        let summary = summaries
+            .iter()
+            .try_fold(Stats::default(), |acc, summary| {
+                statistics::merge_stats(&schema, &column_ids, &acc, summary)
+            })?;
+        let new_snapshot = TableSnapshot {
+            snapshot_id: Uuid::new_v4(),
+            prev_snapshot_id: Some(snapshot.snapshot_id),
+            timestamp: Some(Utc::now()),
+            schema: self.table_info.meta.schema.as_ref().clone(),
+            summary,
+            segments,
+        };
+        self.commit_snapshot(ctx, new_snapshot).await?;
+        Ok(true)
+    }
+}
@@ -0,0 +1,74 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+//
+
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_exception::Result;
+use common_planners::DeletePlan;
+use common_planners::TruncateTablePlan;
+
+use crate::sessions::QueryContext;
+use crate::storages::fuse::operations::mutation::BlockMutation;
+use crate::storages::fuse::FuseTable;
+
+impl FuseTable {
+    #[inline]
+    pub async fn do_delete(&self, ctx: Arc<QueryContext>, delete_plan: DeletePlan) -> Result<()> {
+        // the blocks are selected again from the latest snapshot on retries
+        self.commit_with_retry(ctx.as_ref(), |table| {
+            let ctx = ctx.clone();
+            let delete_plan = delete_plan.clone();
+            async move { table.try_delete(ctx, delete_plan).await }
+        })
+        .await
+    }
+
+    async fn try_delete(&self, ctx: Arc<QueryContext>, delete_plan: DeletePlan) -> Result<()> {
+        let selection = match &delete_plan.selection {
+            Some(selection) => selection,
+            None => {
+                let truncate_plan = TruncateTablePlan {
+                    db: delete_plan.db,
+                    table: delete_plan.table,
+                };
+                return self.do_truncate(ctx, truncate_plan).await;
+            }
+        };
+
+        let executor = self.selection_executor(selection)?;
+        self.mutate_blocks(ctx, Some(selection), |block| {
+            // rows are kept unless the selection is evaluated to true
+            let remaining_rows = Self::selection_mask(&executor, &block)?
+                .iter()
+                .enumerate()
+                .filter(|(_, selected)| !**selected)
+                .map(|(row, _)| row as u32)
+                .collect::<Vec<_>>();
+
+            if remaining_rows.len() == block.num_rows() {
+                return Ok(BlockMutation::Untouched);
+            }
+
+            if remaining_rows.is_empty() {
+                return Ok(BlockMutation::Removed);
+            }
+
+            let remaining_block = DataBlock::block_take_by_indices(&block, &[], &remaining_rows)?;
+            Ok(BlockMutation::Replaced(remaining_block))
+        })
+        .await
+    }
+}
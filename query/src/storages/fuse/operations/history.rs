@@ -0,0 +1,48 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+//
+
+use std::sync::Arc;
+
+use common_dal::read_obj;
+use common_exception::Result;
+
+use crate::sessions::QueryContext;
+use crate::storages::fuse::io;
+use crate::storages::fuse::meta::TableSnapshot;
+use crate::storages::fuse::FuseTable;
+
+impl FuseTable {
+    /// Reads the snapshots of the table, from the current one back to the oldest one kept by
+    /// the purge (or the very first one), by following the `prev_snapshot_id` chain.
+    pub(crate) async fn snapshot_history(
+        &self,
+        ctx: Arc<QueryContext>,
+    ) -> Result<Vec<TableSnapshot>> {
+        let mut snapshots = vec![];
+        let mut location = self.snapshot_loc();
+        let prefix = self.location_prefix();
+        let oldest_snapshot_id = self.oldest_snapshot_id()?;
+        let da = ctx.get_data_accessor()?;
+        while let Some(loc) = location {
+            let snapshot: TableSnapshot = read_obj(da.clone(), loc).await?;
+            location = snapshot
+                .prev_snapshot_id
+                .filter(|_| Some(snapshot.snapshot_id) != oldest_snapshot_id)
+                .map(|id| io::snapshot_location(&prefix, id.to_simple().to_string()));
+            snapshots.push(snapshot);
+        }
+        Ok(snapshots)
+    }
+}
@@ -16,12 +16,22 @@
 #[cfg(test)]
 mod read_plan_test;
 
+mod alter;
 mod append;
 mod commit;
+mod compact;
+mod delete;
+mod history;
+mod mutation;
+mod navigate;
 mod operation_log;
+mod optimize;
+mod purge;
 mod read;
 mod read_plan;
 mod truncate;
+mod update;
 
+pub use compact::CompactionThresholds;
 pub use operation_log::AppendOperationLogEntry;
 pub use operation_log::TableOperationLog;
@@ -0,0 +1,231 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+//
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use chrono::Utc;
+use common_dal::read_obj;
+use common_dal::DataAccessor;
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_planners::Expression;
+use common_planners::Extras;
+use uuid::Uuid;
+
+use crate::pipelines::transforms::ExpressionExecutor;
+use crate::sessions::QueryContext;
+use crate::storages::fuse::index::apply_range_filter;
+use crate::storages::fuse::io;
+use crate::storages::fuse::io::BlockAppender;
+use crate::storages::fuse::io::BlockPartInfo;
+use crate::storages::fuse::io::BlockReader;
+use crate::storages::fuse::meta::BlockMeta;
+use crate::storages::fuse::meta::SegmentInfo;
+use crate::storages::fuse::meta::Stats;
+use crate::storages::fuse::meta::TableSnapshot;
+use crate::storages::fuse::statistics;
+use crate::storages::fuse::FuseTable;
+
+pub(crate) enum BlockMutation {
+    // none of the rows are changed
+    Untouched,
+    // all the rows are removed
+    Removed,
+    // the block is replaced by a new block
+    Replaced(DataBlock),
+}
+
+impl FuseTable {
+    /// Rewrites the blocks whose statistics can not rule out the selection (all the blocks
+    /// if there is no selection) with the given mutator, and commits a new snapshot if
+    /// any of them is changed.
+    pub(crate) async fn mutate_blocks<F>(
+        &self,
+        ctx: Arc<QueryContext>,
+        selection: Option<&Expression>,
+        mutator: F,
+    ) -> Result<()>
+    where
+        F: Fn(DataBlock) -> Result<BlockMutation>,
+    {
+        let snapshot = match self.table_snapshot(ctx.clone()).await? {
+            Some(snapshot) => snapshot,
+            None => return Ok(()),
+        };
+
+        // 1. the blocks whose statistics can not rule out the selection
+        let da = ctx.get_data_accessor()?;
+        let schema = self.table_info.schema();
+        let push_downs = selection.map(|selection| Extras {
+            filters: vec![selection.clone()],
+            ..Extras::default()
+        });
+        let column_ids = self.column_ids()?;
+        let location_prefix = self.location_prefix();
+        let candidates = apply_range_filter(
+            &snapshot,
+            schema.clone(),
+            &column_ids,
+            push_downs,
+            da.clone(),
+        )
+        .await?
+        .into_iter()
+        .map(|block_meta| block_meta.location.location)
+        .collect::<HashSet<_>>();
+
+        if candidates.is_empty() {
+            return Ok(());
+        }
+
+        // 2. rewrite the candidate blocks, and the segments they belong to
+        let reader = self.full_block_reader(da.clone())?;
+        let mut segments = Vec::with_capacity(snapshot.segments.len());
+        let mut summaries = Vec::with_capacity(snapshot.segments.len());
+        let mut mutated = false;
+        for segment_location in &snapshot.segments {
+            let segment: SegmentInfo = read_obj(da.clone(), segment_location.clone()).await?;
+
+            let mut segment_changed = false;
+            let mut block_metas = Vec::with_capacity(segment.blocks.len());
+            for block_meta in &segment.blocks {
+                if !candidates.contains(&block_meta.location.location) {
+                    block_metas.push(block_meta.clone());
+                    continue;
+                }
+
+                let block = Self::read_block(&reader, block_meta).await?;
+                match mutator(block)? {
+                    BlockMutation::Untouched => block_metas.push(block_meta.clone()),
+                    BlockMutation::Removed => segment_changed = true,
+                    BlockMutation::Replaced(new_block) => {
+                        segment_changed = true;
+                        let new_block_meta = BlockAppender::append_block(
+                            da.clone(),
+                            &location_prefix,
+                            new_block,
+                            &column_ids,
+                        )
+                        .await?;
+                        block_metas.push(new_block_meta);
+                    }
+                }
+            }
+
+            if !segment_changed {
+                segments.push(segment_location.clone());
+                summaries.push(segment.summary);
+                continue;
+            }
+
+            mutated = true;
+            if block_metas.is_empty() {
+                continue;
+            }
+
+            // the kept blocks are summarized again
+            for block_meta in block_metas.iter_mut() {
+                block_meta.file_size = io::block_file_size(da.as_ref(), block_meta).await?;
+            }
+            let new_segment = SegmentInfo {
+                summary: statistics::reduce_block_metas(&block_metas, &schema, &column_ids)?,
+                blocks: block_metas,
+            };
+            let new_segment_location = io::gen_segment_info_location(&location_prefix);
+            da.put(&new_segment_location, serde_json::to_vec(&new_segment)?)
+                .await?;
+            segments.push(new_segment_location);
+            summaries.push(new_segment.summary);
+        }
+
+        if !mutated {
+            return Ok(());
+        }
+
+        // 3. commit the new snapshot
+        let summary = summaries
+            .iter()
+            .try_fold(Stats::default(), |acc, summary| {
+                statistics::merge_stats(&schema, &column_ids, &acc, summary)
+            })?;
+
+        let new_snapshot = TableSnapshot {
+            snapshot_id: Uuid::new_v4(),
+            prev_snapshot_id: Some(snapshot.snapshot_id),
+            timestamp: Some(Utc::now()),
+            schema: self.table_info.meta.schema.as_ref().clone(),
+            summary,
+            segments,
+        };
+        self.commit_snapshot(ctx, new_snapshot).await
+    }
+
+    /// Creates an executor which evaluates the selection over the blocks of this table.
+    pub(crate) fn selection_executor(&self, selection: &Expression) -> Result<ExpressionExecutor> {
+        let schema = self.table_info.schema();
+        let selection_field = selection.to_data_field(&schema)?;
+        let executor = ExpressionExecutor::try_create(
+            "selection executor",
+            schema,
+            DataSchemaRefExt::create(vec![selection_field]),
+            vec![selection.clone()],
+            false,
+        )?;
+        executor.validate()?;
+        Ok(executor)
+    }
+
+    /// Evaluates the selection over the block, a row is selected only if the selection is
+    /// evaluated to true on it.
+    pub(crate) fn selection_mask(
+        executor: &ExpressionExecutor,
+        block: &DataBlock,
+    ) -> Result<Vec<bool>> {
+        let selected = executor.execute(block)?.column(0).to_array()?;
+        Ok(selected
+            .cast_with_type(&DataType::Boolean)?
+            .bool()?
+            .collect_values()
+            .iter()
+            .map(|selected| *selected == Some(true))
+            .collect())
+    }
+
+    pub(crate) fn full_block_reader(&self, da: Arc<dyn DataAccessor>) -> Result<BlockReader> {
+        let arrow_schema = self.table_info.schema().to_arrow();
+        let table_schema = Arc::new(DataSchema::from(arrow_schema));
+        let projection = (0..table_schema.fields().len()).collect::<Vec<_>>();
+        Ok(BlockReader::new(
+            da,
+            table_schema,
+            self.column_ids()?,
+            projection,
+        ))
+    }
+
+    pub(crate) async fn read_block(
+        reader: &BlockReader,
+        block_meta: &BlockMeta,
+    ) -> Result<DataBlock> {
+        let part_info = BlockPartInfo::from_block_meta(block_meta);
+        let blocks = reader.read(&part_info).await?;
+        match blocks.is_empty() {
+            true => Ok(DataBlock::empty_with_schema(reader.schema())),
+            false => DataBlock::concat_blocks(&blocks),
+        }
+    }
+}
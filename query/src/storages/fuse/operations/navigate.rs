@@ -0,0 +1,89 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+//
+
+use std::sync::Arc;
+
+use common_dal::read_obj;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use uuid::Uuid;
+
+use crate::sessions::QueryContext;
+use crate::storages::fuse::io;
+use crate::storages::fuse::meta::TableSnapshot;
+use crate::storages::fuse::FuseTable;
+use crate::storages::fuse::TBL_OPT_KEY_SNAPSHOT_LOC;
+use crate::storages::NavigationPoint;
+
+impl FuseTable {
+    /// Walks back through the snapshots of the table, from the current one, to the snapshot
+    /// of the given point:
+    /// - for a snapshot id, the snapshot of that id
+    /// - for a time point, the latest snapshot taken at or before it
+    ///
+    /// The returned table reads the data of that snapshot, with the current schema.
+    pub async fn do_navigate(
+        &self,
+        ctx: Arc<QueryContext>,
+        point: &NavigationPoint,
+    ) -> Result<Arc<FuseTable>> {
+        let is_target: Box<dyn Fn(&TableSnapshot) -> bool + Send + Sync> = match point {
+            NavigationPoint::SnapshotID(id) => {
+                let snapshot_id = Uuid::parse_str(id).map_err(|e| {
+                    ErrorCode::BadArguments(format!("invalid snapshot id {}, {}", id, e))
+                })?;
+                Box::new(move |snapshot: &TableSnapshot| snapshot.snapshot_id == snapshot_id)
+            }
+            NavigationPoint::TimePoint(time_point) => {
+                let time_point = *time_point;
+                Box::new(
+                    move |snapshot: &TableSnapshot| matches!(snapshot.timestamp, Some(ts) if ts <= time_point),
+                )
+            }
+        };
+
+        let not_found = || {
+            ErrorCode::TableHistoricalDataNotFound(format!(
+                "No historical data of table {} found at {:?}",
+                self.table_info.desc, point
+            ))
+        };
+
+        let mut location = self.snapshot_loc().ok_or_else(not_found)?;
+        let prefix = self.location_prefix();
+        let oldest_snapshot_id = self.oldest_snapshot_id()?;
+        let da = ctx.get_data_accessor()?;
+        loop {
+            let snapshot: TableSnapshot = read_obj(da.clone(), location.clone()).await?;
+            if is_target(&snapshot) {
+                break;
+            }
+
+            // the snapshots before the oldest one have been purged
+            if Some(snapshot.snapshot_id) == oldest_snapshot_id {
+                return Err(not_found());
+            }
+            let prev_snapshot_id = snapshot.prev_snapshot_id.ok_or_else(not_found)?;
+            location = io::snapshot_location(&prefix, prev_snapshot_id.to_simple().to_string());
+        }
+
+        let mut table_info = self.table_info.clone();
+        table_info
+            .meta
+            .options
+            .insert(TBL_OPT_KEY_SNAPSHOT_LOC.to_owned(), location);
+        Ok(Arc::new(FuseTable { table_info }))
+    }
+}
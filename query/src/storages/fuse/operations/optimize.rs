@@ -0,0 +1,37 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+//
+
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_planners::OptimizeTableAction;
+use common_planners::OptimizeTablePlan;
+
+use crate::sessions::QueryContext;
+use crate::storages::fuse::FuseTable;
+
+impl FuseTable {
+    #[inline]
+    pub async fn do_optimize(
+        &self,
+        ctx: Arc<QueryContext>,
+        optimize_plan: OptimizeTablePlan,
+    ) -> Result<()> {
+        match &optimize_plan.action {
+            OptimizeTableAction::Compact => self.do_compact(ctx).await,
+            OptimizeTableAction::Purge(retention) => self.do_purge(ctx, retention).await,
+        }
+    }
+}
@@ -0,0 +1,195 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+//
+
+use std::collections::HashSet;
+use std::convert::TryFrom;
+use std::sync::Arc;
+
+use chrono::DateTime;
+use chrono::Duration;
+use chrono::Utc;
+use common_dal::read_obj;
+use common_exception::Result;
+use common_meta_types::UpsertTableOptionReq;
+use common_planners::PurgeRetention;
+use uuid::Uuid;
+
+use crate::sessions::QueryContext;
+use crate::storages::fuse::io;
+use crate::storages::fuse::meta::SegmentInfo;
+use crate::storages::fuse::meta::TableSnapshot;
+use crate::storages::fuse::FuseTable;
+use crate::storages::fuse::TBL_OPT_KEY_OLDEST_SNAPSHOT_ID;
+
+impl FuseTable {
+    /// Removes the snapshots which are out of the retention, and the segments and blocks
+    /// which are referenced by none of the retained snapshots.
+    ///
+    /// The objects under the location prefix of the table that are referenced by none of the
+    /// retained snapshots (e.g. written by failed commits, or by the attempts of delete, update
+    /// and compact that were retried) are removed as well, if they are older than the retention,
+    /// i.e. the cutoff time, or the time the oldest retained snapshot is taken.
+    ///
+    /// NOTE: the tables located under the prefixes shared by all the tables (see
+    /// `FuseTable::location_prefix`) only get the objects referenced by the purged snapshots
+    /// removed, the other ones can not be told apart from the objects of the other tables.
+    pub(crate) async fn do_purge(
+        &self,
+        ctx: Arc<QueryContext>,
+        retention: &PurgeRetention,
+    ) -> Result<()> {
+        let history = self.snapshot_history(ctx.clone()).await?;
+        let retained_count = Self::retained_count(&history, retention);
+        let (retained, purged) = history.split_at(retained_count);
+        if retained.is_empty() {
+            return Ok(());
+        }
+
+        let da = ctx.get_data_accessor()?;
+        let retained_segments = retained
+            .iter()
+            .flat_map(|snapshot| snapshot.segments.iter())
+            .collect::<HashSet<_>>();
+        let mut retained_blocks = HashSet::new();
+        for location in &retained_segments {
+            let segment: SegmentInfo = read_obj(da.clone(), location.to_string()).await?;
+            retained_blocks.extend(segment.blocks.into_iter().map(|b| b.location.location));
+        }
+
+        let prefix = self.location_prefix();
+        let oldest = &retained[retained.len() - 1];
+        if !purged.is_empty() {
+            let purged_segments = purged
+                .iter()
+                .flat_map(|snapshot| snapshot.segments.iter())
+                .filter(|location| !retained_segments.contains(location))
+                .collect::<HashSet<_>>();
+            let mut purged_blocks = HashSet::new();
+            for location in &purged_segments {
+                let segment: SegmentInfo = read_obj(da.clone(), location.to_string()).await?;
+                purged_blocks.extend(
+                    segment
+                        .blocks
+                        .into_iter()
+                        .map(|b| b.location.location)
+                        .filter(|location| !retained_blocks.contains(location)),
+                );
+            }
+
+            // Cuts the history off before anything is removed, so that the history is never
+            // walked into the removed snapshots. If the removal fails halfway, the rest of the
+            // objects are left unreferenced, and removed by the next purge.
+            let oldest_snapshot_id = oldest.snapshot_id;
+            self.commit_with_retry(ctx.as_ref(), |table| {
+                let ctx = ctx.clone();
+                async move {
+                    table
+                        .commit_oldest_snapshot_id(ctx.as_ref(), oldest_snapshot_id)
+                        .await
+                }
+            })
+            .await?;
+
+            for location in &purged_blocks {
+                da.remove(location).await?;
+            }
+            for location in &purged_segments {
+                da.remove(location).await?;
+            }
+            for snapshot in purged {
+                let location =
+                    io::snapshot_location(&prefix, snapshot.snapshot_id.to_simple().to_string());
+                da.remove(&location).await?;
+            }
+        }
+
+        // the objects of the table that are referenced by none of the retained snapshots
+        if prefix.is_empty() {
+            return Ok(());
+        }
+        let cutoff = match retention {
+            PurgeRetention::Snapshots(_) => oldest.timestamp,
+            PurgeRetention::Seconds(seconds) => Self::retention_cutoff(*seconds),
+        };
+        let cutoff = match cutoff {
+            Some(cutoff) => cutoff,
+            None => return Ok(()),
+        };
+        let retained_snapshots = retained
+            .iter()
+            .map(|snapshot| {
+                io::snapshot_location(&prefix, snapshot.snapshot_id.to_simple().to_string())
+            })
+            .collect::<HashSet<_>>();
+        for object in da.list(&prefix).await? {
+            let location = &object.location;
+            if object.last_modified < cutoff
+                && !retained_snapshots.contains(location)
+                && !retained_segments.contains(location)
+                && !retained_blocks.contains(location)
+            {
+                da.remove(location).await?;
+            }
+        }
+        Ok(())
+    }
+
+    // Records the oldest snapshot that is kept, the history before it is no longer walked.
+    async fn commit_oldest_snapshot_id(&self, ctx: &QueryContext, snapshot_id: Uuid) -> Result<()> {
+        let catalog = ctx.get_catalog();
+        catalog
+            .upsert_table_option(UpsertTableOptionReq::new(
+                &self.table_info.ident,
+                TBL_OPT_KEY_OLDEST_SNAPSHOT_ID,
+                snapshot_id.to_simple().to_string(),
+            ))
+            .await?;
+        Ok(())
+    }
+
+    // The time point `seconds` ago, or None if it is out of the representable time range.
+    fn retention_cutoff(seconds: u64) -> Option<DateTime<Utc>> {
+        i64::try_from(seconds)
+            .ok()
+            .filter(|seconds| *seconds <= i64::MAX / 1000)
+            .and_then(|seconds| Utc::now().checked_sub_signed(Duration::seconds(seconds)))
+    }
+
+    // How many snapshots of the history (the latest first) are retained, the latest snapshot is
+    // always retained.
+    fn retained_count(history: &[TableSnapshot], retention: &PurgeRetention) -> usize {
+        let count = match retention {
+            PurgeRetention::Snapshots(n) => *n as usize,
+            PurgeRetention::Seconds(seconds) => {
+                match Self::retention_cutoff(*seconds) {
+                    // the retention is longer than the representable time range
+                    None => history.len(),
+                    // the snapshots taken after the cutoff, and the one that is current at the
+                    // cutoff
+                    Some(cutoff) => {
+                        history
+                            .iter()
+                            .take_while(
+                                |snapshot| matches!(snapshot.timestamp, Some(t) if t > cutoff),
+                            )
+                            .count()
+                            + 1
+                    }
+                }
+            }
+        };
+        count.max(1).min(history.len())
+    }
+}
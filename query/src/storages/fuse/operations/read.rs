@@ -19,12 +19,12 @@ use async_stream::stream;
 use common_datavalues::DataSchema;
 use common_exception::Result;
 use common_planners::Extras;
-use common_streams::ParquetSource;
 use common_streams::SendableDataBlockStream;
-use common_streams::Source;
 use futures::StreamExt;
 
 use crate::sessions::QueryContext;
+use crate::storages::fuse::io::BlockPartInfo;
+use crate::storages::fuse::io::BlockReader;
 use crate::storages::fuse::FuseTable;
 
 impl FuseTable {
@@ -66,22 +66,22 @@ impl FuseTable {
         let arrow_schema = self.table_info.schema().to_arrow();
         let table_schema = Arc::new(DataSchema::from(arrow_schema));
 
+        let reader = BlockReader::new(da, table_schema, self.column_ids()?, projection);
+
         let mut iter = futures::stream::iter(iter);
         let stream = stream! {
             while let Some(part) = iter.next().await {
-                let mut source = ParquetSource::new(
-                    da.clone(),
-                    part.name.clone(),
-                    table_schema.clone(),
-                    projection.clone(),
-                );
-                loop {
-                    let block = source.read().await;
-                    match block {
-                        Ok(None) => break,
-                        Ok(Some(b)) =>  yield(Ok(b)),
-                        Err(e) => yield(Err(e)),
+                let blocks = match BlockPartInfo::decode(&part) {
+                    Ok(part_info) => reader.read(&part_info).await,
+                    Err(e) => Err(e),
+                };
+                match blocks {
+                    Ok(blocks) => {
+                        for b in blocks {
+                            yield(Ok(b))
+                        }
                     }
+                    Err(e) => yield(Err(e)),
                 }
             }
         };
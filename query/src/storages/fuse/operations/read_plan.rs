@@ -19,13 +19,14 @@ use std::sync::Arc;
 use common_dal::read_obj;
 use common_exception::Result;
 use common_planners::Extras;
-use common_planners::Part;
 use common_planners::Partitions;
 use common_planners::Statistics;
 
 use crate::sessions::QueryContext;
 use crate::storages::fuse::index::apply_range_filter;
+use crate::storages::fuse::io::BlockPartInfo;
 use crate::storages::fuse::meta::BlockMeta;
+use crate::storages::fuse::meta::ColumnId;
 use crate::storages::fuse::FuseTable;
 
 impl FuseTable {
@@ -39,11 +40,12 @@ impl FuseTable {
         if let Some(loc) = location {
             let da = ctx.get_data_accessor()?;
             let schema = self.table_info.schema();
+            let column_ids = self.column_ids()?;
             let push_downs_c = push_downs.clone();
             let snapshot = read_obj(da.clone(), loc).await?;
-            let block_metas = apply_range_filter(&snapshot, schema, push_downs_c, da).await?;
-            let (statistics, parts) = Self::to_partitions(&block_metas, push_downs);
-            Ok((statistics, parts))
+            let block_metas =
+                apply_range_filter(&snapshot, schema, &column_ids, push_downs_c, da).await?;
+            Self::to_partitions(&block_metas, &column_ids, push_downs)
         } else {
             Ok((Statistics::default(), vec![]))
         }
@@ -51,17 +53,22 @@ impl FuseTable {
 
     pub(crate) fn to_partitions(
         blocks_metas: &[BlockMeta],
+        column_ids: &[ColumnId],
         push_downs: Option<Extras>,
-    ) -> (Statistics, Partitions) {
-        let proj_cols =
-            push_downs.and_then(|extras| extras.projection.map(HashSet::<usize>::from_iter));
-        blocks_metas.iter().fold(
+    ) -> Result<(Statistics, Partitions)> {
+        // the projection is given in positions of the table schema, while the column statistics
+        // are keyed by column ids
+        let proj_cols = push_downs.and_then(|extras| {
+            extras.projection.map(|proj| {
+                proj.iter()
+                    .map(|idx| column_ids[*idx])
+                    .collect::<HashSet<_>>()
+            })
+        });
+        blocks_metas.iter().try_fold(
             (Statistics::default(), Partitions::default()),
             |(mut stats, mut parts), block_meta| {
-                parts.push(Part {
-                    name: block_meta.location.location.clone(),
-                    version: 0,
-                });
+                parts.push(BlockPartInfo::from_block_meta(block_meta).encode()?);
 
                 stats.read_rows += block_meta.row_count as usize;
 
@@ -70,14 +77,14 @@ impl FuseTable {
                         stats.read_bytes += block_meta
                             .col_stats
                             .iter()
-                            .filter(|(cid, _)| proj.contains(&(**cid as usize)))
+                            .filter(|(cid, _)| proj.contains(*cid))
                             .map(|(_, col_stats)| col_stats.in_memory_size)
                             .sum::<u64>() as usize
                     }
                     None => stats.read_bytes += block_meta.block_size as usize,
                 }
 
-                (stats, parts)
+                Ok((stats, parts))
             },
         )
     }
@@ -48,6 +48,7 @@ fn test_to_partitions() -> Result<()> {
             .iter()
             .map(|(_, col_stats)| col_stats.in_memory_size)
             .sum(),
+        file_size: 0,
         col_stats: cols_stats.clone(),
         location: BlockLocation {
             location: "".to_string(),
@@ -61,7 +62,8 @@ fn test_to_partitions() -> Result<()> {
         .collect::<Vec<_>>();
 
     // CASE I:  no projection
-    let (s, _) = FuseTable::to_partitions(&blocks_metas, None);
+    let column_ids = (0..num_of_col).into_iter().collect::<Vec<u32>>();
+    let (s, _) = FuseTable::to_partitions(&blocks_metas, &column_ids, None)?;
     let expected_block_size: u64 = cols_stats
         .iter()
         .map(|(_, col_stats)| col_stats.in_memory_size)
@@ -89,7 +91,7 @@ fn test_to_partitions() -> Result<()> {
         limit: None,
         order_by: vec![],
     });
-    let (stats, _) = FuseTable::to_partitions(&blocks_metas, push_down);
+    let (stats, _) = FuseTable::to_partitions(&blocks_metas, &column_ids, push_down)?;
     assert_eq!(expected_block_size * num_of_block, stats.read_bytes as u64);
     Ok(())
 }
@@ -15,6 +15,7 @@
 
 use std::sync::Arc;
 
+use chrono::Utc;
 use common_exception::Result;
 use common_meta_types::UpsertTableOptionReq;
 use common_planners::TruncateTablePlan;
@@ -40,8 +41,11 @@ impl FuseTable {
             new_snapshot.prev_snapshot_id = Some(prev_id);
             new_snapshot.summary = Default::default();
             new_snapshot.snapshot_id = Uuid::new_v4();
-            let new_snapshot_loc =
-                io::snapshot_location(new_snapshot.snapshot_id.to_simple().to_string().as_str()); // TODO refine this
+            new_snapshot.timestamp = Some(Utc::now());
+            let new_snapshot_loc = io::snapshot_location(
+                &self.location_prefix(),
+                new_snapshot.snapshot_id.to_simple().to_string().as_str(),
+            ); // TODO refine this
             let da = ctx.get_data_accessor()?;
             let bytes = serde_json::to_vec(&new_snapshot)?;
             da.put(&new_snapshot_loc, bytes).await?;
@@ -0,0 +1,123 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+//
+
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_exception::Result;
+use common_planners::col;
+use common_planners::Expression;
+use common_planners::UpdatePlan;
+
+use crate::pipelines::transforms::ExpressionExecutor;
+use crate::sessions::QueryContext;
+use crate::storages::fuse::operations::mutation::BlockMutation;
+use crate::storages::fuse::FuseTable;
+
+impl FuseTable {
+    #[inline]
+    pub async fn do_update(&self, ctx: Arc<QueryContext>, update_plan: UpdatePlan) -> Result<()> {
+        // the blocks are selected again from the latest snapshot on retries
+        self.commit_with_retry(ctx.as_ref(), |table| {
+            let ctx = ctx.clone();
+            let update_plan = update_plan.clone();
+            async move { table.try_update(ctx, update_plan).await }
+        })
+        .await
+    }
+
+    async fn try_update(&self, ctx: Arc<QueryContext>, update_plan: UpdatePlan) -> Result<()> {
+        let update_executor = self.update_executor(&update_plan)?;
+        let selection_executor = match &update_plan.selection {
+            Some(selection) => Some(self.selection_executor(selection)?),
+            None => None,
+        };
+
+        let selection = update_plan.selection.as_ref();
+        self.mutate_blocks(ctx, selection, |block| {
+            let (updated_rows, kept_rows) = match &selection_executor {
+                None => ((0..block.num_rows() as u32).collect::<Vec<_>>(), vec![]),
+                Some(executor) => Self::selection_mask(executor, &block)?
+                    .iter()
+                    .enumerate()
+                    .fold(
+                        (vec![], vec![]),
+                        |(mut updated, mut kept), (row, selected)| {
+                            match selected {
+                                true => updated.push(row as u32),
+                                false => kept.push(row as u32),
+                            }
+                            (updated, kept)
+                        },
+                    ),
+            };
+
+            if updated_rows.is_empty() {
+                return Ok(BlockMutation::Untouched);
+            }
+
+            let updated_block = match kept_rows.is_empty() {
+                true => block.clone(),
+                false => DataBlock::block_take_by_indices(&block, &[], &updated_rows)?,
+            };
+            let updated_block = update_executor.execute(&updated_block)?;
+
+            match kept_rows.is_empty() {
+                true => Ok(BlockMutation::Replaced(updated_block)),
+                false => {
+                    let kept_block = DataBlock::block_take_by_indices(&block, &[], &kept_rows)?;
+                    let new_block = DataBlock::concat_blocks(&[kept_block, updated_block])?;
+                    Ok(BlockMutation::Replaced(new_block))
+                }
+            }
+        })
+        .await
+    }
+
+    // Evaluates the SET expressions, the columns which are not assigned are kept as they are.
+    fn update_executor(&self, update_plan: &UpdatePlan) -> Result<ExpressionExecutor> {
+        let schema = self.table_info.schema();
+        let exprs = schema
+            .fields()
+            .iter()
+            .map(|field| {
+                let assignment = update_plan
+                    .update_list
+                    .iter()
+                    .find(|(name, _)| name == field.name());
+                match assignment {
+                    None => col(field.name()),
+                    Some((name, expr)) => Expression::Alias(
+                        name.clone(),
+                        Box::new(Expression::Cast {
+                            expr: Box::new(expr.clone()),
+                            data_type: field.data_type().clone(),
+                        }),
+                    ),
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let executor = ExpressionExecutor::try_create(
+            "update executor",
+            schema.clone(),
+            schema,
+            exprs,
+            false,
+        )?;
+        executor.validate()?;
+        Ok(executor)
+    }
+}
@@ -43,7 +43,11 @@ impl StatisticsAccumulator {
 }
 
 impl StatisticsAccumulator {
-    pub fn acc(&mut self, block: &DataBlock) -> common_exception::Result<()> {
+    pub fn acc(
+        &mut self,
+        block: &DataBlock,
+        column_ids: &[ColumnId],
+    ) -> common_exception::Result<()> {
         let row_count = block.num_rows() as u64;
         let block_in_memory_size = block.memory_size() as u64;
 
@@ -52,7 +56,7 @@ impl StatisticsAccumulator {
         self.in_memory_size += block_in_memory_size;
         self.last_block_rows = block.num_rows() as u64;
         self.last_block_size = block.memory_size() as u64;
-        let block_stats = util::block_stats(block)?;
+        let block_stats = util::block_stats(block, column_ids)?;
         self.last_block_col_stats = Some(block_stats.clone());
         self.blocks_stats.push(block_stats);
         Ok(())
@@ -24,4 +24,5 @@ pub use accumulator::StatisticsAccumulator;
 pub use block_meta_acc::BlockMetaAccumulator;
 pub use util::merge_append_operations;
 pub use util::merge_stats;
+pub use util::reduce_block_metas;
 pub use util::reduce_block_stats;
@@ -31,9 +31,9 @@ use crate::storages::fuse::table_test_fixture::TestFixture;
 fn test_ft_stats_block_stats() -> common_exception::Result<()> {
     let schema = DataSchemaRefExt::create(vec![DataField::new("a", DataType::Int32, false)]);
     let block = DataBlock::create_by_array(schema, vec![Series::new(vec![1, 2, 3])]);
-    let r = util::block_stats(&block)?;
+    let r = util::block_stats(&block, &[3])?;
     assert_eq!(1, r.len());
-    let col_stats = r.get(&0).unwrap();
+    let col_stats = r.get(&3).unwrap();
     assert_eq!(col_stats.min, DataValue::Int32(Some(1)));
     assert_eq!(col_stats.max, DataValue::Int32(Some(3)));
     Ok(())
@@ -45,9 +45,9 @@ fn test_ft_stats_col_stats_reduce() -> common_exception::Result<()> {
     let schema = DataSchemaRefExt::create(vec![DataField::new("a", DataType::Int32, false)]);
     let col_stats = blocks
         .iter()
-        .map(|b| util::block_stats(&b.clone().unwrap()))
+        .map(|b| util::block_stats(&b.clone().unwrap(), &[0]))
         .collect::<common_exception::Result<Vec<_>>>()?;
-    let r = util::reduce_block_stats(&col_stats, &schema);
+    let r = util::reduce_block_stats(&col_stats, &schema, &[0]);
     assert!(r.is_ok());
     let r = r.unwrap();
     assert_eq!(1, r.len());
@@ -57,6 +57,48 @@ fn test_ft_stats_col_stats_reduce() -> common_exception::Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_ft_stats_col_stats_reduce_with_dropped_column() -> common_exception::Result<()> {
+    // stats of column 1 are from the blocks written before it was dropped
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", DataType::Int32, false),
+        DataField::new("b", DataType::String, false),
+    ]);
+    let block = DataBlock::create_by_array(schema, vec![
+        Series::new(vec![1, 2, 3]),
+        Series::new(vec!["x", "y", "z"]),
+    ]);
+    let old_stats = util::block_stats(&block, &[0, 1])?;
+
+    // column 2 is added after column 1 is dropped
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", DataType::Int32, false),
+        DataField::new("c", DataType::String, false),
+    ]);
+    let block = DataBlock::create_by_array(schema.clone(), vec![
+        Series::new(vec![4, 5, 6]),
+        Series::new(vec!["u", "v", "w"]),
+    ]);
+    let new_stats = util::block_stats(&block, &[0, 2])?;
+
+    let r = util::reduce_block_stats(&[old_stats, new_stats], &schema, &[0, 2])?;
+    assert_eq!(2, r.len());
+    assert!(r.get(&1).is_none());
+    let col_stats = r.get(&0).unwrap();
+    assert_eq!(col_stats.min, DataValue::Int32(Some(1)));
+    assert_eq!(col_stats.max, DataValue::Int32(Some(6)));
+    let col_stats = r.get(&2).unwrap();
+    assert_eq!(
+        col_stats.min,
+        DataValue::String(Some("u".as_bytes().to_vec()))
+    );
+    assert_eq!(
+        col_stats.max,
+        DataValue::String(Some("w".as_bytes().to_vec()))
+    );
+    Ok(())
+}
+
 #[test]
 fn test_ft_stats_accumulator() -> common_exception::Result<()> {
     let blocks = TestFixture::gen_block_stream(10, 1);
@@ -64,7 +106,7 @@ fn test_ft_stats_accumulator() -> common_exception::Result<()> {
     let mut meta_acc = block_meta_acc::BlockMetaAccumulator::new();
     blocks.iter().try_for_each(|item| {
         let item = item.clone().unwrap();
-        stats_acc.acc(&item)?;
+        stats_acc.acc(&item, &[0])?;
         meta_acc.acc(1, "".to_owned(), &mut stats_acc);
         Ok::<_, ErrorCode>(())
     })?;
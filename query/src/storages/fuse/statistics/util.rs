@@ -23,20 +23,20 @@ use common_datavalues::DataSchema;
 use common_exception::ErrorCode;
 use common_exception::Result;
 
+use crate::storages::fuse::meta::BlockMeta;
 use crate::storages::fuse::meta::ColumnId;
 use crate::storages::fuse::meta::Stats;
 use crate::storages::fuse::operations::AppendOperationLogEntry;
 use crate::storages::index::BlockStatistics;
 use crate::storages::index::ColumnStatistics;
 
-pub fn block_stats(data_block: &DataBlock) -> Result<BlockStatistics> {
-    // NOTE:
-    // column id is FAKED, this is OK as long as table schema is NOT changed (which is not realistic)
-    // we should extend DataField with column_id ...
-    (0..)
-        .into_iter()
+// The columns of the block are supposed to be laid out as the table schema, of which the
+// column ids are given by `column_ids`
+pub fn block_stats(data_block: &DataBlock, column_ids: &[ColumnId]) -> Result<BlockStatistics> {
+    column_ids
+        .iter()
         .zip(data_block.columns().iter())
-        .map(|(idx, col)| {
+        .map(|(column_id, col)| {
             let min = match col {
                 DataColumn::Array(s) => s.min(),
                 DataColumn::Constant(v, _) => Ok(v.clone()),
@@ -67,14 +67,18 @@ pub fn block_stats(data_block: &DataBlock) -> Result<BlockStatistics> {
                 in_memory_size,
             };
 
-            Ok((idx, col_stats))
+            Ok((*column_id, col_stats))
         })
         .collect()
 }
 
+// The data type of a column is taken from the table schema, of which the column ids are given by
+// `column_ids`. The statistics of the dropped columns, which are kept by the blocks written before
+// they were dropped, are not reduced any more.
 pub fn reduce_block_stats<T: Borrow<BlockStatistics>>(
     stats: &[T],
     schema: &DataSchema,
+    column_ids: &[ColumnId],
 ) -> Result<BlockStatistics> {
     let len = stats.len();
 
@@ -100,6 +104,11 @@ pub fn reduce_block_stats<T: Borrow<BlockStatistics>>(
     col_stat_list
         .iter()
         .try_fold(HashMap::with_capacity(len), |mut acc, (id, stats)| {
+            let data_type = match column_ids.iter().position(|column_id| column_id == id) {
+                Some(idx) => schema.field(idx).data_type(),
+                None => return Ok(acc),
+            };
+
             let mut min_stats = Vec::with_capacity(stats.len());
             let mut max_stats = Vec::with_capacity(stats.len());
             let mut null_count = 0;
@@ -115,9 +124,6 @@ pub fn reduce_block_stats<T: Borrow<BlockStatistics>>(
                 in_memory_size += col_stats.in_memory_size;
             }
 
-            // TODO panic
-            let data_type = schema.field((*id) as usize).data_type();
-
             // TODO
             // for some data types, we shall balance the accuracy and the length
             // e.g. for a string col, which max value is "abcdef....", we record the max as something like "b"
@@ -139,19 +145,50 @@ pub fn reduce_block_stats<T: Borrow<BlockStatistics>>(
         })
 }
 
-pub fn merge_stats(schema: &DataSchema, l: &Stats, r: &Stats) -> Result<Stats> {
+pub fn merge_stats(
+    schema: &DataSchema,
+    column_ids: &[ColumnId],
+    l: &Stats,
+    r: &Stats,
+) -> Result<Stats> {
     let s = Stats {
         row_count: l.row_count + r.row_count,
         block_count: l.block_count + r.block_count,
         uncompressed_byte_size: l.uncompressed_byte_size + r.uncompressed_byte_size,
         compressed_byte_size: l.compressed_byte_size + r.compressed_byte_size,
-        col_stats: reduce_block_stats(&[&l.col_stats, &r.col_stats], schema)?,
+        col_stats: reduce_block_stats(&[&l.col_stats, &r.col_stats], schema, column_ids)?,
     };
     Ok(s)
 }
 
+pub fn reduce_block_metas(
+    block_metas: &[BlockMeta],
+    schema: &DataSchema,
+    column_ids: &[ColumnId],
+) -> Result<Stats> {
+    let mut row_count = 0;
+    let mut uncompressed_byte_size = 0;
+    let mut compressed_byte_size = 0;
+    let mut blocks_stats = Vec::with_capacity(block_metas.len());
+    for block_meta in block_metas {
+        row_count += block_meta.row_count;
+        uncompressed_byte_size += block_meta.block_size;
+        compressed_byte_size += block_meta.file_size;
+        blocks_stats.push(&block_meta.col_stats);
+    }
+
+    Ok(Stats {
+        row_count,
+        block_count: block_metas.len() as u64,
+        uncompressed_byte_size,
+        compressed_byte_size,
+        col_stats: reduce_block_stats(&blocks_stats, schema, column_ids)?,
+    })
+}
+
 pub fn merge_append_operations(
     schema: &DataSchema,
+    column_ids: &[ColumnId],
     append_log_entries: Vec<AppendOperationLogEntry>,
 ) -> Result<(Vec<String>, Stats)> {
     let (s, seg_locs) = append_log_entries.iter().try_fold(
@@ -166,7 +203,8 @@ pub fn merge_append_operations(
             acc.block_count += stats.block_count;
             acc.uncompressed_byte_size += stats.uncompressed_byte_size;
             acc.compressed_byte_size += stats.compressed_byte_size;
-            acc.col_stats = reduce_block_stats(&[&acc.col_stats, &stats.col_stats], schema)?;
+            acc.col_stats =
+                reduce_block_stats(&[&acc.col_stats, &stats.col_stats], schema, column_ids)?;
             seg_acc.push(loc.clone());
             Ok::<_, ErrorCode>((acc, seg_acc))
         },
@@ -19,20 +19,32 @@ use std::sync::Arc;
 
 use common_dal::read_obj;
 use common_datablocks::DataBlock;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_meta_types::TableInfo;
+use common_planners::AlterTablePlan;
+use common_planners::DeletePlan;
 use common_planners::Extras;
+use common_planners::OptimizeTablePlan;
 use common_planners::Partitions;
 use common_planners::ReadDataSourcePlan;
 use common_planners::Statistics;
 use common_planners::TruncateTablePlan;
+use common_planners::UpdatePlan;
 use common_streams::DataBlockStream;
 use common_streams::SendableDataBlockStream;
+use uuid::Uuid;
 
 use crate::sessions::QueryContext;
+use crate::storages::fuse::meta::ColumnId;
 use crate::storages::fuse::meta::TableSnapshot;
 use crate::storages::fuse::operations::AppendOperationLogEntry;
+use crate::storages::fuse::FUSE_TBL_SNAPSHOT_PREFIX;
+use crate::storages::fuse::TBL_OPT_KEY_COLUMN_IDS;
+use crate::storages::fuse::TBL_OPT_KEY_NEXT_COLUMN_ID;
+use crate::storages::fuse::TBL_OPT_KEY_OLDEST_SNAPSHOT_ID;
 use crate::storages::fuse::TBL_OPT_KEY_SNAPSHOT_LOC;
+use crate::storages::NavigationPoint;
 use crate::storages::StorageContext;
 use crate::storages::Table;
 
@@ -118,6 +130,34 @@ impl Table for FuseTable {
     ) -> Result<()> {
         self.do_truncate(ctx, truncate_plan).await
     }
+
+    async fn delete(&self, ctx: Arc<QueryContext>, delete_plan: DeletePlan) -> Result<()> {
+        self.do_delete(ctx, delete_plan).await
+    }
+
+    async fn update(&self, ctx: Arc<QueryContext>, update_plan: UpdatePlan) -> Result<()> {
+        self.do_update(ctx, update_plan).await
+    }
+
+    async fn alter_table(&self, ctx: Arc<QueryContext>, alter_plan: AlterTablePlan) -> Result<()> {
+        self.do_alter_table(ctx, alter_plan).await
+    }
+
+    async fn optimize(
+        &self,
+        ctx: Arc<QueryContext>,
+        optimize_plan: OptimizeTablePlan,
+    ) -> Result<()> {
+        self.do_optimize(ctx, optimize_plan).await
+    }
+
+    async fn navigate_to(
+        &self,
+        ctx: Arc<QueryContext>,
+        point: &NavigationPoint,
+    ) -> Result<Arc<dyn Table>> {
+        Ok(self.do_navigate(ctx, point).await?)
+    }
 }
 
 impl FuseTable {
@@ -128,6 +168,72 @@ impl FuseTable {
             .cloned()
     }
 
+    /// The prefix of the locations of the objects (snapshots, segments and blocks) of the table,
+    /// i.e. `{table_id}/`.
+    ///
+    /// The tables whose snapshots were written under the prefixes shared by all the tables keep
+    /// using them, since the history is walked through the snapshot locations.
+    pub(crate) fn location_prefix(&self) -> String {
+        match self.snapshot_loc() {
+            Some(loc) if loc.starts_with(&format!("{}/", FUSE_TBL_SNAPSHOT_PREFIX)) => {
+                "".to_owned()
+            }
+            _ => format!("{}/", self.table_info.ident.table_id),
+        }
+    }
+
+    /// The id of the oldest snapshot that is kept by the purge, the history before it is gone.
+    pub(crate) fn oldest_snapshot_id(&self) -> Result<Option<Uuid>> {
+        match self
+            .table_info
+            .options()
+            .get(TBL_OPT_KEY_OLDEST_SNAPSHOT_ID)
+        {
+            None => Ok(None),
+            Some(id) => Uuid::parse_str(id).map(Some).map_err(|e| {
+                ErrorCode::BadOption(format!(
+                    "invalid table option {}: {}, {}",
+                    TBL_OPT_KEY_OLDEST_SNAPSHOT_ID, id, e
+                ))
+            }),
+        }
+    }
+
+    /// The ids of the columns of the table schema, which are kept unchanged while the schema
+    /// evolves. For tables that have never been altered, column ids are the positional indexes.
+    ///
+    /// NOTE: columns are only appended to the table schema, so the column ids are always in
+    /// ascending order, as well as the columns of the blocks.
+    pub(crate) fn column_ids(&self) -> Result<Vec<ColumnId>> {
+        match self.table_info.options().get(TBL_OPT_KEY_COLUMN_IDS) {
+            None => Ok((0..self.table_info.schema().fields().len() as ColumnId).collect()),
+            Some(column_ids) => column_ids
+                .split(',')
+                .map(|id| {
+                    id.parse::<ColumnId>().map_err(|e| {
+                        ErrorCode::BadOption(format!(
+                            "invalid table option {}: {}, {}",
+                            TBL_OPT_KEY_COLUMN_IDS, column_ids, e
+                        ))
+                    })
+                })
+                .collect(),
+        }
+    }
+
+    /// The id of the next column added to the table.
+    pub(crate) fn next_column_id(&self) -> Result<ColumnId> {
+        match self.table_info.options().get(TBL_OPT_KEY_NEXT_COLUMN_ID) {
+            None => Ok(self.table_info.schema().fields().len() as ColumnId),
+            Some(next_column_id) => next_column_id.parse::<ColumnId>().map_err(|e| {
+                ErrorCode::BadOption(format!(
+                    "invalid table option {}: {}, {}",
+                    TBL_OPT_KEY_NEXT_COLUMN_ID, next_column_id, e
+                ))
+            }),
+        }
+    }
+
     pub(crate) async fn table_snapshot(
         &self,
         ctx: Arc<QueryContext>,
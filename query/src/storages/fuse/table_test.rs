@@ -14,7 +14,14 @@
 //
 
 use common_base::tokio;
+use common_dal::read_obj;
+use common_dal::DataAccessor;
+use common_datablocks::DataBlock;
+use common_exception::ErrorCode;
 use common_exception::Result;
+use common_planners::col;
+use common_planners::lit;
+use common_planners::DeletePlan;
 use common_planners::ReadDataSourcePlan;
 use common_planners::TruncateTablePlan;
 use futures::TryStreamExt;
@@ -22,8 +29,14 @@ use futures::TryStreamExt;
 use crate::catalogs::Catalog;
 use crate::interpreters::InterpreterFactory;
 use crate::sql::PlanParser;
+use crate::storages::fuse::io;
+use crate::storages::fuse::meta::SegmentInfo;
+use crate::storages::fuse::meta::TableSnapshot;
+use crate::storages::fuse::operations::CompactionThresholds;
 use crate::storages::fuse::table_test_fixture::TestFixture;
+use crate::storages::fuse::FuseTable;
 use crate::storages::fuse::TBL_OPT_KEY_CHUNK_BLOCK_NUM;
+use crate::storages::fuse::TBL_OPT_KEY_SNAPSHOT_LOC;
 use crate::storages::ToReadDataSourcePlan;
 
 #[tokio::test]
@@ -231,6 +244,612 @@ async fn test_fuse_table_truncate() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_fuse_table_delete() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let ctx = fixture.ctx();
+
+    let create_table_plan = fixture.default_crate_table_plan();
+    let tbl_name = create_table_plan.table.clone();
+    let db_name = create_table_plan.db.clone();
+    let catalog = ctx.get_catalog();
+    catalog.create_table(create_table_plan.into()).await?;
+
+    // 10 blocks of [1, 2, 3]
+    let table = fixture.latest_default_table().await?;
+    let num_blocks = 10;
+    let stream = Box::pin(futures::stream::iter(TestFixture::gen_block_stream(
+        num_blocks, 1,
+    )));
+    let r = table.append_data(ctx.clone(), stream).await?;
+    table
+        .commit(ctx.clone(), r.try_collect().await?, false)
+        .await?;
+
+    // 1. none of the blocks are touched, if the selection is ruled out by the statistics
+    let table = fixture.latest_default_table().await?;
+    let prev_version = table.get_table_info().ident.version;
+    let query = format!("delete from {}.{} where id > 3", db_name, tbl_name);
+    execute_query(&fixture, &query).await?;
+
+    let table = fixture.latest_default_table().await?;
+    assert_eq!(prev_version, table.get_table_info().ident.version);
+
+    // 2. delete some of the rows of each block
+    let query = format!("delete from {}.{} where id = 2", db_name, tbl_name);
+    execute_query(&fixture, &query).await?;
+
+    let table = fixture.latest_default_table().await?;
+    assert_ne!(prev_version, table.get_table_info().ident.version);
+    let (stats, parts) = table.read_partitions(ctx.clone(), None).await?;
+    assert_eq!(parts.len(), 10);
+    assert_eq!(stats.read_rows, 10 * 2);
+
+    // 3. delete all the rows
+    let query = format!("delete from {}.{} where id < 10", db_name, tbl_name);
+    execute_query(&fixture, &query).await?;
+
+    let table = fixture.latest_default_table().await?;
+    let (stats, parts) = table.read_partitions(ctx.clone(), None).await?;
+    assert_eq!(parts.len(), 0);
+    assert_eq!(stats.read_rows, 0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_fuse_table_update() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let ctx = fixture.ctx();
+
+    let create_table_plan = fixture.default_crate_table_plan();
+    let tbl_name = create_table_plan.table.clone();
+    let db_name = create_table_plan.db.clone();
+    let catalog = ctx.get_catalog();
+    catalog.create_table(create_table_plan.into()).await?;
+
+    // 3 blocks of [1, 2, 3]
+    let table = fixture.latest_default_table().await?;
+    let num_blocks = 3;
+    let stream = Box::pin(futures::stream::iter(TestFixture::gen_block_stream(
+        num_blocks, 1,
+    )));
+    let r = table.append_data(ctx.clone(), stream).await?;
+    table
+        .commit(ctx.clone(), r.try_collect().await?, false)
+        .await?;
+
+    // 1. none of the blocks are touched, if the selection is ruled out by the statistics
+    let table = fixture.latest_default_table().await?;
+    let prev_version = table.get_table_info().ident.version;
+    let query = format!("update {}.{} set id = 0 where id > 3", db_name, tbl_name);
+    execute_query(&fixture, &query).await?;
+
+    let table = fixture.latest_default_table().await?;
+    assert_eq!(prev_version, table.get_table_info().ident.version);
+
+    // 2. update some of the rows of each block
+    let query = format!(
+        "update {}.{} set id = id * 10 where id = 2",
+        db_name, tbl_name
+    );
+    execute_query(&fixture, &query).await?;
+
+    let table = fixture.latest_default_table().await?;
+    assert_ne!(prev_version, table.get_table_info().ident.version);
+    let (stats, parts) = table.read_partitions(ctx.clone(), None).await?;
+    assert_eq!(parts.len(), 3);
+    assert_eq!(stats.read_rows, 3 * 3);
+
+    let query = format!("select id from {}.{}", db_name, tbl_name);
+    let blocks = execute_query(&fixture, &query).await?;
+    let expected = vec![
+        "+----+", //
+        "| id |", //
+        "+----+", //
+        "| 1  |", //
+        "| 1  |", //
+        "| 1  |", //
+        "| 20 |", //
+        "| 20 |", //
+        "| 20 |", //
+        "| 3  |", //
+        "| 3  |", //
+        "| 3  |", //
+        "+----+", //
+    ];
+    common_datablocks::assert_blocks_sorted_eq(expected, blocks.as_slice());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_fuse_table_alter() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let ctx = fixture.ctx();
+
+    let create_table_plan = fixture.default_crate_table_plan();
+    let tbl_name = create_table_plan.table.clone();
+    let db_name = create_table_plan.db.clone();
+    let catalog = ctx.get_catalog();
+    catalog.create_table(create_table_plan.into()).await?;
+
+    // 1 block of [1, 2, 3]
+    let table = fixture.latest_default_table().await?;
+    let stream = Box::pin(futures::stream::iter(TestFixture::gen_block_stream(1, 1)));
+    let r = table.append_data(ctx.clone(), stream).await?;
+    table
+        .commit(ctx.clone(), r.try_collect().await?, false)
+        .await?;
+
+    // 1. the rows written before the column is added are read as NULLs
+    let table = fixture.latest_default_table().await?;
+    let prev_version = table.get_table_info().ident.version;
+    let query = format!("alter table {}.{} add column c Int64", db_name, tbl_name);
+    execute_query(&fixture, &query).await?;
+    let table = fixture.latest_default_table().await?;
+    assert_ne!(prev_version, table.get_table_info().ident.version);
+    assert_eq!(table.schema().fields().len(), 2);
+
+    let query = format!("insert into {}.{} values (4, 40)", db_name, tbl_name);
+    execute_query(&fixture, &query).await?;
+
+    let query = format!("select id, c from {}.{}", db_name, tbl_name);
+    let blocks = execute_query(&fixture, &query).await?;
+    let expected = vec![
+        "+----+------+", //
+        "| id | c    |", //
+        "+----+------+", //
+        "| 1  | NULL |", //
+        "| 2  | NULL |", //
+        "| 3  | NULL |", //
+        "| 4  | 40   |", //
+        "+----+------+", //
+    ];
+    common_datablocks::assert_blocks_sorted_eq(expected, blocks.as_slice());
+
+    // 2. a renamed column keeps its data
+    let query = format!("alter table {}.{} rename column c to d", db_name, tbl_name);
+    execute_query(&fixture, &query).await?;
+    let query = format!("select d from {}.{} where d > 0", db_name, tbl_name);
+    let blocks = execute_query(&fixture, &query).await?;
+    let expected = vec![
+        "+----+", //
+        "| d  |", //
+        "+----+", //
+        "| 40 |", //
+        "+----+", //
+    ];
+    common_datablocks::assert_blocks_sorted_eq(expected, blocks.as_slice());
+
+    // 3. the data of a dropped column are ignored, and never show up again
+    let query = format!("alter table {}.{} drop column d", db_name, tbl_name);
+    execute_query(&fixture, &query).await?;
+    let query = format!("alter table {}.{} add column d Int64", db_name, tbl_name);
+    execute_query(&fixture, &query).await?;
+    let query = format!("select id, d from {}.{}", db_name, tbl_name);
+    let blocks = execute_query(&fixture, &query).await?;
+    let expected = vec![
+        "+----+------+", //
+        "| id | d    |", //
+        "+----+------+", //
+        "| 1  | NULL |", //
+        "| 2  | NULL |", //
+        "| 3  | NULL |", //
+        "| 4  | NULL |", //
+        "+----+------+", //
+    ];
+    common_datablocks::assert_blocks_sorted_eq(expected, blocks.as_slice());
+
+    // 4. unknown columns
+    let query = format!("alter table {}.{} drop column x", db_name, tbl_name);
+    assert!(execute_query(&fixture, &query).await.is_err());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_fuse_table_time_travel() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let ctx = fixture.ctx();
+
+    let create_table_plan = fixture.default_crate_table_plan();
+    let tbl_name = create_table_plan.table.clone();
+    let db_name = create_table_plan.db.clone();
+    let catalog = ctx.get_catalog();
+    catalog.create_table(create_table_plan.into()).await?;
+
+    // 2 snapshots, each of which appends a block of [1, 2, 3]
+    let mut snapshot_ids = vec![];
+    for _ in 0..2 {
+        let table = fixture.latest_default_table().await?;
+        let stream = Box::pin(futures::stream::iter(TestFixture::gen_block_stream(1, 1)));
+        let r = table.append_data(ctx.clone(), stream).await?;
+        table
+            .commit(ctx.clone(), r.try_collect().await?, false)
+            .await?;
+
+        let table = fixture.latest_default_table().await?;
+        let snapshot_loc = table
+            .get_table_info()
+            .options()
+            .get(TBL_OPT_KEY_SNAPSHOT_LOC)
+            .unwrap();
+        let snapshot: TableSnapshot =
+            read_obj(ctx.get_data_accessor()?, snapshot_loc.clone()).await?;
+        assert!(snapshot.timestamp.is_some());
+        snapshot_ids.push(snapshot.snapshot_id.to_simple().to_string());
+    }
+
+    // 1. by snapshot id
+    let expected_rows = [3, 6];
+    for (snapshot_id, expected) in snapshot_ids.iter().zip(expected_rows) {
+        let query = format!(
+            "select count(*) as c from {}.{} at (snapshot => '{}')",
+            db_name, tbl_name, snapshot_id
+        );
+        let blocks = execute_query(&fixture, &query).await?;
+        assert_eq!(blocks[0].column(0).try_get(0)?.as_u64()?, expected);
+    }
+
+    // 2. by time point
+    let query = format!(
+        "select count(*) as c from {}.{} at (timestamp => '2100-01-01 00:00:00')",
+        db_name, tbl_name
+    );
+    let blocks = execute_query(&fixture, &query).await?;
+    assert_eq!(blocks[0].column(0).try_get(0)?.as_u64()?, 6);
+
+    // 3. no historical data before the table is created
+    let query = format!(
+        "select count(*) as c from {}.{} at (timestamp => '2000-01-01 00:00:00')",
+        db_name, tbl_name
+    );
+    let res = execute_query(&fixture, &query).await;
+    assert_eq!(
+        res.unwrap_err().code(),
+        ErrorCode::TableHistoricalDataNotFound("").code()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_fuse_history_table_function() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let ctx = fixture.ctx();
+
+    let create_table_plan = fixture.default_crate_table_plan();
+    let tbl_name = create_table_plan.table.clone();
+    let db_name = create_table_plan.db.clone();
+    let catalog = ctx.get_catalog();
+    catalog.create_table(create_table_plan.into()).await?;
+
+    // no snapshot yet
+    let query = format!("select * from fuse_history('{}', '{}')", db_name, tbl_name);
+    let blocks = execute_query(&fixture, &query).await?;
+    assert_eq!(blocks.iter().map(|b| b.num_rows()).sum::<usize>(), 0);
+
+    // 2 snapshots, each of which appends a block of [1, 2, 3]
+    for _ in 0..2 {
+        let table = fixture.latest_default_table().await?;
+        let stream = Box::pin(futures::stream::iter(TestFixture::gen_block_stream(1, 1)));
+        let r = table.append_data(ctx.clone(), stream).await?;
+        table
+            .commit(ctx.clone(), r.try_collect().await?, false)
+            .await?;
+    }
+
+    // the latest snapshot comes first
+    let query = format!(
+        "select segment_count, block_count, row_count from fuse_history('{}', '{}')",
+        db_name, tbl_name
+    );
+    let blocks = execute_query(&fixture, &query).await?;
+    let expected = vec![
+        "+---------------+-------------+-----------+",
+        "| segment_count | block_count | row_count |",
+        "+---------------+-------------+-----------+",
+        "| 2             | 2           | 6         |",
+        "| 1             | 1           | 3         |",
+        "+---------------+-------------+-----------+",
+    ];
+    common_datablocks::assert_blocks_eq(expected, blocks.as_slice());
+
+    // only fuse tables are supported
+    let query = "select * from fuse_history('system', 'tables')";
+    let res = execute_query(&fixture, query).await;
+    assert_eq!(res.unwrap_err().code(), ErrorCode::BadArguments("").code());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_fuse_table_optimize_purge() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let ctx = fixture.ctx();
+    let da = ctx.get_data_accessor()?;
+
+    let create_table_plan = fixture.default_crate_table_plan();
+    let tbl_name = create_table_plan.table.clone();
+    let db_name = create_table_plan.db.clone();
+    let catalog = ctx.get_catalog();
+    catalog.create_table(create_table_plan.into()).await?;
+
+    // 3 snapshots, each of which appends a segment of a block
+    for _ in 0..3 {
+        let table = fixture.latest_default_table().await?;
+        let stream = Box::pin(futures::stream::iter(TestFixture::gen_block_stream(1, 1)));
+        let r = table.append_data(ctx.clone(), stream).await?;
+        table
+            .commit(ctx.clone(), r.try_collect().await?, false)
+            .await?;
+    }
+
+    let history_query = format!("select * from fuse_history('{}', '{}')", db_name, tbl_name);
+    let count_query = format!("select count(*) from {}.{}", db_name, tbl_name);
+    let (snapshots, segments, blocks) = referenced_objects(&fixture).await?;
+    assert_eq!((snapshots.len(), segments.len(), blocks.len()), (3, 3, 3));
+
+    // 1. the first snapshot is purged, but its segment is still referenced by the others
+    let query = format!(
+        "optimize table {}.{} purge retain 2 snapshots",
+        db_name, tbl_name
+    );
+    execute_query(&fixture, &query).await?;
+    assert_eq!(history_len(&fixture, &history_query).await?, 2);
+    assert_eq!(count_existing(da.as_ref(), &snapshots).await, 2);
+    assert_eq!(count_existing(da.as_ref(), &segments).await, 3);
+    assert_eq!(count_existing(da.as_ref(), &blocks).await, 3);
+    let blocks = execute_query(&fixture, &count_query).await?;
+    assert_eq!(blocks[0].column(0).try_get(0)?.as_u64()?, 9);
+
+    // 2. nothing is referenced after truncation, except the latest snapshot, the objects of the
+    // table that are referenced by no snapshot at all are removed only if they are older than
+    // the retained snapshot
+    let table = fixture.latest_default_table().await?;
+    let prefix = table
+        .as_any()
+        .downcast_ref::<FuseTable>()
+        .unwrap()
+        .location_prefix();
+    let orphans = vec![
+        format!("{}_b/orphan", prefix),
+        format!("{}_sg/orphan", prefix),
+    ];
+    let unrelated = "_b/orphan".to_owned();
+    for location in orphans.iter().chain(std::iter::once(&unrelated)) {
+        da.put(location, vec![1]).await?;
+    }
+    let query = format!("truncate table {}.{}", db_name, tbl_name);
+    execute_query(&fixture, &query).await?;
+    // the modification times kept by the file systems are coarse
+    tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+    let fresh_orphan = format!("{}_b/fresh_orphan", prefix);
+    da.put(&fresh_orphan, vec![1]).await?;
+    let query = format!("optimize table {}.{} purge", db_name, tbl_name);
+    execute_query(&fixture, &query).await?;
+    assert_eq!(history_len(&fixture, &history_query).await?, 1);
+    assert_eq!(count_existing(da.as_ref(), &snapshots).await, 0);
+    assert_eq!(count_existing(da.as_ref(), &segments).await, 0);
+    assert_eq!(count_existing(da.as_ref(), &blocks).await, 0);
+    assert_eq!(count_existing(da.as_ref(), &orphans).await, 0);
+    assert_eq!(
+        count_existing(da.as_ref(), &[fresh_orphan, unrelated]).await,
+        2
+    );
+    let blocks = execute_query(&fixture, &count_query).await?;
+    assert_eq!(blocks[0].column(0).try_get(0)?.as_u64()?, 0);
+
+    // 3. all the snapshots are taken within the retention period
+    let table = fixture.latest_default_table().await?;
+    let stream = Box::pin(futures::stream::iter(TestFixture::gen_block_stream(1, 1)));
+    let r = table.append_data(ctx.clone(), stream).await?;
+    table
+        .commit(ctx.clone(), r.try_collect().await?, false)
+        .await?;
+    let query = format!("optimize table {}.{} purge retain 1 day", db_name, tbl_name);
+    execute_query(&fixture, &query).await?;
+    assert_eq!(history_len(&fixture, &history_query).await?, 2);
+    let blocks = execute_query(&fixture, &count_query).await?;
+    assert_eq!(blocks[0].column(0).try_get(0)?.as_u64()?, 3);
+
+    Ok(())
+}
+
+async fn history_len(fixture: &TestFixture, history_query: &str) -> Result<usize> {
+    let blocks = execute_query(fixture, history_query).await?;
+    Ok(blocks.iter().map(|b| b.num_rows()).sum())
+}
+
+// the locations of the snapshots, segments and blocks referenced by the history of the table
+async fn referenced_objects(
+    fixture: &TestFixture,
+) -> Result<(Vec<String>, Vec<String>, Vec<String>)> {
+    let ctx = fixture.ctx();
+    let da = ctx.get_data_accessor()?;
+    let table = fixture.latest_default_table().await?;
+    let fuse_table = table.as_any().downcast_ref::<FuseTable>().unwrap();
+    let history = fuse_table.snapshot_history(ctx.clone()).await?;
+
+    let snapshots = history
+        .iter()
+        .map(|snapshot| {
+            io::snapshot_location(
+                &fuse_table.location_prefix(),
+                snapshot.snapshot_id.to_simple().to_string(),
+            )
+        })
+        .collect::<Vec<_>>();
+    let mut segments = history
+        .iter()
+        .flat_map(|snapshot| snapshot.segments.clone())
+        .collect::<Vec<_>>();
+    segments.sort();
+    segments.dedup();
+    let mut blocks = vec![];
+    for location in &segments {
+        let segment: SegmentInfo = read_obj(da.clone(), location.clone()).await?;
+        blocks.extend(segment.blocks.into_iter().map(|b| b.location.location));
+    }
+    Ok((snapshots, segments, blocks))
+}
+
+async fn count_existing(da: &dyn DataAccessor, locations: &[String]) -> usize {
+    let mut count = 0;
+    for location in locations {
+        if da.read(location).await.is_ok() {
+            count += 1;
+        }
+    }
+    count
+}
+
+#[tokio::test]
+async fn test_fuse_table_compact_if_needed() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let ctx = fixture.ctx();
+
+    let create_table_plan = fixture.default_crate_table_plan();
+    let tbl_name = create_table_plan.table.clone();
+    let db_name = create_table_plan.db.clone();
+    let catalog = ctx.get_catalog();
+    catalog.create_table(create_table_plan.into()).await?;
+
+    // 3 segments, each of which has a tiny block
+    for _ in 0..3 {
+        let table = fixture.latest_default_table().await?;
+        let stream = Box::pin(futures::stream::iter(TestFixture::gen_block_stream(1, 1)));
+        let r = table.append_data(ctx.clone(), stream).await?;
+        table
+            .commit(ctx.clone(), r.try_collect().await?, false)
+            .await?;
+    }
+
+    // 1. neither of the thresholds is reached
+    let table = fixture.latest_default_table().await?;
+    let fuse_table = table.as_any().downcast_ref::<FuseTable>().unwrap();
+    let thresholds = CompactionThresholds {
+        segment_count: 4,
+        small_block_count: 4,
+    };
+    assert!(
+        !fuse_table
+            .compact_if_needed(ctx.clone(), thresholds)
+            .await?
+    );
+    assert_eq!(latest_snapshot(&fixture).await?.segments.len(), 3);
+
+    // 2. the small blocks are merged into one
+    let thresholds = CompactionThresholds {
+        segment_count: 4,
+        small_block_count: 3,
+    };
+    assert!(
+        fuse_table
+            .compact_if_needed(ctx.clone(), thresholds)
+            .await?
+    );
+    let snapshot = latest_snapshot(&fixture).await?;
+    assert_eq!(snapshot.segments.len(), 1);
+    assert_eq!(snapshot.summary.block_count, 1);
+    assert_eq!(snapshot.summary.row_count, 9);
+
+    // 3. nothing to compact any more
+    let query = format!("optimize table {}.{} compact", db_name, tbl_name);
+    execute_query(&fixture, &query).await?;
+    assert_eq!(
+        latest_snapshot(&fixture).await?.snapshot_id,
+        snapshot.snapshot_id
+    );
+
+    let query = format!("select sum(id) from {}.{}", db_name, tbl_name);
+    let blocks = execute_query(&fixture, &query).await?;
+    assert_eq!(blocks[0].column(0).try_get(0)?.as_i64()?, 18);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_fuse_table_commit_conflict() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let ctx = fixture.ctx();
+
+    let create_table_plan = fixture.default_crate_table_plan();
+    let tbl_name = create_table_plan.table.clone();
+    let db_name = create_table_plan.db.clone();
+    let catalog = ctx.get_catalog();
+    catalog.create_table(create_table_plan.into()).await?;
+
+    // 1. two appends against the same version of table, both of them should be committed
+    let table = fixture.latest_default_table().await?;
+    let stream = Box::pin(futures::stream::iter(TestFixture::gen_block_stream(1, 1)));
+    let r1 = table.append_data(ctx.clone(), stream).await?;
+    let stream = Box::pin(futures::stream::iter(TestFixture::gen_block_stream(1, 1)));
+    let r2 = table.append_data(ctx.clone(), stream).await?;
+    table
+        .commit(ctx.clone(), r1.try_collect().await?, false)
+        .await?;
+    table
+        .commit(ctx.clone(), r2.try_collect().await?, false)
+        .await?;
+
+    let snapshot = latest_snapshot(&fixture).await?;
+    assert_eq!(snapshot.segments.len(), 2);
+    assert_eq!(snapshot.summary.row_count, 6);
+    let query = format!("select sum(id) from {}.{}", db_name, tbl_name);
+    let blocks = execute_query(&fixture, &query).await?;
+    assert_eq!(blocks[0].column(0).try_get(0)?.as_i64()?, 12);
+
+    // 2. the schema is changed by a concurrent transaction, the conflict can not be resolved
+    let table = fixture.latest_default_table().await?;
+    let stream = Box::pin(futures::stream::iter(TestFixture::gen_block_stream(1, 1)));
+    let r = table.append_data(ctx.clone(), stream).await?;
+    let query = format!("alter table {}.{} add column c Int64", db_name, tbl_name);
+    execute_query(&fixture, &query).await?;
+    let res = table
+        .commit(ctx.clone(), r.try_collect().await?, false)
+        .await;
+    assert!(res.is_err());
+    assert_eq!(
+        res.unwrap_err().code(),
+        ErrorCode::TableCommitConflict("").code()
+    );
+    assert_eq!(latest_snapshot(&fixture).await?.summary.row_count, 6);
+
+    // 3. a delete against a stale version of table is run again against the latest one
+    let table = fixture.latest_default_table().await?;
+    let query = format!("insert into {}.{} values (10, 100)", db_name, tbl_name);
+    execute_query(&fixture, &query).await?;
+    let delete_plan = DeletePlan {
+        db: db_name.clone(),
+        table: tbl_name.clone(),
+        selection: Some(col("id").eq(lit(1))),
+    };
+    table.delete(ctx.clone(), delete_plan).await?;
+
+    assert_eq!(latest_snapshot(&fixture).await?.summary.row_count, 5);
+    let query = format!("select sum(id) from {}.{}", db_name, tbl_name);
+    let blocks = execute_query(&fixture, &query).await?;
+    assert_eq!(blocks[0].column(0).try_get(0)?.as_i64()?, 20);
+
+    Ok(())
+}
+
+async fn latest_snapshot(fixture: &TestFixture) -> Result<TableSnapshot> {
+    let table = fixture.latest_default_table().await?;
+    let fuse_table = table.as_any().downcast_ref::<FuseTable>().unwrap();
+    Ok(fuse_table.table_snapshot(fixture.ctx()).await?.unwrap())
+}
+
+// every query is executed in a new query context, so that the latest table is used
+async fn execute_query(fixture: &TestFixture, query: &str) -> Result<Vec<DataBlock>> {
+    let ctx = fixture.new_query_ctx()?;
+    let plan = PlanParser::parse(query, ctx.clone()).await?;
+    let interpreter = InterpreterFactory::get(ctx, plan)?;
+    interpreter.execute(None).await?.try_collect().await
+}
+
 #[tokio::test]
 async fn test_fuse_table_compact() -> Result<()> {
     let fixture = TestFixture::new().await;
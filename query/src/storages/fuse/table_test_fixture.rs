@@ -30,8 +30,10 @@ use tempfile::TempDir;
 use uuid::Uuid;
 
 use crate::catalogs::Catalog;
+use crate::clusters::Cluster;
 use crate::configs::Config;
 use crate::sessions::QueryContext;
+use crate::sessions::QueryContextShared;
 use crate::storages::fuse::TBL_OPT_KEY_CHUNK_BLOCK_NUM;
 use crate::storages::Table;
 
@@ -77,6 +79,24 @@ impl TestFixture {
         self.ctx.clone()
     }
 
+    /// Creates a query context which shares the catalog and the storage of `ctx()`.
+    ///
+    /// Tables are cached in a query context, a new one is needed to see the changes
+    /// made by the previous queries.
+    pub fn new_query_ctx(&self) -> Result<Arc<QueryContext>> {
+        let session = self
+            .ctx
+            .get_sessions_manager()
+            .create_session("TestSession")?;
+        let ctx = QueryContext::from_shared(QueryContextShared::try_create(
+            self.ctx.get_config(),
+            Arc::new(session.as_ref().clone()),
+            Cluster::empty(),
+        ));
+        ctx.get_settings().set_max_threads(8)?;
+        Ok(ctx)
+    }
+
     pub fn default_db_name(&self) -> String {
         gen_db_name(&self.prefix)
     }
@@ -75,19 +75,15 @@ impl RangeFilter {
     }
 
     pub fn eval(&self, stats: &BlockStatistics) -> Result<bool> {
-        let columns = self
-            .stat_columns
-            .iter()
-            .map(|c| {
-                let stat = stats.get(&c.column_id).ok_or_else(|| {
-                    ErrorCode::UnknownException(format!(
-                        "Unable to get the colStats by ColumnId: {}",
-                        c.column_id
-                    ))
-                })?;
-                c.apply_stat_value(stat)?.to_array()
-            })
-            .collect::<Result<Vec<_>>>()?;
+        let mut columns = Vec::with_capacity(self.stat_columns.len());
+        for c in &self.stat_columns {
+            match stats.get(&c.column_id) {
+                Some(stat) => columns.push(c.apply_stat_value(stat)?.to_array()?),
+                // the column is not stored in the block (e.g. it is added to the table after the
+                // block has been written), the block can not be pruned by it.
+                None => return Ok(true),
+            }
+        }
         let data_block = DataBlock::create_by_array(self.schema.clone(), columns);
         let executed_data_block = self.executor.execute(&data_block)?;
 
@@ -106,6 +102,9 @@ pub(crate) fn build_verifiable_expr(
 
     let (exprs, op) = match expr {
         Expression::Literal { .. } => return expr.clone(),
+        Expression::ScalarFunction { op, args } if op.to_lowercase() == "in" => {
+            return build_verifiable_in_list(args, schema, stat_columns).unwrap_or(unhandled);
+        }
         Expression::ScalarFunction { op, args } => (args.clone(), op.clone()),
         Expression::BinaryExpression { left, op, right } => match op.to_lowercase().as_str() {
             "and" => {
@@ -130,13 +129,35 @@ pub(crate) fn build_verifiable_expr(
         .map_or(unhandled.clone(), |mut v| v.build().unwrap_or(unhandled))
 }
 
+/// Rewrite `x IN (v1, v2, ...)` to `x = v1 OR x = v2 OR ...` when all the values are literals.
+fn build_verifiable_in_list(
+    args: &[Expression],
+    schema: &DataSchemaRef,
+    stat_columns: &mut StatColumns,
+) -> Option<Expression> {
+    let (column, values) = args.split_first()?;
+    if values.is_empty()
+        || values
+            .iter()
+            .any(|v| !matches!(v, Expression::Literal { .. }))
+    {
+        return None;
+    }
+
+    values
+        .iter()
+        .map(|value| build_verifiable_expr(&column.clone().eq(value.clone()), schema, stat_columns))
+        .reduce(|acc, expr| acc.or(expr))
+}
+
 fn inverse_operator(op: &str) -> Result<&str> {
     match op {
         "<" => Ok(">"),
         "<=" => Ok(">="),
         ">" => Ok("<"),
         ">=" => Ok("<="),
-        "like" | "not like" | "ilike" | "not ilike" => Err(ErrorCode::UnknownException(format!(
+        "like" | "not like" | "ilike" | "not ilike" | "regexp" | "not regexp" | "rlike"
+        | "not rlike" => Err(ErrorCode::UnknownException(format!(
             "cannot inverse the operator: {:?}",
             op
         ))),
@@ -329,7 +350,7 @@ impl<'a> VerifiableExprBuilder<'a> {
     }
 
     fn build(&mut self) -> Result<Expression> {
-        // TODO: support in/not in.
+        // TODO: support not in.
         match self.op {
             "isnull" => {
                 let nulls_expr = self.nulls_column_expr()?;
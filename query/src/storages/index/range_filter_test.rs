@@ -77,6 +77,16 @@ fn test_range_filter() -> Result<()> {
             expr: col("a").eq(lit(1)).and(col("b").not_eq(lit(3))),
             expect: true,
         },
+        Test {
+            name: "a in (0, 30)",
+            expr: Expression::create_scalar_function("in", vec![col("a"), lit(0), lit(30)]),
+            expect: false,
+        },
+        Test {
+            name: "b in (1, 5)",
+            expr: Expression::create_scalar_function("in", vec![col("b"), lit(1), lit(5)]),
+            expect: true,
+        },
         Test {
             name: "a is null",
             expr: Expression::create_scalar_function("isNull", vec![col("a")]),
@@ -137,6 +147,66 @@ fn test_range_filter() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_range_filter_datetime64() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![DataField::new(
+        "a",
+        DataType::DateTime64(3, None),
+        false,
+    )]);
+
+    // [2021-08-30 10:47:42.100, 2021-08-30 10:47:42.900]
+    let mut stats: BlockStatistics = HashMap::new();
+    stats.insert(0u32, ColumnStatistics {
+        min: DataValue::Int64(Some(1630320462100)),
+        max: DataValue::Int64(Some(1630320462900)),
+        null_count: 0,
+        in_memory_size: 0,
+    });
+
+    struct Test {
+        name: &'static str,
+        expr: Expression,
+        expect: bool,
+    }
+
+    let tests: Vec<Test> = vec![
+        Test {
+            name: "a > '2021-08-30 10:47:42.950'",
+            expr: col("a").gt(lit("2021-08-30 10:47:42.950".as_bytes())),
+            expect: false,
+        },
+        Test {
+            name: "a >= '2021-08-30 10:47:42.900'",
+            expr: col("a").gt_eq(lit("2021-08-30 10:47:42.900".as_bytes())),
+            expect: true,
+        },
+        Test {
+            name: "a < '2021-08-30 10:47:42'",
+            expr: col("a").lt(lit("2021-08-30 10:47:42".as_bytes())),
+            expect: false,
+        },
+        Test {
+            name: "a = '2021-08-30 10:47:42.500'",
+            expr: col("a").eq(lit("2021-08-30 10:47:42.500".as_bytes())),
+            expect: true,
+        },
+        Test {
+            name: "a = '2021-08-30 10:47:42.050'",
+            expr: col("a").eq(lit("2021-08-30 10:47:42.050".as_bytes())),
+            expect: false,
+        },
+    ];
+
+    for test in tests {
+        let prune = RangeFilter::try_create(&test.expr, schema.clone())?;
+        let actual = prune.eval(&stats)?;
+        assert_eq!(test.expect, actual, "{:#?}", test.name);
+    }
+
+    Ok(())
+}
+
 #[test]
 fn test_build_verifiable_function() -> Result<()> {
     let schema = DataSchemaRefExt::create(vec![
@@ -167,6 +237,16 @@ fn test_build_verifiable_function() -> Result<()> {
             expr: col("a").eq(lit(1)).and(col("b").not_eq(lit(3))),
             expect: "(((min_a <= 1) and (max_a >= 1)) and ((min_b != 3) or (max_b != 3)))",
         },
+        Test {
+            name: "a in (1, 3)",
+            expr: Expression::create_scalar_function("in", vec![col("a"), lit(1), lit(3)]),
+            expect: "(((min_a <= 1) and (max_a >= 1)) or ((min_a <= 3) and (max_a >= 3)))",
+        },
+        Test {
+            name: "a not in (1, 3)",
+            expr: Expression::create_scalar_function("not_in", vec![col("a"), lit(1), lit(3)]),
+            expect: "true",
+        },
         Test {
             name: "a is null",
             expr: Expression::create_scalar_function("isNull", vec![col("a")]),
@@ -40,6 +40,9 @@ pub struct MemoryTable {
 }
 
 impl MemoryTable {
+    /// The engine of the tables which the CTEs are materialized into, see `create_with_blocks`.
+    pub const MATERIALIZED_CTE_ENGINE: &'static str = "MaterializedCTE";
+
     pub fn try_create(ctx: StorageContext, table_info: TableInfo) -> Result<Box<dyn Table>> {
         let table_id = &table_info.ident.table_id;
         let blocks = {
@@ -58,6 +61,14 @@ impl MemoryTable {
         let table = Self { table_info, blocks };
         Ok(Box::new(table))
     }
+
+    /// Create a table holding the blocks by itself, it is not registered in any database.
+    pub fn create_with_blocks(table_info: TableInfo, blocks: Vec<DataBlock>) -> MemoryTable {
+        MemoryTable {
+            table_info,
+            blocks: Arc::new(RwLock::new(blocks)),
+        }
+    }
 }
 
 #[async_trait::async_trait]
@@ -13,7 +13,6 @@
 // limitations under the License.
 
 mod csv;
-mod fuse;
 mod index;
 mod memory;
 mod null;
@@ -22,13 +21,16 @@ mod storage_context;
 mod storage_factory;
 mod system;
 
+pub mod fuse;
 pub mod github;
 mod storage_table;
 mod storage_table_read_plan;
 
+pub use memory::MemoryTable;
 pub use storage_context::StorageContext;
 pub use storage_factory::StorageCreator;
 pub use storage_factory::StorageFactory;
+pub use storage_table::NavigationPoint;
 pub use storage_table::Table;
 pub use storage_table_read_plan::ToReadDataSourcePlan;
 pub use system::SystemDatabase;
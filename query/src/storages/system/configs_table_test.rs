@@ -38,7 +38,7 @@ async fn test_configs_table() -> Result<()> {
     let result = stream.try_collect::<Vec<_>>().await?;
     let block = &result[0];
     assert_eq!(block.num_columns(), 4);
-    assert_eq!(block.num_rows(), 33);
+    assert_eq!(block.num_rows(), 36);
 
     let expected = vec![
         "+-----------------------------------+------------------+-------+-------------+",
@@ -51,6 +51,9 @@ async fn test_configs_table() -> Result<()> {
         "| clickhouse_handler_port           | 9000             | query |             |",
         "| cluster_id                        |                  | query |             |",
         "| flight_api_address                | 127.0.0.1:9090   | query |             |",
+        "| fuse_compact_block_threshold      | 100              | query |             |",
+        "| fuse_compact_interval_secs        | 0                | query |             |",
+        "| fuse_compact_segment_threshold    | 100              | query |             |",
         "| http_api_address                  | 127.0.0.1:8080   | query |             |",
         "| http_handler_host                 | 127.0.0.1        | query |             |",
         "| http_handler_port                 | 8000             | query |             |",
@@ -0,0 +1,216 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+//
+
+use std::any::Any;
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_types::TableIdent;
+use common_meta_types::TableInfo;
+use common_meta_types::TableMeta;
+use common_planners::Expression;
+use common_planners::ReadDataSourcePlan;
+use common_streams::DataBlockStream;
+use common_streams::SendableDataBlockStream;
+
+use crate::catalogs::Catalog;
+use crate::sessions::QueryContext;
+use crate::storages::fuse::FuseTable;
+use crate::storages::Table;
+use crate::table_functions::table_function_factory::TableArgs;
+use crate::table_functions::TableFunction;
+
+/// Lists the snapshots of a fuse table, e.g. `SELECT * FROM fuse_history('db', 'table')`,
+/// from the current snapshot back to the first one.
+pub struct FuseHistoryTable {
+    table_info: TableInfo,
+    arg_database_name: String,
+    arg_table_name: String,
+}
+
+impl FuseHistoryTable {
+    pub fn create(
+        database_name: &str,
+        table_func_name: &str,
+        table_id: u64,
+        table_args: TableArgs,
+    ) -> Result<Arc<dyn TableFunction>> {
+        let (arg_database_name, arg_table_name) = parse_table_args(table_func_name, &table_args)?;
+
+        let schema = DataSchemaRefExt::create(vec![
+            DataField::new("snapshot_id", DataType::String, false),
+            DataField::new("prev_snapshot_id", DataType::String, true),
+            DataField::new("timestamp", DataType::String, true),
+            DataField::new("segment_count", DataType::UInt64, false),
+            DataField::new("block_count", DataType::UInt64, false),
+            DataField::new("row_count", DataType::UInt64, false),
+            DataField::new("bytes_uncompressed", DataType::UInt64, false),
+            DataField::new("bytes_compressed", DataType::UInt64, false),
+        ]);
+
+        let table_info = TableInfo {
+            ident: TableIdent::new(table_id, 0),
+            desc: format!("'{}'.'{}'", database_name, table_func_name),
+            name: table_func_name.to_string(),
+            meta: TableMeta {
+                schema,
+                engine: "FuseHistory".to_string(),
+                options: Default::default(),
+            },
+        };
+
+        Ok(Arc::new(FuseHistoryTable {
+            table_info,
+            arg_database_name,
+            arg_table_name,
+        }))
+    }
+}
+
+fn parse_table_args(table_func_name: &str, table_args: &TableArgs) -> Result<(String, String)> {
+    let bad_arguments = || {
+        ErrorCode::BadArguments(format!(
+            "Must have exactly two string arguments (database and table name) for table function.{}",
+            table_func_name
+        ))
+    };
+
+    let args = match table_args {
+        Some(args) if args.len() == 2 => args,
+        _ => return Err(bad_arguments()),
+    };
+
+    let string_arg = |arg: &Expression| match arg {
+        Expression::Literal {
+            value: DataValue::String(Some(value)),
+            ..
+        } => Ok(String::from_utf8(value.clone())?),
+        _ => Err(bad_arguments()),
+    };
+
+    Ok((string_arg(&args[0])?, string_arg(&args[1])?))
+}
+
+#[async_trait::async_trait]
+impl Table for FuseHistoryTable {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    fn table_args(&self) -> Option<Vec<Expression>> {
+        Some(vec![
+            Expression::create_literal(DataValue::String(Some(
+                self.arg_database_name.as_bytes().to_vec(),
+            ))),
+            Expression::create_literal(DataValue::String(Some(
+                self.arg_table_name.as_bytes().to_vec(),
+            ))),
+        ])
+    }
+
+    async fn read(
+        &self,
+        ctx: Arc<QueryContext>,
+        _plan: &ReadDataSourcePlan,
+    ) -> Result<SendableDataBlockStream> {
+        let table = ctx
+            .get_catalog()
+            .get_table(&self.arg_database_name, &self.arg_table_name)
+            .await?;
+        let fuse_table = table.as_any().downcast_ref::<FuseTable>().ok_or_else(|| {
+            ErrorCode::BadArguments(format!(
+                "Expects a fuse table, but table {}.{} is of engine {}",
+                self.arg_database_name,
+                self.arg_table_name,
+                table.engine()
+            ))
+        })?;
+
+        let snapshots = fuse_table.snapshot_history(ctx).await?;
+
+        let snapshot_ids = snapshots
+            .iter()
+            .map(|s| s.snapshot_id.to_simple().to_string())
+            .collect::<Vec<_>>();
+        let prev_snapshot_ids = snapshots
+            .iter()
+            .map(|s| s.prev_snapshot_id.map(|id| id.to_simple().to_string()))
+            .collect::<Vec<_>>();
+        let timestamps = snapshots
+            .iter()
+            .map(|s| s.timestamp.map(|t| t.to_rfc3339()))
+            .collect::<Vec<_>>();
+        let segment_counts = snapshots
+            .iter()
+            .map(|s| s.segments.len() as u64)
+            .collect::<Vec<_>>();
+        let block_counts = snapshots
+            .iter()
+            .map(|s| s.summary.block_count)
+            .collect::<Vec<_>>();
+        let row_counts = snapshots
+            .iter()
+            .map(|s| s.summary.row_count)
+            .collect::<Vec<_>>();
+        let uncompressed_sizes = snapshots
+            .iter()
+            .map(|s| s.summary.uncompressed_byte_size)
+            .collect::<Vec<_>>();
+        let compressed_sizes = snapshots
+            .iter()
+            .map(|s| s.summary.compressed_byte_size)
+            .collect::<Vec<_>>();
+
+        let snapshot_ids: Vec<&str> = snapshot_ids.iter().map(|s| s.as_str()).collect();
+        let prev_snapshot_ids: Vec<Option<&str>> =
+            prev_snapshot_ids.iter().map(|s| s.as_deref()).collect();
+        let timestamps: Vec<Option<&str>> = timestamps.iter().map(|s| s.as_deref()).collect();
+
+        let block = DataBlock::create_by_array(self.table_info.schema(), vec![
+            Series::new(snapshot_ids),
+            Series::new(prev_snapshot_ids),
+            Series::new(timestamps),
+            Series::new(segment_counts),
+            Series::new(block_counts),
+            Series::new(row_counts),
+            Series::new(uncompressed_sizes),
+            Series::new(compressed_sizes),
+        ]);
+
+        Ok(Box::pin(DataBlockStream::create(
+            self.table_info.schema(),
+            None,
+            vec![block],
+        )))
+    }
+}
+
+impl TableFunction for FuseHistoryTable {
+    fn function_name(&self) -> &str {
+        self.name()
+    }
+
+    fn as_table<'a>(self: Arc<Self>) -> Arc<dyn Table + 'a>
+    where Self: 'a {
+        self
+    }
+}
@@ -18,12 +18,14 @@ mod memory_block_part_test;
 #[cfg(test)]
 mod numbers_table_test;
 
+mod fuse_history_table;
 mod memory_block_part;
 mod numbers_stream;
 mod numbers_table;
 mod table_function;
 mod table_function_factory;
 
+pub use fuse_history_table::FuseHistoryTable;
 pub use memory_block_part::generate_block_parts;
 pub use numbers_table::NumbersTable;
 pub use table_function::TableFunction;
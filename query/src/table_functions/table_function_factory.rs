@@ -24,6 +24,7 @@ use common_planners::Expression;
 
 use crate::catalogs::SYS_TBL_FUC_ID_END;
 use crate::catalogs::SYS_TBL_FUNC_ID_BEGIN;
+use crate::table_functions::FuseHistoryTable;
 use crate::table_functions::NumbersTable;
 use crate::table_functions::TableFunction;
 
@@ -93,6 +94,11 @@ impl TableFunctionFactory {
             (next_id(), number_table_func_creator),
         );
 
+        creators.insert(
+            "fuse_history".to_string(),
+            (next_id(), Arc::new(FuseHistoryTable::create)),
+        );
+
         TableFunctionFactory {
             creators: RwLock::new(creators),
         }
@@ -0,0 +1,23 @@
+SELECT toTypeName(CAST(1.5 AS DECIMAL(10, 2)));
+SELECT CAST('123.456' AS DECIMAL(10, 2));
+SELECT CAST(-0.125 AS DECIMAL(10, 2));
+SELECT CAST(1 AS DECIMAL(5, 2)) + CAST(0.1 AS DECIMAL(5, 2));
+SELECT CAST(0.1 AS DECIMAL(10, 1)) * 3;
+SELECT CAST(10 AS DECIMAL(10, 2)) / 3;
+SELECT CAST(1.25 AS DECIMAL(10, 2)) = 1.25;
+SELECT CAST('1.5' AS DECIMAL(10, 1)) > 1;
+SELECT CAST(1.5 AS DECIMAL(18, 4)) < toInt64(123456789012345678);
+SELECT CAST(CAST(2.75 AS DECIMAL(10, 2)) AS Int32);
+SELECT CAST(Null AS DECIMAL(10, 2));
+SELECT CAST(1 AS DECIMAL(20, 2)); -- {ErrorCode 6}
+SELECT CAST(1000 AS DECIMAL(5, 2)); -- {ErrorCode 49}
+SELECT CAST(1 AS DECIMAL(5, 2)) / 0; -- {ErrorCode 6}
+
+CREATE TABLE IF NOT EXISTS t(a DECIMAL(10, 2), b INT) Engine = Memory;
+INSERT INTO t VALUES (1.10, 1), (2.20, 1), (3.30, 2);
+SELECT sum(a), avg(a) FROM t;
+SELECT sum(a), avg(a) FROM t WHERE b > 10;
+SELECT b, sum(a) FROM t GROUP BY b ORDER BY b;
+SELECT sum(CAST(999999999999999999 AS DECIMAL(18, 0)) * if(number < 10, 1, -1)) FROM numbers(20);
+SELECT a FROM t WHERE a > 2 ORDER BY a;
+DROP TABLE t;
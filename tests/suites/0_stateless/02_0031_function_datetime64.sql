@@ -0,0 +1,18 @@
+SELECT toTypeName(toDateTime64('2021-08-30 10:47:42.123'));
+SELECT toDateTime64('2021-08-30 10:47:42.123456');
+SELECT toDateTime64('2021-08-30 10:47:42');
+SELECT toDateTime64(1630320462123);
+SELECT toSecond(toDateTime64('2021-08-30 10:47:42.999'));
+SELECT toStartOfSecond(toDateTime64('2021-08-30 10:47:42.999'));
+SELECT toStartOfMinute(toDateTime64('2021-08-30 10:47:42.999'));
+SELECT addSeconds(toDateTime64('2021-08-30 10:47:42.123'), 18);
+SELECT addMonths(toDateTime64('2021-01-31 10:47:42.123'), 1);
+SELECT toDateTime64('2021-08-30 10:47:42.123') > toDateTime('2021-08-30 10:47:42');
+SELECT toDateTime(toDateTime64('2021-08-30 10:47:42.999'));
+SELECT toDateTime64('2021-08-30 10:47:42.500') - toDateTime64('2021-08-30 10:47:42.123');
+
+CREATE TABLE IF NOT EXISTS t(a DateTime64(3), b DateTime64(6)) Engine = Memory;
+INSERT INTO t VALUES ('2021-08-30 10:47:42.500', '2021-08-30 10:47:42.123456'), ('2021-08-30 10:47:42.100', '2021-08-30 10:47:42');
+SELECT * FROM t ORDER BY a;
+SELECT a FROM t WHERE a > '2021-08-30 10:47:42.200';
+DROP TABLE t;
@@ -0,0 +1,19 @@
+SELECT parse_json('{"b": [1, 2], "a": 1}');
+SELECT toTypeName(parse_json('1'));
+SELECT get_path(parse_json('{"a": {"b": [1, {"c": "d"}]}}'), 'a.b[1].c');
+SELECT json_extract_path_text('{"a": {"b": [1, {"c": "d"}]}}', 'a.b[1].c');
+SELECT json_extract_path_text('{"a": 1}', 'x');
+SELECT check_json('{"a": 1}');
+SELECT check_json('{"a": 1') IS NOT NULL;
+SELECT CAST(parse_json('"12"') AS Int32) + 1;
+SELECT CAST('[1, "x", null]' AS VARIANT);
+SELECT parse_json('{a: 1}'); -- {ErrorCode 46}
+SELECT get_path(parse_json('{"a": 1}'), 'a..b'); -- {ErrorCode 6}
+
+CREATE TABLE IF NOT EXISTS t(id Int32, v Variant) Engine = Memory;
+INSERT INTO t VALUES (1, '{"name": "a", "tags": ["x", "y"], "age": 10}'), (2, '{"name": "b", "age": 20.5}'), (3, '[1, 2, 3]');
+SELECT id, v['name'], v['tags'][1] FROM t ORDER BY id;
+SELECT id FROM t WHERE v['name'] = 'b';
+SELECT sum(CAST(v['age'] AS Float64)) FROM t;
+SELECT id, get_path(v, 'tags[0]') FROM t ORDER BY id;
+DROP TABLE t;
@@ -0,0 +1,14 @@
+SELECT map('a', 1, 'b', 2);
+SELECT toTypeName(map('a', 1, 'b', 2));
+SELECT get(map('a', 1, 'b', 2), 'b');
+SELECT mapKeys(map('a', 1, 'b', 2)), mapValues(map('a', 1, 'b', 2));
+SELECT map('a', 1, 'b'); -- {ErrorCode 28}
+SELECT map(1.5, 1); -- {ErrorCode 7}
+
+CREATE TABLE IF NOT EXISTS t(id Int32, m Map(String, Int64)) Engine = Memory;
+INSERT INTO t VALUES (1, map('a', 1, 'b', 2)), (2, map('a', 10)), (3, map('b', 5, 'c', 7));
+SELECT id, m, m['a'] FROM t ORDER BY id;
+SELECT id FROM t WHERE m['b'] > 2;
+SELECT sumMap(m), minMap(m), maxMap(m) FROM t;
+SELECT id % 2 AS g, sumMap(m) FROM t GROUP BY g ORDER BY g;
+DROP TABLE t;
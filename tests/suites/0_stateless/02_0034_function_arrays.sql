@@ -0,0 +1,18 @@
+SELECT array(1, 2, 3), array('a', 'b');
+SELECT length(array(1, 2, 3));
+SELECT array(10, 20, 30)[1], array(10, 20, 30)[4];
+SELECT has(array(1, 2, 3), 2), has(array(1, 2, 3), 5);
+SELECT array(); -- {ErrorCode 28}
+
+SELECT number, arrayJoin(array(number, number * 10)) AS x FROM numbers(3) ORDER BY number, x;
+SELECT sum(x) FROM (SELECT unnest(array(number, number)) AS x FROM numbers(5));
+
+SELECT groupArray(number) FROM numbers(5);
+SELECT length(groupArray(2)(number)) FROM numbers(10);
+
+SELECT arrayMap(x -> x * 2, array(1, 2, 3));
+SELECT arrayMap((x, y) -> x + y, array(1, 2), array(10, 20));
+SELECT arrayFilter(x -> x > 1, array(1, 2, 3));
+SELECT number, arrayMap(x -> x + 1, array(number, number * 2)) FROM numbers(2) ORDER BY number;
+SELECT arrayMap(x -> x + number, array(1, 2)) FROM numbers(1); -- {ErrorCode 5}
+SELECT arrayFilter(x -> x + 1, array(1, 2)); -- {ErrorCode 7}
@@ -0,0 +1,17 @@
+SELECT lower('ABC'), lcase('Ab'), upper('abc'), ucase('aB');
+SELECT length('你好'), char_length('你好'), character_length('abc'), octet_length('abc');
+SELECT length(NULL);
+SELECT length(123), octet_length(1.5);
+SELECT concat('a', 'b', 'c'), concat('a', NULL), concat('a', 1);
+SELECT concat_ws(',', 'a', NULL, 'b'), concat_ws(NULL, 'a', 'b');
+SELECT replace('aXbXc', 'X', '--'), replace('abc', '', 'Z'), replace(NULL, 'a', 'b');
+SELECT reverse('abc'), reverse('你好'), reverse('');
+SELECT left('hello', 2), right('hello', 2), left('你好世界', 3), right('abc', 10), left('abc', -1);
+SELECT lpad('hi', 5, 'xy'), rpad('hi', 5, 'xy'), lpad('hello', 3, '?'), lpad('hi', -1, 'x');
+SELECT lpad('hi', 1000001, 'x'); -- {ErrorCode 6}
+SELECT split_part('a,b,c', ',', 2), split_part('a,b,c', ',', -1), split_part('a,b,c', ',', 4);
+SELECT starts_with('hello', 'he'), starts_with('hello', 'lo'), ends_with('hello', 'lo'), ends_with(NULL, 'lo');
+SELECT concat('[', space(3), ']'), space(0);
+SELECT space(1000001); -- {ErrorCode 6}
+SELECT concat(); -- {ErrorCode 28}
+SELECT upper(name) FROM (SELECT concat('n', toString(number)) AS name FROM numbers(3)) ORDER BY name;
@@ -0,0 +1,10 @@
+SELECT regexp_like('GET /index.html', '^(GET|POST) '), regexp_like('get /', '^GET'), regexp_like('get /', '^GET', 'i');
+SELECT regexp_like(NULL, 'a'), regexp_like('a', NULL);
+SELECT count(*) FROM numbers(100) WHERE regexp_like(toString(number), '^[1-3]5$');
+SELECT regexp_instr('dog cat dog', 'dog'), regexp_instr('dog cat dog', 'dog', 2), regexp_instr('dog cat dog', 'cat', 1, 1, 1), regexp_instr('dog cat dog', 'cow');
+SELECT regexp_substr('abc def ghi', '[a-z]+', 1, 3), regexp_substr('abc', 'x');
+SELECT regexp_replace('a b c', 'b|c', 'X'), regexp_replace('abc abc abc', 'abc', 'X', 1, 2), regexp_replace('2021-12-01', '([0-9]+)-([0-9]+)-([0-9]+)', '$3/$2/$1');
+SELECT extractAll('a=1, b=22, c=333', '[a-z]=([0-9]+)'), extractAll('abc', '[0-9]');
+SELECT regexp_like('abc', '('); -- {ErrorCode 6}
+SELECT regexp_substr('abc', 'a', 0); -- {ErrorCode 6}
+SELECT regexp_like('abc'); -- {ErrorCode 28}
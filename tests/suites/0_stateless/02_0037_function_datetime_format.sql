@@ -0,0 +1,36 @@
+SELECT formatDateTime(toDateTime('2021-12-01 04:00:30'), '%Y-%m-%d %H:%M:%S Q%Q');
+SELECT formatDateTime(toDateTime('2021-12-01 04:00:30'), '%F %T', 'Asia/Shanghai');
+SELECT formatDateTime(toDate('2021-12-01'), '%d/%m/%y %j %u');
+SELECT formatDateTime(toDateTime('2021-12-01 04:00:30'), '%K'); -- {ErrorCode 6}
+SELECT date_format(toDateTime('2021-12-01 04:00:30'), '%W %D %M %Y %H:%i:%s');
+SELECT date_format('2021-12-01 04:00:30', '%Y%m%d');
+
+SELECT parseDateTimeBestEffort('2021-12-01T12:00:30+08:00');
+SELECT parseDateTimeBestEffort('Dec 1, 2021');
+SELECT parseDateTimeBestEffort('2021/12/01 12:00:30', 'Asia/Shanghai');
+SELECT parseDateTimeBestEffort('not a date');
+SELECT str_to_date('01/12/2021 04:00:30', '%d/%m/%Y %H:%i:%s');
+SELECT str_to_date('December 1, 2021', '%M %e, %Y');
+SELECT str_to_date('2021', '%d/%m/%Y');
+
+SELECT toUnixTimestamp(toDateTime('2021-12-01 04:00:30'));
+SELECT toUnixTimestamp('2021-12-01 12:00:30', 'Asia/Shanghai');
+SELECT fromUnixTime(1638331230);
+SELECT fromUnixTime(1638331230, '%Y-%m-%d %T');
+
+SELECT dateDiff('day', toDateTime('2021-12-01 23:00:00'), toDateTime('2021-12-02 01:00:00'));
+SELECT dateDiff('month', toDate('2021-01-31'), toDate('2021-03-01'));
+SELECT dateDiff('hours', toDateTime('2021-12-01 00:00:00'), toDateTime('2021-11-30 12:00:00'));
+SELECT dateDiff('fortnight', toDate('2021-01-31'), toDate('2021-03-01')); -- {ErrorCode 6}
+SELECT date_trunc('month', toDateTime('2021-12-15 04:00:30'));
+SELECT date_trunc('quarter', toDate('2021-12-15'));
+SELECT date_trunc('week', toDate('2021-12-01'));
+SELECT date_trunc('hour', toDateTime('2021-12-15 04:50:30'));
+
+SELECT toYear(toDateTime('2021-12-31 16:00:00')), toQuarter(toDateTime('2021-12-31 16:00:00'));
+SELECT toYear(toTimeZone(toDateTime('2021-12-31 16:00:00'), 'Asia/Shanghai')), toQuarter(toTimeZone(toDateTime('2021-12-31 16:00:00'), 'Asia/Shanghai'));
+SELECT toHour(toTimeZone(toDateTime('2021-12-01 04:00:30'), 'Asia/Shanghai'));
+SELECT toTimeZone(toDateTime('2021-12-01 04:00:30'), 'Mars/Olympus'); -- {ErrorCode 6}
+SELECT EXTRACT(YEAR FROM toDateTime('2021-12-01 04:00:30')), EXTRACT(MONTH FROM toDateTime('2021-12-01 04:00:30')), EXTRACT(DAY FROM toDateTime('2021-12-01 04:00:30'));
+SELECT EXTRACT(HOUR FROM toDateTime('2021-12-01 04:00:30')), EXTRACT(MINUTE FROM toDateTime('2021-12-01 04:00:30')), EXTRACT(SECOND FROM toDateTime('2021-12-01 04:00:30'));
+SELECT EXTRACT(HOUR FROM toTimeZone(toDateTime('2021-12-01 04:00:30'), 'Asia/Shanghai'));
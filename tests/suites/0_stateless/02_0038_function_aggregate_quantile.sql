@@ -0,0 +1,4 @@
+SELECT median(number), quantile(0.25)(number), percentile_cont(0.5)(number), percentile_disc(0.5)(number) FROM numbers(10);
+SELECT number % 2 AS k, median(number) FROM numbers(10) GROUP BY k ORDER BY k;
+SELECT quantileTDigest(0.5)(number) > 490 AND quantileTDigest(0.5)(number) < 510 FROM numbers(1000);
+SELECT quantile(2)(number) FROM numbers(10); -- {ErrorCode 6}
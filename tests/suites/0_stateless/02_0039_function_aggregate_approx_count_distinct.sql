@@ -0,0 +1,5 @@
+SELECT approx_count_distinct(number) BETWEEN 950 AND 1050 FROM numbers(1000);
+SELECT uniqHLL12(number % 100) BETWEEN 95 AND 105 FROM numbers_mt(100000);
+SELECT uniqHLL12(number % 10, number % 3) BETWEEN 28 AND 32 FROM numbers(1000);
+SELECT approx_count_distinct(number) FROM numbers(0);
+SELECT approx_count_distinct() FROM numbers(10); -- {ErrorCode 28}
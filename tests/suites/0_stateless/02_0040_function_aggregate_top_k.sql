@@ -0,0 +1,7 @@
+SELECT topK(2)(number % 3) FROM numbers(10);
+SELECT topK(1)(number % 10 % 7) FROM numbers(1000);
+SELECT groupUniqArray(number % 3) FROM numbers(10);
+SELECT length(groupUniqArray(2)(number % 3)) FROM numbers(10);
+SELECT any(number) < 10, anyLast(number) < 10 FROM numbers(10);
+SELECT any(number) FROM numbers(0);
+SELECT topK(0)(number) FROM numbers(10); -- {ErrorCode 6}
@@ -0,0 +1,4 @@
+select a.number, b.number from numbers(5) as a join numbers(3) as b on a.number = b.number order by a.number;
+select a.number, b.number from numbers(4) as a left join numbers(2) as b on a.number = b.number order by a.number;
+select a.number, b.number from numbers(3) as a join numbers(3) as b using (number) where a.number > 0 order by a.number;
+select count() from numbers(3) as a, numbers(4) as b;
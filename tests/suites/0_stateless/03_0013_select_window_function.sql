@@ -0,0 +1,11 @@
+SELECT number, row_number() OVER (ORDER BY number DESC) FROM numbers(5) ORDER BY number;
+SELECT number % 3 AS k, number, rank() OVER (PARTITION BY number % 3 ORDER BY number) FROM numbers(6) ORDER BY number;
+SELECT number % 3 AS k, rank() OVER (ORDER BY number % 3), dense_rank() OVER (ORDER BY number % 3) FROM numbers(6) ORDER BY k;
+SELECT number, lag(number) OVER (ORDER BY number), lead(number, 2, 100) OVER (ORDER BY number) FROM numbers(5) ORDER BY number;
+SELECT number, sum(number) OVER (ORDER BY number ROWS BETWEEN 1 PRECEDING AND 1 FOLLOWING), count(*) OVER (), max(number) OVER (PARTITION BY number % 2) FROM numbers(5) ORDER BY number;
+SELECT number, sum(number) OVER (ORDER BY number % 2) FROM numbers(5) ORDER BY number;
+SELECT number, first_value(number) OVER (PARTITION BY number % 2 ORDER BY number), last_value(number) OVER (PARTITION BY number % 2 ORDER BY number ROWS BETWEEN CURRENT ROW AND UNBOUNDED FOLLOWING) FROM numbers(6) ORDER BY number;
+SELECT number % 2 AS k, sum(number), row_number() OVER (ORDER BY sum(number) DESC) FROM numbers(10) GROUP BY number % 2 ORDER BY k;
+SELECT row_number() FROM numbers(3); -- {ErrorCode 5}
+SELECT number FROM numbers(3) WHERE row_number() OVER () > 1; -- {ErrorCode 5}
+SELECT sum(number) OVER (ORDER BY number RANGE BETWEEN 1 PRECEDING AND CURRENT ROW) FROM numbers(3); -- {ErrorCode 2}
@@ -0,0 +1,9 @@
+SELECT number FROM numbers(3) UNION ALL SELECT number FROM numbers(2) ORDER BY number;
+SELECT number % 3 AS k FROM numbers(10) UNION SELECT number FROM numbers(5) ORDER BY k;
+SELECT number FROM numbers(10) INTERSECT SELECT number * 2 FROM numbers(4) ORDER BY number;
+SELECT number FROM numbers(6) EXCEPT SELECT number FROM numbers(3) ORDER BY number;
+SELECT number, 'x' AS s FROM numbers(1) UNION ALL SELECT 10, 'y' ORDER BY number;
+SELECT count() FROM (SELECT number FROM numbers(3) UNION ALL SELECT number FROM numbers(4) UNION ALL SELECT 1);
+SELECT number FROM numbers(5) UNION ALL SELECT number FROM numbers(5) ORDER BY number DESC LIMIT 3;
+SELECT 1, 2 UNION ALL SELECT 1; -- {ErrorCode 6}
+SELECT 1 INTERSECT ALL SELECT 1; -- {ErrorCode 2}
@@ -0,0 +1,16 @@
+WITH t AS (SELECT number FROM numbers(5)) SELECT * FROM t ORDER BY number;
+WITH t(a, b) AS (SELECT number, number * 2 FROM numbers(3)) SELECT a, b FROM t ORDER BY a;
+WITH t1 AS (SELECT number FROM numbers(10)), t2 AS (SELECT number FROM t1 WHERE number > 6) SELECT * FROM t2 ORDER BY number;
+WITH t AS (SELECT number FROM numbers(4)) SELECT count(*) FROM t AS a, t AS b;
+WITH t AS (SELECT number FROM numbers(4)) SELECT a.number FROM t AS a JOIN t AS b ON a.number = b.number + 1 ORDER BY a.number;
+WITH t AS (SELECT number FROM numbers(5)) SELECT number FROM t WHERE number > 2 AND NOT EXISTS (SELECT number FROM t WHERE number > 10) ORDER BY number;
+WITH t AS (SELECT 1 AS a) SELECT * FROM (WITH t AS (SELECT 2 AS a) SELECT a FROM t);
+WITH t AS (SELECT number FROM numbers(3)) SELECT number FROM t UNION ALL SELECT number FROM t ORDER BY number;
+set enable_materialized_cte = 1;
+WITH t AS (SELECT number FROM numbers(4)) SELECT a.number FROM t AS a JOIN t AS b ON a.number = b.number + 1 ORDER BY a.number;
+WITH t AS (SELECT number FROM numbers(4)) SELECT count(*), sum(a.number) FROM t AS a, t AS b;
+WITH t AS (SELECT number FROM numbers(4)) SELECT number FROM t WHERE number IN (SELECT number + 2 FROM t) ORDER BY number;
+WITH a AS (SELECT 1 AS n), t AS (SELECT n FROM a) SELECT t1.n, s.n FROM t AS t1, t AS t2, (WITH a AS (SELECT 2 AS n), t AS (SELECT n FROM a) SELECT x.n FROM t AS x, t AS y) AS s;
+set enable_materialized_cte = 0;
+WITH t(a, b) AS (SELECT 1) SELECT * FROM t; -- {ErrorCode 5}
+WITH t AS (SELECT 1), t AS (SELECT 2) SELECT * FROM t; -- {ErrorCode 5}
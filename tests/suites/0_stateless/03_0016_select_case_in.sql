@@ -0,0 +1,11 @@
+SELECT number, CASE WHEN number < 3 THEN 'low' WHEN number < 6 THEN 'mid' ELSE 'high' END FROM numbers(8) ORDER BY number;
+SELECT number, CASE number % 3 WHEN 0 THEN 'zero' WHEN 1 THEN 'one' ELSE 'other' END AS c FROM numbers(4) ORDER BY number;
+SELECT number FROM numbers(10) WHERE number IN (1, 3, 5, 100) ORDER BY number;
+SELECT number FROM numbers(5) WHERE number NOT IN (1, 3) ORDER BY number;
+SELECT 2 IN (1, 2), 3 NOT IN (1, 2), 3 IN (1, 2);
+SELECT name FROM system.databases WHERE name IN ('system', 'not_exists');
+SELECT number FROM numbers(10) WHERE number IN (SELECT number * 3 FROM numbers(3)) ORDER BY number;
+SELECT number FROM numbers(6) WHERE number NOT IN (SELECT number FROM numbers(3)) ORDER BY number;
+SELECT 1 NOT IN (2, NULL), NULL NOT IN (1, 2), 1 IN (1, NULL), 3 IN (1, NULL);
+SELECT count(*) FROM numbers(5) WHERE number NOT IN (1, NULL);
+SELECT 1 IN (SELECT number, number FROM numbers(2)); -- {ErrorCode 6}
@@ -0,0 +1,18 @@
+DROP DATABASE IF EXISTS db1;
+CREATE DATABASE db1;
+USE db1;
+
+CREATE TABLE IF NOT EXISTS t(a Int32, b varchar) Engine = fuse;
+INSERT INTO t(a,b) VALUES(1, 'v1'),(2, 'v2');
+INSERT INTO t(a,b) VALUES(3, 'v3'),(4, 'v4');
+DELETE FROM t WHERE a > 10;
+SELECT * FROM t ORDER BY a;
+DELETE FROM t WHERE a = 2 OR a = 3;
+SELECT * FROM t ORDER BY a;
+DELETE FROM t;
+SELECT COUNT(*) FROM t;
+
+DROP TABLE t;
+DELETE FROM t; -- {ErrorCode 25}
+
+DROP DATABASE db1;
@@ -0,0 +1,19 @@
+DROP DATABASE IF EXISTS db1;
+CREATE DATABASE db1;
+USE db1;
+
+CREATE TABLE IF NOT EXISTS t(a Int32, b varchar) Engine = fuse;
+INSERT INTO t(a,b) VALUES(1, 'v1'),(2, 'v2');
+INSERT INTO t(a,b) VALUES(3, 'v3'),(4, 'v4');
+UPDATE t SET b = 'x' WHERE a > 10;
+SELECT * FROM t ORDER BY a;
+UPDATE t SET a = a * 10, b = 'u' WHERE a = 2 OR a = 3;
+SELECT * FROM t ORDER BY a;
+UPDATE t SET b = 'all';
+SELECT * FROM t ORDER BY a;
+UPDATE t SET c = 1; -- {ErrorCode 58}
+
+DROP TABLE t;
+UPDATE t SET a = 1; -- {ErrorCode 25}
+
+DROP DATABASE db1;
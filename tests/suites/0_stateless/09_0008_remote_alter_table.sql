@@ -0,0 +1,28 @@
+DROP DATABASE IF EXISTS db1;
+CREATE DATABASE db1;
+USE db1;
+
+CREATE TABLE IF NOT EXISTS t(a Int32, b varchar) Engine = fuse;
+INSERT INTO t(a,b) VALUES(1, 'v1'),(2, 'v2');
+ALTER TABLE t ADD COLUMN c Int64;
+INSERT INTO t(a,b,c) VALUES(3, 'v3', 30);
+SELECT * FROM t ORDER BY a;
+SELECT a FROM t WHERE c > 10;
+ALTER TABLE t RENAME COLUMN b TO d;
+SELECT a, d FROM t ORDER BY a;
+ALTER TABLE t DROP COLUMN c;
+SELECT * FROM t ORDER BY a;
+ALTER TABLE t ADD c Int64;
+SELECT a, c FROM t ORDER BY a;
+ALTER TABLE t ADD COLUMN a Int32; -- {ErrorCode 6}
+ALTER TABLE t ADD COLUMN e Int32 DEFAULT 1; -- {ErrorCode 2}
+ALTER TABLE t ADD COLUMN e Int32 NOT NULL; -- {ErrorCode 2}
+ALTER TABLE t ADD COLUMN e Int32 NULL;
+SELECT a, e FROM t ORDER BY a;
+ALTER TABLE t DROP COLUMN x; -- {ErrorCode 58}
+ALTER TABLE t RENAME COLUMN x TO y; -- {ErrorCode 58}
+
+DROP TABLE t;
+ALTER TABLE t ADD COLUMN e Int32; -- {ErrorCode 25}
+
+DROP DATABASE db1;
@@ -0,0 +1,14 @@
+DROP DATABASE IF EXISTS db1;
+CREATE DATABASE db1;
+USE db1;
+
+CREATE TABLE IF NOT EXISTS t(a Int32, b varchar) Engine = fuse;
+INSERT INTO t(a,b) VALUES(1, 'v1'),(2, 'v2');
+INSERT INTO t(a,b) VALUES(3, 'v3'),(4, 'v4');
+SELECT * FROM t AT (TIMESTAMP => '2100-01-01 00:00:00') ORDER BY a;
+SELECT count(*) FROM t AT (TIMESTAMP => '2100-01-01 00:00:00') AS x WHERE x.a > 1;
+SELECT * FROM t AT (TIMESTAMP => '2000-01-01 00:00:00'); -- {ErrorCode 5003}
+SELECT * FROM t AT (SNAPSHOT => 'not-a-snapshot-id'); -- {ErrorCode 6}
+
+DROP TABLE t;
+DROP DATABASE db1;
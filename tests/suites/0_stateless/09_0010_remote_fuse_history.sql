@@ -0,0 +1,19 @@
+DROP DATABASE IF EXISTS db_09_0010;
+CREATE DATABASE db_09_0010;
+USE db_09_0010;
+
+CREATE TABLE t(c int) Engine = fuse;
+SELECT count(*) FROM fuse_history('db_09_0010', 't');
+
+INSERT INTO t VALUES(1);
+INSERT INTO t VALUES(2), (3);
+
+SELECT count(*) FROM fuse_history('db_09_0010', 't');
+SELECT segment_count, block_count, row_count FROM fuse_history('db_09_0010', 't');
+SELECT count(*) FROM fuse_history('db_09_0010', 't') WHERE prev_snapshot_id IS NULL;
+
+-- only fuse tables are supported
+SELECT * FROM fuse_history('system', 'tables'); -- {ErrorCode 6}
+
+DROP TABLE t;
+DROP DATABASE db_09_0010;
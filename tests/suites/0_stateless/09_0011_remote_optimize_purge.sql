@@ -0,0 +1,23 @@
+DROP DATABASE IF EXISTS db_09_0011;
+CREATE DATABASE db_09_0011;
+USE db_09_0011;
+
+CREATE TABLE t(c int) Engine = fuse;
+INSERT INTO t VALUES(1);
+INSERT INTO t VALUES(2);
+INSERT INTO t VALUES(3);
+SELECT count(*) FROM fuse_history('db_09_0011', 't');
+
+OPTIMIZE TABLE t PURGE RETAIN 2 SNAPSHOTS;
+SELECT count(*) FROM fuse_history('db_09_0011', 't');
+SELECT sum(c) FROM t;
+
+OPTIMIZE TABLE t PURGE RETAIN 1 DAY;
+SELECT count(*) FROM fuse_history('db_09_0011', 't');
+
+OPTIMIZE TABLE t PURGE;
+SELECT count(*) FROM fuse_history('db_09_0011', 't');
+SELECT sum(c) FROM t;
+
+DROP TABLE t;
+DROP DATABASE db_09_0011;
@@ -0,0 +1,20 @@
+DROP DATABASE IF EXISTS db_09_0012;
+CREATE DATABASE db_09_0012;
+USE db_09_0012;
+
+CREATE TABLE t(c int) Engine = fuse;
+INSERT INTO t VALUES(1);
+INSERT INTO t VALUES(2);
+INSERT INTO t VALUES(3);
+SELECT segment_count, block_count FROM fuse_history('db_09_0012', 't') LIMIT 1;
+
+OPTIMIZE TABLE t COMPACT;
+SELECT segment_count, block_count FROM fuse_history('db_09_0012', 't') LIMIT 1;
+SELECT sum(c) FROM t;
+
+-- nothing to compact
+OPTIMIZE TABLE t COMPACT;
+SELECT count(*) FROM fuse_history('db_09_0012', 't');
+
+DROP TABLE t;
+DROP DATABASE db_09_0012;